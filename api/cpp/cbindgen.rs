@@ -118,6 +118,7 @@ fn builtin_structs(path: &Path) -> anyhow::Result<()> {
     writeln!(structs_priv, "#pragma once")?;
     writeln!(structs_priv, "// This file is auto-generated from {}", file!())?;
     writeln!(structs_priv, "#include \"slint_builtin_structs.h\"")?;
+    writeln!(structs_priv, "#include \"slint_point.h\"")?;
     writeln!(structs_priv, "#include \"slint_enums_internal.h\"")?;
     writeln!(structs_priv, "namespace slint::cbindgen_private {{")?;
     writeln!(structs_priv, "enum class KeyEventType : uint8_t;")?;
@@ -126,6 +127,10 @@ macro_rules! struct_file {
             writeln!(structs_priv, "using slint::StandardListViewItem;")?;
             &mut structs_pub
         }};
+        (TreeViewItem) => {{
+            writeln!(structs_priv, "using slint::TreeViewItem;")?;
+            &mut structs_pub
+        }};
         ($_:ident) => {
             &mut structs_priv
         };
@@ -154,6 +159,7 @@ struct $Name:ident {
                     let pub_type = match stringify!($pub_type) {
                         "i32" => "int32_t",
                         "f32" | "Coord" => "float",
+                        "LogicalPosition" => "slint::LogicalPosition",
                         other => other,
                     };
                     writeln!(file, "    {} {};", pub_type, stringify!($pub_field))?;
@@ -219,7 +225,9 @@ fn default_config() -> cbindgen::Config {
             ("KeyEventArg".into(), "KeyEvent".into()),
             ("PointerEventArg".into(), "PointerEvent".into()),
             ("PointerScrollEventArg".into(), "PointerScrollEvent".into()),
+            ("DropEventArg".into(), "DropEvent".into()),
             ("PointArg".into(), "slint::LogicalPosition".into()),
+            ("StringArg".into(), "SharedString".into()),
             ("FloatArg".into(), "float".into()),
             ("IntArg".into(), "int".into()),
             ("MenuEntryArg".into(), "MenuEntry".into()),
@@ -291,9 +299,13 @@ fn gen_corelib(
         "TouchArea",
         "FocusScope",
         "SwipeGestureHandler",
+        "PinchGestureHandler",
+        "DragArea",
+        "DropArea",
         "Flickable",
         "SimpleText",
         "ComplexText",
+        "StyledText",
         "Path",
         "WindowItem",
         "TextInput",
@@ -329,6 +341,7 @@ fn gen_corelib(
         "PointerEventButton",
         "PointerEvent",
         "PointerScrollEvent",
+        "DropEvent",
         "Rect",
         "SortOrder",
         "BitmapFont",
@@ -349,6 +362,7 @@ fn gen_corelib(
         "GraphicsAPI",
         "CloseRequestResponse",
         "StandardListViewItem",
+        "TreeViewItem",
         "Rgb8Pixel",
         "Rgba8Pixel",
     ];
@@ -381,7 +395,9 @@ fn gen_corelib(
         "KeyEventArg",
         "PointerEventArg",
         "PointerScrollEventArg",
+        "DropEventArg",
         "PointArg",
+        "StringArg",
         "Point",
         "MenuEntryModel",
         "MenuEntryArg",
@@ -454,7 +470,8 @@ fn gen_corelib(
         .write_to_file(include_dir.join("slint_sharedvector_internal.h"));
 
     let mut properties_config = config.clone();
-    properties_config.export.exclude.clear();
+    // Keyframe contains an EasingCurve, so it is defined in slint_properties.h
+    properties_config.export.exclude = vec!["Keyframe".into()];
     properties_config.structure.derive_eq = true;
     properties_config.structure.derive_neq = true;
     private_exported_types.extend(properties_config.export.include.iter().cloned());
@@ -463,7 +480,10 @@ fn gen_corelib(
         .with_src(crate_dir.join("properties.rs"))
         .with_src(crate_dir.join("properties/ffi.rs"))
         .with_src(crate_dir.join("callbacks.rs"))
-        .with_after_include("namespace slint { class Color; class Brush; }")
+        .with_after_include(
+            "namespace slint { class Color; class Brush; template<typename T> struct SharedVector; }
+namespace slint::cbindgen_private { template<typename T> struct Keyframe; }",
+        )
         .generate()
         .context("Unable to generate bindings for slint_properties_internal.h")?
         .write_to_file(include_dir.join("slint_properties_internal.h"));
@@ -721,7 +741,8 @@ fn gen_corelib(
     );
     config.export.body.insert(
         "EasingCurve".to_owned(),
-        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} {}".into()
+        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} {}
+    constexpr EasingCurve(Spring_Body x) : tag(Tag::Spring), spring{x} {}".into()
     );
     config.export.body.insert(
         "LayoutInfo".to_owned(),
@@ -14,6 +14,7 @@
 #include "slint_window.h"
 #include "slint_models.h"
 #include "slint_item_tree.h"
+#include "slint_date_time.h"
 
 #include <vector>
 #include <chrono>
@@ -110,6 +111,8 @@ inline bool operator==(const EasingCurve &a, const EasingCurve &b)
         return false;
     } else if (a.tag == EasingCurve::Tag::CubicBezier) {
         return std::equal(a.cubic_bezier._0, a.cubic_bezier._0 + 4, b.cubic_bezier._0);
+    } else if (a.tag == EasingCurve::Tag::Spring) {
+        return std::equal(a.spring._0, a.spring._0 + 3, b.spring._0);
     }
     return true;
 }
@@ -164,6 +167,42 @@ box_layout_info_ortho(cbindgen_private::Slice<cbindgen_private::BoxLayoutCellDat
     return cbindgen_private::slint_box_layout_info_ortho(cells, &padding);
 }
 
+inline SharedVector<float> solve_flexbox_layout(const cbindgen_private::FlexboxLayoutData &data,
+                                                cbindgen_private::Orientation orientation,
+                                                cbindgen_private::Slice<int> repeater_indexes)
+{
+    SharedVector<float> result;
+    cbindgen_private::Slice<uint32_t> ri { reinterpret_cast<uint32_t *>(repeater_indexes.ptr),
+                                           repeater_indexes.len };
+    cbindgen_private::slint_solve_flexbox_layout(&data, orientation, ri, &result);
+    return result;
+}
+
+inline cbindgen_private::LayoutInfo
+flexbox_layout_info(const cbindgen_private::FlexboxLayoutData &data,
+                    cbindgen_private::Orientation orientation)
+{
+    return cbindgen_private::slint_flexbox_layout_info(&data, orientation);
+}
+
+/// Returns the cell data of a repeated item tree in a FlexboxLayout: the item trees whose root
+/// sets a `flex-*` property have a `flexbox_layout_data` function, the others use the defaults.
+template<typename SubComponent>
+inline cbindgen_private::FlexboxLayoutCellData
+flexbox_layout_data(const SubComponent &sub_comp, cbindgen_private::Orientation o)
+{
+    if constexpr (requires { sub_comp->flexbox_layout_data(o); }) {
+        return sub_comp->flexbox_layout_data(o);
+    } else {
+        cbindgen_private::FlexboxLayoutCellData cell;
+        cell.constraint = sub_comp->box_layout_data(o).constraint;
+        cell.flex_grow = 0;
+        cell.flex_shrink = 1;
+        cell.flex_basis = -1;
+        return cell;
+    }
+}
+
 /// Access the layout cache of an item within a repeater
 inline float layout_cache_access(const SharedVector<float> &cache, int offset, int repeater_index)
 {
@@ -285,11 +285,11 @@ namespace private_api {
 
 template<>
 inline void Property<slint::Brush>::set_animated_value(
-        const slint::Brush &new_value,
-        const cbindgen_private::PropertyAnimation &animation_data) const
+        const slint::Brush &new_value, const cbindgen_private::PropertyAnimation &animation_data,
+        const SharedVector<Keyframe<slint::Brush>> &keyframes) const
 {
     cbindgen_private::slint_property_set_animated_value_brush(&inner, &value, &new_value,
-                                                              &animation_data);
+                                                              &animation_data, &keyframes);
 }
 
 } // namespace private_api
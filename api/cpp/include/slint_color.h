@@ -290,10 +290,11 @@ namespace private_api {
 template<>
 inline void
 Property<Color>::set_animated_value(const Color &new_value,
-                                    const cbindgen_private::PropertyAnimation &animation_data) const
+                                    const cbindgen_private::PropertyAnimation &animation_data,
+                                    const SharedVector<Keyframe<Color>> &keyframes) const
 {
     cbindgen_private::slint_property_set_animated_value_color(&inner, value, new_value,
-                                                              &animation_data);
+                                                              &animation_data, &keyframes);
 }
 
 } // namespace private_api
@@ -0,0 +1,216 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+#pragma once
+
+#include "slint_internal.h"
+
+#include <algorithm>
+#include <chrono>
+#include <limits>
+#include <optional>
+
+namespace slint {
+
+/// A DateTime is a point in time with a fixed offset from UTC. This is the type used for
+/// `date-time` properties in Slint.
+///
+/// It stores a timestamp with millisecond precision and the offset from UTC that is used to
+/// compute the calendar fields and to format the value.
+///
+/// Two values are only equal if they have the same timestamp and the same offset from UTC.
+class DateTime
+{
+public:
+    /// Default constructs a DateTime at the Unix epoch (1970-01-01 00:00:00 UTC).
+    DateTime() = default;
+
+    /// Creates a DateTime in UTC from the number of milliseconds since the Unix epoch.
+    [[nodiscard]] static DateTime from_timestamp_millis(int64_t timestamp)
+    {
+        DateTime result;
+        result.inner.timestamp = timestamp;
+        return result;
+    }
+
+    /// Creates a DateTime in UTC from a time point of the system clock.
+    [[nodiscard]] static DateTime from_time_point(std::chrono::system_clock::time_point time_point)
+    {
+        return from_timestamp_millis(std::chrono::duration_cast<std::chrono::milliseconds>(
+                                             time_point.time_since_epoch())
+                                             .count());
+    }
+
+    /// Returns the current date and time, with the offset of the local time zone.
+    [[nodiscard]] static DateTime now()
+    {
+        DateTime result;
+        cbindgen_private::slint_date_time_now(&result.inner);
+        return result;
+    }
+
+    /// Parses \a text with a strftime-like \a format string. If the format has no offset
+    /// from UTC (`%z`), the time is in the local time zone.
+    /// Returns an empty optional if the text doesn't match the format.
+    [[nodiscard]] static std::optional<DateTime> parse(const SharedString &text,
+                                                       const SharedString &format)
+    {
+        DateTime result;
+        if (cbindgen_private::slint_date_time_parse(&text, &format, &result.inner)) {
+            return result;
+        }
+        return std::nullopt;
+    }
+
+    /// Formats the date and time with a strftime-like \a format string.
+    /// Returns an empty string if the format is not valid.
+    [[nodiscard]] SharedString format(const SharedString &format) const
+    {
+        SharedString result;
+        cbindgen_private::slint_date_time_format(&inner, &format, &result);
+        return result;
+    }
+
+    /// Returns the number of milliseconds since the Unix epoch.
+    [[nodiscard]] int64_t timestamp_millis() const { return inner.timestamp; }
+
+    /// Returns this point in time as a time point of the system clock.
+    [[nodiscard]] std::chrono::system_clock::time_point to_time_point() const
+    {
+        return std::chrono::system_clock::time_point(std::chrono::duration_cast<
+                                                     std::chrono::system_clock::duration>(
+                std::chrono::milliseconds(inner.timestamp)));
+    }
+
+    /// Returns the offset from UTC. It's positive east of Greenwich.
+    [[nodiscard]] std::chrono::seconds utc_offset() const
+    {
+        return std::chrono::seconds(inner.utc_offset);
+    }
+
+    /// Returns the same point in time with another offset from UTC. The offset is clamped to
+    /// less than a day.
+    [[nodiscard]] DateTime with_utc_offset(std::chrono::seconds utc_offset) const
+    {
+        DateTime result = *this;
+        result.inner.utc_offset =
+                int32_t(std::clamp<int64_t>(utc_offset.count(), -max_utc_offset, max_utc_offset));
+        return result;
+    }
+
+    /// Returns the same point in time with the offset of the local time zone.
+    [[nodiscard]] DateTime to_local() const
+    {
+        DateTime result;
+        cbindgen_private::slint_date_time_to_local(&inner, &result.inner);
+        return result;
+    }
+
+    /// Returns the year.
+    [[nodiscard]] int year() const { return field(0); }
+    /// Returns the month, starting from 1.
+    [[nodiscard]] int month() const { return field(1); }
+    /// Returns the day of the month, starting from 1.
+    [[nodiscard]] int day() const { return field(2); }
+    /// Returns the hour, from 0 to 23.
+    [[nodiscard]] int hour() const { return field(3); }
+    /// Returns the minute, from 0 to 59.
+    [[nodiscard]] int minute() const { return field(4); }
+    /// Returns the second, from 0 to 59.
+    [[nodiscard]] int second() const { return field(5); }
+    /// Returns the day of the week, from 1 for Monday to 7 for Sunday.
+    [[nodiscard]] int weekday() const { return field(6); }
+
+    /// Returns the same time of the day at another date, or an empty optional if the date
+    /// doesn't exist.
+    [[nodiscard]] std::optional<DateTime> with_date(int year, int month, int day) const
+    {
+        DateTime result;
+        if (month >= 0 && day >= 0
+            && cbindgen_private::slint_date_time_with_date(&inner, year, uint32_t(month),
+                                                           uint32_t(day), &result.inner)) {
+            return result;
+        }
+        return std::nullopt;
+    }
+
+    /// Returns the same date at another time of the day, or an empty optional if the time
+    /// doesn't exist.
+    [[nodiscard]] std::optional<DateTime> with_time(int hour, int minute, int second) const
+    {
+        DateTime result;
+        if (hour >= 0 && minute >= 0 && second >= 0
+            && cbindgen_private::slint_date_time_with_time(&inner, uint32_t(hour),
+                                                           uint32_t(minute), uint32_t(second),
+                                                           &result.inner)) {
+            return result;
+        }
+        return std::nullopt;
+    }
+
+    /// Returns the point in time that is \a duration later.
+    friend DateTime operator+(const DateTime &date_time, std::chrono::milliseconds duration)
+    {
+        DateTime result = date_time;
+        result.inner.timestamp = saturating_add(date_time.inner.timestamp, duration.count());
+        return result;
+    }
+
+    /// Returns the point in time that is \a duration earlier.
+    friend DateTime operator-(const DateTime &date_time, std::chrono::milliseconds duration)
+    {
+        DateTime result = date_time;
+        result.inner.timestamp = saturating_sub(date_time.inner.timestamp, duration.count());
+        return result;
+    }
+
+    /// Returns the time elapsed from \a rhs to \a lhs.
+    friend std::chrono::milliseconds operator-(const DateTime &lhs, const DateTime &rhs)
+    {
+        return std::chrono::milliseconds(saturating_sub(lhs.inner.timestamp, rhs.inner.timestamp));
+    }
+
+    /// Returns true if \a lhs and \a rhs have the same timestamp and offset from UTC.
+    friend bool operator==(const DateTime &lhs, const DateTime &rhs)
+    {
+        return lhs.inner.timestamp == rhs.inner.timestamp
+                && lhs.inner.utc_offset == rhs.inner.utc_offset;
+    }
+
+private:
+    static constexpr int64_t max_utc_offset = 86399;
+
+    // Like in Rust, the arithmetic saturates instead of overflowing
+    static int64_t saturating_add(int64_t a, int64_t b)
+    {
+        if (b > 0 && a > std::numeric_limits<int64_t>::max() - b) {
+            return std::numeric_limits<int64_t>::max();
+        }
+        if (b < 0 && a < std::numeric_limits<int64_t>::min() - b) {
+            return std::numeric_limits<int64_t>::min();
+        }
+        return a + b;
+    }
+
+    static int64_t saturating_sub(int64_t a, int64_t b)
+    {
+        if (b < 0 && a > std::numeric_limits<int64_t>::max() + b) {
+            return std::numeric_limits<int64_t>::max();
+        }
+        if (b > 0 && a < std::numeric_limits<int64_t>::min() + b) {
+            return std::numeric_limits<int64_t>::min();
+        }
+        return a - b;
+    }
+
+    int field(int index) const
+    {
+        int32_t fields[7] = {};
+        cbindgen_private::slint_date_time_fields(&inner, &fields);
+        return fields[index];
+    }
+
+    cbindgen_private::DateTime inner = {};
+};
+
+}
@@ -6,7 +6,9 @@
 #include "slint_item_tree.h"
 
 #include <algorithm>
+#include <cmath>
 #include <functional>
+#include <map>
 #include <memory>
 #include <optional>
 
@@ -331,6 +333,83 @@ public:
     }
 };
 
+namespace private_api {
+
+template<typename V>
+V access_map_value(const std::map<SharedString, V> &map, const SharedString &key)
+{
+    if (auto it = map.find(key); it != map.end()) {
+        return it->second;
+    } else {
+        return V {};
+    }
+}
+
+template<typename V>
+std::shared_ptr<slint::Model<SharedString>> map_keys(const std::map<SharedString, V> &map)
+{
+    std::vector<SharedString> keys;
+    keys.reserve(map.size());
+    for (const auto &[key, _] : map) {
+        keys.push_back(key);
+    }
+    return std::make_shared<slint::VectorModel<SharedString>>(std::move(keys));
+}
+
+template<typename M>
+auto array_slice(const std::shared_ptr<M> &model, int start, int end)
+{
+    using T = std::decay_t<decltype(*model->row_data(0))>;
+    std::vector<T> result;
+    end = std::clamp(end, 0, int(model_length(model)));
+    for (int i = std::clamp(start, 0, end); i < end; ++i) {
+        result.push_back(access_array_index(model, i));
+    }
+    return std::shared_ptr<slint::Model<T>>(
+            std::make_shared<slint::VectorModel<T>>(std::move(result)));
+}
+
+template<typename M1, typename M2>
+auto array_concat(const std::shared_ptr<M1> &first, const std::shared_ptr<M2> &second)
+{
+    using T = std::decay_t<decltype(*first->row_data(0))>;
+    std::vector<T> result;
+    for (long int i = 0, count = model_length(first); i < count; ++i) {
+        result.push_back(access_array_index(first, i));
+    }
+    for (long int i = 0, count = model_length(second); i < count; ++i) {
+        result.push_back(access_array_index(second, i));
+    }
+    return std::shared_ptr<slint::Model<T>>(
+            std::make_shared<slint::VectorModel<T>>(std::move(result)));
+}
+
+template<typename M, typename V>
+int array_index_of(const std::shared_ptr<M> &model, const V &value)
+{
+    for (long int i = 0, count = model_length(model); i < count; ++i) {
+        if (access_array_index(model, i) == value) {
+            return int(i);
+        }
+    }
+    return -1;
+}
+
+/// Converts the `{at: percent, easing: easing, value: T}` keyframes of an `animate` block
+template<typename M>
+auto to_keyframes(const std::shared_ptr<M> &model)
+{
+    using T = std::tuple_element_t<2, std::decay_t<decltype(*model->row_data(0))>>;
+    SharedVector<cbindgen_private::Keyframe<T>> result;
+    for (long int i = 0, count = model_length(model); i < count; ++i) {
+        auto [at, easing, value] = access_array_index(model, i);
+        result.push_back({ at / 100, value, easing });
+    }
+    return result;
+}
+
+} // namespace private_api
+
 template<typename ModelData>
 class FilterModel;
 
@@ -724,11 +803,12 @@ struct SortModelInner : private_api::ModelChangeListener
         for (size_t i = 0; i < sorted_rows.size(); ++i)
             sorted_rows[i] = i;
 
-        std::sort(sorted_rows.begin(), sorted_rows.end(), [this](auto lhs_index, auto rhs_index) {
-            auto lhs_elem = source_model->row_data(lhs_index);
-            auto rhs_elem = source_model->row_data(rhs_index);
-            return rhs_elem && lhs_elem && comp(*lhs_elem, *rhs_elem);
-        });
+        std::stable_sort(sorted_rows.begin(), sorted_rows.end(),
+                         [this](auto lhs_index, auto rhs_index) {
+                             auto lhs_elem = source_model->row_data(lhs_index);
+                             auto rhs_elem = source_model->row_data(rhs_index);
+                             return rhs_elem && lhs_elem && comp(*lhs_elem, *rhs_elem);
+                         });
 
         sorted_rows_dirty = false;
     }
@@ -885,6 +965,62 @@ private:
 
 namespace private_api {
 
+/// Starts the exit transition of an instance that was removed, and keeps it in `leaving`
+/// until the transition is over.
+template<typename C>
+void start_exit_transition(const ComponentHandle<C> &instance,
+                           std::vector<std::pair<uint64_t, ComponentHandle<C>>> &leaving)
+{
+    if constexpr (requires(const C &c) { c.start_exit_transition(); }) {
+        if (auto duration = instance->start_exit_transition(); duration > 0) {
+            leaving.emplace_back(cbindgen_private::slint_animation_tick() + uint64_t(duration),
+                                 instance);
+        }
+    }
+}
+
+/// Drops the instances whose exit transition is over. While some instances are still leaving,
+/// this requests another animation frame so that it is called again.
+template<typename C>
+void drop_finished_exit_transitions(std::vector<std::pair<uint64_t, ComponentHandle<C>>> &leaving)
+{
+    if (leaving.empty()) {
+        return;
+    }
+    auto now = cbindgen_private::slint_animation_tick();
+    // Move the finished instances out first, as dropping them may re-enter
+    std::vector<std::pair<uint64_t, ComponentHandle<C>>> finished;
+    std::erase_if(leaving, [&](auto &l) {
+        if (l.first > now) {
+            return false;
+        }
+        finished.push_back(std::move(l));
+        return true;
+    });
+}
+
+/// Visits the instances that are playing their exit transition. They are only rendered, behind
+/// the other instances. As they are skipped when visiting from front to back, they no longer
+/// receive input events.
+template<typename C>
+uint64_t visit_leaving_instances(const std::vector<std::pair<uint64_t, ComponentHandle<C>>> &leaving,
+                                 TraversalOrder order, private_api::ItemVisitorRefMut visitor,
+                                 std::size_t first_index)
+{
+    if (order == TraversalOrder::FrontToBack) {
+        return std::numeric_limits<uint64_t>::max();
+    }
+    for (std::size_t i = 0; i < leaving.size(); ++i) {
+        vtable::VRef<private_api::ItemTreeVTable> ref { &C::static_vtable,
+                                                        const_cast<C *>(&(*leaving[i].second)) };
+        if (ref.vtable->visit_children_item(ref, -1, order, visitor)
+            != std::numeric_limits<uint64_t>::max()) {
+            return first_index + i;
+        }
+    }
+    return std::numeric_limits<uint64_t>::max();
+}
+
 template<typename C, typename ModelData>
 class Repeater
 {
@@ -897,12 +1033,26 @@ class Repeater
             std::optional<ComponentHandle<C>> ptr;
         };
         std::vector<RepeatedInstanceWithState> data;
+        /// The model index of the first instance in `data`. Only a GridView instantiates a
+        /// window of the rows, for the other repeaters this is always 0.
+        std::size_t offset = 0;
+        /// The instances whose row was removed, kept alive until their exit transition ends
+        std::vector<std::pair<uint64_t, ComponentHandle<C>>> leaving;
         private_api::Property<bool> is_dirty { true };
         std::shared_ptr<Model<ModelData>> model;
 
         void row_added(size_t index, size_t count) override
         {
-            if (index > data.size()) {
+            if (index < offset) {
+                if (index + count < offset) {
+                    return;
+                }
+                count -= offset - index;
+                index = 0;
+            } else {
+                index -= offset;
+            }
+            if (count == 0 || index > data.size()) {
                 // Can happen before ensure_updated was called
                 return;
             }
@@ -916,10 +1066,10 @@ class Repeater
         }
         void row_changed(size_t index) override
         {
-            if (index >= data.size()) {
+            if (index < offset || index - offset >= data.size()) {
                 return;
             }
-            auto &c = data[index];
+            auto &c = data[index - offset];
             if (model && c.ptr) {
                 if (auto data = model->row_data(index)) {
                     (*c.ptr)->update_data(index, *data);
@@ -931,11 +1081,26 @@ class Repeater
         }
         void row_removed(size_t index, size_t count) override
         {
-            if (index + count > data.size()) {
+            if (index < offset) {
+                if (index + count < offset) {
+                    return;
+                }
+                count -= offset - index;
+                index = 0;
+            } else {
+                index -= offset;
+            }
+            if (count == 0 || index >= data.size()) {
                 // Can happen before ensure_updated was called
                 return;
             }
+            count = std::min(count, data.size() - index);
             is_dirty.set(true);
+            for (std::size_t i = index; i < index + count; ++i) {
+                if (data[i].ptr) {
+                    start_exit_transition(*data[i].ptr, leaving);
+                }
+            }
             data.erase(data.begin() + index, data.begin() + index + count);
             for (std::size_t i = index; i < data.size(); ++i) {
                 // all the indexes are dirty
@@ -958,15 +1123,8 @@ class Repeater
         return { &C::static_vtable, const_cast<C *>(&(**x.ptr)) };
     }
 
-public:
-    template<typename F>
-    void set_model_binding(F &&binding) const
-    {
-        model.set_binding(std::forward<F>(binding));
-    }
-
-    template<typename Parent>
-    void ensure_updated(const Parent *parent) const
+    /// Re-creates the inner state, listening to the new model, when the model changed
+    void update_model() const
     {
         if (model.is_dirty()) {
             auto old_model = model.get_internal();
@@ -979,27 +1137,41 @@ public:
                 }
             }
         }
+    }
+
+public:
+    template<typename F>
+    void set_model_binding(F &&binding) const
+    {
+        model.set_binding(std::forward<F>(binding));
+    }
+
+    template<typename Parent>
+    void ensure_updated(const Parent *parent) const
+    {
+        update_model();
 
         if (inner && inner->is_dirty.get()) {
             inner->is_dirty.set(false);
             if (auto m = model.get()) {
                 auto count = m->row_count();
                 inner->data.resize(count);
+                std::vector<size_t> indices_to_init;
                 for (size_t i = 0; i < count; ++i) {
                     auto &c = inner->data[i];
-                    bool created = false;
                     if (!c.ptr) {
                         c.ptr = C::create(parent);
-                        created = true;
+                        indices_to_init.push_back(i);
                     }
                     if (c.state == RepeaterInner::State::Dirty) {
                         if (auto data = m->row_data(i)) {
                             (*c.ptr)->update_data(i, *data);
                         }
                     }
-                    if (created) {
-                        (*c.ptr)->init();
-                    }
+                }
+                // Initialize once all the instances exist, as the init code may query the layout
+                for (auto i : indices_to_init) {
+                    (*inner->data[i].ptr)->init();
                 }
             } else {
                 inner->data.clear();
@@ -1009,6 +1181,9 @@ public:
             // layout property tracker becomes dirty.
             model.get();
         }
+        if (inner) {
+            drop_finished_exit_transitions(inner->leaving);
+        }
     }
 
     template<typename Parent>
@@ -1025,8 +1200,91 @@ public:
         viewport_height->set(h);
     }
 
+    template<typename Parent>
+    void ensure_updated_gridview(const Parent *parent,
+                                 const private_api::Property<float> *viewport_height,
+                                 float viewport_y, float gridview_height, int columns,
+                                 float cell_height, float spacing) const
+    {
+        update_model();
+        if (!inner) {
+            viewport_height->set(0);
+            return;
+        }
+        // Query is_dirty to track model changes
+        inner->is_dirty.get();
+        inner->is_dirty.set(false);
+        drop_finished_exit_transitions(inner->leaving);
+
+        auto m = model.get();
+        std::size_t row_count = m ? m->row_count() : 0;
+        auto column_count = std::size_t(std::max(columns, 1));
+        auto grid_rows = (row_count + column_count - 1) / column_count;
+        float row_step = cell_height + spacing;
+        viewport_height->set(std::max(0.f, row_step * grid_rows - spacing));
+
+        std::size_t first_row = 0, end_row = grid_rows;
+        if (row_step > 0) {
+            first_row = std::min(std::size_t(std::max(0.f, std::floor(-viewport_y / row_step))),
+                                 grid_rows);
+            end_row = std::min(
+                    std::size_t(std::max(0.f, std::ceil((gridview_height - viewport_y) / row_step))),
+                    grid_rows);
+        }
+        auto new_offset = first_row * column_count;
+        auto new_end = std::max(std::min(end_row * column_count, row_count), new_offset);
+
+        auto old_offset = inner->offset;
+        auto old_data = std::move(inner->data);
+        inner->data.clear();
+        // The instances of the cells that are no longer visible, which can be recycled
+        std::vector<ComponentHandle<C>> recycled;
+        for (std::size_t i = 0; i < old_data.size(); ++i) {
+            auto idx = old_offset + i;
+            if ((idx < new_offset || idx >= new_end) && old_data[i].ptr) {
+                recycled.push_back(std::move(*old_data[i].ptr));
+                old_data[i].ptr.reset();
+            }
+        }
+
+        std::vector<size_t> indices_to_init;
+        inner->data.reserve(new_end - new_offset);
+        for (auto idx = new_offset; idx < new_end; ++idx) {
+            typename RepeaterInner::RepeatedInstanceWithState c;
+            if (idx >= old_offset && idx - old_offset < old_data.size()
+                && old_data[idx - old_offset].ptr) {
+                c = std::move(old_data[idx - old_offset]);
+            } else if (!recycled.empty()) {
+                c.ptr = std::move(recycled.back());
+                recycled.pop_back();
+            } else {
+                indices_to_init.push_back(inner->data.size());
+                c.ptr = C::create(parent);
+            }
+            if (c.state == RepeaterInner::State::Dirty) {
+                if (auto data = m->row_data(idx)) {
+                    (*c.ptr)->update_data(idx, *data);
+                }
+                c.state = RepeaterInner::State::Clean;
+            }
+            inner->data.push_back(std::move(c));
+        }
+        inner->offset = new_offset;
+        old_data.clear();
+        recycled.clear();
+
+        // Initialize once all the instances exist, as the init code may query the layout
+        for (auto i : indices_to_init) {
+            (*inner->data[i].ptr)->init();
+        }
+    }
+
     uint64_t visit(TraversalOrder order, private_api::ItemVisitorRefMut visitor) const
     {
+        if (auto r = visit_leaving_instances(inner->leaving, order, visitor, inner->data.size());
+            r != std::numeric_limits<uint64_t>::max()) {
+            return r;
+        }
         for (std::size_t i = 0; i < inner->data.size(); ++i) {
             auto index = order == TraversalOrder::BackToFront ? i : inner->data.size() - 1 - i;
             auto ref = item_at(index);
@@ -1040,16 +1298,16 @@ public:
 
     vtable::VWeak<private_api::ItemTreeVTable> instance_at(std::size_t i) const
     {
-        if (i >= inner->data.size()) {
+        if (i < inner->offset || i - inner->offset >= inner->data.size()) {
             return {};
         }
-        const auto &x = inner->data.at(i);
+        const auto &x = inner->data.at(i - inner->offset);
         return vtable::VWeak<private_api::ItemTreeVTable> { x.ptr->into_dyn() };
     }
 
     private_api::IndexRange index_range() const
     {
-        return private_api::IndexRange { 0, inner->data.size() };
+        return private_api::IndexRange { inner->offset, inner->offset + inner->data.size() };
     }
 
     std::size_t len() const { return inner ? inner->data.size() : 0; }
@@ -1095,6 +1353,8 @@ class Conditional
 {
     private_api::Property<bool> model;
     mutable std::optional<ComponentHandle<C>> instance;
+    /// The instances that were removed, kept alive until their exit transition ends
+    mutable std::vector<std::pair<uint64_t, ComponentHandle<C>>> leaving;
 
 public:
     template<typename F>
@@ -1107,15 +1367,23 @@ public:
     void ensure_updated(const Parent *parent) const
     {
         if (!model.get()) {
+            if (instance) {
+                start_exit_transition(*instance, leaving);
+            }
             instance = std::nullopt;
         } else if (!instance) {
             instance = C::create(parent);
             (*instance)->init();
         }
+        drop_finished_exit_transitions(leaving);
     }
 
     uint64_t visit(TraversalOrder order, private_api::ItemVisitorRefMut visitor) const
     {
+        if (auto r = visit_leaving_instances(leaving, order, visitor, 1);
+            r != std::numeric_limits<uint64_t>::max()) {
+            return r;
+        }
         if (instance) {
             vtable::VRef<private_api::ItemTreeVTable> ref { &C::static_vtable,
                                                             const_cast<C *>(&(**instance)) };
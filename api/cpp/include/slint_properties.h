@@ -13,51 +13,74 @@ struct ChangeTracker
 };
 }
 
+#include "slint_sharedvector.h"
 #include "slint_properties_internal.h"
 #include "slint_builtin_structs_internal.h"
 
+namespace slint::cbindgen_private {
+/// A stop of a keyframe animation, see Keyframe in properties_animations.rs
+template<typename T>
+struct Keyframe
+{
+    float position;
+    T value;
+    EasingCurve easing;
+
+    friend bool operator==(const Keyframe &, const Keyframe &) = default;
+};
+}
+
 namespace slint::private_api {
 
+using cbindgen_private::Keyframe;
 using cbindgen_private::StateInfo;
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, int *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        const SharedVector<Keyframe<int32_t>> *keyframes,
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *,
+                                                               SharedVector<Keyframe<int32_t>> *))
 {
     cbindgen_private::slint_property_set_animated_binding_int(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, keyframes, transition_data);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, float *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        const SharedVector<Keyframe<float>> *keyframes,
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *,
+                                                               SharedVector<Keyframe<float>> *))
 {
     cbindgen_private::slint_property_set_animated_binding_float(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, keyframes, transition_data);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, Color *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        const SharedVector<Keyframe<Color>> *keyframes,
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *,
+                                                               SharedVector<Keyframe<Color>> *))
 {
     cbindgen_private::slint_property_set_animated_binding_color(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, keyframes, transition_data);
 }
 
 inline void slint_property_set_animated_binding_helper(
         const cbindgen_private::PropertyHandleOpaque *handle, void (*binding)(void *, Brush *),
         void *user_data, void (*drop_user_data)(void *),
         const cbindgen_private::PropertyAnimation *animation_data,
-        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *))
+        const SharedVector<Keyframe<Brush>> *keyframes,
+        cbindgen_private::PropertyAnimation (*transition_data)(void *, uint64_t *,
+                                                               SharedVector<Keyframe<Brush>> *))
 {
     cbindgen_private::slint_property_set_animated_binding_brush(
-            handle, binding, user_data, drop_user_data, animation_data, transition_data);
+            handle, binding, user_data, drop_user_data, animation_data, keyframes, transition_data);
 }
 
 template<typename T>
@@ -105,10 +128,12 @@ struct Property
     }
 
     inline void set_animated_value(const T &value,
-                                   const cbindgen_private::PropertyAnimation &animation_data) const;
+                                   const cbindgen_private::PropertyAnimation &animation_data,
+                                   const SharedVector<Keyframe<T>> &keyframes = {}) const;
     template<typename F>
-    inline void
-    set_animated_binding(F binding, const cbindgen_private::PropertyAnimation &animation_data) const
+    inline void set_animated_binding(F binding,
+                                     const cbindgen_private::PropertyAnimation &animation_data,
+                                     const SharedVector<Keyframe<T>> &keyframes = {}) const
     {
         private_api::slint_property_set_animated_binding_helper(
                 &inner,
@@ -116,7 +141,7 @@ struct Property
                     *reinterpret_cast<T *>(value) = (*reinterpret_cast<F *>(user_data))();
                 },
                 new F(binding), [](void *user_data) { delete reinterpret_cast<F *>(user_data); },
-                &animation_data, nullptr);
+                &animation_data, &keyframes, nullptr);
     }
 
     template<typename F, typename Trans>
@@ -127,6 +152,7 @@ struct Property
             F binding;
             Trans animation;
         };
+        SharedVector<Keyframe<T>> no_keyframes;
         private_api::slint_property_set_animated_binding_helper(
                 &inner,
                 [](void *user_data, T *value) {
@@ -135,8 +161,9 @@ struct Property
                 },
                 new UserData { binding, animation },
                 [](void *user_data) { delete reinterpret_cast<UserData *>(user_data); }, nullptr,
-                [](void *user_data, uint64_t *instant) {
-                    return reinterpret_cast<UserData *>(user_data)->animation(instant);
+                &no_keyframes,
+                [](void *user_data, uint64_t *instant, SharedVector<Keyframe<T>> *keyframes) {
+                    return reinterpret_cast<UserData *>(user_data)->animation(instant, keyframes);
                 });
     }
 
@@ -197,19 +224,21 @@ private:
 
 template<>
 inline void Property<int32_t>::set_animated_value(
-        const int32_t &new_value, const cbindgen_private::PropertyAnimation &animation_data) const
+        const int32_t &new_value, const cbindgen_private::PropertyAnimation &animation_data,
+        const SharedVector<Keyframe<int32_t>> &keyframes) const
 {
     cbindgen_private::slint_property_set_animated_value_int(&inner, value, new_value,
-                                                            &animation_data);
+                                                            &animation_data, &keyframes);
 }
 
 template<>
 inline void
 Property<float>::set_animated_value(const float &new_value,
-                                    const cbindgen_private::PropertyAnimation &animation_data) const
+                                    const cbindgen_private::PropertyAnimation &animation_data,
+                                    const SharedVector<Keyframe<float>> &keyframes) const
 {
     cbindgen_private::slint_property_set_animated_value_float(&inner, value, new_value,
-                                                              &animation_data);
+                                                              &animation_data, &keyframes);
 }
 
 template<typename F>
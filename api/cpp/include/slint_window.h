@@ -571,6 +571,80 @@ public:
         inner.dispatch_pointer_event(event);
     }
 
+    /// Dispatches an event for a touch point that started touching the surface.
+    ///
+    /// Use this function when you're implementing your own backend and want to forward touch
+    /// events.
+    ///
+    /// \a id identifies the touch point and must be unique among the active touch points. The
+    /// first touch point that starts while no other one is active acts like the left mouse
+    /// button.
+    /// \a pos represents the logical position of the touch point relative to the window.
+    void dispatch_touch_started_event(uint64_t id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_started = WindowEvent::TouchStarted_Body {
+                                    .tag = WindowEvent::Tag::TouchStarted,
+                                    .id = id,
+                                    .position = { pos.x, pos.y } } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches an event for an active touch point that moved.
+    ///
+    /// Use this function when you're implementing your own backend and want to forward touch
+    /// events.
+    ///
+    /// \a id identifies the touch point, as passed to dispatch_touch_started_event().
+    /// \a pos represents the logical position of the touch point relative to the window.
+    void dispatch_touch_moved_event(uint64_t id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_moved = WindowEvent::TouchMoved_Body {
+                                    .tag = WindowEvent::Tag::TouchMoved,
+                                    .id = id,
+                                    .position = { pos.x, pos.y } } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches an event for a touch point that was lifted from the surface.
+    ///
+    /// Use this function when you're implementing your own backend and want to forward touch
+    /// events.
+    ///
+    /// \a id identifies the touch point, as passed to dispatch_touch_started_event().
+    /// \a pos represents the logical position of the touch point relative to the window.
+    void dispatch_touch_ended_event(uint64_t id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_ended = WindowEvent::TouchEnded_Body {
+                                    .tag = WindowEvent::Tag::TouchEnded,
+                                    .id = id,
+                                    .position = { pos.x, pos.y } } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches an event for a touch point whose tracking was cancelled by the system.
+    ///
+    /// Use this function when you're implementing your own backend and want to forward touch
+    /// events.
+    ///
+    /// \a id identifies the touch point, as passed to dispatch_touch_started_event().
+    /// \a pos represents the logical position of the touch point relative to the window.
+    void dispatch_touch_cancelled_event(uint64_t id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_cancelled = WindowEvent::TouchCancelled_Body {
+                                    .tag = WindowEvent::Tag::TouchCancelled,
+                                    .id = id,
+                                    .position = { pos.x, pos.y } } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
     /// Set the logical size of this window after a resize event
     ///
     /// The backend must send this event to ensure that the `width` and `height` property of the
@@ -10,9 +10,9 @@
 
 use alloc::rc::Rc;
 use core::ffi::c_void;
-use i_slint_core::items::OperatingSystemType;
+use i_slint_core::items::{NumberFormatStyle, OperatingSystemType};
 use i_slint_core::window::{ffi::WindowAdapterRcOpaque, WindowAdapter};
-use i_slint_core::SharedString;
+use i_slint_core::{SharedString, SharedVector};
 
 pub mod platform;
 
@@ -156,6 +156,80 @@ pub extern "C" fn slint_string_character_count(string: &SharedString) -> usize {
     unicode_segmentation::UnicodeSegmentation::graphemes(string.as_str(), true).count()
 }
 
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_string_trim(string: &SharedString, out: &mut SharedString) {
+    *out = string.trim().into();
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_string_contains(string: &SharedString, sub: &SharedString) -> bool {
+    string.contains(sub.as_str())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_string_starts_with(string: &SharedString, prefix: &SharedString) -> bool {
+    string.starts_with(prefix.as_str())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_string_ends_with(string: &SharedString, suffix: &SharedString) -> bool {
+    string.ends_with(suffix.as_str())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_string_replace(
+    string: &SharedString,
+    from: &SharedString,
+    to: &SharedString,
+    out: &mut SharedString,
+) {
+    *out = i_slint_core::string::shared_string_replace(string, from, to);
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_string_substring(
+    string: &SharedString,
+    start: i32,
+    length: i32,
+    out: &mut SharedString,
+) {
+    *out = i_slint_core::string::shared_string_substring(string, start, length);
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_string_split(
+    string: &SharedString,
+    separator: &SharedString,
+    out: &mut SharedVector<SharedString>,
+) {
+    *out = i_slint_core::string::shared_string_split(string, separator);
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_string_matches(string: &SharedString, pattern: &SharedString) -> bool {
+    i_slint_core::string::shared_string_matches(string, pattern)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn slint_format_number(
+    value: f64,
+    style: NumberFormatStyle,
+    min_fraction: i32,
+    max_fraction: i32,
+    grouping: bool,
+    currency: &SharedString,
+    out: &mut SharedString,
+) {
+    *out = i_slint_core::translations::format_number(
+        value,
+        style,
+        min_fraction,
+        max_fraction,
+        grouping,
+        currency,
+    );
+}
+
 #[unsafe(no_mangle)]
 pub extern "C" fn slint_string_to_usize(string: &SharedString, value: &mut usize) -> bool {
     match string.as_str().parse::<usize>() {
@@ -10,7 +10,7 @@
 use i_slint_core::model::{ModelRc, SharedVectorModel};
 use i_slint_core::{Brush, Color, SharedVector};
 use napi::bindgen_prelude::*;
-use napi::{Env, JsBoolean, JsNumber, JsObject, JsString, JsUnknown, Result};
+use napi::{Env, JsBoolean, JsDate, JsNumber, JsObject, JsString, JsUnknown, Result};
 use napi_derive::napi;
 use slint_interpreter::Value;
 use smol_str::SmolStr;
@@ -68,6 +68,13 @@ pub fn to_js_unknown(env: &Env, value: &Value) -> Result<JsUnknown> {
             }
             Ok(o.into_unknown())
         }
+        Value::Map(map) => {
+            let mut o = env.create_object()?;
+            for (key, value) in map.iter() {
+                o.set_property(env.create_string(key)?, to_js_unknown(env, value)?)?;
+            }
+            Ok(o.into_unknown())
+        }
         Value::Brush(brush) => {
             Ok(SlintBrush::from(brush.clone()).into_instance(*env)?.as_object(*env).into_unknown())
         }
@@ -80,6 +87,9 @@ pub fn to_js_unknown(env: &Env, value: &Value) -> Result<JsUnknown> {
             }
         }
         Value::EnumerationValue(_, value) => env.create_string(value).map(|v| v.into_unknown()),
+        Value::DateTime(date_time) => {
+            env.create_date(date_time.timestamp_millis() as f64).map(|v| v.into_unknown())
+        }
         _ => env.get_undefined().map(|v| v.into_unknown()),
     }
 }
@@ -102,6 +112,19 @@ pub fn to_value(env: &Env, unknown: JsUnknown, typ: &Type) -> Result<Value> {
             let js_string: JsString = unknown.try_into()?;
             Ok(Value::String(js_string.into_utf8()?.as_str()?.into()))
         }
+        Type::DateTime => {
+            let timestamp = if unknown.is_date()? {
+                // Safety: we just checked that this is a Date
+                let js_date: JsDate = unsafe { unknown.cast() };
+                js_date.value_of()?
+            } else {
+                let js_number: JsNumber = unknown.try_into()?;
+                js_number.get_double()?
+            };
+            Ok(Value::DateTime(i_slint_core::date_time::DateTime::from_timestamp_millis(
+                timestamp as i64,
+            )))
+        }
         Type::Bool => {
             let js_bool: JsBoolean = unknown.try_into()?;
             Ok(Value::Bool(js_bool.get_value()?))
@@ -257,6 +280,18 @@ fn try_convert_image<BufferType: AsRef<[u8]> + FromNapiValue>(
                 Ok(Value::Model(rust_model))
             }
         }
+        Type::Map(v) => {
+            let js_object = unknown.coerce_to_object()?;
+            let keys = js_object.get_property_names()?;
+            let mut map = std::collections::BTreeMap::new();
+            for i in 0..keys.get_array_length()? {
+                let key: JsString = keys.get_element(i)?;
+                let key = key.into_utf8()?.as_str()?.to_string();
+                let value: JsUnknown = js_object.get_named_property(&key)?;
+                map.insert(key.into(), to_value(env, value, v)?);
+            }
+            Ok(Value::Map(map))
+        }
         Type::Enumeration(e) => {
             let js_string: JsString = unknown.try_into()?;
             let value: SmolStr = js_string.into_utf8()?.as_str()?.into();
@@ -218,6 +218,7 @@ struct MyComponent { /*...*/ }
 #[doc(hidden)]
 #[deprecated(note = "Experimental type was made public by mistake")]
 pub use i_slint_core::component_factory::ComponentFactory;
+pub use i_slint_core::date_time::DateTime;
 #[cfg(not(target_arch = "wasm32"))]
 pub use i_slint_core::graphics::{BorrowedOpenGLTextureBuilder, BorrowedOpenGLTextureOrigin};
 // keep in sync with internal/interpreter/api.rs
@@ -225,8 +226,10 @@ struct MyComponent { /*...*/ }
     Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
 };
 pub use i_slint_core::model::{
-    FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc, ModelTracker,
-    ReverseModel, SortModel, StandardListViewItem, TableColumn, VecModel,
+    FilterModel, FlattenedTreeModel, FlattenedTreeRow, MapModel, Model, ModelExt, ModelNotify,
+    ModelPeer, ModelRc, ModelTracker, ReverseModel, SortModel, StandardListViewItem, TableColumn,
+    TreeModel, TreeModelChangeListener, TreeModelNotify, TreeModelTracker, TreeNode, TreeViewItem,
+    VecModel, VecTreeModel,
 };
 pub use i_slint_core::sharedvector::SharedVector;
 pub use i_slint_core::timers::{Timer, TimerMode};
@@ -101,6 +101,64 @@ pub fn set_animated_property_binding_for_transition<
     )
 }
 
+pub fn set_animated_property_binding_with_keyframes<
+    T: Clone + i_slint_core::properties::InterpolatedPropertyValue + 'static,
+    StrongRef: StrongItemTreeRef + 'static,
+>(
+    property: Pin<&Property<T>>,
+    component_strong: &StrongRef,
+    binding: fn(StrongRef) -> T,
+    animation_data: PropertyAnimation,
+    keyframes: SharedVector<Keyframe<T>>,
+) {
+    let weak = component_strong.to_weak();
+    property.set_animated_binding_with_keyframes(
+        move || binding(<StrongRef as StrongItemTreeRef>::from_weak(&weak).unwrap()),
+        animation_data,
+        keyframes,
+    )
+}
+
+pub fn set_animated_property_binding_for_transition_with_keyframes<
+    T: Clone + i_slint_core::properties::InterpolatedPropertyValue + 'static,
+    StrongRef: StrongItemTreeRef + 'static,
+>(
+    property: Pin<&Property<T>>,
+    component_strong: &StrongRef,
+    binding: fn(StrongRef) -> T,
+    compute_animation_details: fn(
+        StrongRef,
+    ) -> (
+        PropertyAnimation,
+        i_slint_core::animations::Instant,
+        SharedVector<Keyframe<T>>,
+    ),
+) {
+    let weak_1 = component_strong.to_weak();
+    let weak_2 = weak_1.clone();
+    property.set_animated_binding_for_transition_with_keyframes(
+        move || binding(<StrongRef as StrongItemTreeRef>::from_weak(&weak_1).unwrap()),
+        move || {
+            compute_animation_details(<StrongRef as StrongItemTreeRef>::from_weak(&weak_2).unwrap())
+        },
+    )
+}
+
+/// Converts the `{at: percent, easing: easing, value: V}` keyframes of an `animate` block
+pub fn to_keyframes<T, V: Clone + 'static>(
+    keyframes: ModelRc<(f32, EasingCurve, V)>,
+    convert_value: impl Fn(V) -> T,
+) -> SharedVector<Keyframe<T>> {
+    keyframes
+        .iter()
+        .map(|(at, easing, value)| Keyframe {
+            position: at / 100.,
+            value: convert_value(value),
+            easing,
+        })
+        .collect()
+}
+
 pub fn set_property_state_binding<StrongRef: StrongItemTreeRef + 'static>(
     property: Pin<&Property<StateInfo>>,
     component_strong: &StrongRef,
@@ -171,6 +229,7 @@ pub fn use_24_hour_format() -> bool {
 /// internal re_exports used by the macro generated
 pub mod re_exports {
     pub use alloc::boxed::Box;
+    pub use alloc::collections::BTreeMap;
     pub use alloc::rc::{Rc, Weak};
     pub use alloc::string::String;
     pub use alloc::{vec, vec::Vec};
@@ -212,15 +271,19 @@ pub mod re_exports {
     pub use i_slint_core::menus::{Menu, MenuFromItemTree, MenuVTable};
     pub use i_slint_core::model::*;
     pub use i_slint_core::properties::{
-        set_state_binding, ChangeTracker, Property, PropertyTracker, StateInfo,
+        set_state_binding, ChangeTracker, Keyframe, Property, PropertyTracker, StateInfo,
     };
     pub use i_slint_core::slice::Slice;
     pub use i_slint_core::string::shared_string_from_number;
     pub use i_slint_core::string::shared_string_from_number_fixed;
     pub use i_slint_core::string::shared_string_from_number_precision;
+    pub use i_slint_core::string::{
+        shared_string_matches, shared_string_replace, shared_string_split, shared_string_substring,
+    };
     pub use i_slint_core::timers::{Timer, TimerMode};
     pub use i_slint_core::translations::{
-        set_bundled_languages, translate_from_bundle, translate_from_bundle_with_plural,
+        format_number, set_bundled_languages, translate_from_bundle,
+        translate_from_bundle_with_plural,
     };
     pub use i_slint_core::window::{
         InputMethodRequest, WindowAdapter, WindowAdapterRc, WindowInner,
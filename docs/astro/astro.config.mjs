@@ -122,6 +122,10 @@ export default defineConfig({
                                                 label: "States and Transitions",
                                                 slug: "guide/language/coding/states",
                                             },
+                                            {
+                                                label: "Style Sheets",
+                                                slug: "guide/language/coding/style-sheets",
+                                            },
                                             {
                                                 label: "Functions and Callbacks",
                                                 slug: "guide/language/coding/functions-and-callbacks",
@@ -254,6 +258,10 @@ export default defineConfig({
                                                 label: "Common Properties",
                                                 slug: "reference/layouts/overview",
                                             },
+                                            {
+                                                label: "FlexboxLayout",
+                                                slug: "reference/layouts/flexboxlayout",
+                                            },
                                             {
                                                 label: "GridLayout",
                                                 slug: "reference/layouts/gridlayout",
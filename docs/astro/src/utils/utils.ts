@@ -70,6 +70,7 @@ export type KnownType =
     | "bool"
     | "brush"
     | "color"
+    | "date-time"
     | "duration"
     | "easing"
     | "enum"
@@ -114,6 +115,11 @@ export function getTypeInfo(typeName: KnownType): TypeInfo {
                 href: linkMap.color.href,
                 defaultValue: "a transparent color",
             };
+        case "date-time":
+            return {
+                href: linkMap.dateTime.href,
+                defaultValue: "1970-01-01 00:00:00 UTC",
+            };
         case "duration":
             return {
                 href: linkMap.duration.href,
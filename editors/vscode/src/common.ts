@@ -311,10 +311,14 @@ function getHelpUrlForElement(
         // elements
         Image: "elements/image",
         Path: "elements/path",
+        StyledText: "elements/styledtext",
         Text: "elements/text",
         Rectangle: "elements/rectangle",
         // gestures
+        DragArea: "gestures/dragarea",
+        DropArea: "gestures/droparea",
         Flickable: "gestures/flickable",
+        PinchGestureHandler: "gestures/pinchgesturehandler",
         SwipeGestureHandler: "gestures/swipegesturehandler",
         TouchArea: "gestures/toucharea",
         // keyboard-input
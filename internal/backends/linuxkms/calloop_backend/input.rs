@@ -4,7 +4,6 @@
 //! This module contains the code to receive input events from libinput
 
 use std::cell::RefCell;
-#[cfg(feature = "libseat")]
 use std::collections::HashMap;
 #[cfg(not(feature = "libseat"))]
 use std::fs::{File, OpenOptions};
@@ -24,7 +23,7 @@
 use input::LibinputInterface;
 
 use input::event::keyboard::{KeyState, KeyboardEventTrait};
-use input::event::touch::TouchEventPosition;
+use input::event::touch::{TouchEventPosition, TouchEventSlot};
 use xkbcommon::*;
 
 use crate::fullscreenwindowadapter::FullscreenWindowAdapter;
@@ -116,7 +115,8 @@ pub struct LibInputHandler<'a> {
     libinput: input::Libinput,
     token: Option<calloop::Token>,
     mouse_pos: Pin<Rc<Property<Option<LogicalPosition>>>>,
-    last_touch_pos: LogicalPosition,
+    /// Last known position of each active touch point, by seat slot
+    touch_points: HashMap<u32, LogicalPosition>,
     window: &'a RefCell<Option<Rc<FullscreenWindowAdapter>>>,
     keystate: Option<xkb::State>,
 }
@@ -138,7 +138,7 @@ pub fn init<T>(
             libinput,
             token: Default::default(),
             mouse_pos: mouse_pos_property.clone(),
-            last_touch_pos: Default::default(),
+            touch_points: Default::default(),
             window,
             keystate: Default::default(),
         };
@@ -232,34 +232,43 @@ fn process_events<F>(
                         _ => {}
                     }
                 }
-                input::Event::Touch(touch_event) => {
-                    if let Some(event) = match touch_event {
-                        input::event::TouchEvent::Down(touch_down_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
-                                touch_down_event.x_transformed(screen_size.width as u32) as _,
-                                touch_down_event.y_transformed(screen_size.height as u32) as _,
-                            );
-                            Some(WindowEvent::PointerPressed {
-                                position: self.last_touch_pos,
-                                button: PointerEventButton::Left,
-                            })
-                        }
-                        input::event::TouchEvent::Up(..) => Some(WindowEvent::PointerReleased {
-                            position: self.last_touch_pos,
-                            button: PointerEventButton::Left,
-                        }),
-                        input::event::TouchEvent::Motion(touch_motion_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
-                                touch_motion_event.x_transformed(screen_size.width as u32) as _,
-                                touch_motion_event.y_transformed(screen_size.height as u32) as _,
-                            );
-                            Some(WindowEvent::PointerMoved { position: self.last_touch_pos })
-                        }
-                        _ => None,
-                    } {
+                input::Event::Touch(touch_event) => match touch_event {
+                    input::event::TouchEvent::Down(touch_down_event) => {
+                        let id = touch_down_event.seat_slot();
+                        let position = LogicalPosition::new(
+                            touch_down_event.x_transformed(screen_size.width as u32) as _,
+                            touch_down_event.y_transformed(screen_size.height as u32) as _,
+                        );
+                        self.touch_points.insert(id, position);
+                        let event = WindowEvent::TouchStarted { id: id.into(), position };
                         window.try_dispatch_event(event).map_err(Self::Error::other)?;
                     }
-                }
+                    input::event::TouchEvent::Motion(touch_motion_event) => {
+                        let id = touch_motion_event.seat_slot();
+                        let position = LogicalPosition::new(
+                            touch_motion_event.x_transformed(screen_size.width as u32) as _,
+                            touch_motion_event.y_transformed(screen_size.height as u32) as _,
+                        );
+                        self.touch_points.insert(id, position);
+                        let event = WindowEvent::TouchMoved { id: id.into(), position };
+                        window.try_dispatch_event(event).map_err(Self::Error::other)?;
+                    }
+                    input::event::TouchEvent::Up(touch_up_event) => {
+                        let id = touch_up_event.seat_slot();
+                        // Up events don't carry a position, use the last known one
+                        let position = self.touch_points.remove(&id).unwrap_or_default();
+                        let event = WindowEvent::TouchEnded { id: id.into(), position };
+                        window.try_dispatch_event(event).map_err(Self::Error::other)?;
+                    }
+                    input::event::TouchEvent::Cancel(..) => {
+                        // A cancel event applies to all the touch points of the device
+                        for (id, position) in self.touch_points.drain() {
+                            let event = WindowEvent::TouchCancelled { id: id.into(), position };
+                            window.try_dispatch_event(event).map_err(Self::Error::other)?;
+                        }
+                    }
+                    _ => {}
+                },
                 input::Event::Keyboard(input::event::KeyboardEvent::Key(key_event)) => {
                     // On Linux key codes have a fixed offset of 8: https://docs.rs/xkbcommon/0.6.0/xkbcommon/xkb/struct.Keycode.html
                     let key_code = xkb::Keycode::new(key_event.key() + 8);
@@ -271,7 +271,10 @@ fn input_event(
                     InputEventResult::EventAccepted
                 }
             }
-            MouseEvent::Wheel { .. } => return InputEventResult::EventIgnored,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::Gesture { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => return InputEventResult::EventIgnored,
         });
         if let MouseEvent::Released { position, .. } = event {
             let geo = self_rc.geometry();
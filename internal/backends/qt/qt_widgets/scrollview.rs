@@ -225,6 +225,9 @@ fn input_event(
                     }
                     InputEventResult::EventAccepted
                 }
+                MouseEvent::Gesture { .. }
+                | MouseEvent::DragMove { .. }
+                | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
             };
             self.data.set(data);
             result
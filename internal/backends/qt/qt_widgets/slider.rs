@@ -251,6 +251,9 @@ fn input_event(
                 debug_assert_ne!(button, PointerEventButton::Left);
                 InputEventResult::EventIgnored
             }
+            MouseEvent::Gesture { .. } | MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => {
+                InputEventResult::EventIgnored
+            }
         };
         data.active_controls = new_control;
 
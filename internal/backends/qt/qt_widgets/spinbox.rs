@@ -204,7 +204,10 @@ fn input_event(
                     }
                     true
                 }
-                MouseEvent::Moved { .. } => false,
+                MouseEvent::Moved { .. }
+                | MouseEvent::Gesture { .. }
+                | MouseEvent::DragMove { .. }
+                | MouseEvent::Drop { .. } => false,
                 MouseEvent::Wheel { delta_y, .. } => {
                     if delta_y > 0. {
                         let v = self.value();
@@ -450,7 +450,10 @@ fn input_event(
                     InputEventResult::EventIgnored
                 }
             }
-            MouseEvent::Wheel { .. } => return InputEventResult::EventIgnored,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::Gesture { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => return InputEventResult::EventIgnored,
         });
         let click_on_press = cpp!(unsafe [] -> bool as "bool" {
             return qApp->style()->styleHint(QStyle::SH_TabBar_SelectMouseType, nullptr, nullptr) == QEvent::MouseButtonPress;
@@ -62,7 +62,6 @@ pub struct EventLoopState {
     // last seen cursor position
     cursor_pos: LogicalPoint,
     pressed: bool,
-    current_touch_id: Option<u64>,
 
     loop_error: Option<PlatformError>,
     current_resize_direction: Option<ResizeDirection>,
@@ -71,6 +70,16 @@ pub struct EventLoopState {
     pumping_events_instantly: bool,
 
     custom_application_handler: Option<Box<dyn crate::CustomApplicationHandler>>,
+
+    /// The files dragged over or dropped on a window. winit sends one event per file, so they are
+    /// collected and dispatched as a single event.
+    pending_files: Option<PendingFiles>,
+}
+
+struct PendingFiles {
+    window_id: winit::window::WindowId,
+    dropped: bool,
+    urls: Vec<String>,
 }
 
 impl EventLoopState {
@@ -82,12 +91,46 @@ pub fn new(
             shared_backend_data,
             cursor_pos: Default::default(),
             pressed: Default::default(),
-            current_touch_id: Default::default(),
             loop_error: Default::default(),
             current_resize_direction: Default::default(),
             pumping_events_instantly: Default::default(),
             custom_application_handler,
+            pending_files: None,
+        }
+    }
+
+    fn add_pending_file(
+        &mut self,
+        window_id: winit::window::WindowId,
+        dropped: bool,
+        path: &std::path::Path,
+    ) {
+        if self
+            .pending_files
+            .as_ref()
+            .is_some_and(|f| f.window_id != window_id || f.dropped != dropped)
+        {
+            self.dispatch_pending_files();
         }
+        self.pending_files
+            .get_or_insert_with(|| PendingFiles { window_id, dropped, urls: Vec::new() })
+            .urls
+            .push(file_url(path));
+    }
+
+    /// Dispatches the collected files as one `text/uri-list`, with one URL per line
+    fn dispatch_pending_files(&mut self) {
+        let Some(files) = self.pending_files.take() else { return };
+        let Some(window) = self.shared_backend_data.window_by_id(files.window_id) else { return };
+        let position = corelib::api::LogicalPosition::new(self.cursor_pos.x, self.cursor_pos.y);
+        let mime_type = "text/uri-list".into();
+        let data = files.urls.join("\n").into();
+        let event = if files.dropped {
+            corelib::platform::WindowEvent::Dropped { position, mime_type, data }
+        } else {
+            corelib::platform::WindowEvent::DragMoved { position, mime_type, data }
+        };
+        self.loop_error = window.window().try_dispatch_event(event).err();
     }
 }
 
@@ -115,6 +158,10 @@ fn window_event(
         window_id: winit::window::WindowId,
         event: WindowEvent,
     ) {
+        if !matches!(event, WindowEvent::HoveredFile(_) | WindowEvent::DroppedFile(_)) {
+            self.dispatch_pending_files();
+        }
+
         let Some(window) = self.shared_backend_data.window_by_id(window_id) else {
             if let Some(handler) = self.custom_application_handler.as_mut() {
                 handler.window_event(event_loop, window_id, None, None, &event);
@@ -345,35 +392,59 @@ macro_rules! winit_key_to_char {
                 runtime_window.process_mouse_input(ev);
             }
             WindowEvent::Touch(touch) => {
-                if Some(touch.id) == self.current_touch_id || self.current_touch_id.is_none() {
-                    let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
-                    let position = euclid::point2(location.x, location.y);
-                    let ev = match touch.phase {
-                        winit::event::TouchPhase::Started => {
-                            self.pressed = true;
-                            if self.current_touch_id.is_none() {
-                                self.current_touch_id = Some(touch.id);
-                            }
-                            MouseEvent::Pressed {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
-                            self.pressed = false;
-                            self.current_touch_id = None;
-                            MouseEvent::Released {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Moved => MouseEvent::Moved { position },
-                    };
-                    runtime_window.process_mouse_input(ev);
-                }
+                let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
+                let position = corelib::api::LogicalPosition::new(location.x, location.y);
+                let id = touch.id;
+                let event = match touch.phase {
+                    winit::event::TouchPhase::Started => {
+                        self.pressed = true;
+                        corelib::platform::WindowEvent::TouchStarted { id, position }
+                    }
+                    winit::event::TouchPhase::Moved => {
+                        corelib::platform::WindowEvent::TouchMoved { id, position }
+                    }
+                    winit::event::TouchPhase::Ended => {
+                        self.pressed = false;
+                        corelib::platform::WindowEvent::TouchEnded { id, position }
+                    }
+                    winit::event::TouchPhase::Cancelled => {
+                        self.pressed = false;
+                        corelib::platform::WindowEvent::TouchCancelled { id, position }
+                    }
+                };
+                self.loop_error = window.window().try_dispatch_event(event).err();
+            }
+            WindowEvent::PinchGesture { delta, phase, .. } => {
+                let event = corelib::platform::WindowEvent::PinchGesture {
+                    position: corelib::api::LogicalPosition::new(
+                        self.cursor_pos.x,
+                        self.cursor_pos.y,
+                    ),
+                    delta: delta as f32,
+                    phase: gesture_phase(phase),
+                };
+                self.loop_error = window.window().try_dispatch_event(event).err();
+            }
+            WindowEvent::RotationGesture { delta, phase, .. } => {
+                let event = corelib::platform::WindowEvent::RotationGesture {
+                    position: corelib::api::LogicalPosition::new(
+                        self.cursor_pos.x,
+                        self.cursor_pos.y,
+                    ),
+                    // winit reports counter-clockwise angles
+                    delta: -delta,
+                    phase: gesture_phase(phase),
+                };
+                self.loop_error = window.window().try_dispatch_event(event).err();
+            }
+            WindowEvent::HoveredFile(path) => self.add_pending_file(window_id, false, &path),
+            WindowEvent::HoveredFileCancelled => {
+                self.loop_error = window
+                    .window()
+                    .try_dispatch_event(corelib::platform::WindowEvent::DragExited)
+                    .err();
             }
+            WindowEvent::DroppedFile(path) => self.add_pending_file(window_id, true, &path),
             WindowEvent::ScaleFactorChanged { scale_factor, inner_size_writer: _ } => {
                 if std::env::var("SLINT_SCALE_FACTOR").is_err() {
                     self.loop_error = window
@@ -459,6 +530,8 @@ fn new_events(&mut self, event_loop: &ActiveEventLoop, cause: winit::event::Star
     }
 
     fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        self.dispatch_pending_files();
+
         if matches!(
             self.custom_application_handler
                 .as_mut()
@@ -613,3 +686,35 @@ pub fn spawn(self) -> Result<(), corelib::platform::PlatformError> {
         Ok(())
     }
 }
+
+fn gesture_phase(phase: winit::event::TouchPhase) -> corelib::platform::GesturePhase {
+    match phase {
+        winit::event::TouchPhase::Started => corelib::platform::GesturePhase::Started,
+        winit::event::TouchPhase::Moved => corelib::platform::GesturePhase::Updated,
+        winit::event::TouchPhase::Ended => corelib::platform::GesturePhase::Ended,
+        winit::event::TouchPhase::Cancelled => corelib::platform::GesturePhase::Cancelled,
+    }
+}
+
+/// Returns the `file://` URL for the path of a file dragged from another application
+fn file_url(path: &std::path::Path) -> String {
+    let path = path.to_string_lossy();
+    let mut url = String::from("file://");
+    if !path.starts_with('/') {
+        // Windows paths like `C:\foo`
+        url.push('/');
+    }
+    for c in path.chars() {
+        match c {
+            '\\' if cfg!(windows) => url.push('/'),
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '/' | '-' | '_' | '.' | '~' | ':' => url.push(c),
+            _ => {
+                let mut buf = [0; 4];
+                for b in c.encode_utf8(&mut buf).bytes() {
+                    url += &format!("%{b:02X}");
+                }
+            }
+        }
+    }
+    url
+}
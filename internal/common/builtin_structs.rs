@@ -86,6 +86,21 @@ struct PointerScrollEvent {
                 }
             }
 
+            /// This structure is passed to the callbacks of the `DropArea` element.
+            struct DropEvent {
+                @name = "slint::private_api::DropEvent"
+                export {
+                    /// The mime type of the data, for example `text/plain` or `text/uri-list`
+                    mime_type: SharedString,
+                    /// The data being dragged
+                    data: SharedString,
+                    /// The position of the pointer, relative to the `DropArea`
+                    position: LogicalPosition,
+                }
+                private {
+                }
+            }
+
             /// This structure is generated and passed to the key press and release callbacks of the `FocusScope` element.
             struct KeyEvent {
                 @name = "slint::private_api::KeyEvent"
@@ -127,6 +142,24 @@ struct StandardListViewItem {
                 }
             }
 
+            /// Represents a visible row in a TreeView.
+            #[non_exhaustive]
+            struct TreeViewItem {
+                @name = "slint::TreeViewItem"
+                export {
+                    /// The text content of the item
+                    text: SharedString,
+                    /// The number of ancestors of the item. It's 0 for the top-level items.
+                    depth: i32,
+                    /// Whether the item has children that can be shown
+                    has_children: bool,
+                    /// Whether the children of the item are shown
+                    expanded: bool,
+                }
+                private {
+                }
+            }
+
             /// This is used to define the column and the column header of a TableView
             #[non_exhaustive]
             struct TableColumn {
@@ -336,6 +336,51 @@ enum LayoutAlignment {
                 SpaceAround,
             }
 
+            /// This enum describes the direction of the main axis of a `FlexboxLayout`, along which
+            /// the children are placed, and the order in which they are placed.
+            enum FlexDirection {
+                /// The children are placed from left to right, and the lines wrap from top to bottom.
+                Row,
+                /// The children are placed from right to left, and the lines wrap from top to bottom.
+                RowReverse,
+                /// The children are placed from top to bottom, and the lines wrap from left to right.
+                Column,
+                /// The children are placed from bottom to top, and the lines wrap from left to right.
+                ColumnReverse,
+            }
+
+            /// Enum representing the `justify-content` property of a `FlexboxLayout`: how the
+            /// remaining space of a line is distributed along the main axis.
+            enum FlexJustifyContent {
+                /// Put the remaining space after the last element of the line.
+                Start,
+                /// Put the remaining space before the first element of the line.
+                End,
+                /// Distribute the remaining space evenly before the first and after the last element of the line.
+                Center,
+                /// Distribute the remaining space evenly between the elements of the line.
+                SpaceBetween,
+                /// Distribute the remaining space evenly around the elements of the line, with half as
+                /// much space before the first and after the last element as between elements.
+                SpaceAround,
+                /// Distribute the remaining space evenly before the first element, between the elements
+                /// and after the last element of the line.
+                SpaceEvenly,
+            }
+
+            /// Enum representing the `align-items` property of a `FlexboxLayout`: how the elements
+            /// are placed within their line along the cross axis.
+            enum FlexAlignItems {
+                /// The elements fill the whole size of the line, within their minimum and maximum size.
+                Stretch,
+                /// The elements use their preferred size and are placed at the start of the line.
+                Start,
+                /// The elements use their preferred size and are placed at the end of the line.
+                End,
+                /// The elements use their preferred size and are centered in the line.
+                Center,
+            }
+
             /// PathEvent is a low-level data structure describing the composition of a path. Typically it is
             /// generated at compile time from a higher-level description, such as SVG commands.
             enum PathEvent {
@@ -385,7 +430,7 @@ enum AccessibleRole {
                 Text,
                 /// The role for a `TableView` or behaves like one.
                 Table,
-                /// The role for a TreeView or behaves like one. (Not provided yet)
+                /// The element is a `TreeView` or behaves like one.
                 Tree,
                 /// The element is a `ProgressIndicator` or behaves like one.
                 ProgressIndicator,
@@ -475,6 +520,16 @@ enum LineCap {
                 Square,
             }
 
+            /// This enum describes how the `format-number()` function formats a number.
+            enum NumberFormatStyle {
+                /// The number is formatted as a plain decimal number, like "1,234.5".
+                Decimal,
+                /// The number is multiplied by 100 and formatted as a percentage, like "12%".
+                Percent,
+                /// The number is formatted as an amount in the currency given by the `currency` option, like "$1,234.50".
+                Currency,
+            }
+
             /// This enum describes the detected operating system types.
             #[non_exhaustive]
             enum OperatingSystemType {
@@ -4,7 +4,7 @@
 //! This module contains the implementation of the builtin macros.
 //! They are just transformations that convert into some more complicated expression tree
 
-use crate::diagnostics::{BuildDiagnostics, Spanned};
+use crate::diagnostics::{BuildDiagnostics, SourceLocation, Spanned};
 use crate::expression_tree::{
     BuiltinFunction, BuiltinMacroFunction, Callable, EasingCurve, Expression, MinMaxOp, Unit,
 };
@@ -31,29 +31,15 @@ pub fn lower_macro(
         BuiltinMacroFunction::Debug => debug_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::CubicBezier => {
             let mut has_error = None;
-            let expected_argument_type_error =
-                "Arguments to cubic bezier curve must be number literal";
             // FIXME: this is not pretty to be handling there.
             // Maybe "cubic_bezier" should be a function that is lowered later
-            let mut a = || match sub_expr.next() {
-                None => {
-                    has_error.get_or_insert((n.to_source_location(), "Not enough arguments"));
-                    0.
-                }
-                Some((Expression::NumberLiteral(val, Unit::None), _)) => val as f32,
-                // handle negative numbers
-                Some((Expression::UnaryOp { sub, op: '-' }, n)) => match *sub {
-                    Expression::NumberLiteral(val, Unit::None) => (-1.0 * val) as f32,
-                    _ => {
-                        has_error
-                            .get_or_insert((n.to_source_location(), expected_argument_type_error));
-                        0.
-                    }
-                },
-                Some((_, n)) => {
-                    has_error.get_or_insert((n.to_source_location(), expected_argument_type_error));
-                    0.
-                }
+            let mut a = || {
+                easing_literal_argument(
+                    n,
+                    sub_expr.next(),
+                    "Arguments to cubic bezier curve must be number literal",
+                    &mut has_error,
+                )
             };
             let expr = Expression::EasingCurve(EasingCurve::CubicBezier(a(), a(), a(), a()));
             if let Some((_, n)) = sub_expr.next() {
@@ -66,8 +52,88 @@ pub fn lower_macro(
 
             expr
         }
+        BuiltinMacroFunction::Spring => {
+            let mut has_error = None;
+            let mut args = Vec::new();
+            for arg in sub_expr {
+                let loc = arg.1.to_source_location();
+                let val = easing_literal_argument(
+                    n,
+                    Some(arg),
+                    "Arguments to spring must be number literals",
+                    &mut has_error,
+                );
+                args.push((val, loc));
+            }
+            if args.len() < 2 {
+                has_error.get_or_insert((
+                    n.to_source_location(),
+                    "spring needs at least the stiffness and damping arguments",
+                ));
+            } else if args.len() > 3 {
+                has_error.get_or_insert((args[3].1.clone(), "Too many arguments for spring"));
+            }
+            for (val, loc) in &args {
+                if val.is_nan() || *val <= 0. {
+                    has_error.get_or_insert((loc.clone(), "Arguments to spring must be positive"));
+                }
+            }
+            if let Some((n, msg)) = has_error {
+                diag.push_error(msg.into(), &n);
+                return Expression::Invalid;
+            }
+            let arg = |i: usize| args.get(i).map_or(1., |(val, _)| *val);
+            Expression::EasingCurve(EasingCurve::Spring(arg(0), arg(1), arg(2)))
+        }
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Hsv => hsv_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::ArraySlice => {
+            array_function_macro(n, BuiltinFunction::ArraySlice, sub_expr.collect(), diag)
+        }
+        BuiltinMacroFunction::ArrayConcat => {
+            array_function_macro(n, BuiltinFunction::ArrayConcat, sub_expr.collect(), diag)
+        }
+        BuiltinMacroFunction::ArrayIndexOf => {
+            array_function_macro(n, BuiltinFunction::ArrayIndexOf, sub_expr.collect(), diag)
+        }
+        BuiltinMacroFunction::ArrayContains => {
+            array_function_macro(n, BuiltinFunction::ArrayContains, sub_expr.collect(), diag)
+        }
+        BuiltinMacroFunction::FormatNumber => format_number_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::StringSubstring => substring_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::ArrayFilter
+        | BuiltinMacroFunction::ArrayMap
+        | BuiltinMacroFunction::ArraySortBy => {
+            unreachable!("array functions taking a lambda are lowered while resolving")
+        }
+    }
+}
+
+/// Returns the value of a number literal argument of an easing curve, or records an error
+fn easing_literal_argument(
+    n: &dyn Spanned,
+    arg: Option<(Expression, Option<NodeOrToken>)>,
+    expected_argument_type_error: &'static str,
+    has_error: &mut Option<(SourceLocation, &'static str)>,
+) -> f32 {
+    match arg {
+        None => {
+            has_error.get_or_insert((n.to_source_location(), "Not enough arguments"));
+            0.
+        }
+        Some((Expression::NumberLiteral(val, Unit::None), _)) => val as f32,
+        // handle negative numbers
+        Some((Expression::UnaryOp { sub, op: '-' }, n)) => match *sub {
+            Expression::NumberLiteral(val, Unit::None) => -val as f32,
+            _ => {
+                has_error.get_or_insert((n.to_source_location(), expected_argument_type_error));
+                0.
+            }
+        },
+        Some((_, n)) => {
+            has_error.get_or_insert((n.to_source_location(), expected_argument_type_error));
+            0.
+        }
     }
 }
 
@@ -321,8 +387,13 @@ fn to_debug_string(
         }
         Type::Float32 | Type::Int32 => expr.maybe_convert_to(Type::String, node, diag),
         Type::String => expr,
+        Type::DateTime => Expression::FunctionCall {
+            function: BuiltinFunction::FormatDateTime.into(),
+            arguments: vec![expr, Expression::StringLiteral("%+".into())],
+            source_location: Some(node.to_source_location()),
+        },
         // TODO
-        Type::Color | Type::Brush | Type::Image | Type::Easing | Type::Array(_) => {
+        Type::Color | Type::Brush | Type::Image | Type::Easing | Type::Array(_) | Type::Map(_) => {
             Expression::StringLiteral("<debug-of-this-type-not-yet-implemented>".into())
         }
         Type::Duration
@@ -429,6 +500,153 @@ fn to_debug_string(
     }
 }
 
+/// Convert the arguments of the array member functions. The first argument is the array itself.
+fn array_function_macro(
+    node: &dyn Spanned,
+    function: BuiltinFunction,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    let mut args = args.into_iter();
+    let (array, _) = args.next().expect("member function without base");
+    let array_ty = array.ty();
+    let Type::Array(element_ty) = &array_ty else { return Expression::Invalid };
+    let mut args = args.collect::<Vec<_>>();
+    let arg_types = match function {
+        BuiltinFunction::ArraySlice => {
+            if args.len() == 1 {
+                // `slice(start)` goes until the end of the array
+                args.push((Expression::NumberLiteral(i32::MAX as _, Unit::None), None));
+            }
+            if args.len() != 2 {
+                diag.push_error(
+                    "`slice` needs one or two arguments: the `start` and optionally the `end`"
+                        .into(),
+                    node,
+                );
+                return Expression::Invalid;
+            }
+            vec![Type::Int32, Type::Int32]
+        }
+        BuiltinFunction::ArrayConcat => vec![array_ty.clone()],
+        _ => vec![(**element_ty).clone()],
+    };
+    if args.len() != arg_types.len() {
+        diag.push_error("Needs 1 argument".into(), node);
+        return Expression::Invalid;
+    }
+    let arguments = std::iter::once(array)
+        .chain(args.into_iter().zip(arg_types).map(|((e, n), ty)| e.maybe_convert_to(ty, &n, diag)))
+        .collect();
+    Expression::FunctionCall {
+        function: Callable::Builtin(function),
+        arguments,
+        source_location: Some(node.to_source_location()),
+    }
+}
+
+/// Expand the options of `format-number(value, options)` into the arguments of [`BuiltinFunction::FormatNumber`].
+/// The options is a struct with any of the fields `style`, `min-fraction`, `max-fraction`, `grouping`, and `currency`
+fn format_number_macro(
+    node: &dyn Spanned,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.is_empty() || args.len() > 2 {
+        diag.push_error(
+            "`format-number` needs one or two arguments: the value and optionally the options"
+                .into(),
+            node,
+        );
+        return Expression::Invalid;
+    }
+    let mut args = args.into_iter();
+    let (value, value_node) = args.next().unwrap();
+    let (options, options_node) = args.next().unwrap_or((Expression::Invalid, None));
+    let fields = match options.ty() {
+        Type::Struct(s) => s.fields.clone(),
+        // No options were given
+        Type::Invalid if options_node.is_none() => Default::default(),
+        Type::Invalid => return Expression::Invalid,
+        ty => {
+            diag.push_error(
+                format!("The options of `format-number` must be a struct, not {ty}"),
+                &options_node,
+            );
+            return Expression::Invalid;
+        }
+    };
+    const OPTIONS: [&str; 5] = ["style", "min-fraction", "max-fraction", "grouping", "currency"];
+    for name in fields.keys() {
+        if !OPTIONS.contains(&name.as_str()) {
+            diag.push_error(
+                format!(
+                    "Unknown option '{name}' for `format-number`. The options are: {}",
+                    OPTIONS.join(", ")
+                ),
+                &options_node,
+            );
+        }
+    }
+    let style_enum = crate::typeregister::BUILTIN.with(|e| e.enums.NumberFormatStyle.clone());
+    let defaults = [
+        Expression::EnumerationValue(style_enum.clone().default_value()),
+        // negative fractions mean the default for the style
+        Expression::NumberLiteral(-1., Unit::None),
+        Expression::NumberLiteral(-1., Unit::None),
+        Expression::BoolLiteral(true),
+        Expression::StringLiteral(Default::default()),
+    ];
+    let types = [Type::Enumeration(style_enum), Type::Int32, Type::Int32, Type::Bool, Type::String];
+    let mut arguments = vec![value.maybe_convert_to(Type::Float32, &value_node, diag)];
+    for ((name, default), ty) in OPTIONS.into_iter().zip(defaults).zip(types) {
+        let option = match &options {
+            Expression::Struct { values, .. } => values.get(name).cloned(),
+            _ if fields.contains_key(name) => Some(Expression::StructFieldAccess {
+                base: Box::new(options.clone()),
+                name: name.into(),
+            }),
+            _ => None,
+        };
+        arguments.push(option.map_or(default, |e| e.maybe_convert_to(ty, &options_node, diag)));
+    }
+    Expression::FunctionCall {
+        function: Callable::Builtin(BuiltinFunction::FormatNumber),
+        arguments,
+        source_location: Some(node.to_source_location()),
+    }
+}
+
+/// `string.substring(start)` goes until the end of the string
+fn substring_macro(
+    node: &dyn Spanned,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    let mut args = args.into_iter();
+    let (string, _) = args.next().expect("member function without base");
+    let mut args = args.collect::<Vec<_>>();
+    if args.len() == 1 {
+        args.push((Expression::NumberLiteral(i32::MAX as _, Unit::None), None));
+    }
+    if args.len() != 2 {
+        diag.push_error(
+            "`substring` needs one or two arguments: the `start` and optionally the `length`"
+                .into(),
+            node,
+        );
+        return Expression::Invalid;
+    }
+    let arguments = std::iter::once(string)
+        .chain(args.into_iter().map(|(e, n)| e.maybe_convert_to(Type::Int32, &n, diag)))
+        .collect();
+    Expression::FunctionCall {
+        function: Callable::Builtin(BuiltinFunction::StringSubstring),
+        arguments,
+        source_location: Some(node.to_source_location()),
+    }
+}
+
 /// Generate an expression which is like `min(lhs, rhs)` if op is '<' or `max(lhs, rhs)` if op is '>'.
 /// counter is an unique id.
 /// The rhs and lhs of the expression must have the same numerical type
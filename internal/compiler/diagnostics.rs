@@ -311,6 +311,12 @@ fn into_iter(self) -> Self::IntoIter {
     }
 }
 
+impl Extend<Diagnostic> for BuildDiagnostics {
+    fn extend<T: IntoIterator<Item = Diagnostic>>(&mut self, iter: T) {
+        self.inner.extend(iter)
+    }
+}
+
 impl BuildDiagnostics {
     pub fn push_diagnostic_with_span(
         &mut self,
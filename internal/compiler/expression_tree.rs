@@ -11,7 +11,7 @@
 use core::cell::RefCell;
 use smol_str::{format_smolstr, SmolStr};
 use std::cell::Cell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::{Rc, Weak};
 
 // FIXME remove the pub
@@ -67,6 +67,17 @@ pub enum BuiltinFunction {
     StringCharacterCount,
     StringToLowercase,
     StringToUppercase,
+    StringTrim,
+    StringContains,
+    StringStartsWith,
+    StringEndsWith,
+    StringReplace,
+    /// the "42".substring(start, length), counted in graphemes like [`Self::StringCharacterCount`]
+    StringSubstring,
+    /// the "a,b".split(","), returns an array of strings
+    StringSplit,
+    /// the "42".matches(pattern), see `i_slint_core::string::shared_string_matches`
+    StringMatches,
     ColorRgbaStruct,
     ColorHsvaStruct,
     ColorBrighter,
@@ -76,6 +87,18 @@ pub enum BuiltinFunction {
     ColorWithAlpha,
     ImageSize,
     ArrayLength,
+    /// the `map.has-key(key)`
+    MapHasKey,
+    /// the `map.keys`, sorted
+    MapKeys,
+    /// `array.slice(start, end)`. Evaluates to an array of the same type as the first argument
+    ArraySlice,
+    /// `array.concat(other)`. Evaluates to an array of the same type as the first argument
+    ArrayConcat,
+    /// `array.index-of(value)`, or -1 if not found
+    ArrayIndexOf,
+    /// `array.contains(value)`
+    ArrayContains,
     Rgb,
     Hsv,
     ColorScheme,
@@ -91,9 +114,30 @@ pub enum BuiltinFunction {
     MonthDayCount,
     MonthOffset,
     FormatDate,
+    /// value, style, min-fraction, max-fraction, grouping, currency (see `i_slint_core::translations::format_number`)
+    FormatNumber,
     DateNow,
     ValidDate,
     ParseDate,
+    DateTimeNow,
+    ParseDateTime,
+    FormatDateTime,
+    DateTimeYear,
+    DateTimeMonth,
+    DateTimeDay,
+    DateTimeHour,
+    DateTimeMinute,
+    DateTimeSecond,
+    DateTimeWeekday,
+    DateTimeUtcOffset,
+    DateTimeWithUtcOffset,
+    DateTimeToLocal,
+    DateTimeWithDate,
+    DateTimeWithTime,
+    /// `date-time + duration`
+    DateTimeAdd,
+    /// `date-time - date-time`, as a duration
+    DateTimeDiff,
     TextInputFocused,
     SetTextInputFocused,
     ImplicitLayoutInfo(Orientation),
@@ -124,12 +168,33 @@ pub enum BuiltinMacroFunction {
     /// Add the right conversion operations so that the return type is the same as the argument type
     Abs,
     CubicBezier,
+    /// `spring(stiffness, damping)` or `spring(stiffness, damping, mass)` with number literals
+    Spring,
     /// The argument can be r,g,b,a or r,g,b and they can be percentages or integer.
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
     Hsv,
     /// transform `debug(a, b, c)` into debug `a + " " + b + " " + c`
     Debug,
+    /// `array.slice(start)` or `array.slice(start, end)`
+    ArraySlice,
+    /// Convert the argument of `array.concat(other)` to the type of the array
+    ArrayConcat,
+    /// Convert the argument of `array.index-of(value)` to the type of the elements
+    ArrayIndexOf,
+    /// Convert the argument of `array.contains(value)` to the type of the elements
+    ArrayContains,
+    /// `format-number(value)` or `format-number(value, { style: ..., min-fraction: ..., ... })`,
+    /// fill the options that are not specified with their default.
+    FormatNumber,
+    /// `string.substring(start)` or `string.substring(start, length)`
+    StringSubstring,
+    /// `array.filter(x => ...)`, handled while resolving as it needs to declare the lambda's variable
+    ArrayFilter,
+    /// `array.map(x => ...)`, handled while resolving as it needs to declare the lambda's variable
+    ArrayMap,
+    /// `array.sort-by(x => ...)`, handled while resolving as it needs to declare the lambda's variable
+    ArraySortBy,
 }
 
 macro_rules! declare_builtin_function_types {
@@ -195,6 +260,14 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
     StringCharacterCount: (Type::String) -> Type::Int32,
     StringToLowercase: (Type::String) -> Type::String,
     StringToUppercase: (Type::String) -> Type::String,
+    StringTrim: (Type::String) -> Type::String,
+    StringContains: (Type::String, Type::String) -> Type::Bool,
+    StringStartsWith: (Type::String, Type::String) -> Type::Bool,
+    StringEndsWith: (Type::String, Type::String) -> Type::Bool,
+    StringReplace: (Type::String, Type::String, Type::String) -> Type::String,
+    StringSubstring: (Type::String, Type::Int32, Type::Int32) -> Type::String,
+    StringSplit: (Type::String, Type::String) -> Type::Array(Rc::new(Type::String)),
+    StringMatches: (Type::String, Type::String) -> Type::Bool,
     ImplicitLayoutInfo(..): (Type::ElementReference) -> Type::Struct(typeregister::layout_info_type()),
     ColorRgbaStruct: (Type::Color) -> Type::Struct(Rc::new(Struct {
         fields: IntoIterator::into_iter([
@@ -236,6 +309,15 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
         rust_attributes: None,
     })),
     ArrayLength: (Type::Model) -> Type::Int32,
+    // The first argument can be any map, which is not checked by the type
+    MapHasKey: (Type::Invalid, Type::String) -> Type::Bool,
+    MapKeys: (Type::Invalid) -> Type::Array(Rc::new(Type::String)),
+    // The array arguments can be any array, which is not checked by the type.
+    // The return type of ArraySlice and ArrayConcat is the type of their first argument
+    ArraySlice: (Type::Invalid, Type::Int32, Type::Int32) -> Type::Invalid,
+    ArrayConcat: (Type::Invalid, Type::Invalid) -> Type::Invalid,
+    ArrayIndexOf: (Type::Invalid, Type::Invalid) -> Type::Int32,
+    ArrayContains: (Type::Invalid, Type::Invalid) -> Type::Bool,
     Rgb: (Type::Int32, Type::Int32, Type::Int32, Type::Float32) -> Type::Color,
     Hsv: (Type::Float32, Type::Float32, Type::Float32, Type::Float32) -> Type::Color,
     ColorScheme: () -> Type::Enumeration(
@@ -247,10 +329,35 @@ pub fn ty(&self, function: &BuiltinFunction) -> Rc<Function> {
     MonthDayCount: (Type::Int32, Type::Int32) -> Type::Int32,
     MonthOffset: (Type::Int32, Type::Int32) -> Type::Int32,
     FormatDate: (Type::String, Type::Int32, Type::Int32, Type::Int32) -> Type::String,
+    FormatNumber: (
+        Type::Float32,
+        Type::Enumeration(typeregister::BUILTIN.with(|e| e.enums.NumberFormatStyle.clone())),
+        Type::Int32,
+        Type::Int32,
+        Type::Bool,
+        Type::String
+    ) -> Type::String,
     TextInputFocused: () -> Type::Bool,
     DateNow: () -> Type::Array(Rc::new(Type::Int32)),
     ValidDate: (Type::String, Type::String) -> Type::Bool,
     ParseDate: (Type::String, Type::String) -> Type::Array(Rc::new(Type::Int32)),
+    DateTimeNow: () -> Type::DateTime,
+    ParseDateTime: (Type::String, Type::String) -> Type::DateTime,
+    FormatDateTime: (Type::DateTime, Type::String) -> Type::String,
+    DateTimeYear: (Type::DateTime) -> Type::Int32,
+    DateTimeMonth: (Type::DateTime) -> Type::Int32,
+    DateTimeDay: (Type::DateTime) -> Type::Int32,
+    DateTimeHour: (Type::DateTime) -> Type::Int32,
+    DateTimeMinute: (Type::DateTime) -> Type::Int32,
+    DateTimeSecond: (Type::DateTime) -> Type::Int32,
+    DateTimeWeekday: (Type::DateTime) -> Type::Int32,
+    DateTimeUtcOffset: (Type::DateTime) -> Type::Duration,
+    DateTimeWithUtcOffset: (Type::DateTime, Type::Duration) -> Type::DateTime,
+    DateTimeToLocal: (Type::DateTime) -> Type::DateTime,
+    DateTimeWithDate: (Type::DateTime, Type::Int32, Type::Int32, Type::Int32) -> Type::DateTime,
+    DateTimeWithTime: (Type::DateTime, Type::Int32, Type::Int32, Type::Int32) -> Type::DateTime,
+    DateTimeAdd: (Type::DateTime, Type::Duration) -> Type::DateTime,
+    DateTimeDiff: (Type::DateTime, Type::DateTime) -> Type::Duration,
     SetTextInputFocused: (Type::Bool) -> Type::Void,
     ItemAbsolutePosition: (Type::ElementReference) -> typeregister::logical_point_type(),
     RegisterCustomFontByPath: (Type::String) -> Type::Void,
@@ -285,9 +392,28 @@ fn is_const(&self) -> bool {
             BuiltinFunction::MonthDayCount => false,
             BuiltinFunction::MonthOffset => false,
             BuiltinFunction::FormatDate => false,
+            // Depends on the selected language
+            BuiltinFunction::FormatNumber => false,
             BuiltinFunction::DateNow => false,
             BuiltinFunction::ValidDate => false,
             BuiltinFunction::ParseDate => false,
+            BuiltinFunction::DateTimeNow => false,
+            // Without an offset in the format, the local time zone is used
+            BuiltinFunction::ParseDateTime | BuiltinFunction::DateTimeToLocal => false,
+            BuiltinFunction::FormatDateTime
+            | BuiltinFunction::DateTimeYear
+            | BuiltinFunction::DateTimeMonth
+            | BuiltinFunction::DateTimeDay
+            | BuiltinFunction::DateTimeHour
+            | BuiltinFunction::DateTimeMinute
+            | BuiltinFunction::DateTimeSecond
+            | BuiltinFunction::DateTimeWeekday
+            | BuiltinFunction::DateTimeUtcOffset
+            | BuiltinFunction::DateTimeWithUtcOffset
+            | BuiltinFunction::DateTimeWithDate
+            | BuiltinFunction::DateTimeWithTime
+            | BuiltinFunction::DateTimeAdd
+            | BuiltinFunction::DateTimeDiff => true,
             // Even if it is not pure, we optimize it away anyway
             BuiltinFunction::Debug => true,
             BuiltinFunction::Mod
@@ -320,7 +446,15 @@ fn is_const(&self) -> bool {
             | BuiltinFunction::StringIsEmpty
             | BuiltinFunction::StringCharacterCount
             | BuiltinFunction::StringToLowercase
-            | BuiltinFunction::StringToUppercase => true,
+            | BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringTrim
+            | BuiltinFunction::StringContains
+            | BuiltinFunction::StringStartsWith
+            | BuiltinFunction::StringEndsWith
+            | BuiltinFunction::StringReplace
+            | BuiltinFunction::StringSubstring
+            | BuiltinFunction::StringSplit
+            | BuiltinFunction::StringMatches => true,
             BuiltinFunction::ColorRgbaStruct
             | BuiltinFunction::ColorHsvaStruct
             | BuiltinFunction::ColorBrighter
@@ -337,6 +471,11 @@ fn is_const(&self) -> bool {
             #[cfg(target_arch = "wasm32")]
             BuiltinFunction::ImageSize => false,
             BuiltinFunction::ArrayLength => true,
+            BuiltinFunction::MapHasKey | BuiltinFunction::MapKeys => true,
+            BuiltinFunction::ArraySlice
+            | BuiltinFunction::ArrayConcat
+            | BuiltinFunction::ArrayIndexOf
+            | BuiltinFunction::ArrayContains => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::Hsv => true,
             BuiltinFunction::SetTextInputFocused => false,
@@ -365,9 +504,27 @@ pub fn is_pure(&self) -> bool {
             BuiltinFunction::MonthDayCount => true,
             BuiltinFunction::MonthOffset => true,
             BuiltinFunction::FormatDate => true,
+            BuiltinFunction::FormatNumber => true,
             BuiltinFunction::DateNow => true,
             BuiltinFunction::ValidDate => true,
             BuiltinFunction::ParseDate => true,
+            BuiltinFunction::DateTimeNow
+            | BuiltinFunction::ParseDateTime
+            | BuiltinFunction::FormatDateTime
+            | BuiltinFunction::DateTimeYear
+            | BuiltinFunction::DateTimeMonth
+            | BuiltinFunction::DateTimeDay
+            | BuiltinFunction::DateTimeHour
+            | BuiltinFunction::DateTimeMinute
+            | BuiltinFunction::DateTimeSecond
+            | BuiltinFunction::DateTimeWeekday
+            | BuiltinFunction::DateTimeUtcOffset
+            | BuiltinFunction::DateTimeWithUtcOffset
+            | BuiltinFunction::DateTimeToLocal
+            | BuiltinFunction::DateTimeWithDate
+            | BuiltinFunction::DateTimeWithTime
+            | BuiltinFunction::DateTimeAdd
+            | BuiltinFunction::DateTimeDiff => true,
             // Even if it has technically side effect, we still consider it as pure for our purpose
             BuiltinFunction::Debug => true,
             BuiltinFunction::Mod
@@ -400,7 +557,15 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::StringIsEmpty
             | BuiltinFunction::StringCharacterCount
             | BuiltinFunction::StringToLowercase
-            | BuiltinFunction::StringToUppercase => true,
+            | BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringTrim
+            | BuiltinFunction::StringContains
+            | BuiltinFunction::StringStartsWith
+            | BuiltinFunction::StringEndsWith
+            | BuiltinFunction::StringReplace
+            | BuiltinFunction::StringSubstring
+            | BuiltinFunction::StringSplit
+            | BuiltinFunction::StringMatches => true,
             BuiltinFunction::ColorRgbaStruct
             | BuiltinFunction::ColorHsvaStruct
             | BuiltinFunction::ColorBrighter
@@ -410,6 +575,11 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::ColorWithAlpha => true,
             BuiltinFunction::ImageSize => true,
             BuiltinFunction::ArrayLength => true,
+            BuiltinFunction::MapHasKey | BuiltinFunction::MapKeys => true,
+            BuiltinFunction::ArraySlice
+            | BuiltinFunction::ArrayConcat
+            | BuiltinFunction::ArrayIndexOf
+            | BuiltinFunction::ArrayContains => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::Hsv => true,
             BuiltinFunction::ImplicitLayoutInfo(_) => true,
@@ -563,6 +733,16 @@ pub enum MinMaxOp {
     Max,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayTransformOp {
+    /// Keep the elements for which the expression is true
+    Filter,
+    /// Replace each element by the value of the expression
+    Map,
+    /// Sort the elements by the value of the expression, in ascending order. The sort is stable.
+    SortBy,
+}
+
 /// The Expression is hold by properties, so it should not hold any strong references to node from the object_tree
 #[derive(Debug, Clone, Default)]
 pub enum Expression {
@@ -635,6 +815,13 @@ pub enum Expression {
         index: Box<Expression>,
     },
 
+    /// Access to the value of a key within a map. Evaluates to the default value if the key is not present.
+    MapIndex {
+        /// This expression should have [`Type::Map`] type
+        map: Box<Expression>,
+        key: Box<Expression>,
+    },
+
     /// Cast an expression to the given type
     Cast {
         from: Box<Expression>,
@@ -693,6 +880,10 @@ pub enum Expression {
         ty: Rc<Struct>,
         values: HashMap<SmolStr, Expression>,
     },
+    Map {
+        value_ty: Type,
+        values: BTreeMap<SmolStr, Expression>,
+    },
 
     PathData(Path),
 
@@ -713,6 +904,24 @@ pub enum Expression {
 
     ReturnStatement(Option<Box<Expression>>),
 
+    /// A `for` loop that evaluates the body for each element of the array.
+    /// The body can contain [`Expression::Break`] and [`Expression::Continue`] until
+    /// the `remove_return` pass, which changes the body so that it is of type bool and
+    /// the loop stops when it evaluates to false.
+    ForLoop {
+        /// This expression should have [`Type::Array`] type
+        array: Box<Expression>,
+        /// The local variable that holds the current element
+        variable: SmolStr,
+        /// The local variable that holds the current index, if any
+        index_variable: Option<SmolStr>,
+        body: Box<Expression>,
+    },
+    /// `break` within the body of a [`Expression::ForLoop`]
+    Break,
+    /// `continue` within the body of a [`Expression::ForLoop`]
+    Continue,
+
     LayoutCacheAccess {
         layout_cache_prop: NamedReference,
         index: usize,
@@ -732,6 +941,17 @@ pub enum Expression {
         rhs: Box<Expression>,
     },
 
+    /// A new array computed from the elements of `array`, such as `array.filter(x => x > 0)`
+    ArrayTransform {
+        op: ArrayTransformOp,
+        /// This expression should have [`Type::Array`] type
+        array: Box<Expression>,
+        /// The local variable that holds the current element when evaluating `expression`
+        variable: SmolStr,
+        /// The condition for `Filter`, the new element for `Map`, or the key for `SortBy`
+        expression: Box<Expression>,
+    },
+
     DebugHook {
         expression: Box<Expression>,
         id: SmolStr,
@@ -768,8 +988,18 @@ pub fn ty(&self) -> Type {
                 Type::Array(ty) => (*ty).clone(),
                 _ => Type::Invalid,
             },
+            Expression::MapIndex { map, .. } => match map.ty() {
+                Type::Map(ty) => (*ty).clone(),
+                _ => Type::Invalid,
+            },
             Expression::Cast { to, .. } => to.clone(),
             Expression::CodeBlock(sub) => sub.last().map_or(Type::Void, |e| e.ty()),
+            Expression::FunctionCall {
+                function:
+                    Callable::Builtin(BuiltinFunction::ArraySlice | BuiltinFunction::ArrayConcat),
+                arguments,
+                ..
+            } => arguments.first().map_or(Type::Invalid, |array| array.ty()),
             Expression::FunctionCall { function, .. } => match function.ty() {
                 Type::Function(f) | Type::Callback(f) => f.return_type.clone(),
                 _ => Type::Invalid,
@@ -844,6 +1074,7 @@ pub fn ty(&self) -> Type {
             Expression::UnaryOp { sub, .. } => sub.ty(),
             Expression::Array { element_ty, .. } => Type::Array(Rc::new(element_ty.clone())),
             Expression::Struct { ty, .. } => ty.clone().into(),
+            Expression::Map { value_ty, .. } => Type::Map(Rc::new(value_ty.clone())),
             Expression::PathData { .. } => Type::PathData,
             Expression::StoreLocalVariable { .. } => Type::Void,
             Expression::ReadLocalVariable { ty, .. } => ty.clone(),
@@ -853,10 +1084,16 @@ pub fn ty(&self) -> Type {
             Expression::EnumerationValue(value) => Type::Enumeration(value.enumeration.clone()),
             // invalid because the expression is unreachable
             Expression::ReturnStatement(_) => Type::Invalid,
+            Expression::ForLoop { .. } => Type::Void,
+            Expression::Break | Expression::Continue => Type::Invalid,
             Expression::LayoutCacheAccess { .. } => Type::LogicalLength,
             Expression::ComputeLayoutInfo(..) => typeregister::layout_info_type().into(),
             Expression::SolveLayout(..) => Type::LayoutCache,
             Expression::MinMax { ty, .. } => ty.clone(),
+            Expression::ArrayTransform { op: ArrayTransformOp::Map, expression, .. } => {
+                Type::Array(Rc::new(expression.ty()))
+            }
+            Expression::ArrayTransform { array, .. } => array.ty(),
             Expression::EmptyComponentFactory => Type::ComponentFactory,
             Expression::DebugHook { expression, .. } => expression.ty(),
         }
@@ -878,6 +1115,10 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                 visitor(array);
                 visitor(index);
             }
+            Expression::MapIndex { map, key } => {
+                visitor(map);
+                visitor(key);
+            }
             Expression::RepeaterIndexReference { .. } => {}
             Expression::RepeaterModelReference { .. } => {}
             Expression::Cast { from, .. } => visitor(from),
@@ -912,6 +1153,11 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                     visitor(x);
                 }
             }
+            Expression::Map { values, .. } => {
+                for x in values.values() {
+                    visitor(x);
+                }
+            }
             Expression::PathData(data) => match data {
                 Path::Elements(elements) => {
                     for element in elements {
@@ -943,6 +1189,11 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
             Expression::ReturnStatement(expr) => {
                 expr.as_deref().map(visitor);
             }
+            Expression::ForLoop { array, body, .. } => {
+                visitor(array);
+                visitor(body);
+            }
+            Expression::Break | Expression::Continue => {}
             Expression::LayoutCacheAccess { repeater_index, .. } => {
                 repeater_index.as_deref().map(visitor);
             }
@@ -952,6 +1203,10 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                 visitor(lhs);
                 visitor(rhs);
             }
+            Expression::ArrayTransform { array, expression, .. } => {
+                visitor(array);
+                visitor(expression);
+            }
             Expression::EmptyComponentFactory => {}
             Expression::DebugHook { expression, .. } => visitor(expression),
         }
@@ -972,6 +1227,10 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                 visitor(array);
                 visitor(index);
             }
+            Expression::MapIndex { map, key } => {
+                visitor(map);
+                visitor(key);
+            }
             Expression::RepeaterIndexReference { .. } => {}
             Expression::RepeaterModelReference { .. } => {}
             Expression::Cast { from, .. } => visitor(from),
@@ -1006,6 +1265,11 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                     visitor(x);
                 }
             }
+            Expression::Map { values, .. } => {
+                for x in values.values_mut() {
+                    visitor(x);
+                }
+            }
             Expression::PathData(data) => match data {
                 Path::Elements(elements) => {
                     for element in elements {
@@ -1040,6 +1304,11 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
             Expression::ReturnStatement(expr) => {
                 expr.as_deref_mut().map(visitor);
             }
+            Expression::ForLoop { array, body, .. } => {
+                visitor(array);
+                visitor(body);
+            }
+            Expression::Break | Expression::Continue => {}
             Expression::LayoutCacheAccess { repeater_index, .. } => {
                 repeater_index.as_deref_mut().map(visitor);
             }
@@ -1049,6 +1318,10 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                 visitor(lhs);
                 visitor(rhs);
             }
+            Expression::ArrayTransform { array, expression, .. } => {
+                visitor(array);
+                visitor(expression);
+            }
             Expression::EmptyComponentFactory => {}
             Expression::DebugHook { expression, .. } => visitor(expression),
         }
@@ -1081,6 +1354,7 @@ pub fn is_constant(&self) -> bool {
             Expression::FunctionParameterReference { .. } => true,
             Expression::StructFieldAccess { base, .. } => base.is_constant(),
             Expression::ArrayIndex { array, index } => array.is_constant() && index.is_constant(),
+            Expression::MapIndex { map, key } => map.is_constant() && key.is_constant(),
             Expression::Cast { from, .. } => from.is_constant(),
             // This is conservative: the return value is the last expression in the block, but
             // we kind of mean "pure" here too, so ensure the whole body is OK.
@@ -1105,6 +1379,7 @@ pub fn is_constant(&self) -> bool {
             //Expression::Array { values, .. } => values.iter().all(Expression::is_constant),
             Expression::Array { .. } => false,
             Expression::Struct { values, .. } => values.iter().all(|(_, v)| v.is_constant()),
+            Expression::Map { values, .. } => values.values().all(Expression::is_constant),
             Expression::PathData(data) => match data {
                 Path::Elements(elements) => elements
                     .iter()
@@ -1126,11 +1401,16 @@ pub fn is_constant(&self) -> bool {
             Expression::ReturnStatement(expr) => {
                 expr.as_ref().map_or(true, |expr| expr.is_constant())
             }
+            Expression::ForLoop { array, body, .. } => array.is_constant() && body.is_constant(),
+            Expression::Break | Expression::Continue => true,
             // TODO:  detect constant property within layouts
             Expression::LayoutCacheAccess { .. } => false,
             Expression::ComputeLayoutInfo(..) => false,
             Expression::SolveLayout(..) => false,
             Expression::MinMax { lhs, rhs, .. } => lhs.is_constant() && rhs.is_constant(),
+            Expression::ArrayTransform { array, expression, .. } => {
+                array.is_constant() && expression.is_constant()
+            }
             Expression::EmptyComponentFactory => true,
             Expression::DebugHook { .. } => false,
         }
@@ -1271,6 +1551,22 @@ pub fn maybe_convert_to(
                 },
                 _ => unreachable!(),
             }
+        } else if let (Type::Map(value_ty), Expression::Map { .. } | Expression::Struct { .. }) =
+            (&target_type, &self)
+        {
+            // Map literals, or struct literals whose field names are then used as keys
+            let values = match self {
+                Expression::Map { values, .. } => values,
+                Expression::Struct { values, .. } => values.into_iter().collect(),
+                _ => unreachable!(),
+            };
+            Expression::Map {
+                values: values
+                    .into_iter()
+                    .map(|(k, e)| (k, e.maybe_convert_to((**value_ty).clone(), node, diag)))
+                    .collect(),
+                value_ty: (**value_ty).clone(),
+            }
         } else if let (Type::Struct(struct_type), Expression::Struct { values, .. }) =
             (&target_type, &self)
         {
@@ -1328,7 +1624,7 @@ pub fn default_value_for_type(ty: &Type) -> Expression {
             Type::Void => Expression::CodeBlock(vec![]),
             Type::Float32 => Expression::NumberLiteral(0., Unit::None),
             Type::String => Expression::StringLiteral(SmolStr::default()),
-            Type::Int32 | Type::Color | Type::UnitProduct(_) => Expression::Cast {
+            Type::Int32 | Type::Color | Type::DateTime | Type::UnitProduct(_) => Expression::Cast {
                 from: Box::new(Expression::NumberLiteral(0., Unit::None)),
                 to: ty.clone(),
             },
@@ -1349,6 +1645,9 @@ pub fn default_value_for_type(ty: &Type) -> Expression {
             Type::Array(element_ty) => {
                 Expression::Array { element_ty: (**element_ty).clone(), values: vec![] }
             }
+            Type::Map(value_ty) => {
+                Expression::Map { value_ty: (**value_ty).clone(), values: Default::default() }
+            }
             Type::Struct(s) => Expression::Struct {
                 ty: s.clone(),
                 values: s
@@ -1428,6 +1727,7 @@ pub fn try_set_rw(
             Expression::StructFieldAccess { base, .. } => base.try_set_rw(ctx, what, node),
             Expression::RepeaterModelReference { .. } => true,
             Expression::ArrayIndex { array, .. } => array.try_set_rw(ctx, what, node),
+            Expression::ReadLocalVariable { .. } => true,
             _ => {
                 ctx.diag.push_error(format!("{what} needs to be done on a property"), node);
                 false
@@ -1594,6 +1894,8 @@ pub enum EasingCurve {
     #[default]
     Linear,
     CubicBezier(f32, f32, f32, f32),
+    /// stiffness, damping, mass
+    Spring(f32, f32, f32),
     EaseInElastic,
     EaseOutElastic,
     EaseInOutElastic,
@@ -1647,6 +1949,12 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             pretty_print(f, index)?;
             write!(f, "]")
         }
+        Expression::MapIndex { map, key } => {
+            pretty_print(f, map)?;
+            write!(f, "[")?;
+            pretty_print(f, key)?;
+            write!(f, "]")
+        }
         Expression::Cast { from, to } => {
             write!(f, "(")?;
             pretty_print(f, from)?;
@@ -1718,6 +2026,15 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             }
             write!(f, " }}")
         }
+        Expression::Map { value_ty: _, values } => {
+            write!(f, "{{ ")?;
+            for (key, e) in values {
+                write!(f, "{key:?}: ")?;
+                pretty_print(f, e)?;
+                write!(f, ", ")?;
+            }
+            write!(f, " }}")
+        }
         Expression::PathData(data) => write!(f, "{data:?}"),
         Expression::EasingCurve(e) => write!(f, "{e:?}"),
         Expression::LinearGradient { angle, stops } => {
@@ -1749,6 +2066,19 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             write!(f, "return ")?;
             e.as_ref().map(|e| pretty_print(f, e)).unwrap_or(Ok(()))
         }
+        Expression::ForLoop { array, variable, index_variable, body } => {
+            write!(f, "for {variable}")?;
+            if let Some(index_variable) = index_variable {
+                write!(f, "[{index_variable}]")?;
+            }
+            write!(f, " in ")?;
+            pretty_print(f, array)?;
+            write!(f, " {{ ")?;
+            pretty_print(f, body)?;
+            write!(f, " }}")
+        }
+        Expression::Break => write!(f, "break"),
+        Expression::Continue => write!(f, "continue"),
         Expression::LayoutCacheAccess { layout_cache_prop, index, repeater_index } => {
             write!(
                 f,
@@ -1770,6 +2100,17 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             pretty_print(f, rhs)?;
             write!(f, ")")
         }
+        Expression::ArrayTransform { op, array, variable, expression } => {
+            pretty_print(f, array)?;
+            match op {
+                ArrayTransformOp::Filter => write!(f, ".filter(")?,
+                ArrayTransformOp::Map => write!(f, ".map(")?,
+                ArrayTransformOp::SortBy => write!(f, ".sort-by(")?,
+            }
+            write!(f, "{variable} => ")?;
+            pretty_print(f, expression)?;
+            write!(f, ")")
+        }
         Expression::EmptyComponentFactory => write!(f, "<empty-component-factory>"),
         Expression::DebugHook { expression, id } => {
             write!(f, "debug-hook(")?;
@@ -466,7 +466,7 @@ pub fn escape_string(str: &str) -> String {
     }
 }
 
-use crate::expression_tree::{BuiltinFunction, EasingCurve, MinMaxOp};
+use crate::expression_tree::{ArrayTransformOp, BuiltinFunction, EasingCurve, MinMaxOp};
 use crate::langtype::{Enumeration, EnumerationValue, NativeClass, Type};
 use crate::layout::Orientation;
 use crate::llr::{
@@ -509,6 +509,7 @@ fn cpp_type(&self) -> Option<SmolStr> {
             Type::String => Some("slint::SharedString".into()),
             Type::Color => Some("slint::Color".into()),
             Type::Duration => Some("std::int64_t".into()),
+            Type::DateTime => Some("slint::DateTime".into()),
             Type::Angle => Some("float".into()),
             Type::PhysicalLength => Some("float".into()),
             Type::LogicalLength => Some("float".into()),
@@ -532,6 +533,9 @@ fn cpp_type(&self) -> Option<SmolStr> {
             Type::Array(i) => {
                 Some(format_smolstr!("std::shared_ptr<slint::Model<{}>>", i.cpp_type()?))
             }
+            Type::Map(v) => {
+                Some(format_smolstr!("std::map<slint::SharedString, {}>", v.cpp_type()?))
+            }
             Type::Image => Some("slint::Image".into()),
             Type::Enumeration(enumeration) => {
                 if enumeration.node.is_some() {
@@ -594,10 +598,16 @@ fn property_set_value_code(
     ctx: &EvaluationContext,
 ) -> String {
     let prop = access_member(property, ctx);
-    if let Some((animation, map)) = &ctx.property_info(property).animation {
-        let mut animation = (*animation).clone();
+    if let Some(((animation, keyframes), map)) = &ctx.property_info(property).animation {
+        let mut animation = animation.clone();
         map.map_expression(&mut animation);
         let animation_code = compile_expression(&animation, ctx);
+        if let Some(keyframes) = keyframes {
+            let mut keyframes = keyframes.clone();
+            map.map_expression(&mut keyframes);
+            let keyframes_code = compile_expression(&keyframes, ctx);
+            return format!("{prop}.set_animated_value({value_expr}, {animation_code}, slint::private_api::to_keyframes({keyframes_code}))");
+        }
         return format!("{prop}.set_animated_value({value_expr}, {animation_code})");
     }
     format!("{prop}.set({value_expr})")
@@ -650,20 +660,32 @@ fn handle_property_init(
                 format!("slint::private_api::set_state_binding({prop_access}, {binding_code});")
             } else {
                 match &binding_expression.animation {
-                    Some(llr::Animation::Static(anim)) => {
+                    Some(llr::Animation::Static(anim, None)) => {
                         let anim = compile_expression(anim, ctx);
                         format!("{prop_access}.set_animated_binding({binding_code}, {anim});")
                     }
+                    Some(llr::Animation::Static(anim, Some(keyframes))) => {
+                        let anim = compile_expression(anim, ctx);
+                        let keyframes = compile_expression(keyframes, ctx);
+                        format!("{prop_access}.set_animated_binding({binding_code}, {anim}, slint::private_api::to_keyframes({keyframes}));")
+                    }
                     Some(llr::Animation::Transition (
-                        anim
+                        anim, keyframes
                     )) => {
                         let anim = compile_expression(anim, ctx);
+                        let set_keyframes = keyframes.as_ref().map_or_else(Default::default, |keyframes| {
+                            format!(
+                                "*keyframes = slint::private_api::to_keyframes({});",
+                                compile_expression(keyframes, ctx)
+                            )
+                        });
                         format!(
                             "{prop_access}.set_animated_binding_for_transition({binding_code},
-                            [this](uint64_t *start_time) -> slint::cbindgen_private::PropertyAnimation {{
+                            [this](uint64_t *start_time, [[maybe_unused]] auto *keyframes) -> slint::cbindgen_private::PropertyAnimation {{
                                 [[maybe_unused]] auto self = this;
                                 auto [anim, time] = {anim};
                                 *start_time = time;
+                                {set_keyframes}
                                 return anim;
                             }});",
                         )
@@ -2141,6 +2163,17 @@ fn generate_sub_component(
             format!(
                 "self->{repeater_id}.ensure_updated_listview(self, &{vp_w}, &{vp_h}, &{vp_y}, {lv_w}.get(), {lv_h}.get());"
             )
+        } else if let Some(gridview) = &repeated.gridview {
+            let vp_y = access_member(&gridview.viewport_y, &ctx);
+            let vp_h = access_member(&gridview.viewport_height, &ctx);
+            let gv_h = access_member(&gridview.gridview_height, &ctx);
+            let columns = access_member(&gridview.columns, &ctx);
+            let cell_h = access_member(&gridview.cell_height, &ctx);
+            let spacing = access_member(&gridview.spacing, &ctx);
+
+            format!(
+                "self->{repeater_id}.ensure_updated_gridview(self, &{vp_h}, {vp_y}.get(), {gv_h}.get(), {columns}.get(), {cell_h}.get(), {spacing}.get());"
+            )
         } else {
             format!("self->{repeater_id}.ensure_updated(self);")
         };
@@ -2558,6 +2591,49 @@ fn generate_repeated_component(
         ));
     }
 
+    if let Some(flex) = &repeated.flexbox_item {
+        let value = |p: &Option<llr::PropertyReference>, default: &str| match p {
+            Some(p) => format!("{}.get()", access_member(p, &ctx)),
+            None => default.into(),
+        };
+        repeater_struct.members.push((
+            Access::Public, // Because Repeater accesses it
+            Declaration::Function(Function {
+                name: "flexbox_layout_data".into(),
+                signature: "(slint::cbindgen_private::Orientation o) const -> slint::cbindgen_private::FlexboxLayoutCellData".to_owned(),
+                statements: Some(vec![
+                    "[[maybe_unused]] auto self = this;".into(),
+                    "slint::cbindgen_private::FlexboxLayoutCellData cell;".into(),
+                    "cell.constraint = layout_info({&static_vtable, const_cast<void *>(static_cast<const void *>(this))}, o);".into(),
+                    format!("cell.flex_grow = {};", value(&flex.flex_grow, "0")),
+                    format!("cell.flex_shrink = {};", value(&flex.flex_shrink, "1")),
+                    format!("cell.flex_basis = {};", value(&flex.flex_basis, "-1")),
+                    "return cell;".into(),
+                ]),
+                ..Function::default()
+            }),
+        ));
+    }
+
+    if let Some(exit) = &repeated.exit_transition {
+        let mut statements = vec!["[[maybe_unused]] auto self = this;".to_string()];
+        statements.extend(exit.frozen_properties.iter().map(|p| {
+            let p = access_member(p, &ctx);
+            format!("{p}.set({p}.get_internal());")
+        }));
+        statements.push(format!("{}.set(true);", access_member(&exit.prop_leaving, &ctx)));
+        statements.push(format!("return {}.get();", access_member(&exit.prop_duration, &ctx)));
+        repeater_struct.members.push((
+            Access::Public, // Because Repeater accesses it
+            Declaration::Function(Function {
+                name: "start_exit_transition".into(),
+                signature: "() const -> int64_t".to_owned(),
+                statements: Some(statements),
+                ..Function::default()
+            }),
+        ));
+    }
+
     if let Some(index_prop) = repeated.index_prop {
         // Override default subtree_index function implementation
         let subtree_index_func = repeater_struct
@@ -3133,6 +3209,9 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             format!("auto {} = {};", ident(name), compile_expression(value, ctx))
         }
         Expression::ReadLocalVariable { name, .. } => ident(name).to_string(),
+        Expression::LocalVariableAssignment { name, value } => {
+            format!("{} = {}", ident(name), compile_expression(value, ctx))
+        }
         Expression::StructFieldAccess { base, name } => match base.ty(ctx) {
             Type::Struct(s)=> {
                 if s.name.is_none() {
@@ -3153,6 +3232,12 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 compile_expression(array, ctx), compile_expression(index, ctx)
             )
         },
+        Expression::MapIndex { map, key } => {
+            format!(
+                "slint::private_api::access_map_value({}, {})",
+                compile_expression(map, ctx), compile_expression(key, ctx)
+            )
+        },
         Expression::Cast { from, to } => {
             let f = compile_expression(from, ctx);
             match (from.ty(ctx), to) {
@@ -3169,6 +3254,9 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 (Type::Float32, Type::Color) => {
                     format!("slint::Color::from_argb_encoded({f})")
                 }
+                (Type::Float32, Type::DateTime) => {
+                    format!("slint::DateTime::from_timestamp_millis({f})")
+                }
                 (Type::Color, Type::Brush) => {
                     format!("slint::Brush({f})")
                 }
@@ -3250,10 +3338,26 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 0 => String::new(),
                 1 => compile_expression(&sub[0], ctx),
                 len => {
+                    let mut assigned = BTreeSet::new();
+                    for e in sub {
+                        e.visit_recursive(&mut |e| {
+                            if let Expression::LocalVariableAssignment { name, .. } = e {
+                                assigned.insert(name.clone());
+                            }
+                        });
+                    }
                     let mut x = sub.iter().enumerate().map(|(i, e)| {
                         if i == len - 1 {
                             return_compile_expression(e, ctx, None) + ";"
                         }
+                        else if let Some((name, value)) = match e {
+                            Expression::StoreLocalVariable { name, value } if assigned.contains(name) => Some((name, value)),
+                            _ => None,
+                        } {
+                            // The variable is modified later, so it must have the exact type
+                            let ty = value.ty(ctx).cpp_type().unwrap_or_else(|| "auto".into());
+                            format!("{ty} {} = {};", ident(name), compile_expression(value, ctx))
+                        }
                         else {
                             compile_expression(e, ctx)
                         }
@@ -3362,6 +3466,44 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 false_code
             )
         }
+        Expression::ForLoop { array, variable, index_variable, body } => {
+            let index_variable = index_variable.as_ref().map_or(String::new(), |index_variable| {
+                format!("int {} = index;", ident(index_variable))
+            });
+            format!(
+                r#"[&] {{ auto model = {array}; for (long int index = 0, count = slint::private_api::model_length(model); index < count; ++index) {{ auto {variable} = slint::private_api::access_array_index(model, index); {index_variable} if (!({body})) break; }} }}()"#,
+                array = compile_expression(array, ctx),
+                variable = ident(variable),
+                body = compile_expression(body, ctx),
+            )
+        }
+        Expression::ArrayTransform { op, array, variable, expression } => {
+            let Type::Array(element_ty) = array.ty(ctx) else { unreachable!() };
+            let element_ty = element_ty.cpp_type().unwrap();
+            let Type::Array(result_ty) = expr.ty(ctx) else { unreachable!() };
+            let result_ty = result_ty.cpp_type().unwrap();
+            let function = compile_array_element_function(variable, &element_ty, expression, ctx);
+            // The functions are called from within the binding once, so that the properties they
+            // read are tracked as dependencies of the binding.
+            let (model, evaluate) = match op {
+                ArrayTransformOp::Filter => (
+                    format!("slint::FilterModel<{element_ty}>>(std::move(model), std::move(function))"),
+                    "result->row_count();",
+                ),
+                ArrayTransformOp::Map => (
+                    format!("slint::MapModel<{element_ty}, {result_ty}>>(std::move(model), std::move(function))"),
+                    "for (size_t row = 0, count = result->row_count(); row < count; ++row) result->row_data(row);",
+                ),
+                ArrayTransformOp::SortBy => (
+                    format!("slint::SortModel<{element_ty}>>(std::move(model), [function = std::move(function)](const auto &a, const auto &b) {{ return function(a) < function(b); }})"),
+                    "if (result->row_count() > 0) result->row_data(0);",
+                ),
+            };
+            format!(
+                r#"[&]() -> std::shared_ptr<slint::Model<{result_ty}>> {{ std::shared_ptr<slint::Model<{element_ty}>> model = {array}; {function} auto result = std::make_shared<{model}; {evaluate} return result; }}()"#,
+                array = compile_expression(array, ctx),
+            )
+        }
         Expression::Array { element_ty, values, as_model } => {
             let ty = element_ty.cpp_type().unwrap();
             let mut val = values.iter().map(|e| format!("{ty} ( {expr} )", expr = compile_expression(e, ctx), ty = ty));
@@ -3381,6 +3523,17 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 )
             }
         }
+        Expression::Map { value_ty, values } => {
+            let ty = value_ty.cpp_type().unwrap();
+            let mut val = values.iter().map(|(k, e)| {
+                format!(
+                    r#"{{ slint::SharedString(u8"{}"), {ty} ( {expr} ) }}"#,
+                    escape_string(k),
+                    expr = compile_expression(e, ctx)
+                )
+            });
+            format!("std::map<slint::SharedString, {ty}>{{ {} }}", val.join(", "))
+        }
         Expression::Struct { ty, values } => {
             if ty.name.is_none()  {
                 let mut elem = ty.fields.iter().map(|(k, t)| {
@@ -3408,6 +3561,9 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
         Expression::EasingCurve(EasingCurve::CubicBezier(a, b, c, d)) => format!(
             "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::CubicBezier, {a}, {b}, {c}, {d})"
         ),
+        Expression::EasingCurve(EasingCurve::Spring(k, d, m)) => format!(
+            "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Spring_Body{{{{ {k}, {d}, {m} }}}})"
+        ),
         Expression::EasingCurve(EasingCurve::EaseInElastic) => "slint::cbindgen_private::EasingCurve::Tag::EaseInElastic".into(),
         Expression::EasingCurve(EasingCurve::EaseOutElastic) => "slint::cbindgen_private::EasingCurve::Tag::EaseOutElastic".into(),
         Expression::EasingCurve(EasingCurve::EaseInOutElastic) => "slint::cbindgen_private::EasingCurve::Tag::EaseInOutElastic".into(),
@@ -3458,12 +3614,14 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             repeater_indices,
             elements,
             orientation,
+            is_flexbox,
             sub_expression,
         } => box_layout_function(
             cells_variable,
             repeater_indices.as_ref().map(SmolStr::as_str),
             elements.as_ref(),
             *orientation,
+            *is_flexbox,
             sub_expression,
             ctx,
         ),
@@ -3513,6 +3671,26 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
     }
 }
 
+/// Generates a `function` lambda that evaluates `expression` for an element of an array, passed as
+/// `variable`. The lambda only keeps a weak reference to the component.
+fn compile_array_element_function(
+    variable: &SmolStr,
+    element_ty: &str,
+    expression: &llr::Expression,
+    ctx: &EvaluationContext,
+) -> String {
+    let return_ty = expression.ty(ctx).cpp_type().unwrap();
+    let variable = ident(variable);
+    let value = compile_expression(expression, ctx);
+    if ctx.current_global().is_some() {
+        format!("auto function = [=, this](const {element_ty} &{variable}) -> {return_ty} {{ return {value}; }};")
+    } else {
+        format!(
+            "auto function = [=, self_weak = self->self_weak](const {element_ty} &{variable}) -> {return_ty} {{ if (auto lock = self_weak.lock()) {{ return {value}; }} return {{}}; }};"
+        )
+    }
+}
+
 fn compile_builtin_function_call(
     function: BuiltinFunction,
     arguments: &[llr::Expression],
@@ -3659,6 +3837,30 @@ fn compile_builtin_function_call(
         BuiltinFunction::StringToUppercase => {
             format!("{}.to_uppercase()", a.next().unwrap())
         }
+        BuiltinFunction::StringTrim => {
+            format!("[](const auto &a){{ slint::SharedString out; slint::cbindgen_private::slint_string_trim(&a, &out); return out; }}({})", a.next().unwrap())
+        }
+        BuiltinFunction::StringContains => {
+            format!("[](const auto &a, const auto &b){{ return slint::cbindgen_private::slint_string_contains(&a, &b); }}({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringStartsWith => {
+            format!("[](const auto &a, const auto &b){{ return slint::cbindgen_private::slint_string_starts_with(&a, &b); }}({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringEndsWith => {
+            format!("[](const auto &a, const auto &b){{ return slint::cbindgen_private::slint_string_ends_with(&a, &b); }}({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringReplace => {
+            format!("[](const auto &a, const auto &from, const auto &to){{ slint::SharedString out; slint::cbindgen_private::slint_string_replace(&a, &from, &to, &out); return out; }}({}, {}, {})", a.next().unwrap(), a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringSubstring => {
+            format!("[](const auto &a, int start, int length){{ slint::SharedString out; slint::cbindgen_private::slint_string_substring(&a, start, length, &out); return out; }}({}, {}, {})", a.next().unwrap(), a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringSplit => {
+            format!("[](const auto &a, const auto &separator){{ slint::SharedVector<slint::SharedString> out; slint::cbindgen_private::slint_string_split(&a, &separator, &out); return std::shared_ptr<slint::Model<slint::SharedString>>(std::make_shared<slint::VectorModel<slint::SharedString>>(std::vector<slint::SharedString>(out.begin(), out.end()))); }}({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::StringMatches => {
+            format!("[](const auto &a, const auto &pattern){{ return slint::cbindgen_private::slint_string_matches(&a, &pattern); }}({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
         BuiltinFunction::ColorRgbaStruct => {
             format!("{}.to_argb_uint()", a.next().unwrap())
         }
@@ -3686,6 +3888,30 @@ fn compile_builtin_function_call(
         BuiltinFunction::ArrayLength => {
             format!("slint::private_api::model_length({})", a.next().unwrap())
         }
+        BuiltinFunction::MapHasKey => {
+            let (map, key) = (a.next().unwrap(), a.next().unwrap());
+            format!("{map}.contains({key})")
+        }
+        BuiltinFunction::MapKeys => {
+            format!("slint::private_api::map_keys({})", a.next().unwrap())
+        }
+        BuiltinFunction::ArraySlice => {
+            let (array, start, end) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            format!("slint::private_api::array_slice({array}, {start}, {end})")
+        }
+        BuiltinFunction::ArrayConcat => {
+            format!("slint::private_api::array_concat({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::ArrayIndexOf => {
+            format!("slint::private_api::array_index_of({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::ArrayContains => {
+            format!(
+                "(slint::private_api::array_index_of({}, {}) >= 0)",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
         BuiltinFunction::Rgb => {
             format!("slint::Color::from_argb_uint8(std::clamp(static_cast<float>({a}) * 255., 0., 255.), std::clamp(static_cast<int>({r}), 0, 255), std::clamp(static_cast<int>({g}), 0, 255), std::clamp(static_cast<int>({b}), 0, 255))",
                 r = a.next().unwrap(),
@@ -3764,6 +3990,11 @@ fn compile_builtin_function_call(
                 a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
             )
         }
+        BuiltinFunction::FormatNumber => {
+            format!("[](double value, auto style, int min_fraction, int max_fraction, bool grouping, const auto &currency) {{ slint::SharedString out; slint::cbindgen_private::slint_format_number(value, style, min_fraction, max_fraction, grouping, &currency, &out); return out; }}({}, {}, {}, {}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
+            )
+        }
         BuiltinFunction::DateNow => {
             "[] { int32_t d=0, m=0, y=0; slint::cbindgen_private::slint_date_time_date_now(&d, &m, &y); return std::make_shared<slint::private_api::ArrayModel<3,int32_t>>(d, m, y); }()".into()
         }
@@ -3779,6 +4010,45 @@ fn compile_builtin_function_call(
                 a.next().unwrap(), a.next().unwrap()
             )
         }
+        BuiltinFunction::DateTimeNow => "slint::DateTime::now()".into(),
+        BuiltinFunction::ParseDateTime => {
+            format!("slint::DateTime::parse({}, {}).value_or(slint::DateTime())", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::FormatDateTime => {
+            format!("({}).format({})", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::DateTimeYear => format!("({}).year()", a.next().unwrap()),
+        BuiltinFunction::DateTimeMonth => format!("({}).month()", a.next().unwrap()),
+        BuiltinFunction::DateTimeDay => format!("({}).day()", a.next().unwrap()),
+        BuiltinFunction::DateTimeHour => format!("({}).hour()", a.next().unwrap()),
+        BuiltinFunction::DateTimeMinute => format!("({}).minute()", a.next().unwrap()),
+        BuiltinFunction::DateTimeSecond => format!("({}).second()", a.next().unwrap()),
+        BuiltinFunction::DateTimeWeekday => format!("({}).weekday()", a.next().unwrap()),
+        BuiltinFunction::DateTimeUtcOffset => {
+            format!("(std::int64_t(({}).utc_offset().count()) * 1000)", a.next().unwrap())
+        }
+        BuiltinFunction::DateTimeWithUtcOffset => {
+            format!("({}).with_utc_offset(std::chrono::seconds(std::int64_t({}) / 1000))", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::DateTimeToLocal => format!("({}).to_local()", a.next().unwrap()),
+        BuiltinFunction::DateTimeWithDate => {
+            format!(
+                "[](const slint::DateTime &dt, int y, int m, int d) {{ return dt.with_date(y, m, d).value_or(dt); }}({}, {}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::DateTimeWithTime => {
+            format!(
+                "[](const slint::DateTime &dt, int h, int m, int s) {{ return dt.with_time(h, m, s).value_or(dt); }}({}, {}, {}, {})",
+                a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap()
+            )
+        }
+        BuiltinFunction::DateTimeAdd => {
+            format!("(({}) + std::chrono::milliseconds(std::int64_t({})))", a.next().unwrap(), a.next().unwrap())
+        }
+        BuiltinFunction::DateTimeDiff => {
+            format!("(({}) - ({})).count()", a.next().unwrap(), a.next().unwrap())
+        }
         BuiltinFunction::SetTextInputFocused => {
             format!("{}.set_text_input_focused({})", access_window_field(ctx), a.next().unwrap())
         }
@@ -4003,12 +4273,13 @@ fn box_layout_function(
     repeated_indices: Option<&str>,
     elements: &[Either<llr::Expression, llr::RepeatedElementIdx>],
     orientation: Orientation,
+    is_flexbox: bool,
     sub_expression: &llr::Expression,
     ctx: &llr_EvaluationContext<CppGeneratorContext>,
 ) -> String {
     let repeated_indices = repeated_indices.map(ident);
-    let mut push_code =
-        "std::vector<slint::cbindgen_private::BoxLayoutCellData> cells_vector;".to_owned();
+    let cell_type = if is_flexbox { "FlexboxLayoutCellData" } else { "BoxLayoutCellData" };
+    let mut push_code = format!("std::vector<slint::cbindgen_private::{cell_type}> cells_vector;");
     let mut repeater_idx = 0usize;
 
     for item in elements {
@@ -4038,11 +4309,18 @@ fn box_layout_function(
                     .unwrap();
                 }
                 repeater_idx += 1;
+                let layout_data = if is_flexbox {
+                    format!(
+                        "slint::private_api::flexbox_layout_data(sub_comp, {})",
+                        to_cpp_orientation(orientation)
+                    )
+                } else {
+                    format!("sub_comp->box_layout_data({})", to_cpp_orientation(orientation))
+                };
                 write!(
                     push_code,
-                    "self->repeater_{id}.for_each([&](const auto &sub_comp){{ cells_vector.push_back(sub_comp->box_layout_data({o})); }});",
+                    "self->repeater_{id}.for_each([&](const auto &sub_comp){{ cells_vector.push_back({layout_data}); }});",
                     id = repeater,
-                    o = to_cpp_orientation(orientation),
                 )
                 .unwrap();
             }
@@ -4058,9 +4336,10 @@ fn box_layout_function(
         format!("std::array<int, {}> {}_array;", 2 * repeater_idx, ri)
     });
     format!(
-        "[&]{{ {} {} slint::cbindgen_private::Slice<slint::cbindgen_private::BoxLayoutCellData>{}{{cells_vector.data(), cells_vector.size()}}; return {}; }}()",
+        "[&]{{ {} {} slint::cbindgen_private::Slice<slint::cbindgen_private::{}>{}{{cells_vector.data(), cells_vector.size()}}; return {}; }}()",
         ri,
         push_code,
+        cell_type,
         ident(cells_variable),
         compile_expression(sub_expression, ctx)
     )
@@ -12,7 +12,9 @@
    this is usually a local variable to the init code that shouldn't rbe relied upon by the binding code.
 */
 
-use crate::expression_tree::{BuiltinFunction, EasingCurve, MinMaxOp, OperatorClass};
+use crate::expression_tree::{
+    ArrayTransformOp, BuiltinFunction, EasingCurve, MinMaxOp, OperatorClass,
+};
 use crate::langtype::{Enumeration, EnumerationValue, Struct, Type};
 use crate::layout::Orientation;
 use crate::llr::{
@@ -85,6 +87,7 @@ fn rust_primitive_type(ty: &Type) -> Option<proc_macro2::TokenStream> {
         Type::Color => Some(quote!(sp::Color)),
         Type::ComponentFactory => Some(quote!(slint::ComponentFactory)),
         Type::Duration => Some(quote!(i64)),
+        Type::DateTime => Some(quote!(sp::DateTime)),
         Type::Angle => Some(quote!(f32)),
         Type::PhysicalLength => Some(quote!(sp::Coord)),
         Type::LogicalLength => Some(quote!(sp::Coord)),
@@ -92,6 +95,7 @@ fn rust_primitive_type(ty: &Type) -> Option<proc_macro2::TokenStream> {
         Type::Percent => Some(quote!(f32)),
         Type::Bool => Some(quote!(bool)),
         Type::Image => Some(quote!(sp::Image)),
+        Type::Easing => Some(quote!(sp::EasingCurve)),
         Type::Struct(s) => {
             if let Some(name) = &s.name {
                 Some(struct_name_to_tokens(name))
@@ -106,6 +110,10 @@ fn rust_primitive_type(ty: &Type) -> Option<proc_macro2::TokenStream> {
             let inner = rust_primitive_type(o)?;
             Some(quote!(sp::ModelRc<#inner>))
         }
+        Type::Map(o) => {
+            let inner = rust_primitive_type(o)?;
+            Some(quote!(sp::BTreeMap<sp::SharedString, #inner>))
+        }
         Type::Enumeration(e) => {
             let i = ident(&e.name);
             if e.node.is_some() {
@@ -127,7 +135,6 @@ fn rust_primitive_type(ty: &Type) -> Option<proc_macro2::TokenStream> {
 fn rust_property_type(ty: &Type) -> Option<proc_macro2::TokenStream> {
     match ty {
         Type::LogicalLength => Some(quote!(sp::LogicalLength)),
-        Type::Easing => Some(quote!(sp::EasingCurve)),
         _ => rust_primitive_type(ty),
     }
 }
@@ -150,6 +157,17 @@ fn set_primitive_property_value(ty: &Type, value_expression: TokenStream) -> Tok
     }
 }
 
+/// Converts the `{at, easing, value}` keyframes of an animation to the keyframes of a property of type `ty`
+fn compile_keyframes(
+    keyframes: &llr::Expression,
+    ty: &Type,
+    ctx: &EvaluationContext,
+) -> TokenStream {
+    let keyframes = compile_expression(keyframes, ctx);
+    let value = set_primitive_property_value(ty, quote!(value));
+    quote!(slint::private_unstable_api::to_keyframes(#keyframes, |value| #value))
+}
+
 /// Generate the rust code for the given component.
 pub fn generate(
     doc: &Document,
@@ -527,14 +545,35 @@ fn handle_property_init(
                 } }
             } else {
                 match &binding_expression.animation {
-                    Some(llr::Animation::Static(anim)) => {
+                    Some(llr::Animation::Static(anim, None)) => {
                         let anim = compile_expression(anim, ctx);
                         quote! { {
                             #init_self_pin_ref
                             slint::private_unstable_api::set_animated_property_binding(#rust_property, &self_rc, #binding_tokens, #anim);
                         } }
                     }
-                    Some(llr::Animation::Transition(anim)) => {
+                    Some(llr::Animation::Static(anim, Some(keyframes))) => {
+                        let anim = compile_expression(anim, ctx);
+                        let keyframes = compile_keyframes(keyframes, prop_type, ctx);
+                        quote! { {
+                            #init_self_pin_ref
+                            slint::private_unstable_api::set_animated_property_binding_with_keyframes(#rust_property, &self_rc, #binding_tokens, #anim, #keyframes);
+                        } }
+                    }
+                    Some(llr::Animation::Transition(anim, Some(keyframes))) => {
+                        let anim = compile_expression(anim, ctx);
+                        let keyframes = compile_keyframes(keyframes, prop_type, ctx);
+                        quote! {
+                            slint::private_unstable_api::set_animated_property_binding_for_transition_with_keyframes(
+                                #rust_property, &self_rc, #binding_tokens, move |self_rc| {
+                                    #init_self_pin_ref
+                                    let (anim, time) = #anim;
+                                    (anim, time, #keyframes)
+                                }
+                            );
+                        }
+                    }
+                    Some(llr::Animation::Transition(anim, None)) => {
                         let anim = compile_expression(anim, ctx);
                         quote! {
                             slint::private_unstable_api::set_animated_property_binding_for_transition(
@@ -843,6 +882,20 @@ fn generate_sub_component(
                         #vp_w, #vp_h, #vp_y, #lv_w.get(), #lv_h
                     );
                 }
+            } else if let Some(gridview) = &repeated.gridview {
+                let vp_y = access_member(&gridview.viewport_y, &ctx).unwrap();
+                let vp_h = access_member(&gridview.viewport_height, &ctx).unwrap();
+                let gv_h = access_member(&gridview.gridview_height, &ctx).unwrap();
+                let columns = access_member(&gridview.columns, &ctx).unwrap();
+                let cell_h = access_member(&gridview.cell_height, &ctx).unwrap();
+                let spacing = access_member(&gridview.spacing, &ctx).unwrap();
+
+                quote! {
+                    #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).ensure_updated_gridview(
+                        || { #rep_inner_component_id::new(_self.self_weak.get().unwrap().clone()).unwrap().into() },
+                        #vp_h, #vp_y.get(), #gv_h.get(), #columns.get(), #cell_h.get(), #spacing.get()
+                    );
+                }
             } else {
                 quote! {
                     #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).ensure_updated(
@@ -1800,6 +1853,51 @@ fn box_layout_data(self: ::core::pin::Pin<&Self>, o: sp::Orientation)
         }
     };
 
+    let flexbox_layout_fn = repeated.flexbox_item.as_ref().map(|flex| {
+        let value = |p: &Option<llr::PropertyReference>, default: f32| match p {
+            Some(p) => {
+                let p = access_member(p, &ctx).unwrap();
+                quote!(#p.get() as _)
+            }
+            None => quote!(#default as _),
+        };
+        let flex_grow = value(&flex.flex_grow, 0.);
+        let flex_shrink = value(&flex.flex_shrink, 1.);
+        let flex_basis = value(&flex.flex_basis, -1.);
+        quote! {
+            fn flexbox_layout_data(self: ::core::pin::Pin<&Self>, o: sp::Orientation)
+                -> sp::FlexboxLayoutCellData
+            {
+                let _self = self;
+                sp::FlexboxLayoutCellData {
+                    constraint: self.as_ref().layout_info(o),
+                    flex_grow: #flex_grow,
+                    flex_shrink: #flex_shrink,
+                    flex_basis: #flex_basis,
+                }
+            }
+        }
+    });
+
+    let exit_transition_fn = repeated.exit_transition.as_ref().map(|exit| {
+        let frozen = exit
+            .frozen_properties
+            .iter()
+            .map(|p| access_member(p, &ctx).unwrap())
+            .collect::<Vec<_>>();
+        let leaving = access_member(&exit.prop_leaving, &ctx).unwrap();
+        let duration = access_member(&exit.prop_duration, &ctx).unwrap();
+        quote! {
+            fn start_exit_transition(&self) -> ::core::time::Duration {
+                let self_rc = self.self_weak.get().unwrap().upgrade().unwrap();
+                let _self = self_rc.as_pin_ref();
+                #(#frozen.set(#frozen.get_internal());)*
+                #leaving.set(true);
+                ::core::time::Duration::from_millis(#duration.get().max(0) as u64)
+            }
+        }
+    });
+
     let data_type = if let Some(data_prop) = repeated.data_prop {
         rust_primitive_type(&root_sc.properties[data_prop].ty).unwrap()
     } else {
@@ -1842,6 +1940,8 @@ fn init(&self) {
                 );
             }
             #extra_fn
+            #flexbox_layout_fn
+            #exit_transition_fn
         }
     )
 }
@@ -1875,10 +1975,18 @@ fn property_set_value_tokens(
     let prop = access_member(property, ctx);
     let prop_type = ctx.property_ty(property);
     let value_tokens = set_primitive_property_value(prop_type, value_tokens);
-    if let Some((animation, map)) = &ctx.property_info(property).animation {
-        let mut animation = (*animation).clone();
+    if let Some(((animation, keyframes), map)) = &ctx.property_info(property).animation {
+        let mut animation = animation.clone();
         map.map_expression(&mut animation);
         let animation_tokens = compile_expression(&animation, ctx);
+        if let Some(keyframes) = keyframes {
+            let mut keyframes = keyframes.clone();
+            map.map_expression(&mut keyframes);
+            let keyframes_tokens = compile_keyframes(&keyframes, prop_type, ctx);
+            return prop.then(|prop| {
+                quote!(#prop.set_animated_value_with_keyframes(#value_tokens as _, #animation_tokens, #keyframes_tokens))
+            });
+        }
         return prop
             .then(|prop| quote!(#prop.set_animated_value(#value_tokens as _, #animation_tokens)));
     }
@@ -2203,6 +2311,9 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 (Type::Float32, Type::Color) => {
                     quote!(sp::Color::from_argb_encoded(#f as u32))
                 }
+                (Type::Float32, Type::DateTime) => {
+                    quote!(sp::DateTime::from_timestamp_millis(#f as i64))
+                }
                 (Type::Color, Type::Brush) => {
                     quote!(slint::Brush::SolidColor(#f))
                 }
@@ -2336,8 +2447,28 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 x.row_data_tracked(index).unwrap_or_default()
             }})
         }
+        Expression::MapIndex { map, key } => {
+            let base_e = compile_expression(map, ctx);
+            let key_e = compile_expression(key, ctx);
+            quote!((#base_e).get(&(#key_e)).cloned().unwrap_or_default())
+        }
         Expression::CodeBlock(sub) => {
-            let map = sub.iter().map(|e| compile_expression(e, ctx));
+            let mut assigned = BTreeSet::new();
+            for e in sub {
+                e.visit_recursive(&mut |e| {
+                    if let Expression::LocalVariableAssignment { name, .. } = e {
+                        assigned.insert(name.clone());
+                    }
+                });
+            }
+            let map = sub.iter().map(|e| match e {
+                Expression::StoreLocalVariable { name, value } if assigned.contains(name) => {
+                    let value = compile_expression(value, ctx);
+                    let name = ident(name);
+                    quote!(let mut #name = #value;)
+                }
+                _ => compile_expression(e, ctx),
+            });
             quote!({ #(#map);* })
         }
         Expression::PropertyAssignment { property, value } => {
@@ -2477,6 +2608,61 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 }
             )
         }
+        Expression::ForLoop { array, variable, index_variable, body } => {
+            let mut is_assigned = false;
+            body.visit_recursive(&mut |e| {
+                is_assigned |= matches!(e, Expression::LocalVariableAssignment { name, .. } if name == variable);
+            });
+            let mutability = is_assigned.then(|| quote!(mut));
+            let array = compile_expression(array, ctx);
+            let variable = ident(variable);
+            let index_variable = index_variable.as_ref().map(|index_variable| {
+                let index_variable = ident(index_variable);
+                quote!(let #index_variable = index as i32;)
+            });
+            let body = compile_expression(body, ctx);
+            quote!({
+                let model = #array;
+                model.model_tracker().track_row_count_changes();
+                for index in 0..model.row_count() {
+                    let #mutability #variable = model.row_data_tracked(index).unwrap_or_default();
+                    #index_variable
+                    if !(#body) {
+                        break;
+                    }
+                }
+            })
+        }
+        Expression::ArrayTransform { op, array, variable, expression } => {
+            let Type::Array(element_ty) = array.ty(ctx) else { unreachable!() };
+            let element_ty = rust_primitive_type(&element_ty).unwrap();
+            let array = compile_expression(array, ctx);
+            let function = compile_array_element_function(variable, &element_ty, expression, ctx);
+            // The closures are called from within the binding once, so that the properties they
+            // read are tracked as dependencies of the binding.
+            let (model, evaluate) = match op {
+                ArrayTransformOp::Filter => {
+                    (quote!(sp::FilterModel::new(model, function)), quote!())
+                }
+                ArrayTransformOp::Map => (
+                    quote!(sp::MapModel::new(model, move |element: #element_ty| function(&element))),
+                    quote!(for row in 0..model.row_count() { model.row_data(row); }),
+                ),
+                ArrayTransformOp::SortBy => (
+                    quote!(sp::SortModel::new(model, move |a: &#element_ty, b: &#element_ty| {
+                        function(a).partial_cmp(&function(b)).unwrap_or(core::cmp::Ordering::Equal)
+                    })),
+                    quote!(model.row_data(0);),
+                ),
+            };
+            quote!({
+                let model = #array;
+                #function
+                let model = sp::ModelRc::new(#model);
+                #evaluate
+                model
+            })
+        }
         Expression::Array { values, element_ty, as_model } => {
             let val = values.iter().map(|e| compile_expression(e, ctx));
             if *as_model {
@@ -2490,6 +2676,14 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 quote!(sp::Slice::from_slice(&[#(#val),*]))
             }
         }
+        Expression::Map { value_ty, values } => {
+            let rust_value_ty = rust_primitive_type(value_ty).unwrap();
+            let keys = values.keys().map(|k| k.as_str());
+            let val = values.values().map(|e| compile_expression(e, ctx));
+            quote!(sp::BTreeMap::<sp::SharedString, #rust_value_ty>::from_iter([
+                #((sp::SharedString::from(#keys), (#val) as _)),*
+            ]))
+        }
         Expression::Struct { ty, values } => {
             let elem = ty.fields.keys().map(|k| values.get(k).map(|e| compile_expression(e, ctx)));
             if let Some(name) = &ty.name {
@@ -2525,12 +2719,20 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
             let name = ident(name);
             quote!(#name.clone())
         }
+        Expression::LocalVariableAssignment { name, value } => {
+            let value = compile_expression(value, ctx);
+            let name = ident(name);
+            quote!(#name = (#value) as _)
+        }
         Expression::EasingCurve(EasingCurve::Linear) => {
             quote!(sp::EasingCurve::Linear)
         }
         Expression::EasingCurve(EasingCurve::CubicBezier(a, b, c, d)) => {
             quote!(sp::EasingCurve::CubicBezier([#a, #b, #c, #d]))
         }
+        Expression::EasingCurve(EasingCurve::Spring(k, d, m)) => {
+            quote!(sp::EasingCurve::Spring([#k, #d, #m]))
+        }
         Expression::EasingCurve(EasingCurve::EaseInElastic) => {
             quote!(sp::EasingCurve::EaseInElastic)
         }
@@ -2597,12 +2799,14 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
             repeater_indices,
             elements,
             orientation,
+            is_flexbox,
             sub_expression,
         } => box_layout_function(
             cells_variable,
             repeater_indices.as_ref().map(SmolStr::as_str),
             elements.as_ref(),
             *orientation,
+            *is_flexbox,
             sub_expression,
             ctx,
         ),
@@ -2658,6 +2862,75 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
     }
 }
 
+/// Generates a `function` closure that evaluates `expression` for a reference to an element of an
+/// array, stored in the local `variable`. The closure only keeps a weak reference to the component,
+/// and a copy of the local variables and function arguments it uses.
+fn compile_array_element_function(
+    variable: &SmolStr,
+    element_ty: &TokenStream,
+    expression: &Expression,
+    ctx: &EvaluationContext,
+) -> TokenStream {
+    let mut declared = BTreeSet::from([variable.clone()]);
+    let mut used = BTreeSet::new();
+    let mut uses_arguments = false;
+    expression.visit_recursive(&mut |e| match e {
+        Expression::ReadLocalVariable { name, .. } => {
+            used.insert(name.clone());
+        }
+        Expression::StoreLocalVariable { name, .. } => {
+            declared.insert(name.clone());
+        }
+        Expression::ForLoop { variable, index_variable, .. } => {
+            declared.insert(variable.clone());
+            declared.extend(index_variable.clone());
+        }
+        Expression::ArrayTransform { variable, .. } => {
+            declared.insert(variable.clone());
+        }
+        Expression::FunctionParameterReference { .. } => uses_arguments = true,
+        _ => {}
+    });
+    let captured = used.difference(&declared).map(|name| ident(name)).collect::<Vec<_>>();
+    let arguments = uses_arguments.then(|| quote!(let args = args.clone();));
+
+    let (self_weak, upgrade) = if let Some(current_global) = ctx.current_global() {
+        let global_id = format_ident!("global_{}", ident(&current_global.name));
+        (
+            quote!(let self_weak = _self.globals.get().unwrap().clone();),
+            quote!(
+                let Some(globals) = self_weak.upgrade() else { return Default::default() };
+                let self_rc = globals.#global_id.clone();
+                let _self = self_rc.as_ref();
+            ),
+        )
+    } else {
+        (
+            quote!(let self_weak = _self.self_weak.get().unwrap().clone();),
+            quote!(
+                let Some(self_rc) = self_weak.upgrade() else { return Default::default() };
+                let _self = self_rc.as_pin_ref();
+            ),
+        )
+    };
+
+    let return_ty = rust_primitive_type(&expression.ty(ctx)).unwrap();
+    let variable = ident(variable);
+    let value = compile_expression(expression, ctx);
+    quote!(
+        let function = {
+            #self_weak
+            #arguments
+            #(let #captured = #captured.clone();)*
+            move |#variable: &#element_ty| -> #return_ty {
+                #upgrade
+                let #variable = #variable.clone();
+                (#value) as _
+            }
+        };
+    )
+}
+
 fn compile_builtin_function_call(
     function: BuiltinFunction,
     arguments: &[Expression],
@@ -3017,6 +3290,35 @@ fn compile_builtin_function_call(
         }
         BuiltinFunction::StringToLowercase => quote!(sp::SharedString::from(#(#a)*.to_lowercase())),
         BuiltinFunction::StringToUppercase => quote!(sp::SharedString::from(#(#a)*.to_uppercase())),
+        BuiltinFunction::StringTrim => quote!(sp::SharedString::from(#(#a)*.trim())),
+        BuiltinFunction::StringContains => {
+            let (s, sub) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#s.contains(#sub.as_str()))
+        }
+        BuiltinFunction::StringStartsWith => {
+            let (s, prefix) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#s.starts_with(#prefix.as_str()))
+        }
+        BuiltinFunction::StringEndsWith => {
+            let (s, suffix) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#s.ends_with(#suffix.as_str()))
+        }
+        BuiltinFunction::StringReplace => {
+            let (s, from, to) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::shared_string_replace(&#s, &#from, &#to))
+        }
+        BuiltinFunction::StringSubstring => {
+            let (s, start, length) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::shared_string_substring(&#s, #start as i32, #length as i32))
+        }
+        BuiltinFunction::StringSplit => {
+            let (s, separator) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::ModelRc::new(sp::SharedVectorModel::from(sp::shared_string_split(&#s, &#separator))))
+        }
+        BuiltinFunction::StringMatches => {
+            let (s, pattern) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::shared_string_matches(&#s, &#pattern))
+        }
         BuiltinFunction::ColorRgbaStruct => quote!( #(#a)*.to_argb_u8()),
         BuiltinFunction::ColorHsvaStruct => quote!( #(#a)*.to_hsva()),
         BuiltinFunction::ColorBrighter => {
@@ -3052,6 +3354,56 @@ fn compile_builtin_function_call(
                 x.row_count() as i32
             }})
         }
+        BuiltinFunction::MapHasKey => {
+            let (map, key) = (a.next().unwrap(), a.next().unwrap());
+            quote!((#map).contains_key(&(#key)))
+        }
+        BuiltinFunction::MapKeys => {
+            quote!(sp::ModelRc::new(sp::VecModel::from(
+                (#(#a)*).keys().cloned().collect::<sp::Vec<sp::SharedString>>()
+            )))
+        }
+        BuiltinFunction::ArraySlice => {
+            let (array, start, end) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(match &#array { x => {
+                x.model_tracker().track_row_count_changes();
+                let end = (#end as i32).clamp(0, x.row_count() as i32);
+                let start = (#start as i32).clamp(0, end);
+                sp::ModelRc::new(sp::VecModel::from(
+                    (start..end).map(|i| x.row_data_tracked(i as usize).unwrap_or_default()).collect::<sp::Vec<_>>()
+                ))
+            }})
+        }
+        BuiltinFunction::ArrayConcat => {
+            let (lhs, rhs) = (a.next().unwrap(), a.next().unwrap());
+            quote!(match (&#lhs, &#rhs) { (lhs, rhs) => {
+                lhs.model_tracker().track_row_count_changes();
+                rhs.model_tracker().track_row_count_changes();
+                sp::ModelRc::new(sp::VecModel::from(
+                    (0..lhs.row_count()).map(|i| lhs.row_data_tracked(i).unwrap_or_default())
+                        .chain((0..rhs.row_count()).map(|i| rhs.row_data_tracked(i).unwrap_or_default()))
+                        .collect::<sp::Vec<_>>()
+                ))
+            }})
+        }
+        BuiltinFunction::ArrayIndexOf | BuiltinFunction::ArrayContains => {
+            let Type::Array(element_ty) = arguments[0].ty(ctx) else {
+                panic!("First argument of {function:?} is not an array")
+            };
+            let element_ty = rust_primitive_type(&element_ty).unwrap();
+            let (array, value) = (a.next().unwrap(), a.next().unwrap());
+            let result = if function == BuiltinFunction::ArrayIndexOf {
+                quote!(position.map_or(-1, |i| i as i32))
+            } else {
+                quote!(position.is_some())
+            };
+            quote!(match &#array { x => {
+                let value: #element_ty = #value as _;
+                x.model_tracker().track_row_count_changes();
+                let position = (0..x.row_count()).position(|i| x.row_data_tracked(i).is_some_and(|v| v == value));
+                #result
+            }})
+        }
 
         BuiltinFunction::Rgb => {
             let (r, g, b, a) =
@@ -3181,6 +3533,17 @@ fn activate(&self, entry: &sp::MenuEntry) {
                 (a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
             quote!(sp::format_date(&#f, #d as u32, #m as u32, #y as i32))
         }
+        BuiltinFunction::FormatNumber => {
+            let (value, style, min_fraction, max_fraction, grouping, currency) = (
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap(),
+            );
+            quote!(sp::format_number(#value as f64, #style, #min_fraction as i32, #max_fraction as i32, #grouping, &#currency))
+        }
         BuiltinFunction::ValidDate => {
             let (d, f) = (a.next().unwrap(), a.next().unwrap());
             quote!(sp::parse_date(#d.as_str(), #f.as_str()).is_some())
@@ -3192,6 +3555,46 @@ fn activate(&self, entry: &sp::MenuEntry) {
         BuiltinFunction::DateNow => {
             quote!(sp::ModelRc::new(sp::VecModel::from_slice(&sp::date_now())))
         }
+        BuiltinFunction::DateTimeNow => quote!(sp::DateTime::now()),
+        BuiltinFunction::ParseDateTime => {
+            let (text, format) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::DateTime::parse(#text.as_str(), #format.as_str()).unwrap_or_default())
+        }
+        BuiltinFunction::FormatDateTime => {
+            let (date_time, format) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#date_time.format(#format.as_str()))
+        }
+        BuiltinFunction::DateTimeYear => quote!(#(#a)*.year()),
+        BuiltinFunction::DateTimeMonth => quote!((#(#a)*.month() as i32)),
+        BuiltinFunction::DateTimeDay => quote!((#(#a)*.day() as i32)),
+        BuiltinFunction::DateTimeHour => quote!((#(#a)*.hour() as i32)),
+        BuiltinFunction::DateTimeMinute => quote!((#(#a)*.minute() as i32)),
+        BuiltinFunction::DateTimeSecond => quote!((#(#a)*.second() as i32)),
+        BuiltinFunction::DateTimeWeekday => quote!((#(#a)*.weekday() as i32)),
+        BuiltinFunction::DateTimeUtcOffset => quote!((#(#a)*.utc_offset() as i64 * 1000)),
+        BuiltinFunction::DateTimeWithUtcOffset => {
+            let (date_time, offset) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#date_time.with_utc_offset((#offset as i64 / 1000) as i32))
+        }
+        BuiltinFunction::DateTimeToLocal => quote!(#(#a)*.to_local()),
+        BuiltinFunction::DateTimeWithDate => {
+            let (date_time, y, m, d) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(match #date_time { x => x.with_date(#y as i32, #m as u32, #d as u32).unwrap_or(x) })
+        }
+        BuiltinFunction::DateTimeWithTime => {
+            let (date_time, h, m, s) =
+                (a.next().unwrap(), a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(match #date_time { x => x.with_time(#h as u32, #m as u32, #s as u32).unwrap_or(x) })
+        }
+        BuiltinFunction::DateTimeAdd => {
+            let (date_time, duration) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#date_time.add_millis(#duration as i64))
+        }
+        BuiltinFunction::DateTimeDiff => {
+            let (lhs, rhs) = (a.next().unwrap(), a.next().unwrap());
+            quote!(#lhs.millis_since(&#rhs))
+        }
         BuiltinFunction::TextInputFocused => {
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).text_input_focused())
@@ -3240,6 +3643,7 @@ fn box_layout_function(
     repeated_indices: Option<&str>,
     elements: &[Either<Expression, llr::RepeatedElementIdx>],
     orientation: Orientation,
+    is_flexbox: bool,
     sub_expression: &Expression,
     ctx: &EvaluationContext,
 ) -> TokenStream {
@@ -3249,6 +3653,8 @@ fn box_layout_function(
     let mut repeated_count = quote!();
     let mut push_code = vec![];
     let mut repeater_idx = 0usize;
+    let layout_data_fn =
+        if is_flexbox { quote!(flexbox_layout_data) } else { quote!(box_layout_data) };
     for item in elements {
         match item {
             Either::Left(value) => {
@@ -3277,7 +3683,7 @@ fn box_layout_function(
                         let internal_vec = _self.#repeater_id.instances_vec();
                         #ri
                         for sub_comp in &internal_vec {
-                            items_vec.push(sub_comp.as_pin_ref().box_layout_data(#orientation))
+                            items_vec.push(sub_comp.as_pin_ref().#layout_data_fn(#orientation))
                         }
                     ));
             }
@@ -38,6 +38,8 @@ pub enum Type {
     String,
     Color,
     Duration,
+    /// A point in time with an offset from UTC
+    DateTime,
     PhysicalLength,
     LogicalLength,
     Rem,
@@ -52,6 +54,8 @@ pub enum Type {
     Brush,
     /// This is usually a model
     Array(Rc<Type>),
+    /// A map with string keys and values of the given type
+    Map(Rc<Type>),
     Struct(Rc<Struct>),
     Enumeration(Rc<Enumeration>),
 
@@ -85,6 +89,7 @@ fn eq(&self, other: &Self) -> bool {
             Type::String => matches!(other, Type::String),
             Type::Color => matches!(other, Type::Color),
             Type::Duration => matches!(other, Type::Duration),
+            Type::DateTime => matches!(other, Type::DateTime),
             Type::Angle => matches!(other, Type::Angle),
             Type::PhysicalLength => matches!(other, Type::PhysicalLength),
             Type::LogicalLength => matches!(other, Type::LogicalLength),
@@ -97,6 +102,7 @@ fn eq(&self, other: &Self) -> bool {
             Type::Easing => matches!(other, Type::Easing),
             Type::Brush => matches!(other, Type::Brush),
             Type::Array(a) => matches!(other, Type::Array(b) if a == b),
+            Type::Map(a) => matches!(other, Type::Map(b) if a == b),
             Type::Struct(lhs) => {
                 matches!(other, Type::Struct(rhs) if lhs.fields == rhs.fields && lhs.name == rhs.name)
             }
@@ -145,6 +151,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Type::Int32 => write!(f, "int"),
             Type::String => write!(f, "string"),
             Type::Duration => write!(f, "duration"),
+            Type::DateTime => write!(f, "date-time"),
             Type::Angle => write!(f, "angle"),
             Type::PhysicalLength => write!(f, "physical-length"),
             Type::LogicalLength => write!(f, "length"),
@@ -155,6 +162,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Type::Bool => write!(f, "bool"),
             Type::Model => write!(f, "model"),
             Type::Array(t) => write!(f, "[{t}]"),
+            Type::Map(t) => write!(f, "{{[string]: {t}}}"),
             Type::Struct(t) => write!(f, "{t}"),
             Type::PathData => write!(f, "pathdata"),
             Type::Easing => write!(f, "easing"),
@@ -199,6 +207,7 @@ pub fn is_property_type(&self) -> bool {
                 | Self::Color
                 | Self::ComponentFactory
                 | Self::Duration
+                | Self::DateTime
                 | Self::Angle
                 | Self::PhysicalLength
                 | Self::LogicalLength
@@ -211,6 +220,7 @@ pub fn is_property_type(&self) -> bool {
                 | Self::ElementReference
                 | Self::Struct { .. }
                 | Self::Array(_)
+                | Self::Map(_)
                 | Self::Brush
                 | Self::InferredProperty
         )
@@ -302,6 +312,7 @@ pub fn default_unit(&self) -> Option<Unit> {
             Type::Int32 => None,
             Type::String => None,
             Type::Color => None,
+            Type::DateTime => None,
             Type::Image => None,
             Type::Bool => None,
             Type::Model => None,
@@ -309,6 +320,7 @@ pub fn default_unit(&self) -> Option<Unit> {
             Type::Easing => None,
             Type::Brush => None,
             Type::Array(_) => None,
+            Type::Map(_) => None,
             Type::Struct { .. } => None,
             Type::Enumeration(_) => None,
             Type::UnitProduct(_) => None,
@@ -5,7 +5,7 @@
 
 use crate::diagnostics::{BuildDiagnostics, DiagnosticLevel, Spanned};
 use crate::expression_tree::*;
-use crate::langtype::{ElementType, PropertyLookupResult, Type};
+use crate::langtype::{ElementType, EnumerationValue, PropertyLookupResult, Type};
 use crate::object_tree::{Component, ElementRc};
 
 use smol_str::{format_smolstr, SmolStr, ToSmolStr};
@@ -23,6 +23,7 @@ pub enum Orientation {
 pub enum Layout {
     GridLayout(GridLayout),
     BoxLayout(BoxLayout),
+    FlexboxLayout(FlexboxLayout),
 }
 
 impl Layout {
@@ -30,18 +31,21 @@ pub fn rect(&self) -> &LayoutRect {
         match self {
             Layout::GridLayout(g) => &g.geometry.rect,
             Layout::BoxLayout(g) => &g.geometry.rect,
+            Layout::FlexboxLayout(g) => &g.geometry.rect,
         }
     }
     pub fn rect_mut(&mut self) -> &mut LayoutRect {
         match self {
             Layout::GridLayout(g) => &mut g.geometry.rect,
             Layout::BoxLayout(g) => &mut g.geometry.rect,
+            Layout::FlexboxLayout(g) => &mut g.geometry.rect,
         }
     }
     pub fn geometry(&self) -> &LayoutGeometry {
         match self {
             Layout::GridLayout(l) => &l.geometry,
             Layout::BoxLayout(l) => &l.geometry,
+            Layout::FlexboxLayout(l) => &l.geometry,
         }
     }
 }
@@ -52,6 +56,7 @@ pub fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedRefe
         match self {
             Layout::GridLayout(grid) => grid.visit_named_references(visitor),
             Layout::BoxLayout(l) => l.visit_named_references(visitor),
+            Layout::FlexboxLayout(l) => l.visit_named_references(visitor),
         }
     }
 }
@@ -129,6 +134,10 @@ pub struct LayoutConstraints {
     pub preferred_height: Option<NamedReference>,
     pub horizontal_stretch: Option<NamedReference>,
     pub vertical_stretch: Option<NamedReference>,
+    /// The `flex-grow`, `flex-shrink` and `flex-basis`, only used in a FlexboxLayout
+    pub flex_grow: Option<NamedReference>,
+    pub flex_shrink: Option<NamedReference>,
+    pub flex_basis: Option<NamedReference>,
     pub fixed_width: bool,
     pub fixed_height: bool,
 }
@@ -148,6 +157,9 @@ pub fn new(element: &ElementRc, diag: &mut BuildDiagnostics, level: DiagnosticLe
             preferred_height: binding_reference(element, "preferred-height"),
             horizontal_stretch: binding_reference(element, "horizontal-stretch"),
             vertical_stretch: binding_reference(element, "vertical-stretch"),
+            flex_grow: binding_reference(element, "flex-grow"),
+            flex_shrink: binding_reference(element, "flex-shrink"),
+            flex_basis: binding_reference(element, "flex-basis"),
             fixed_width: false,
             fixed_height: false,
         };
@@ -272,6 +284,20 @@ pub fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedRefe
         if let Some(e) = self.vertical_stretch.as_mut() {
             visitor(&mut *e);
         }
+        if let Some(e) = self.flex_grow.as_mut() {
+            visitor(&mut *e);
+        }
+        if let Some(e) = self.flex_shrink.as_mut() {
+            visitor(&mut *e);
+        }
+        if let Some(e) = self.flex_basis.as_mut() {
+            visitor(&mut *e);
+        }
+    }
+
+    /// Iterate over the `flex-*` properties that have a binding
+    pub fn flex_properties(&self) -> impl Iterator<Item = &NamedReference> {
+        self.flex_grow.iter().chain(self.flex_shrink.iter()).chain(self.flex_basis.iter())
     }
 }
 
@@ -490,6 +516,40 @@ fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReferenc
     }
 }
 
+/// Internal representation of a FlexboxLayout
+#[derive(Debug, Clone)]
+pub struct FlexboxLayout {
+    /// The value of the `direction` property, which must be known at compile time
+    pub direction: EnumerationValue,
+    pub wrap: Option<NamedReference>,
+    pub justify_content: Option<NamedReference>,
+    pub align_items: Option<NamedReference>,
+    pub elems: Vec<LayoutItem>,
+    pub geometry: LayoutGeometry,
+}
+
+impl FlexboxLayout {
+    /// The orientation of the main axis, along which the elements are placed before wrapping
+    pub fn main_orientation(&self) -> Orientation {
+        match self.direction.to_string().as_str() {
+            "row" | "row-reverse" => Orientation::Horizontal,
+            _ => Orientation::Vertical,
+        }
+    }
+
+    fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReference)) {
+        for cell in &mut self.elems {
+            cell.constraints.visit_named_references(visitor);
+        }
+        for e in [&mut self.wrap, &mut self.justify_content, &mut self.align_items] {
+            if let Some(e) = e.as_mut() {
+                visitor(&mut *e);
+            }
+        }
+        self.geometry.visit_named_references(visitor);
+    }
+}
+
 /// Get the implicit layout info of a particular element
 pub fn implicit_layout_info_call(elem: &ElementRc, orientation: Orientation) -> Expression {
     let mut elem_it = elem.clone();
@@ -579,7 +639,10 @@ pub fn is_layout(base_type: &ElementType) -> bool {
     match base_type {
         ElementType::Component(c) => is_layout(&c.root_element.borrow().base_type),
         ElementType::Builtin(be) => {
-            matches!(be.name.as_str(), "GridLayout" | "HorizontalLayout" | "VerticalLayout")
+            matches!(
+                be.name.as_str(),
+                "GridLayout" | "HorizontalLayout" | "VerticalLayout" | "FlexboxLayout"
+            )
         }
         _ => false,
     }
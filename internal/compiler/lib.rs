@@ -105,6 +105,9 @@ pub struct CompilerConfiguration {
     /// The compiler will look in these paths for library imports.
     pub library_paths: HashMap<String, std::path::PathBuf>,
     /// the name of the style. (eg: "native")
+    ///
+    /// A comma separated list of styles (eg: "fluent,material") compiles all of them in, and the
+    /// `StyleSelector` global exported by `std-widgets.slint` selects one at runtime.
     pub style: Option<String>,
 
     /// Callback to load import files which is called if the file could not be found
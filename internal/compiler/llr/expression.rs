@@ -4,7 +4,7 @@
 use super::{
     GlobalIdx, PropertyReference, RepeatedElementIdx, SubComponentIdx, SubComponentInstanceIdx,
 };
-use crate::expression_tree::{BuiltinFunction, MinMaxOp, OperatorClass};
+use crate::expression_tree::{ArrayTransformOp, BuiltinFunction, MinMaxOp, OperatorClass};
 use crate::langtype::Type;
 use crate::layout::Orientation;
 use core::num::NonZeroUsize;
@@ -44,6 +44,12 @@ pub enum Expression {
         ty: Type,
     },
 
+    /// Change the value of a local variable previously declared with [`Self::StoreLocalVariable`]
+    LocalVariableAssignment {
+        name: SmolStr,
+        value: Box<Expression>,
+    },
+
     /// Access to a field of the given name within a struct.
     StructFieldAccess {
         /// This expression should have [`Type::Struct`] type
@@ -58,6 +64,13 @@ pub enum Expression {
         index: Box<Expression>,
     },
 
+    /// Access to the value of a key within a map
+    MapIndex {
+        /// This expression should have [`Type::Map`] type
+        map: Box<Expression>,
+        key: Box<Expression>,
+    },
+
     /// Cast an expression to the given type
     Cast {
         from: Box<Expression>,
@@ -134,6 +147,17 @@ pub enum Expression {
         false_expr: Box<Expression>,
     },
 
+    /// Execute the body for each element of the array.
+    /// The element and its index are stored in the given local variables.
+    /// The body is of type bool, and the loop stops when it evaluates to false.
+    ForLoop {
+        /// This expression should have [`Type::Array`] type
+        array: Box<Expression>,
+        variable: SmolStr,
+        index_variable: Option<SmolStr>,
+        body: Box<Expression>,
+    },
+
     Array {
         element_ty: Type,
         values: Vec<Expression>,
@@ -144,6 +168,10 @@ pub enum Expression {
         ty: Rc<crate::langtype::Struct>,
         values: BTreeMap<SmolStr, Expression>,
     },
+    Map {
+        value_ty: Type,
+        values: BTreeMap<SmolStr, Expression>,
+    },
 
     EasingCurve(crate::expression_tree::EasingCurve),
 
@@ -177,6 +205,8 @@ pub enum Expression {
         /// Either an expression of type BoxLayoutCellData, or an index to the repeater
         elements: Vec<Either<Expression, RepeatedElementIdx>>,
         orientation: Orientation,
+        /// When true, the cells are FlexboxLayoutCellData instead of BoxLayoutCellData
+        is_flexbox: bool,
         sub_expression: Box<Expression>,
     },
 
@@ -195,6 +225,16 @@ pub enum Expression {
         rhs: Box<Expression>,
     },
 
+    /// A new array computed from the elements of `array`, such as `array.filter(x => x > 0)`
+    ArrayTransform {
+        op: ArrayTransformOp,
+        /// This expression should have [`Type::Array`] type
+        array: Box<Expression>,
+        /// The local variable that holds the current element when evaluating `expression`
+        variable: SmolStr,
+        expression: Box<Expression>,
+    },
+
     EmptyComponentFactory,
 
     /// A reference to bundled translated string
@@ -228,7 +268,7 @@ pub fn default_value_for_type(ty: &Type) -> Option<Self> {
             | Type::UnitProduct(_) => Expression::NumberLiteral(0.),
             Type::Percent => Expression::NumberLiteral(1.),
             Type::String => Expression::StringLiteral(SmolStr::default()),
-            Type::Color => {
+            Type::Color | Type::DateTime => {
                 Expression::Cast { from: Box::new(Expression::NumberLiteral(0.)), to: ty.clone() }
             }
             Type::Image => Expression::ImageReference {
@@ -243,6 +283,9 @@ pub fn default_value_for_type(ty: &Type) -> Option<Self> {
                 values: vec![],
                 as_model: true,
             },
+            Type::Map(value_ty) => {
+                Expression::Map { value_ty: (**value_ty).clone(), values: Default::default() }
+            }
             Type::Struct(s) => Expression::Struct {
                 ty: s.clone(),
                 values: s
@@ -272,6 +315,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::FunctionParameterReference { index } => ctx.arg_type(*index).clone(),
             Self::StoreLocalVariable { .. } => Type::Void,
             Self::ReadLocalVariable { ty, .. } => ty.clone(),
+            Self::LocalVariableAssignment { .. } => Type::Void,
             Self::StructFieldAccess { base, name } => match base.ty(ctx) {
                 Type::Struct(s) => s.fields[name].clone(),
                 _ => unreachable!(),
@@ -280,8 +324,16 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
                 Type::Array(ty) => (*ty).clone(),
                 _ => unreachable!(),
             },
+            Self::MapIndex { map, .. } => match map.ty(ctx) {
+                Type::Map(ty) => (*ty).clone(),
+                _ => unreachable!(),
+            },
             Self::Cast { to, .. } => to.clone(),
             Self::CodeBlock(sub) => sub.last().map_or(Type::Void, |e| e.ty(ctx)),
+            Self::BuiltinFunctionCall {
+                function: BuiltinFunction::ArraySlice | BuiltinFunction::ArrayConcat,
+                arguments,
+            } => arguments[0].ty(ctx),
             Self::BuiltinFunctionCall { function, .. } => function.ty().return_type.clone(),
             Self::CallBackCall { callback, .. } => match ctx.property_ty(callback) {
                 Type::Callback(callback) => callback.return_type.clone(),
@@ -306,8 +358,10 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::UnaryOp { sub, .. } => sub.ty(ctx),
             Self::ImageReference { .. } => Type::Image,
             Self::Condition { false_expr, .. } => false_expr.ty(ctx),
+            Self::ForLoop { .. } => Type::Void,
             Self::Array { element_ty, .. } => Type::Array(element_ty.clone().into()),
             Self::Struct { ty, .. } => ty.clone().into(),
+            Self::Map { value_ty, .. } => Type::Map(value_ty.clone().into()),
             Self::EasingCurve(_) => Type::Easing,
             Self::LinearGradient { .. } => Type::Brush,
             Self::RadialGradient { .. } => Type::Brush,
@@ -318,6 +372,10 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
                 Type::Array(super::lower_expression::grid_layout_cell_data_ty().into())
             }
             Self::MinMax { ty, .. } => ty.clone(),
+            Self::ArrayTransform { op: ArrayTransformOp::Map, expression, .. } => {
+                Type::Array(expression.ty(ctx).into())
+            }
+            Self::ArrayTransform { array, .. } => array.ty(ctx),
             Self::EmptyComponentFactory => Type::ComponentFactory,
             Self::TranslationReference { .. } => Type::String,
         }
@@ -334,11 +392,16 @@ macro_rules! visit_impl {
             Expression::FunctionParameterReference { .. } => {}
             Expression::StoreLocalVariable { value, .. } => $visitor(value),
             Expression::ReadLocalVariable { .. } => {}
+            Expression::LocalVariableAssignment { value, .. } => $visitor(value),
             Expression::StructFieldAccess { base, .. } => $visitor(base),
             Expression::ArrayIndex { array, index } => {
                 $visitor(array);
                 $visitor(index);
             }
+            Expression::MapIndex { map, key } => {
+                $visitor(map);
+                $visitor(key);
+            }
             Expression::Cast { from, .. } => $visitor(from),
             Expression::CodeBlock(b) => b.$iter().for_each($visitor),
             Expression::BuiltinFunctionCall { arguments, .. }
@@ -368,8 +431,13 @@ macro_rules! visit_impl {
                 $visitor(true_expr);
                 $visitor(false_expr);
             }
+            Expression::ForLoop { array, body, .. } => {
+                $visitor(array);
+                $visitor(body);
+            }
             Expression::Array { values, .. } => values.$iter().for_each($visitor),
             Expression::Struct { values, .. } => values.$values().for_each($visitor),
+            Expression::Map { values, .. } => values.$values().for_each($visitor),
             Expression::EasingCurve(_) => {}
             Expression::LinearGradient { angle, stops } => {
                 $visitor(angle);
@@ -402,6 +470,10 @@ macro_rules! visit_impl {
                 $visitor(lhs);
                 $visitor(rhs);
             }
+            Expression::ArrayTransform { array, expression, .. } => {
+                $visitor(array);
+                $visitor(expression);
+            }
             Expression::EmptyComponentFactory => {}
             Expression::TranslationReference { format_args, plural, string_index: _ } => {
                 $visitor(format_args);
@@ -440,9 +512,12 @@ pub fn visit_property_references(
                 Expression::PropertyReference(p) => p,
                 Expression::CallBackCall { callback, .. } => callback,
                 Expression::PropertyAssignment { property, .. } => {
-                    if let Some((a, map)) = &ctx.property_info(property).animation {
+                    if let Some(((a, keyframes), map)) = &ctx.property_info(property).animation {
                         let ctx2 = map.map_context(ctx);
                         a.visit_property_references(&ctx2, visitor);
+                        if let Some(keyframes) = keyframes {
+                            keyframes.visit_property_references(&ctx2, visitor);
+                        }
                     }
                     property
                 }
@@ -752,7 +827,8 @@ fn arg_type(&self, index: usize) -> &Type {
 pub(crate) struct PropertyInfoResult<'a> {
     pub analysis: Option<&'a crate::object_tree::PropertyAnalysis>,
     pub binding: Option<(&'a super::BindingExpression, ContextMap)>,
-    pub animation: Option<(&'a Expression, ContextMap)>,
+    /// The animation and the optional keyframes
+    pub animation: Option<(&'a (Expression, Option<Expression>), ContextMap)>,
     pub property_decl: Option<&'a super::Property>,
 }
 
@@ -44,9 +44,12 @@ pub fn ty(&self, ctx: &dyn super::TypeResolutionContext) -> Type {
 
 #[derive(Debug, Clone)]
 pub enum Animation {
-    /// The expression is a Struct with the animation fields
-    Static(Expression),
-    Transition(Expression),
+    /// The expression is a Struct with the animation fields.
+    /// The optional keyframes expression is an array of `{at: percent, value: T, easing: easing}`
+    Static(Expression, Option<Expression>),
+    /// The expression is a tuple of the animation and the time at which the state changed.
+    /// The optional keyframes expression reads the state again to select the keyframes
+    Transition(Expression, Option<Expression>),
 }
 
 #[derive(Debug, Clone)]
@@ -154,6 +157,38 @@ pub struct ListViewInfo {
     pub prop_height: PropertyReference,
 }
 
+#[derive(Debug, Clone)]
+/// The property references are in the parent context
+pub struct GridViewInfo {
+    pub viewport_y: PropertyReference,
+    pub viewport_height: PropertyReference,
+    /// The GridView's inner visible height (not counting eventual scrollbar)
+    pub gridview_height: PropertyReference,
+    pub columns: PropertyReference,
+    pub cell_height: PropertyReference,
+    pub spacing: PropertyReference,
+}
+
+#[derive(Debug, Clone)]
+/// The `flex-*` properties of the root of a repeated component placed in a FlexboxLayout.
+/// The property references are in the repeated's component context
+pub struct FlexboxItemInfo {
+    pub flex_grow: Option<PropertyReference>,
+    pub flex_shrink: Option<PropertyReference>,
+    pub flex_basis: Option<PropertyReference>,
+}
+
+#[derive(Debug, Clone)]
+/// The property references are in the repeated's component context
+pub struct ExitTransitionInfo {
+    /// Set to true to start the `out` transition
+    pub prop_leaving: PropertyReference,
+    /// How long the instance must be kept alive after setting `prop_leaving`
+    pub prop_duration: PropertyReference,
+    /// Properties to set to their current value before setting `prop_leaving`
+    pub frozen_properties: Vec<PropertyReference>,
+}
+
 #[derive(Debug)]
 pub struct RepeatedElement {
     pub model: MutExpression,
@@ -167,6 +202,13 @@ pub struct RepeatedElement {
 
     pub listview: Option<ListViewInfo>,
 
+    pub gridview: Option<GridViewInfo>,
+
+    /// Set when the root of the repeated component sets one of the `flex-*` properties
+    pub flexbox_item: Option<FlexboxItemInfo>,
+
+    pub exit_transition: Option<ExitTransitionInfo>,
+
     /// Access through this in case of the element being a `is_component_placeholder`
     pub container_item_index: Option<ItemInstanceIdx>,
 }
@@ -268,7 +310,8 @@ pub struct SubComponent {
     pub property_init: Vec<(PropertyReference, BindingExpression)>,
     pub change_callbacks: Vec<(PropertyReference, MutExpression)>,
     /// The animation for properties which are animated
-    pub animations: HashMap<PropertyReference, Expression>,
+    /// (the animation struct and the optional keyframes)
+    pub animations: HashMap<PropertyReference, (Expression, Option<Expression>)>,
     pub two_way_bindings: Vec<(PropertyReference, PropertyReference)>,
     pub const_properties: Vec<PropertyReference>,
     /// Code that is run in the sub component constructor, after property initializations
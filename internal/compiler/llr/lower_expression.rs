@@ -12,7 +12,7 @@
 use super::lower_to_item_tree::{LoweredElement, LoweredSubComponentMapping, LoweringState};
 use super::{Animation, PropertyIdx, PropertyReference, RepeatedElementIdx};
 use crate::expression_tree::{BuiltinFunction, Callable, Expression as tree_Expression};
-use crate::langtype::{EnumerationValue, Struct, Type};
+use crate::langtype::{Enumeration, EnumerationValue, Struct, Type};
 use crate::layout::Orientation;
 use crate::llr::Expression as llr_Expression;
 use crate::namedreference::NamedReference;
@@ -124,6 +124,10 @@ pub fn lower_expression(
             array: Box::new(lower_expression(array, ctx)),
             index: Box::new(lower_expression(index, ctx)),
         },
+        tree_Expression::MapIndex { map, key } => llr_Expression::MapIndex {
+            map: Box::new(lower_expression(map, ctx)),
+            key: Box::new(lower_expression(key, ctx)),
+        },
         tree_Expression::Cast { from, to } => {
             llr_Expression::Cast { from: Box::new(lower_expression(from, ctx)), to: to.clone() }
         }
@@ -205,6 +209,17 @@ pub fn lower_expression(
                 },
             }
         }
+        tree_Expression::ForLoop { array, variable, index_variable, body } => {
+            llr_Expression::ForLoop {
+                array: Box::new(lower_expression(array, ctx)),
+                variable: variable.clone(),
+                index_variable: index_variable.clone(),
+                body: Box::new(lower_expression(body, ctx)),
+            }
+        }
+        tree_Expression::Break | tree_Expression::Continue => {
+            panic!("break and continue should have been lowered by the remove_return pass")
+        }
         tree_Expression::Array { element_ty, values } => llr_Expression::Array {
             element_ty: element_ty.clone(),
             values: values.iter().map(|e| lower_expression(e, ctx)).collect::<_>(),
@@ -217,6 +232,13 @@ pub fn lower_expression(
                 .map(|(s, e)| (s.clone(), lower_expression(e, ctx)))
                 .collect::<_>(),
         },
+        tree_Expression::Map { value_ty, values } => llr_Expression::Map {
+            value_ty: value_ty.clone(),
+            values: values
+                .iter()
+                .map(|(s, e)| (s.clone(), lower_expression(e, ctx)))
+                .collect::<_>(),
+        },
         tree_Expression::PathData(data) => compile_path(data, ctx),
         tree_Expression::EasingCurve(x) => llr_Expression::EasingCurve(x.clone()),
         tree_Expression::LinearGradient { angle, stops } => llr_Expression::LinearGradient {
@@ -245,6 +267,14 @@ pub fn lower_expression(
         }
         tree_Expression::ComputeLayoutInfo(l, o) => compute_layout_info(l, *o, ctx),
         tree_Expression::SolveLayout(l, o) => solve_layout(l, *o, ctx),
+        tree_Expression::ArrayTransform { op, array, variable, expression } => {
+            llr_Expression::ArrayTransform {
+                op: *op,
+                array: Box::new(lower_expression(array, ctx)),
+                variable: variable.clone(),
+                expression: Box::new(lower_expression(expression, ctx)),
+            }
+        }
         tree_Expression::MinMax { ty, op, lhs, rhs } => llr_Expression::MinMax {
             ty: ty.clone(),
             op: *op,
@@ -359,6 +389,20 @@ fn lower_assignment(
 
             llr_Expression::ArrayIndexAssignment { array, index, value }
         }
+        tree_Expression::ReadLocalVariable { name, ty } => {
+            let rhs = lower_expression(rhs, ctx);
+            let value = Box::new(if op == '=' {
+                rhs
+            } else {
+                llr_Expression::BinaryExpression {
+                    lhs: llr_Expression::ReadLocalVariable { name: name.clone(), ty: ty.clone() }
+                        .into(),
+                    rhs: rhs.into(),
+                    op,
+                }
+            });
+            llr_Expression::LocalVariableAssignment { name: name.clone(), value }
+        }
         _ => panic!("not a rvalue"),
     }
 }
@@ -482,6 +526,15 @@ fn lower_animation_element(
         }
     }
 
+    fn lower_keyframes(
+        a: &ElementRc,
+        ctx: &mut ExpressionLoweringCtx<'_>,
+    ) -> Option<llr_Expression> {
+        let keyframes = a.borrow().bindings.get("keyframes")?.clone();
+        let keyframes = keyframes.borrow();
+        Some(lower_expression(&keyframes.expression, ctx))
+    }
+
     fn animation_fields() -> impl Iterator<Item = (SmolStr, Type)> {
         IntoIterator::into_iter([
             (SmolStr::new_static("duration"), Type::Int32),
@@ -505,7 +558,9 @@ fn animation_ty() -> Rc<Struct> {
     }
 
     match a {
-        PropertyAnimation::Static(a) => Animation::Static(lower_animation_element(a, ctx)),
+        PropertyAnimation::Static(a) => {
+            Animation::Static(lower_animation_element(a, ctx), lower_keyframes(a, ctx))
+        }
         PropertyAnimation::Transition { state_ref, animations } => {
             let set_state = llr_Expression::StoreLocalVariable {
                 name: "state".into(),
@@ -527,6 +582,31 @@ fn animation_ty() -> Rc<Struct> {
                     false_expr: Box::new(get_anim),
                 }
             }
+            // The keyframes of the transitions are selected with the same conditions as the animation
+            let keyframes_ty = animations.iter().find_map(|tr| {
+                Some(tr.animation.borrow().bindings.get("keyframes")?.borrow().ty())
+            });
+            let keyframes = keyframes_ty.map(|keyframes_ty| {
+                let mut get_keyframes =
+                    llr_Expression::default_value_for_type(&keyframes_ty).unwrap();
+                for tr in animations.iter().rev() {
+                    let condition = lower_expression(
+                        &tr.condition(tree_Expression::ReadLocalVariable {
+                            name: "state".into(),
+                            ty: state_ref.ty(),
+                        }),
+                        ctx,
+                    );
+                    get_keyframes = llr_Expression::Condition {
+                        condition: Box::new(condition),
+                        true_expr: Box::new(lower_keyframes(&tr.animation, ctx).unwrap_or_else(
+                            || llr_Expression::default_value_for_type(&keyframes_ty).unwrap(),
+                        )),
+                        false_expr: Box::new(get_keyframes),
+                    }
+                }
+                llr_Expression::CodeBlock(vec![set_state.clone(), get_keyframes])
+            });
             let result = llr_Expression::Struct {
                 // This is going to be a tuple
                 ty: Rc::new(Struct {
@@ -556,7 +636,7 @@ fn animation_ty() -> Rc<Struct> {
                 ])
                 .collect(),
             };
-            Animation::Transition(llr_Expression::CodeBlock(vec![set_state, result]))
+            Animation::Transition(llr_Expression::CodeBlock(vec![set_state, result]), keyframes)
         }
     }
 }
@@ -598,11 +678,15 @@ fn compute_layout_info(
                     repeater_indices: None,
                     elements,
                     orientation: o,
+                    is_flexbox: false,
                     sub_expression: Box::new(sub_expression),
                 },
                 None => sub_expression,
             }
         }
+        crate::layout::Layout::FlexboxLayout(layout) => {
+            flexbox_layout_function(layout, o, false, ctx)
+        }
     }
 }
 
@@ -702,6 +786,7 @@ fn solve_layout(
                     repeater_indices: Some("repeated_indices".into()),
                     elements,
                     orientation: o,
+                    is_flexbox: false,
                     sub_expression: Box::new(llr_Expression::ExtraBuiltinFunctionCall {
                         function: "solve_box_layout".into(),
                         arguments: vec![
@@ -728,6 +813,205 @@ fn solve_layout(
                 },
             }
         }
+        crate::layout::Layout::FlexboxLayout(layout) => {
+            flexbox_layout_function(layout, o, true, ctx)
+        }
+    }
+}
+
+/// Return the expression that solves the FlexboxLayout in the given orientation, or computes
+/// its layout info if `solve` is false.
+///
+/// Only the data needed for the orientation is passed: the cells and the size of the cross axis
+/// are only needed to solve the cross axis, and the cross axis depends on the main axis.
+fn flexbox_layout_function(
+    layout: &crate::layout::FlexboxLayout,
+    o: Orientation,
+    solve: bool,
+    ctx: &mut ExpressionLoweringCtx,
+) -> llr_Expression {
+    let main = layout.main_orientation();
+    let is_cross = o != main;
+    let main_cells = flexbox_layout_cells(layout, main, "cells_main", ctx);
+    let cross_cells = if is_cross {
+        flexbox_layout_cells(layout, o, "cells_cross", ctx)
+    } else {
+        FlexboxLayoutCellsResult {
+            cells: llr_Expression::Array {
+                element_ty: crate::typeregister::flexbox_layout_cell_data_type(),
+                values: vec![],
+                as_model: false,
+            },
+            compute_cells: None,
+        }
+    };
+    let size = |orientation: Orientation, ctx: &ExpressionLoweringCtx| {
+        let needed = if orientation == main {
+            // Without wrapping, there is only one line whatever the main size
+            solve || (is_cross && layout.wrap.is_some())
+        } else {
+            solve && is_cross
+        };
+        if needed {
+            layout_geometry_size(&layout.geometry.rect, orientation, ctx)
+        } else {
+            llr_Expression::NumberLiteral(0.)
+        }
+    };
+    let width = size(Orientation::Horizontal, ctx);
+    let height = size(Orientation::Vertical, ctx);
+    let (padding_h, spacing_h) =
+        generate_layout_padding_and_spacing(&layout.geometry, Orientation::Horizontal, ctx);
+    let (padding_v, spacing_v) =
+        generate_layout_padding_and_spacing(&layout.geometry, Orientation::Vertical, ctx);
+    let enum_property = |nr: &Option<NamedReference>, e: Rc<Enumeration>| match nr {
+        Some(nr) => llr_Expression::PropertyReference(ctx.map_property_reference(nr)),
+        None => llr_Expression::EnumerationValue(EnumerationValue {
+            value: e.default_value,
+            enumeration: e,
+        }),
+    };
+    let (justify_content_enum, align_items_enum, orientation_enum) = crate::typeregister::BUILTIN
+        .with(|e| {
+            (
+                e.enums.FlexJustifyContent.clone(),
+                e.enums.FlexAlignItems.clone(),
+                e.enums.Orientation.clone(),
+            )
+        });
+    let justify_content = enum_property(&layout.justify_content, justify_content_enum.clone());
+    let align_items = enum_property(&layout.align_items, align_items_enum.clone());
+    let wrap = match &layout.wrap {
+        Some(nr) => llr_Expression::PropertyReference(ctx.map_property_reference(nr)),
+        None => llr_Expression::BoolLiteral(false),
+    };
+    let (cells_h, cells_v) = match main {
+        Orientation::Horizontal => (main_cells.cells, cross_cells.cells),
+        Orientation::Vertical => (cross_cells.cells, main_cells.cells),
+    };
+    let data = make_struct(
+        "FlexboxLayoutData",
+        [
+            ("width", Type::Float32, width),
+            ("height", Type::Float32, height),
+            ("spacing_horizontal", Type::Float32, spacing_h),
+            ("spacing_vertical", Type::Float32, spacing_v),
+            ("padding_horizontal", padding_h.ty(ctx), padding_h),
+            ("padding_vertical", padding_v.ty(ctx), padding_v),
+            (
+                "direction",
+                Type::Enumeration(layout.direction.enumeration.clone()),
+                llr_Expression::EnumerationValue(layout.direction.clone()),
+            ),
+            ("wrap", Type::Bool, wrap),
+            ("justify_content", Type::Enumeration(justify_content_enum), justify_content),
+            ("align_items", Type::Enumeration(align_items_enum), align_items),
+            ("cells_horizontal", cells_h.ty(ctx), cells_h),
+            ("cells_vertical", cells_v.ty(ctx), cells_v),
+        ],
+    );
+    let orientation = llr_Expression::EnumerationValue(EnumerationValue {
+        value: match o {
+            Orientation::Horizontal => 0,
+            Orientation::Vertical => 1,
+        },
+        enumeration: orientation_enum,
+    });
+    let has_repeater = main_cells.compute_cells.is_some();
+    let mut expression = if solve {
+        let repeated_indices = if has_repeater {
+            llr_Expression::ReadLocalVariable {
+                name: "repeated_indices".into(),
+                ty: Type::Array(Type::Int32.into()),
+            }
+        } else {
+            llr_Expression::Array { element_ty: Type::Int32, values: vec![], as_model: false }
+        };
+        llr_Expression::ExtraBuiltinFunctionCall {
+            function: "solve_flexbox_layout".into(),
+            arguments: vec![data, orientation, repeated_indices],
+            return_ty: Type::LayoutCache,
+        }
+    } else {
+        llr_Expression::ExtraBuiltinFunctionCall {
+            function: "flexbox_layout_info".into(),
+            arguments: vec![data, orientation],
+            return_ty: crate::typeregister::layout_info_type().into(),
+        }
+    };
+    // The repeater indices are computed along with the cells of the solved orientation
+    for (cells, orientation) in [(cross_cells.compute_cells, o), (main_cells.compute_cells, main)] {
+        if let Some((cells_variable, elements)) = cells {
+            expression = llr_Expression::BoxLayoutFunction {
+                cells_variable,
+                repeater_indices: (solve && orientation == o).then(|| "repeated_indices".into()),
+                elements,
+                orientation,
+                is_flexbox: true,
+                sub_expression: Box::new(expression),
+            };
+        }
+    }
+    expression
+}
+
+struct FlexboxLayoutCellsResult {
+    cells: llr_Expression,
+    /// When there are repeater involved, we need to do a BoxLayoutFunction with the
+    /// given cell variable and elements
+    compute_cells: Option<(String, Vec<Either<llr_Expression, RepeatedElementIdx>>)>,
+}
+
+fn flexbox_layout_cells(
+    layout: &crate::layout::FlexboxLayout,
+    orientation: Orientation,
+    cells_variable: &str,
+    ctx: &mut ExpressionLoweringCtx,
+) -> FlexboxLayoutCellsResult {
+    let element_ty = crate::typeregister::flexbox_layout_cell_data_type();
+    let cell_data = |li: &crate::layout::LayoutItem, ctx: &mut ExpressionLoweringCtx| {
+        let layout_info = get_layout_info(&li.element, ctx, &li.constraints, orientation);
+        let flex_property = |nr: &Option<NamedReference>, default: f64| match nr {
+            Some(nr) => llr_Expression::PropertyReference(ctx.map_property_reference(nr)),
+            None => llr_Expression::NumberLiteral(default),
+        };
+        make_struct(
+            "FlexboxLayoutCellData",
+            [
+                ("constraint", crate::typeregister::layout_info_type().into(), layout_info),
+                ("flex_grow", Type::Float32, flex_property(&li.constraints.flex_grow, 0.)),
+                ("flex_shrink", Type::Float32, flex_property(&li.constraints.flex_shrink, 1.)),
+                ("flex_basis", Type::Float32, flex_property(&li.constraints.flex_basis, -1.)),
+            ],
+        )
+    };
+
+    if layout.elems.iter().all(|i| i.element.borrow().repeated.is_none()) {
+        let cells = llr_Expression::Array {
+            values: layout.elems.iter().map(|li| cell_data(li, ctx)).collect(),
+            element_ty,
+            as_model: false,
+        };
+        FlexboxLayoutCellsResult { cells, compute_cells: None }
+    } else {
+        let mut elements = vec![];
+        for item in &layout.elems {
+            if item.element.borrow().repeated.is_some() {
+                let repeater_index =
+                    match ctx.mapping.element_mapping.get(&item.element.clone().into()).unwrap() {
+                        LoweredElement::Repeated { repeated_index } => *repeated_index,
+                        _ => panic!(),
+                    };
+                elements.push(Either::Right(repeater_index))
+            } else {
+                elements.push(Either::Left(cell_data(item, ctx)));
+            }
+        }
+        let cells = llr_Expression::ReadLocalVariable {
+            name: cells_variable.into(),
+            ty: Type::Array(Rc::new(element_ty)),
+        };
+        FlexboxLayoutCellsResult { cells, compute_cells: Some((cells_variable.into(), elements)) }
     }
 }
 
@@ -455,10 +455,10 @@ fn lower_sub_component(
         {
             if let Some(anim) = binding.animation.as_ref() {
                 match super::lower_expression::lower_animation(anim, &mut ctx) {
-                    Animation::Static(anim) => {
-                        sub_component.animations.insert(prop, anim);
+                    Animation::Static(anim, keyframes) => {
+                        sub_component.animations.insert(prop, (anim, keyframes));
                     }
-                    Animation::Transition(_) => {
+                    Animation::Transition(..) => {
                         // Cannot set a property with a transition anyway
                     }
                 }
@@ -664,6 +664,38 @@ fn lower_repeated_component(
         }
     });
 
+    let gridview = repeated.is_gridview.as_ref().map(|gv| GridViewInfo {
+        viewport_y: ctx.map_property_reference(&gv.viewport_y),
+        viewport_height: ctx.map_property_reference(&gv.viewport_height),
+        gridview_height: ctx.map_property_reference(&gv.gridview_height),
+        columns: ctx.map_property_reference(&gv.columns),
+        cell_height: ctx.map_property_reference(&gv.cell_height),
+        spacing: ctx.map_property_reference(&gv.spacing),
+    });
+
+    let flexbox_item = {
+        let constraints = component.root_constraints.borrow();
+        let map = |nr: &Option<NamedReference>| {
+            nr.as_ref().map(|nr| sc.mapping.map_property_reference(nr, ctx.state))
+        };
+        let has_flex_properties = constraints.flex_properties().next().is_some();
+        has_flex_properties.then(|| FlexboxItemInfo {
+            flex_grow: map(&constraints.flex_grow),
+            flex_shrink: map(&constraints.flex_shrink),
+            flex_basis: map(&constraints.flex_basis),
+        })
+    };
+
+    let exit_transition = repeated.exit_transition.as_ref().map(|exit| ExitTransitionInfo {
+        prop_leaving: sc.mapping.map_property_reference(&exit.leaving, ctx.state),
+        prop_duration: sc.mapping.map_property_reference(&exit.duration, ctx.state),
+        frozen_properties: exit
+            .frozen_properties
+            .iter()
+            .map(|nr| sc.mapping.map_property_reference(nr, ctx.state))
+            .collect(),
+    });
+
     let parent_index = parent_component_container.map(|p| (*p.borrow().item_index.get().unwrap()));
     let container_item_index =
         parent_index.and_then(|pii| sub_component.items.position(|i| i.index_in_tree == pii));
@@ -679,6 +711,9 @@ fn lower_repeated_component(
         data_prop: (!repeated.is_conditional_element).then_some(0usize.into()),
         index_in_tree: *e.item_index.get().unwrap(),
         listview,
+        gridview,
+        flexbox_item,
+        exit_transition,
         container_item_index,
     }
 }
@@ -68,6 +68,40 @@ pub fn count_property_use(root: &CompilationUnit) {
                 visit_property(&lv.prop_y, &rep_ctx);
                 visit_property(&lv.prop_height, &rep_ctx);
             }
+            if let Some(gv) = &r.gridview {
+                visit_property(&gv.viewport_y, ctx);
+                visit_property(&gv.viewport_height, ctx);
+                visit_property(&gv.gridview_height, ctx);
+                visit_property(&gv.columns, ctx);
+                visit_property(&gv.cell_height, ctx);
+                visit_property(&gv.spacing, ctx);
+            }
+            if let Some(flex) = &r.flexbox_item {
+                let rep_ctx = EvaluationContext::new_sub_component(
+                    root,
+                    r.sub_tree.root,
+                    (),
+                    Some(ParentCtx::new(ctx, Some(idx))),
+                );
+                for p in
+                    [&flex.flex_grow, &flex.flex_shrink, &flex.flex_basis].into_iter().flatten()
+                {
+                    visit_property(p, &rep_ctx);
+                }
+            }
+            if let Some(exit) = &r.exit_transition {
+                let rep_ctx = EvaluationContext::new_sub_component(
+                    root,
+                    r.sub_tree.root,
+                    (),
+                    Some(ParentCtx::new(ctx, Some(idx))),
+                );
+                visit_property(&exit.prop_leaving, &rep_ctx);
+                visit_property(&exit.prop_duration, &rep_ctx);
+                for p in &exit.frozen_properties {
+                    visit_property(p, &rep_ctx);
+                }
+            }
             for idx in r.data_prop.iter().chain(r.index_prop.iter()) {
                 // prevent optimizing model properties
                 let p = &root.sub_components[r.sub_tree.root].properties[*idx];
@@ -166,8 +200,11 @@ fn visit_property(pr: &PropertyReference, ctx: &EvaluationContext) {
 fn visit_binding_expression(binding: &BindingExpression, ctx: &EvaluationContext) {
     binding.expression.borrow().visit_property_references(ctx, &mut visit_property);
     match &binding.animation {
-        Some(Animation::Static(e) | Animation::Transition(e)) => {
-            e.visit_property_references(ctx, &mut visit_property)
+        Some(Animation::Static(e, keyframes) | Animation::Transition(e, keyframes)) => {
+            e.visit_property_references(ctx, &mut visit_property);
+            if let Some(keyframes) = keyframes {
+                keyframes.visit_property_references(ctx, &mut visit_property);
+            }
         }
         None => (),
     }
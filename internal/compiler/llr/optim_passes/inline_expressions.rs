@@ -28,8 +28,10 @@ fn expression_cost(exp: &Expression, ctx: &EvaluationContext) -> isize {
         Expression::FunctionParameterReference { .. } => return isize::MAX,
         Expression::StoreLocalVariable { .. } => 0,
         Expression::ReadLocalVariable { .. } => 1,
+        Expression::LocalVariableAssignment { .. } => 0,
         Expression::StructFieldAccess { .. } => 1,
         Expression::ArrayIndex { .. } => ARRAY_INDEX_COST,
+        Expression::MapIndex { .. } => ARRAY_INDEX_COST,
         Expression::Cast { .. } => 0,
         Expression::CodeBlock(_) => 0,
         Expression::BuiltinFunctionCall { function, .. } => builtin_function_cost(function),
@@ -56,10 +58,13 @@ fn expression_cost(exp: &Expression, ctx: &EvaluationContext) -> isize {
                 )
                 .saturating_add(10);
         }
+        Expression::ForLoop { .. } => return isize::MAX,
+        Expression::ArrayTransform { .. } => return isize::MAX,
         // Never inline an array because it is a model and when shared it needs to keep its identity
         // (cf #5249)  (otherwise it would be `ALLOC_COST`)
         Expression::Array { .. } => return isize::MAX,
         Expression::Struct { .. } => 1,
+        Expression::Map { .. } => ALLOC_COST,
         Expression::EasingCurve(_) => 1,
         Expression::LinearGradient { .. } => ALLOC_COST,
         Expression::RadialGradient { .. } => ALLOC_COST,
@@ -119,6 +124,14 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::StringCharacterCount => 50,
         BuiltinFunction::StringToLowercase => ALLOC_COST,
         BuiltinFunction::StringToUppercase => ALLOC_COST,
+        BuiltinFunction::StringTrim => ALLOC_COST,
+        BuiltinFunction::StringContains => 50,
+        BuiltinFunction::StringStartsWith => 50,
+        BuiltinFunction::StringEndsWith => 50,
+        BuiltinFunction::StringReplace => ALLOC_COST,
+        BuiltinFunction::StringSubstring => ALLOC_COST,
+        BuiltinFunction::StringSplit => ALLOC_COST,
+        BuiltinFunction::StringMatches => ALLOC_COST,
         BuiltinFunction::ColorRgbaStruct => 50,
         BuiltinFunction::ColorHsvaStruct => 50,
         BuiltinFunction::ColorBrighter => 50,
@@ -128,6 +141,10 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::ColorWithAlpha => 50,
         BuiltinFunction::ImageSize => 50,
         BuiltinFunction::ArrayLength => 50,
+        BuiltinFunction::MapHasKey => 50,
+        BuiltinFunction::MapKeys => ALLOC_COST,
+        BuiltinFunction::ArraySlice | BuiltinFunction::ArrayConcat => ALLOC_COST,
+        BuiltinFunction::ArrayIndexOf | BuiltinFunction::ArrayContains => ALLOC_COST,
         BuiltinFunction::Rgb => 50,
         BuiltinFunction::Hsv => 50,
         BuiltinFunction::ImplicitLayoutInfo(_) => isize::MAX,
@@ -141,9 +158,27 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::MonthDayCount => isize::MAX,
         BuiltinFunction::MonthOffset => isize::MAX,
         BuiltinFunction::FormatDate => isize::MAX,
+        BuiltinFunction::FormatNumber => 2 * ALLOC_COST + PROPERTY_ACCESS_COST,
         BuiltinFunction::DateNow => isize::MAX,
         BuiltinFunction::ValidDate => isize::MAX,
         BuiltinFunction::ParseDate => isize::MAX,
+        BuiltinFunction::DateTimeNow => isize::MAX,
+        BuiltinFunction::ParseDateTime => isize::MAX,
+        BuiltinFunction::FormatDateTime => isize::MAX,
+        BuiltinFunction::DateTimeYear
+        | BuiltinFunction::DateTimeMonth
+        | BuiltinFunction::DateTimeDay
+        | BuiltinFunction::DateTimeHour
+        | BuiltinFunction::DateTimeMinute
+        | BuiltinFunction::DateTimeSecond
+        | BuiltinFunction::DateTimeWeekday => 20,
+        BuiltinFunction::DateTimeUtcOffset
+        | BuiltinFunction::DateTimeWithUtcOffset
+        | BuiltinFunction::DateTimeAdd
+        | BuiltinFunction::DateTimeDiff => 10,
+        BuiltinFunction::DateTimeToLocal
+        | BuiltinFunction::DateTimeWithDate
+        | BuiltinFunction::DateTimeWithTime => 50,
         BuiltinFunction::SetTextInputFocused => PROPERTY_ACCESS_COST,
         BuiltinFunction::TextInputFocused => PROPERTY_ACCESS_COST,
         BuiltinFunction::Translate => 2 * ALLOC_COST + PROPERTY_ACCESS_COST,
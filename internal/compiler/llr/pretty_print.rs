@@ -5,7 +5,7 @@
 
 use itertools::Itertools;
 
-use crate::expression_tree::MinMaxOp;
+use crate::expression_tree::{ArrayTransformOp, MinMaxOp};
 
 use super::{
     CompilationUnit, EvaluationContext, Expression, ParentCtx, PropertyReference, SubComponentIdx,
@@ -257,8 +257,12 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 write!(f, "{} = {}", name, e(value))
             }
             Expression::ReadLocalVariable { name, .. } => write!(f, "{name}"),
+            Expression::LocalVariableAssignment { name, value } => {
+                write!(f, "{} = {}", name, e(value))
+            }
             Expression::StructFieldAccess { base, name } => write!(f, "{}.{}", e(base), name),
             Expression::ArrayIndex { array, index } => write!(f, "{}[{}]", e(array), e(index)),
+            Expression::MapIndex { map, key } => write!(f, "{}[{}]", e(map), e(key)),
             Expression::Cast { from, to } => write!(f, "{} /*as {:?}*/", e(from), to),
             Expression::CodeBlock(v) => {
                 write!(f, "{{ {} }}", v.iter().map(e).join("; "))
@@ -311,6 +315,13 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
             Expression::Condition { condition, true_expr, false_expr } => {
                 write!(f, "({} ? {} : {})", e(condition), e(true_expr), e(false_expr))
             }
+            Expression::ForLoop { array, variable, index_variable, body } => {
+                write!(f, "for {variable}")?;
+                if let Some(index_variable) = index_variable {
+                    write!(f, "[{index_variable}]")?;
+                }
+                write!(f, " in {} {{ {} }}", e(array), e(body))
+            }
             Expression::Array { values, .. } => {
                 write!(f, "[{}]", values.iter().map(e).join(", "))
             }
@@ -319,6 +330,11 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 "{{ {} }}",
                 values.iter().map(|(k, v)| format!("{}: {}", k, e(v))).join(", ")
             ),
+            Expression::Map { values, .. } => write!(
+                f,
+                "{{ {} }}",
+                values.iter().map(|(k, v)| format!("{:?}: {}", k, e(v))).join(", ")
+            ),
             Expression::EasingCurve(x) => write!(f, "{x:?}"),
             Expression::LinearGradient { angle, stops } => write!(
                 f,
@@ -346,6 +362,14 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
             Expression::ComputeDialogLayoutCells { .. } => {
                 write!(f, "ComputeDialogLayoutCells(TODO)",)
             }
+            Expression::ArrayTransform { op, array, variable, expression } => {
+                let name = match op {
+                    ArrayTransformOp::Filter => "filter",
+                    ArrayTransformOp::Map => "map",
+                    ArrayTransformOp::SortBy => "sort-by",
+                };
+                write!(f, "{}.{name}({variable} => {})", e(array), e(expression))
+            }
             Expression::MinMax { ty: _, op, lhs, rhs } => match op {
                 MinMaxOp::Min => write!(f, "min({}, {})", e(lhs), e(rhs)),
                 MinMaxOp::Max => write!(f, "max({}, {})", e(lhs), e(rhs)),
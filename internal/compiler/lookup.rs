@@ -11,10 +11,10 @@
 };
 use crate::langtype::{ElementType, Enumeration, EnumerationValue, Type};
 use crate::namedreference::NamedReference;
-use crate::object_tree::{ElementRc, PropertyVisibility};
+use crate::object_tree::{ElementRc, PropertyVisibility, MEDIA_QUERY_CONDITION};
 use crate::parser::NodeOrToken;
 use crate::typeregister::TypeRegister;
-use smol_str::{SmolStr, ToSmolStr};
+use smol_str::{format_smolstr, SmolStr, ToSmolStr};
 use std::cell::RefCell;
 
 mod named_colors;
@@ -48,6 +48,13 @@ pub struct LookupCtx<'a> {
 
     /// The token currently processed
     pub current_token: Option<NodeOrToken>,
+
+    /// A stack of scopes of local variables declared with `let` or by a `for` loop.
+    /// (The last scope is looked up first)
+    pub local_variables: Vec<Vec<(SmolStr, Type)>>,
+
+    /// The number of `for` loops that encloses the current expression
+    pub loop_depth: usize,
 }
 
 impl<'a> LookupCtx<'a> {
@@ -62,6 +69,8 @@ pub fn empty_context(type_register: &'a TypeRegister, diag: &'a mut BuildDiagnos
             type_register,
             type_loader: None,
             current_token: None,
+            local_variables: Default::default(),
+            loop_depth: 0,
         }
     }
 
@@ -218,6 +227,29 @@ fn lookup(&self, ctx: &LookupCtx, name: &SmolStr) -> Option<LookupResult> {
     }
 }
 
+/// The name of the local variable, in the expression tree, for a variable declared as `name` in the source
+pub fn local_variable_name(name: &str) -> SmolStr {
+    format_smolstr!("local_{name}")
+}
+
+struct LocalVariableLookup;
+impl LookupObject for LocalVariableLookup {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&SmolStr, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        for (name, ty) in ctx.local_variables.iter().rev().flat_map(|scope| scope.iter().rev()) {
+            let e =
+                Expression::ReadLocalVariable { name: local_variable_name(name), ty: ty.clone() };
+            if let Some(r) = f(name, e.into()) {
+                return Some(r);
+            }
+        }
+        None
+    }
+}
+
 struct ArgumentsLookup;
 impl LookupObject for ArgumentsLookup {
     fn for_each_entry<R>(
@@ -264,6 +296,16 @@ fn for_each_entry<R>(
             })
             .or_else(|| f("true", Expression::BoolLiteral(true)))
             .or_else(|| f("false", Expression::BoolLiteral(false)))
+            .or_else(|| {
+                // In the condition of a `@media` block, `width` and `height` are the size of the element
+                if ctx.property_name != Some(MEDIA_QUERY_CONDITION) {
+                    return None;
+                }
+                let last = last?;
+                ["width", "height"].into_iter().find_map(|n| {
+                    f(n, Expression::PropertyReference(NamedReference::new(last, n.into())))
+                })
+            })
         // "root" is just a normal id
     }
 }
@@ -673,6 +715,7 @@ fn for_each_entry<R>(
         r.or_else(|| {
             f(&SmolStr::new_static("cubic-bezier"), BuiltinMacroFunction::CubicBezier.into())
         })
+        .or_else(|| f(&SmolStr::new_static("spring"), BuiltinMacroFunction::Spring.into()))
     }
 }
 
@@ -738,7 +781,7 @@ fn for_each_entry<R>(
         let mut f = |n, e: LookupResult| f(&SmolStr::new_static(n), e);
         let b = |b| LookupResult::from(Callable::Builtin(b));
         None.or_else(|| {
-            let style = ctx.type_loader.and_then(|tl| tl.compiler_config.style.as_ref());
+            let style = ctx.type_loader.map(|tl| &tl.resolved_style);
             f(
                 "color-scheme",
                 if style.is_some_and(|s| s.ends_with("-light")) {
@@ -801,9 +844,21 @@ fn for_each_entry<R>(
         (MathFunctions, ColorFunctions)
             .for_each_entry(ctx, f)
             .or_else(|| f(&SmolStr::new_static("debug"), BuiltinMacroFunction::Debug.into()))
+            .or_else(|| {
+                f(&SmolStr::new_static("format-number"), BuiltinMacroFunction::FormatNumber.into())
+            })
             .or_else(|| {
                 f(&SmolStr::new_static("animation-tick"), BuiltinFunction::AnimationTick.into())
             })
+            .or_else(|| {
+                f(&SmolStr::new_static("date-time-now"), BuiltinFunction::DateTimeNow.into())
+            })
+            .or_else(|| {
+                f(&SmolStr::new_static("parse-date-time"), BuiltinFunction::ParseDateTime.into())
+            })
+            .or_else(|| {
+                f(&SmolStr::new_static("format-date-time"), BuiltinFunction::FormatDateTime.into())
+            })
     }
 }
 
@@ -830,16 +885,22 @@ fn for_each_entry<R>(
 
 pub fn global_lookup() -> impl LookupObject {
     (
-        ArgumentsLookup,
+        LocalVariableLookup,
         (
-            SpecialIdLookup,
+            ArgumentsLookup,
             (
-                IdLookup,
+                SpecialIdLookup,
                 (
-                    InScopeLookup,
+                    IdLookup,
                     (
-                        LookupType,
-                        (BuiltinNamespaceLookup, (ReturnTypeSpecificLookup, BuiltinFunctionLookup)),
+                        InScopeLookup,
+                        (
+                            LookupType,
+                            (
+                                BuiltinNamespaceLookup,
+                                (ReturnTypeSpecificLookup, BuiltinFunctionLookup),
+                            ),
+                        ),
                     ),
                 ),
             ),
@@ -874,7 +935,9 @@ fn for_each_entry<R>(
                 Type::String => StringExpression(self).for_each_entry(ctx, f),
                 Type::Brush | Type::Color => ColorExpression(self).for_each_entry(ctx, f),
                 Type::Image => ImageExpression(self).for_each_entry(ctx, f),
+                Type::DateTime => DateTimeExpression(self).for_each_entry(ctx, f),
                 Type::Array(_) => ArrayExpression(self).for_each_entry(ctx, f),
+                Type::Map(_) => MapExpression(self).for_each_entry(ctx, f),
                 Type::Float32 | Type::Int32 | Type::Percent => {
                     NumberExpression(self).for_each_entry(ctx, f)
                 }
@@ -899,7 +962,9 @@ fn lookup(&self, ctx: &LookupCtx, name: &SmolStr) -> Option<LookupResult> {
                 Type::String => StringExpression(self).lookup(ctx, name),
                 Type::Brush | Type::Color => ColorExpression(self).lookup(ctx, name),
                 Type::Image => ImageExpression(self).lookup(ctx, name),
+                Type::DateTime => DateTimeExpression(self).lookup(ctx, name),
                 Type::Array(_) => ArrayExpression(self).lookup(ctx, name),
+                Type::Map(_) => MapExpression(self).lookup(ctx, name),
                 Type::Float32 | Type::Int32 | Type::Percent => {
                     NumberExpression(self).lookup(ctx, name)
                 }
@@ -933,6 +998,13 @@ fn for_each_entry<R>(
                 arguments: vec![self.0.clone()],
             })
         };
+        let member_macro = |m: BuiltinMacroFunction| {
+            LookupResult::Callable(LookupResultCallable::MemberFunction {
+                base: self.0.clone(),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: LookupResultCallable::Macro(m).into(),
+            })
+        };
 
         let mut f = |s, res| f(&SmolStr::new_static(s), res);
         None.or_else(|| f("is-float", member_function(BuiltinFunction::StringIsFloat)))
@@ -941,6 +1013,14 @@ fn for_each_entry<R>(
             .or_else(|| f("character-count", function_call(BuiltinFunction::StringCharacterCount)))
             .or_else(|| f("to-lowercase", member_function(BuiltinFunction::StringToLowercase)))
             .or_else(|| f("to-uppercase", member_function(BuiltinFunction::StringToUppercase)))
+            .or_else(|| f("trim", member_function(BuiltinFunction::StringTrim)))
+            .or_else(|| f("contains", member_function(BuiltinFunction::StringContains)))
+            .or_else(|| f("starts-with", member_function(BuiltinFunction::StringStartsWith)))
+            .or_else(|| f("ends-with", member_function(BuiltinFunction::StringEndsWith)))
+            .or_else(|| f("replace", member_function(BuiltinFunction::StringReplace)))
+            .or_else(|| f("substring", member_macro(BuiltinMacroFunction::StringSubstring)))
+            .or_else(|| f("split", member_function(BuiltinFunction::StringSplit)))
+            .or_else(|| f("matches", member_function(BuiltinFunction::StringMatches)))
     }
 }
 struct ColorExpression<'a>(&'a Expression);
@@ -992,6 +1072,46 @@ fn for_each_entry<R>(
     }
 }
 
+struct DateTimeExpression<'a>(&'a Expression);
+impl LookupObject for DateTimeExpression<'_> {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&SmolStr, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        let member_function = |f: BuiltinFunction| {
+            LookupResult::Callable(LookupResultCallable::MemberFunction {
+                base: self.0.clone(),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: LookupResultCallable::Callable(Callable::Builtin(f)).into(),
+            })
+        };
+        let function_call = |f: BuiltinFunction| {
+            LookupResult::from(Expression::FunctionCall {
+                function: Callable::Builtin(f),
+                source_location: ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                arguments: vec![self.0.clone()],
+            })
+        };
+
+        let mut f = |s, res| f(&SmolStr::new_static(s), res);
+        None.or_else(|| f("year", function_call(BuiltinFunction::DateTimeYear)))
+            .or_else(|| f("month", function_call(BuiltinFunction::DateTimeMonth)))
+            .or_else(|| f("day", function_call(BuiltinFunction::DateTimeDay)))
+            .or_else(|| f("hour", function_call(BuiltinFunction::DateTimeHour)))
+            .or_else(|| f("minute", function_call(BuiltinFunction::DateTimeMinute)))
+            .or_else(|| f("second", function_call(BuiltinFunction::DateTimeSecond)))
+            .or_else(|| f("weekday", function_call(BuiltinFunction::DateTimeWeekday)))
+            .or_else(|| f("utc-offset", function_call(BuiltinFunction::DateTimeUtcOffset)))
+            .or_else(|| {
+                f("with-utc-offset", member_function(BuiltinFunction::DateTimeWithUtcOffset))
+            })
+            .or_else(|| f("to-local", member_function(BuiltinFunction::DateTimeToLocal)))
+            .or_else(|| f("with-date", member_function(BuiltinFunction::DateTimeWithDate)))
+            .or_else(|| f("with-time", member_function(BuiltinFunction::DateTimeWithTime)))
+    }
+}
+
 struct ImageExpression<'a>(&'a Expression);
 impl LookupObject for ImageExpression<'_> {
     fn for_each_entry<R>(
@@ -1029,9 +1149,50 @@ fn for_each_entry<R>(
                 arguments: vec![self.0.clone()],
             })
         };
-        None.or_else(|| {
-            f(&SmolStr::new_static("length"), member_function(BuiltinFunction::ArrayLength))
-        })
+        let member_macro = |m: BuiltinMacroFunction| {
+            LookupResult::Callable(LookupResultCallable::MemberFunction {
+                base: self.0.clone(),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: LookupResultCallable::Macro(m).into(),
+            })
+        };
+        let mut f = |s, res| f(&SmolStr::new_static(s), res);
+        None.or_else(|| f("length", member_function(BuiltinFunction::ArrayLength)))
+            .or_else(|| f("slice", member_macro(BuiltinMacroFunction::ArraySlice)))
+            .or_else(|| f("concat", member_macro(BuiltinMacroFunction::ArrayConcat)))
+            .or_else(|| f("index-of", member_macro(BuiltinMacroFunction::ArrayIndexOf)))
+            .or_else(|| f("contains", member_macro(BuiltinMacroFunction::ArrayContains)))
+            .or_else(|| f("filter", member_macro(BuiltinMacroFunction::ArrayFilter)))
+            .or_else(|| f("map", member_macro(BuiltinMacroFunction::ArrayMap)))
+            .or_else(|| f("sort-by", member_macro(BuiltinMacroFunction::ArraySortBy)))
+    }
+}
+
+struct MapExpression<'a>(&'a Expression);
+impl LookupObject for MapExpression<'_> {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&SmolStr, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        let member_function = |f: BuiltinFunction| {
+            LookupResult::Callable(LookupResultCallable::MemberFunction {
+                base: self.0.clone(),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: LookupResultCallable::Callable(Callable::Builtin(f)).into(),
+            })
+        };
+        let function_call = |f: BuiltinFunction| {
+            LookupResult::from(Expression::FunctionCall {
+                function: Callable::Builtin(f),
+                source_location: ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                arguments: vec![self.0.clone()],
+            })
+        };
+
+        let mut f = |s, res| f(&SmolStr::new_static(s), res);
+        None.or_else(|| f("has-key", member_function(BuiltinFunction::MapHasKey)))
+            .or_else(|| f("keys", function_call(BuiltinFunction::MapKeys)))
     }
 }
 
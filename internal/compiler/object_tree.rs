@@ -624,11 +624,12 @@ fn clone(&self) -> Self {
         fn deep_clone(e: &ElementRc) -> ElementRc {
             let e = e.borrow();
             debug_assert!(e.children.is_empty());
-            debug_assert!(e.property_declarations.is_empty());
             debug_assert!(e.states.is_empty() && e.transitions.is_empty());
             Rc::new(RefCell::new(Element {
                 id: e.id.clone(),
                 base_type: e.base_type.clone(),
+                // The keyframes declaration
+                property_declarations: e.property_declarations.clone(),
                 bindings: e.bindings.clone(),
                 property_analysis: e.property_analysis.clone(),
                 enclosing_component: e.enclosing_component.clone(),
@@ -746,9 +747,14 @@ pub struct Element {
 
     pub states: Vec<State>,
     pub transitions: Vec<Transition>,
+    /// The `@media (condition) { ... }` blocks, lowered in the lower_states pass
+    pub media_queries: Vec<MediaQuery>,
 
     /// true when this item's geometry is handled by a layout
     pub child_of_layout: bool,
+    /// The animation declared with `animate-layout { ... }`, applied to the geometry set by a layout.
+    /// When set on a layout, it applies to all its children.
+    pub layout_animation: Option<ElementRc>,
     /// The property pointing to the layout info. `(horizontal, vertical)`
     pub layout_info_prop: Option<(NamedReference, NamedReference)>,
     /// Whether we have `preferred-{width,height}: 100%`
@@ -875,6 +881,10 @@ macro_rules! indent {
         indent!();
         writeln!(f, "transitions {:?} ", e.transitions)?;
     }
+    if !e.media_queries.is_empty() {
+        indent!();
+        writeln!(f, "media queries {:?} ", e.media_queries)?;
+    }
     for c in &e.children {
         indent!();
         pretty_print(f, &c.borrow(), indentation)?
@@ -949,6 +959,49 @@ pub struct ListViewInfo {
     pub listview_width: NamedReference,
 }
 
+#[derive(Debug, Clone)]
+pub struct GridViewInfo {
+    pub viewport_y: NamedReference,
+    pub viewport_height: NamedReference,
+    /// The GridView's inner visible height (not counting eventual scrollbar)
+    pub gridview_height: NamedReference,
+    /// The number of cells in a row
+    pub columns: NamedReference,
+    pub cell_width: NamedReference,
+    pub cell_height: NamedReference,
+    pub spacing: NamedReference,
+}
+
+/// The id of the state in which the `in` block of a repeated element puts its instances
+/// before they are initialized
+pub const ENTER_TRANSITION_STATE_ID: &str = "$enter";
+/// The id of the state in which the `out` block of a repeated element puts its removed instances
+pub const EXIT_TRANSITION_STATE_ID: &str = "$exit";
+
+/// The `in` block of a repeated or conditional element. It is lowered to a state that
+/// is left when the instance is initialized.
+#[derive(Debug, Clone)]
+pub struct EnterTransitionInfo {
+    /// Boolean property that the init code sets to true
+    pub entered: NamedReference,
+    /// The properties changed by the `in` block. The init code reads them before setting
+    /// `entered` so that the animations start from the values of the `in` block.
+    pub properties: Vec<NamedReference>,
+}
+
+/// The `out` block of a repeated or conditional element. It is lowered to a state that
+/// is entered when the instance is removed.
+#[derive(Debug, Clone)]
+pub struct ExitTransitionInfo {
+    /// Boolean property that the runtime sets to true when the instance is removed
+    pub leaving: NamedReference,
+    /// How long the runtime keeps the removed instance alive (its binding is set in lower_states)
+    pub duration: NamedReference,
+    /// Geometry properties that the runtime sets to their current value when the instance is
+    /// removed, because a layout doesn't place it anymore
+    pub frozen_properties: Vec<NamedReference>,
+}
+
 #[derive(Debug, Clone)]
 /// If the parent element is a repeated element, this has information about the models
 pub struct RepeatedElementInfo {
@@ -961,6 +1014,12 @@ pub struct RepeatedElementInfo {
     pub is_conditional_element: bool,
     /// When the for is the delegate of a ListView
     pub is_listview: Option<ListViewInfo>,
+    /// When the for is the delegate of a GridView
+    pub is_gridview: Option<GridViewInfo>,
+    /// The `in { ... }` block
+    pub enter_transition: Option<EnterTransitionInfo>,
+    /// The `out { ... }` block
+    pub exit_transition: Option<ExitTransitionInfo>,
 }
 
 pub type ElementRc = Rc<RefCell<Element>>;
@@ -1011,8 +1070,10 @@ pub fn from_node(
                 error_on(&n, "sub elements");
             }
             node.PropertyAnimation().for_each(|n| error_on(&n, "animations"));
+            node.LayoutAnimation().for_each(|n| error_on(&n, "animations"));
             node.States().for_each(|n| error_on(&n, "states"));
             node.Transitions().for_each(|n| error_on(&n, "transitions"));
+            node.MediaQuery().for_each(|n| error_on(&n, "media queries"));
             node.CallbackDeclaration().for_each(|cb| {
                 if parser::identifier_text(&cb.DeclaredIdentifier()).is_some_and(|s| s == "init") {
                     error_on(&cb, "an 'init' callback")
@@ -1069,9 +1130,14 @@ pub fn from_node(
             let PropertyLookupResult {
                 resolved_name: prop_name,
                 property_type: maybe_existing_prop_type,
+                property_visibility: existing_visibility,
                 ..
             } = r.lookup_property(&unresolved_prop_name);
             match maybe_existing_prop_type {
+                // A declared `class` property shadows the reserved one of the style sheets
+                Type::String
+                    if prop_name == "class"
+                        && existing_visibility == PropertyVisibility::Constexpr => {}
                 Type::Callback { .. } => {
                     diag.push_error(
                         format!("Cannot declare property '{prop_name}' when a callback with the same name exists"),
@@ -1457,6 +1523,14 @@ pub fn from_node(
             }
         }
 
+        for anim in node.LayoutAnimation() {
+            if r.layout_animation.is_some() {
+                diag.push_error("Duplicated animation".into(), &anim);
+                continue;
+            }
+            r.layout_animation = Some(layout_animation_element_from_node(&anim, diag, tr));
+        }
+
         for ch in node.PropertyChangedCallback() {
             let Some(prop) = parser::identifier_text(&ch.DeclaredIdentifier()) else { continue };
             let lookup_result = r.lookup_property(&prop);
@@ -1581,21 +1655,11 @@ pub fn from_node(
             let s = State {
                 id: parser::identifier_text(&state.DeclaredIdentifier()).unwrap_or_default(),
                 condition: state.Expression().map(|e| Expression::Uncompiled(e.into())),
-                property_changes: state
-                    .StatePropertyChange()
-                    .filter_map(|s| {
-                        lookup_property_from_qualified_name_for_state(s.QualifiedName(), &r, diag)
-                            .map(|(ne, ty)| {
-                                if !ty.is_property_type() && !matches!(ty, Type::Invalid) {
-                                    diag.push_error(
-                                        format!("'{}' is not a property", **s.QualifiedName()),
-                                        &s,
-                                    );
-                                }
-                                (ne, Expression::Uncompiled(s.BindingExpression().into()), s)
-                            })
-                    })
-                    .collect(),
+                property_changes: state_property_changes_from_node(
+                    state.StatePropertyChange(),
+                    &r,
+                    diag,
+                ),
             };
             for trs in state.Transition() {
                 let mut t = Transition::from_node(trs, &r, tr, diag);
@@ -1605,6 +1669,31 @@ pub fn from_node(
             r.borrow_mut().states.push(s);
         }
 
+        for media_query in node.MediaQuery() {
+            let m = MediaQuery {
+                condition: Expression::Uncompiled(media_query.Expression().into()),
+                property_changes: state_property_changes_from_node(
+                    media_query.StatePropertyChange(),
+                    &r,
+                    diag,
+                ),
+                node: media_query,
+            };
+            r.borrow_mut().media_queries.push(m);
+        }
+
+        if !node.parent().and_then(|p| p.parent()).is_some_and(|p| {
+            matches!(p.kind(), SyntaxKind::RepeatedElement | SyntaxKind::ConditionalElement)
+        }) {
+            for trs in node.Transition() {
+                diag.push_error(
+                    "'in' and 'out' transitions can only be used on elements created with 'for' or 'if'"
+                        .into(),
+                    &trs,
+                );
+            }
+        }
+
         for ts in node.Transitions() {
             if !is_legacy_syntax {
                 diag.push_error("'transitions' block are no longer supported. Use 'in {...}' and 'out {...}' directly in the state definition".into(), &ts);
@@ -1684,7 +1773,21 @@ fn from_repeated_node(
         } else {
             None
         };
-        let rei = RepeatedElementInfo {
+        let is_gridview = if parent.borrow().base_type.to_string() == "GridView" {
+            let nr = |name| NamedReference::new(parent, SmolStr::new_static(name));
+            Some(GridViewInfo {
+                viewport_y: nr("viewport-y"),
+                viewport_height: nr("viewport-height"),
+                gridview_height: nr("visible-height"),
+                columns: nr("columns"),
+                cell_width: nr("actual-cell-width"),
+                cell_height: nr("actual-cell-height"),
+                spacing: nr("spacing"),
+            })
+        } else {
+            None
+        };
+        let mut rei = RepeatedElementInfo {
             model: Expression::Uncompiled(node.Expression().into()),
             model_data_id: node
                 .DeclaredIdentifier()
@@ -1696,6 +1799,9 @@ fn from_repeated_node(
                 .unwrap_or_default(),
             is_conditional_element: false,
             is_listview,
+            is_gridview,
+            enter_transition: None,
+            exit_transition: None,
         };
         let e = Element::from_sub_element_node(
             node.SubElement(),
@@ -1705,6 +1811,8 @@ fn from_repeated_node(
             diag,
             tr,
         );
+        (rei.enter_transition, rei.exit_transition) =
+            element_transitions_from_node(&e, &node.SubElement().Element(), diag, tr);
         e.borrow_mut().repeated = Some(rei);
         e
     }
@@ -1717,12 +1825,15 @@ fn from_conditional_node(
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
     ) -> ElementRc {
-        let rei = RepeatedElementInfo {
+        let mut rei = RepeatedElementInfo {
             model: Expression::Uncompiled(node.Expression().into()),
             model_data_id: SmolStr::default(),
             index_id: SmolStr::default(),
             is_conditional_element: true,
             is_listview: None,
+            is_gridview: None,
+            enter_transition: None,
+            exit_transition: None,
         };
         let e = Element::from_sub_element_node(
             node.SubElement(),
@@ -1732,6 +1843,8 @@ fn from_conditional_node(
             diag,
             tr,
         );
+        (rei.enter_transition, rei.exit_transition) =
+            element_transitions_from_node(&e, &node.SubElement().Element(), diag, tr);
         e.borrow_mut().repeated = Some(rei);
         e
     }
@@ -1994,6 +2107,8 @@ pub fn type_from_node(
         type_struct_from_node(object_node, diag, tr, None, None)
     } else if let Some(array_node) = node.ArrayType() {
         Type::Array(Rc::new(type_from_node(array_node.Type(), diag, tr)))
+    } else if let Some(map_node) = node.MapType() {
+        Type::Map(Rc::new(type_from_node(map_node.Type(), diag, tr)))
     } else {
         assert!(diag.has_errors());
         Type::Invalid
@@ -2027,7 +2142,7 @@ fn animation_element_from_node(
     diag: &mut BuildDiagnostics,
     tr: &TypeRegister,
 ) -> Option<ElementRc> {
-    let anim_type = tr.property_animation_type_for_property(prop_type);
+    let anim_type = tr.property_animation_type_for_property(prop_type.clone());
     if !matches!(anim_type, ElementType::Builtin(..)) {
         diag.push_error(
             format!(
@@ -2040,6 +2155,26 @@ fn animation_element_from_node(
     } else {
         let mut anim_element =
             Element { id: "".into(), base_type: anim_type, ..Default::default() };
+        // The type of the keyframes depends on the type of the animated property
+        let keyframe_type = Struct {
+            fields: [
+                (SmolStr::new_static("at"), Type::Percent),
+                (SmolStr::new_static("value"), prop_type),
+                (SmolStr::new_static("easing"), Type::Easing),
+            ]
+            .into_iter()
+            .collect(),
+            name: None,
+            node: None,
+            rust_attributes: None,
+        };
+        anim_element.property_declarations.insert(
+            SmolStr::new_static("keyframes"),
+            PropertyDeclaration {
+                property_type: Type::Array(Rc::new(Type::Struct(Rc::new(keyframe_type)))),
+                ..Default::default()
+            },
+        );
         anim_element.parse_bindings(
             anim.Binding().filter_map(|b| {
                 Some((b.child_token(SyntaxKind::Identifier)?, b.BindingExpression().into()))
@@ -2054,6 +2189,27 @@ fn animation_element_from_node(
     }
 }
 
+fn layout_animation_element_from_node(
+    anim: &syntax_nodes::LayoutAnimation,
+    diag: &mut BuildDiagnostics,
+    tr: &TypeRegister,
+) -> ElementRc {
+    // The layout animates the lengths of the x, y, width, and height properties
+    let anim_type = tr.property_animation_type_for_property(Type::LogicalLength);
+    let mut anim_element = Element { id: "".into(), base_type: anim_type, ..Default::default() };
+    anim_element.parse_bindings(
+        anim.Binding().filter_map(|b| {
+            Some((b.child_token(SyntaxKind::Identifier)?, b.BindingExpression().into()))
+        }),
+        false,
+        diag,
+    );
+
+    apply_default_type_properties(&mut anim_element);
+
+    Rc::new(RefCell::new(anim_element))
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct QualifiedTypeName {
     pub members: Vec<SmolStr>,
@@ -2329,6 +2485,17 @@ fn visit_element_expressions_simple(
     }
     elem.borrow_mut().states = states;
 
+    let mut media_queries = std::mem::take(&mut elem.borrow_mut().media_queries);
+    for m in &mut media_queries {
+        vis(&mut m.condition, Some(MEDIA_QUERY_CONDITION), &|| Type::Bool);
+        for (ne, e, _) in &mut m.property_changes {
+            vis(e, Some(ne.name()), &|| {
+                ne.element().borrow().lookup_property(ne.name()).property_type
+            });
+        }
+    }
+    elem.borrow_mut().media_queries = media_queries;
+
     let mut transitions = std::mem::take(&mut elem.borrow_mut().transitions);
     for t in &mut transitions {
         for (_, _, a) in &mut t.property_animations {
@@ -2337,6 +2504,11 @@ fn visit_element_expressions_simple(
     }
     elem.borrow_mut().transitions = transitions;
 
+    let layout_animation = elem.borrow().layout_animation.clone();
+    if let Some(a) = layout_animation {
+        visit_element_expressions_simple(&a, &mut vis);
+    }
+
     let component = elem.borrow().enclosing_component.upgrade().unwrap();
     if Rc::ptr_eq(&component.root_element, elem) {
         for e in component.init_code.borrow_mut().iter_mut() {
@@ -2390,6 +2562,13 @@ pub fn visit_all_named_references_in_element(
         }
     }
     elem.borrow_mut().states = states;
+    let mut media_queries = std::mem::take(&mut elem.borrow_mut().media_queries);
+    for m in &mut media_queries {
+        for (r, _, _) in &mut m.property_changes {
+            vis(r);
+        }
+    }
+    elem.borrow_mut().media_queries = media_queries;
     let mut transitions = std::mem::take(&mut elem.borrow_mut().transitions);
     for t in &mut transitions {
         for (r, _, _) in &mut t.property_animations {
@@ -2406,8 +2585,35 @@ pub fn visit_all_named_references_in_element(
             vis(&mut lv.listview_height);
             vis(&mut lv.listview_width);
         }
+        if let Some(gv) = &mut r.is_gridview {
+            vis(&mut gv.viewport_y);
+            vis(&mut gv.viewport_height);
+            vis(&mut gv.gridview_height);
+            vis(&mut gv.columns);
+            vis(&mut gv.cell_width);
+            vis(&mut gv.cell_height);
+            vis(&mut gv.spacing);
+        }
     }
+    let (mut enter, mut exit) = repeated
+        .as_mut()
+        .map(|r| (r.enter_transition.take(), r.exit_transition.take()))
+        .unwrap_or_default();
     elem.borrow_mut().repeated = repeated;
+    // These refer to the repeated element itself, so visit them once `repeated` is restored
+    if let Some(enter) = &mut enter {
+        vis(&mut enter.entered);
+        enter.properties.iter_mut().for_each(&mut vis);
+    }
+    if let Some(exit) = &mut exit {
+        vis(&mut exit.leaving);
+        vis(&mut exit.duration);
+        exit.frozen_properties.iter_mut().for_each(&mut vis);
+    }
+    if let Some(r) = &mut elem.borrow_mut().repeated {
+        r.enter_transition = enter;
+        r.exit_transition = exit;
+    }
     let mut layout_info_prop = std::mem::take(&mut elem.borrow_mut().layout_info_prop);
     layout_info_prop.as_mut().map(|(h, b)| (vis(h), vis(b)));
     elem.borrow_mut().layout_info_prop = layout_info_prop;
@@ -2507,6 +2713,17 @@ pub struct State {
     pub property_changes: Vec<(NamedReference, Expression, syntax_nodes::StatePropertyChange)>,
 }
 
+/// The property name given to the lookup of the condition of a [`MediaQuery`]
+pub const MEDIA_QUERY_CONDITION: &str = "$media query$";
+
+/// A `@media (condition) { ... }` block: the property changes apply while the condition is true
+#[derive(Debug, Clone)]
+pub struct MediaQuery {
+    pub condition: Expression,
+    pub property_changes: Vec<(NamedReference, Expression, syntax_nodes::StatePropertyChange)>,
+    pub node: syntax_nodes::MediaQuery,
+}
+
 #[derive(Debug, Clone)]
 pub struct Transition {
     pub direction: TransitionDirection,
@@ -2544,23 +2761,128 @@ fn from_node(
                 .DeclaredIdentifier()
                 .and_then(|x| parser::identifier_text(&x))
                 .unwrap_or_default(),
-            property_animations: trs
-                .PropertyAnimation()
-                .flat_map(|pa| pa.QualifiedName().map(move |qn| (pa.clone(), qn)))
-                .filter_map(|(pa, qn)| {
-                    lookup_property_from_qualified_name_for_state(qn.clone(), r, diag).and_then(
-                        |(ne, prop_type)| {
-                            animation_element_from_node(&pa, &qn, prop_type, diag, tr)
-                                .map(|anim_element| (ne, qn.to_source_location(), anim_element))
-                        },
-                    )
-                })
-                .collect(),
+            property_animations: transition_animations_from_node(&trs, r, tr, diag),
             node: trs.clone(),
         }
     }
 }
 
+fn state_property_changes_from_node(
+    changes: impl Iterator<Item = syntax_nodes::StatePropertyChange>,
+    r: &ElementRc,
+    diag: &mut BuildDiagnostics,
+) -> Vec<(NamedReference, Expression, syntax_nodes::StatePropertyChange)> {
+    changes
+        .filter_map(|s| {
+            lookup_property_from_qualified_name_for_state(s.QualifiedName(), r, diag).map(
+                |(ne, ty)| {
+                    if !ty.is_property_type() && !matches!(ty, Type::Invalid) {
+                        diag.push_error(format!("'{}' is not a property", **s.QualifiedName()), &s);
+                    }
+                    (ne, Expression::Uncompiled(s.BindingExpression().into()), s)
+                },
+            )
+        })
+        .collect()
+}
+
+fn transition_animations_from_node(
+    trs: &syntax_nodes::Transition,
+    r: &ElementRc,
+    tr: &TypeRegister,
+    diag: &mut BuildDiagnostics,
+) -> Vec<(NamedReference, SourceLocation, ElementRc)> {
+    trs.PropertyAnimation()
+        .flat_map(|pa| pa.QualifiedName().map(move |qn| (pa.clone(), qn)))
+        .filter_map(|(pa, qn)| {
+            lookup_property_from_qualified_name_for_state(qn.clone(), r, diag).and_then(
+                |(ne, prop_type)| {
+                    animation_element_from_node(&pa, &qn, prop_type, diag, tr)
+                        .map(|anim_element| (ne, qn.to_source_location(), anim_element))
+                },
+            )
+        })
+        .collect()
+}
+
+/// Lower the `in { ... }` and `out { ... }` blocks of a repeated or conditional element to states
+/// of that element, driven by properties that are set by the init code and by the runtime.
+fn element_transitions_from_node(
+    e: &ElementRc,
+    node: &syntax_nodes::Element,
+    diag: &mut BuildDiagnostics,
+    tr: &TypeRegister,
+) -> (Option<EnterTransitionInfo>, Option<ExitTransitionInfo>) {
+    let mut enter = None;
+    let mut exit = None;
+    let mut states = Vec::new();
+    for trs in node.Transition() {
+        let is_enter = trs.first_token().is_some_and(|t| t.text() == "in");
+        if (is_enter && enter.is_some()) || (!is_enter && exit.is_some()) {
+            diag.push_error(
+                format!("Duplicated '{}' transition", if is_enter { "in" } else { "out" }),
+                &trs,
+            );
+            continue;
+        }
+        let property_changes = state_property_changes_from_node(trs.StatePropertyChange(), e, diag);
+        let property_animations = transition_animations_from_node(&trs, e, tr, diag);
+        let (flag_name, state_id, direction) = if is_enter {
+            ("transition-entered", ENTER_TRANSITION_STATE_ID, TransitionDirection::Out)
+        } else {
+            ("transition-leaving", EXIT_TRANSITION_STATE_ID, TransitionDirection::In)
+        };
+        let flag = crate::layout::create_new_prop(e, SmolStr::new_static(flag_name), Type::Bool);
+        flag.mark_as_set();
+        let flag_expr = Expression::PropertyReference(flag.clone());
+        let condition = if is_enter {
+            Expression::UnaryOp { sub: Box::new(flag_expr), op: '!' }
+        } else {
+            flag_expr
+        };
+        if is_enter {
+            enter = Some(EnterTransitionInfo {
+                entered: flag,
+                properties: property_changes.iter().map(|(nr, _, _)| nr.clone()).collect(),
+            });
+        } else {
+            let frozen_properties = ["x", "y", "width", "height"]
+                .into_iter()
+                .map(|p| NamedReference::new(e, SmolStr::new_static(p)))
+                .filter(|nr| {
+                    e.borrow().lookup_property(nr.name()).property_type == Type::LogicalLength
+                        && !property_changes.iter().any(|(changed, _, _)| changed == nr)
+                })
+                .collect::<Vec<_>>();
+            frozen_properties.iter().for_each(NamedReference::mark_as_set);
+            exit = Some(ExitTransitionInfo {
+                leaving: flag,
+                duration: crate::layout::create_new_prop(
+                    e,
+                    SmolStr::new_static("transition-exit-duration"),
+                    Type::Duration,
+                ),
+                frozen_properties,
+            });
+        }
+        e.borrow_mut().transitions.push(Transition {
+            direction,
+            state_id: SmolStr::new_static(state_id),
+            property_animations,
+            node: trs.clone(),
+        });
+        states.push(State {
+            id: SmolStr::new_static(state_id),
+            condition: Some(condition),
+            property_changes,
+        });
+    }
+    // The exit state takes precedence over the enter state and over the states of the element
+    states.sort_by_key(|s| s.id != EXIT_TRANSITION_STATE_ID);
+    e.borrow_mut().states.splice(0..0, states);
+    (enter, exit)
+}
+
 #[derive(Clone, Debug, derive_more::Deref)]
 pub struct ExportedName {
     #[deref]
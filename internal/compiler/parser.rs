@@ -330,15 +330,23 @@ fn source_file(&self) -> Option<&SourceFile> {
     }
     // syntax kind
     {
-        Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration, *EnumDeclaration ],
+        Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration, *EnumDeclaration,
+                      *StyleSheet ],
         /// `DeclaredIdentifier := Element { ... }`
         Component -> [ DeclaredIdentifier, Element ],
+        /// `style-sheet { ... }`
+        StyleSheet -> [ *StyleRule ],
+        /// `Text, .primary { color: red; }`
+        StyleRule -> [ *StyleSelector, *Binding ],
+        /// `Button.primary` or `#title`. The element type, classes and id are tokens of the selector
+        StyleSelector -> [],
         /// `id := Element { ... }`
         SubElement -> [ Element ],
         Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
                      *CallbackDeclaration, *ConditionalElement, *Function, *SubElement,
-                     *RepeatedElement, *PropertyAnimation, *PropertyChangedCallback,
-                     *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
+                     *RepeatedElement, *PropertyAnimation, *LayoutAnimation, *PropertyChangedCallback,
+                     *TwoWayBinding, *States, *Transitions, *Transition, *MediaQuery,
+                     ?ChildrenPlaceholder ],
         RepeatedElement -> [ ?DeclaredIdentifier, ?RepeatedIndex, Expression , SubElement],
         RepeatedIndex -> [],
         ConditionalElement -> [ Expression , SubElement],
@@ -354,6 +362,8 @@ fn source_file(&self) -> Option<&SourceFile> {
         PropertyDeclaration-> [ ?Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
         PropertyAnimation-> [ *QualifiedName, *Binding ],
+        /// `animate-layout { ... }`
+        LayoutAnimation-> [ *Binding ],
         /// `changed xxx => {...}`  where `xxx` is the DeclaredIdentifier
         PropertyChangedCallback-> [ DeclaredIdentifier, CodeBlock ],
         /// wraps Identifiers, like `Rectangle` or `SomeModule.SomeType`
@@ -367,13 +377,22 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// the right-hand-side of a binding
         // Fixme: the test should be a or
         BindingExpression-> [ ?CodeBlock, ?Expression ],
-        CodeBlock-> [ *Expression, *ReturnStatement ],
+        CodeBlock-> [ *Expression, *ReturnStatement, *LetStatement, *ForStatement,
+                      *BreakStatement, *ContinueStatement ],
         ReturnStatement -> [ ?Expression ],
+        /// `let foo = expression;` or `let foo: type = expression;`
+        LetStatement -> [ DeclaredIdentifier, ?Type, Expression ],
+        /// `for x in expression { ... }` or `for x[index] in expression { ... }`
+        ForStatement -> [ DeclaredIdentifier, ?RepeatedIndex, Expression, CodeBlock ],
+        /// `break;`
+        BreakStatement -> [],
+        /// `continue;`
+        ContinueStatement -> [],
         // FIXME: the test should test that as alternative rather than several of them (but it can also be a literal)
         Expression-> [ ?Expression, ?FunctionCallExpression, ?IndexExpression, ?SelfAssignment,
                        ?ConditionalExpression, ?QualifiedName, ?BinaryExpression, ?Array, ?ObjectLiteral,
                        ?UnaryOpExpression, ?CodeBlock, ?StringTemplate, ?AtImageUrl, ?AtGradient, ?AtTr,
-                       ?MemberAccess ],
+                       ?MemberAccess, ?Lambda ],
         /// Concatenate the Expressions to make a string (usually expended from a template string)
         StringTemplate -> [*Expression],
         /// `@image-url("foo.png")`
@@ -388,6 +407,8 @@ fn source_file(&self) -> Option<&SourceFile> {
         TrPlural -> [Expression],
         /// expression()
         FunctionCallExpression -> [*Expression],
+        /// `item => expression` as an argument of a function call
+        Lambda -> [DeclaredIdentifier, Expression],
         /// `expression[index]`
         IndexExpression -> [2 Expression],
         /// `expression += expression`
@@ -402,9 +423,9 @@ fn source_file(&self) -> Option<&SourceFile> {
         MemberAccess -> [Expression],
         /// `[ ... ]`
         Array -> [ *Expression ],
-        /// `{ foo: bar }`
+        /// `{ foo: bar }` or `{ "foo": bar }`
         ObjectLiteral -> [ *ObjectMember ],
-        /// `foo: bar` inside an ObjectLiteral
+        /// `foo: bar` or `"foo": bar` inside an ObjectLiteral
         ObjectMember -> [ Expression ],
         /// `states: [...]`
         States -> [*State],
@@ -412,10 +433,14 @@ fn source_file(&self) -> Option<&SourceFile> {
         State -> [DeclaredIdentifier, ?Expression, *StatePropertyChange, *Transition],
         /// binding within a state
         StatePropertyChange -> [ QualifiedName, BindingExpression ],
+        /// `@media (condition) { ... }`. The Expression is the condition.
+        MediaQuery -> [Expression, *StatePropertyChange],
         /// `transitions: [...]`
         Transitions -> [*Transition],
-        /// There is an identifier "in", "out", "in-out", the DeclaredIdentifier is the state name
-        Transition -> [?DeclaredIdentifier, *PropertyAnimation],
+        /// There is an identifier "in", "out", "in-out", the DeclaredIdentifier is the state name.
+        /// Directly in an element, this is the enter or exit transition of a `for` or `if` and
+        /// it can also contain property changes
+        Transition -> [?DeclaredIdentifier, *PropertyAnimation, *StatePropertyChange],
         /// Export a set of declared components by name
         ExportsList -> [ *ExportSpecifier, ?Component, *StructDeclaration, ?ExportModule, *EnumDeclaration ],
         /// Declare the first identifier to be exported, either under its name or instead
@@ -433,13 +458,15 @@ fn source_file(&self) -> Option<&SourceFile> {
         ExternalName -> [],
         InternalName -> [],
         /// The representation of a type
-        Type -> [ ?QualifiedName, ?ObjectType, ?ArrayType ],
+        Type -> [ ?QualifiedName, ?ObjectType, ?ArrayType, ?MapType ],
         /// `{foo: string, bar: string} `
         ObjectType ->[ *ObjectTypeMember ],
         /// `foo: type` inside an ObjectType
         ObjectTypeMember -> [ Type ],
         /// `[ type ]`
         ArrayType -> [ Type ],
+        /// `{[string]: type}`
+        MapType -> [ Type ],
         /// `struct Foo { ... }`
         StructDeclaration -> [DeclaredIdentifier, ObjectType, ?AtRustAttr],
         /// `enum Foo { bli, bla, blu }`
@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use super::element::{parse_element, parse_element_content};
+use super::element::{parse_element, parse_element_content, parse_property_binding};
 use super::prelude::*;
 use super::r#type::{parse_enum_declaration, parse_rustattr, parse_struct_declaration};
 
@@ -15,6 +15,7 @@
 /// struct Foo { foo: foo }
 /// enum Foo { hello }
 /// @rust-attr(...) struct X {}
+/// style-sheet { Text { color: red; } } component Type { }
 /// /* empty */
 /// ```
 pub fn parse_document(p: &mut impl Parser) -> bool {
@@ -52,6 +53,11 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
                     break;
                 }
             }
+            "style-sheet" | "style_sheet" if p.nth(1).kind() == SyntaxKind::LBrace => {
+                if !parse_style_sheet(&mut *p) {
+                    break;
+                }
+            }
             "@" if p.nth(1).as_str() == "rust-attr" => {
                 let checkpoint = p.checkpoint();
                 if !parse_rustattr(&mut *p) {
@@ -90,6 +96,126 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
     false
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,StyleSheet
+/// style-sheet { }
+/// style-sheet { Text { color: red; } .primary { background: blue; } }
+/// ```
+fn parse_style_sheet(p: &mut impl Parser) -> bool {
+    debug_assert!(matches!(p.peek().as_str(), "style-sheet" | "style_sheet"));
+    let mut p = p.start_node(SyntaxKind::StyleSheet);
+    p.consume(); // "style-sheet"
+    if !p.expect(SyntaxKind::LBrace) {
+        return false;
+    }
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace => {
+                p.consume();
+                return true;
+            }
+            SyntaxKind::Eof => return p.expect(SyntaxKind::RBrace),
+            _ => {
+                if !parse_style_rule(&mut *p) {
+                    return false;
+                }
+            }
+        }
+    }
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,StyleRule
+/// Text { }
+/// Text { color: red; font-size: 12px; }
+/// .primary, #title, Button.primary.large { background: { blue } }
+/// ```
+fn parse_style_rule(p: &mut impl Parser) -> bool {
+    let mut p = p.start_node(SyntaxKind::StyleRule);
+    loop {
+        if !parse_style_selector(&mut *p) {
+            return false;
+        }
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    if !p.expect(SyntaxKind::LBrace) {
+        return false;
+    }
+    let mut had_parse_error = false;
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace => {
+                p.consume();
+                return true;
+            }
+            SyntaxKind::Eof => return p.expect(SyntaxKind::RBrace),
+            SyntaxKind::Identifier if p.nth(1).kind() == SyntaxKind::Colon => {
+                parse_property_binding(&mut *p)
+            }
+            _ => {
+                if !had_parse_error {
+                    p.error("Only property bindings are allowed in a style rule");
+                    had_parse_error = true;
+                }
+                p.consume();
+            }
+        }
+    }
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,StyleSelector
+/// Text
+/// .primary
+/// #title
+/// #my-title_2
+/// Button.primary.large#ok
+/// ```
+fn parse_style_selector(p: &mut impl Parser) -> bool {
+    let mut p = p.start_node(SyntaxKind::StyleSelector);
+    let mut is_empty = !p.test(SyntaxKind::Identifier);
+    loop {
+        match p.peek().kind() {
+            SyntaxKind::Dot => {
+                p.consume();
+                if !p.expect(SyntaxKind::Identifier) {
+                    return false;
+                }
+            }
+            SyntaxKind::ColorLiteral => {
+                // The lexer sees `#my-id` as the color `#my` followed by `-id`
+                p.consume();
+                loop {
+                    match p.peek().kind() {
+                        SyntaxKind::Minus
+                            if matches!(
+                                p.nth(1).kind(),
+                                SyntaxKind::Identifier | SyntaxKind::NumberLiteral
+                            ) =>
+                        {
+                            p.consume();
+                            p.consume();
+                        }
+                        SyntaxKind::Identifier if p.peek().as_str().starts_with('_') => {
+                            p.consume();
+                        }
+                        _ => break,
+                    }
+                }
+            }
+            _ => break,
+        }
+        is_empty = false;
+    }
+    if is_empty {
+        p.error("Expected a selector: an element type, a '.class', or an '#id'");
+        return false;
+    }
+    true
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,Component
 /// Type := Base { }
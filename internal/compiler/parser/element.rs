@@ -47,7 +47,10 @@ pub fn parse_element(p: &mut impl Parser) -> bool {
 /// property<int> width;
 /// animate someProp { }
 /// animate * { }
+/// animate-layout { }
+/// in { opacity: 0; animate opacity { } }
 /// @children
+/// @media (width < 100px) { opacity: 0.5; }
 /// double_binding <=> element.property;
 /// public pure function foo() {}
 /// changed foo => {}
@@ -60,6 +63,14 @@ pub fn parse_element_content(p: &mut impl Parser) {
             SyntaxKind::Eof => return,
             SyntaxKind::Identifier => match p.nth(1).kind() {
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
+                SyntaxKind::LBrace
+                    if matches!(p.peek().as_str(), "animate-layout" | "animate_layout") =>
+                {
+                    parse_layout_animation(&mut *p);
+                }
+                SyntaxKind::LBrace if matches!(p.peek().as_str(), "in" | "out") => {
+                    parse_element_transition(&mut *p);
+                }
                 SyntaxKind::ColonEqual | SyntaxKind::LBrace => {
                     had_parse_error |= !parse_sub_element(&mut *p)
                 }
@@ -125,6 +136,9 @@ pub fn parse_element_content(p: &mut impl Parser) {
                     }
                 }
             },
+            SyntaxKind::At if p.nth(1).as_str() == "media" => {
+                parse_media_query(&mut *p);
+            }
             SyntaxKind::At => {
                 let checkpoint = p.checkpoint();
                 p.consume();
@@ -223,7 +237,7 @@ fn parse_if_element(p: &mut impl Parser) {
 /// foo: bar;
 /// foo: {}
 /// ```
-fn parse_property_binding(p: &mut impl Parser) {
+pub fn parse_property_binding(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::Binding);
     p.consume();
     p.expect(SyntaxKind::Colon);
@@ -447,6 +461,23 @@ fn parse_property_animation(p: &mut impl Parser) {
             parse_qualified_name(&mut *p);
         }
     };
+    parse_animation_bindings(&mut *p);
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,LayoutAnimation
+/// animate-layout { duration: 1000; }
+/// animate-layout {  }
+/// ```
+fn parse_layout_animation(p: &mut impl Parser) {
+    debug_assert!(matches!(p.peek().as_str(), "animate-layout" | "animate_layout"));
+    let mut p = p.start_node(SyntaxKind::LayoutAnimation);
+    p.expect(SyntaxKind::Identifier); // animate-layout
+    parse_animation_bindings(&mut *p);
+}
+
+/// Parses the `{ duration: ...; easing: ...; }` part of an animation
+fn parse_animation_bindings(p: &mut impl Parser) {
     p.expect(SyntaxKind::LBrace);
 
     loop {
@@ -559,6 +590,46 @@ fn parse_state(p: &mut impl Parser) -> bool {
     }
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,MediaQuery
+/// @media (width < 600px) { }
+/// @media (width < 600px && height > 400px) { x: 1px; foo.visible: false; }
+/// @media (root.compact) { foo.bar: { 1px + 2px } }
+/// ```
+fn parse_media_query(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().kind(), SyntaxKind::At);
+    let mut p = p.start_node(SyntaxKind::MediaQuery);
+    p.expect(SyntaxKind::At);
+    p.expect(SyntaxKind::Identifier); // "media"
+    if !p.expect(SyntaxKind::LParent) {
+        return;
+    }
+    parse_expression(&mut *p);
+    if !p.expect(SyntaxKind::RParent) || !p.expect(SyntaxKind::LBrace) {
+        return;
+    }
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace => {
+                p.consume();
+                return;
+            }
+            SyntaxKind::Eof => return,
+            _ => {
+                let checkpoint = p.checkpoint();
+                if !parse_qualified_name(&mut *p)
+                    || !p.expect(SyntaxKind::Colon)
+                    || !parse_binding_expression(&mut *p)
+                {
+                    p.test(SyntaxKind::RBrace);
+                    return;
+                }
+                let _ = p.start_node_at(checkpoint, SyntaxKind::StatePropertyChange);
+            }
+        }
+    }
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,Transitions
 /// transitions []
@@ -622,6 +693,42 @@ fn parse_transition_inner(p: &mut impl Parser) -> bool {
     }
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Transition
+/// in { }
+/// in { opacity: 0; animate opacity { duration: 88ms; } }
+/// out { animate x, foo.y { } x: -100px; foo.y: 42px; }
+/// ```
+fn parse_element_transition(p: &mut impl Parser) {
+    debug_assert!(matches!(p.peek().as_str(), "in" | "out"));
+    let mut p = p.start_node(SyntaxKind::Transition);
+    p.consume(); // "in" or "out"
+    p.expect(SyntaxKind::LBrace);
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace => {
+                p.consume();
+                return;
+            }
+            SyntaxKind::Eof => return,
+            SyntaxKind::Identifier if p.peek().as_str() == "animate" => {
+                parse_property_animation(&mut *p);
+            }
+            _ => {
+                let checkpoint = p.checkpoint();
+                if !parse_qualified_name(&mut *p)
+                    || !p.expect(SyntaxKind::Colon)
+                    || !parse_binding_expression(&mut *p)
+                {
+                    p.test(SyntaxKind::RBrace);
+                    return;
+                }
+                let _ = p.start_node_at(checkpoint, SyntaxKind::StatePropertyChange);
+            }
+        }
+    }
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,Function
 /// function foo() {}
@@ -282,6 +282,7 @@ fn parse_array(p: &mut impl Parser) {
 /// {a:b}
 /// { a: "foo" , }
 /// {a:b, c: 4 + 4, d: [a,] }
+/// { "a": 1, "b c": 2 }
 /// ```
 fn parse_object_notation(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::ObjectLiteral);
@@ -289,7 +290,10 @@ fn parse_object_notation(p: &mut impl Parser) {
 
     while p.nth(0).kind() != SyntaxKind::RBrace {
         let mut p = p.start_node(SyntaxKind::ObjectMember);
-        p.expect(SyntaxKind::Identifier);
+        // A string literal as a key makes it a map literal
+        if !p.test(SyntaxKind::StringLiteral) {
+            p.expect(SyntaxKind::Identifier);
+        }
         p.expect(SyntaxKind::Colon);
         parse_expression(&mut *p);
         if !p.test(SyntaxKind::Comma) {
@@ -305,12 +309,17 @@ fn parse_object_notation(p: &mut impl Parser) {
 /// (foo)
 /// (foo, bar, foo)
 /// (foo, bar(), xx+xx,)
+/// (x => x + 1)
 /// ```
 fn parse_function_arguments(p: &mut impl Parser) {
     p.expect(SyntaxKind::LParent);
 
     while p.nth(0).kind() != SyntaxKind::RParent {
-        parse_expression(&mut *p);
+        if p.nth(0).kind() == SyntaxKind::Identifier && p.nth(1).kind() == SyntaxKind::FatArrow {
+            parse_lambda(&mut *p);
+        } else {
+            parse_expression(&mut *p);
+        }
         if !p.test(SyntaxKind::Comma) {
             break;
         }
@@ -318,6 +327,22 @@ fn parse_function_arguments(p: &mut impl Parser) {
     p.expect(SyntaxKind::RParent);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Expression
+/// x => x + 1
+/// item => item.name == "foo"
+/// ```
+fn parse_lambda(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::Expression);
+    let mut p = p.start_node(SyntaxKind::Lambda);
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    parse_expression(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,StringTemplate
 /// "foo\{bar}"
@@ -4,6 +4,7 @@
 use super::element::parse_code_block;
 use super::expressions::parse_expression;
 use super::prelude::*;
+use super::r#type::parse_type;
 
 #[cfg_attr(test, parser_test)]
 /// ```test
@@ -14,6 +15,10 @@
 /// if (true) { foo = bar; } else { bar = foo;  }
 /// return;
 /// if (true) { return 42; }
+/// let foo = 42;
+/// for x in model { sum += x; }
+/// break;
+/// continue;
 /// ```
 pub fn parse_statement(p: &mut impl Parser) -> bool {
     if p.nth(0).kind() == SyntaxKind::RBrace {
@@ -40,6 +45,30 @@ pub fn parse_statement(p: &mut impl Parser) -> bool {
         return true;
     }
 
+    if p.peek().as_str() == "for" && p.nth(1).kind() == SyntaxKind::Identifier {
+        parse_for_statement(p);
+        return true;
+    }
+
+    if p.peek().as_str() == "let" && p.nth(1).kind() == SyntaxKind::Identifier {
+        parse_let_statement(p);
+        return true;
+    }
+
+    if matches!(p.peek().as_str(), "break" | "continue")
+        && matches!(p.nth(1).kind(), SyntaxKind::Semicolon | SyntaxKind::RBrace)
+    {
+        let kind = if p.peek().as_str() == "break" {
+            SyntaxKind::BreakStatement
+        } else {
+            SyntaxKind::ContinueStatement
+        };
+        let mut p = p.start_node(kind);
+        p.expect(SyntaxKind::Identifier); // "break" or "continue"
+        p.test(SyntaxKind::Semicolon);
+        return true;
+    }
+
     if p.peek().as_str() == "return" {
         let mut p = p.start_node_at(checkpoint, SyntaxKind::ReturnStatement);
         p.expect(SyntaxKind::Identifier); // "return"
@@ -98,3 +127,61 @@ fn parse_if_statement(p: &mut impl Parser) {
         let _ = p.start_node(SyntaxKind::CodeBlock);
     }
 }
+
+#[cfg_attr(test, parser_test)]
+/// ```test,ForStatement
+/// for x in model { }
+/// for x[idx] in [1, 2, 3] { foo += x * idx; }
+/// for x in foo.bar { if (x > 2) { break; } else { continue; } }
+/// ```
+fn parse_for_statement(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::ForStatement);
+    debug_assert_eq!(p.peek().as_str(), "for");
+    p.expect(SyntaxKind::Identifier); // "for"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    if p.nth(0).kind() == SyntaxKind::LBracket {
+        let mut p = p.start_node(SyntaxKind::RepeatedIndex);
+        p.expect(SyntaxKind::LBracket);
+        p.expect(SyntaxKind::Identifier);
+        p.expect(SyntaxKind::RBracket);
+    }
+    if p.peek().as_str() != "in" {
+        p.error("Invalid 'for' syntax: there should be a 'in' token");
+        drop(p.start_node(SyntaxKind::Expression));
+        drop(p.start_node(SyntaxKind::CodeBlock));
+        return;
+    }
+    p.consume(); // "in"
+    parse_expression(&mut *p);
+    if p.nth(0).kind() != SyntaxKind::LBrace {
+        p.error("Expected '{' after the expression of the 'for' statement");
+        drop(p.start_node(SyntaxKind::CodeBlock));
+        return;
+    }
+    parse_code_block(&mut *p);
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,LetStatement
+/// let foo = 42;
+/// let foo: int = 42;
+/// let foo: [string] = [];
+/// ```
+fn parse_let_statement(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::LetStatement);
+    debug_assert_eq!(p.peek().as_str(), "let");
+    p.expect(SyntaxKind::Identifier); // "let"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    if p.test(SyntaxKind::Colon) {
+        parse_type(&mut *p);
+    }
+    p.expect(SyntaxKind::Equal);
+    parse_expression(&mut *p);
+    p.expect(SyntaxKind::Semicolon);
+}
@@ -11,10 +11,12 @@
 /// string
 /// [ int ]
 /// {a: string, b: int}
+/// {[string]: int}
 /// ```
 pub fn parse_type(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::Type);
     match p.nth(0).kind() {
+        SyntaxKind::LBrace if p.nth(1).kind() == SyntaxKind::LBracket => parse_type_map(&mut *p),
         SyntaxKind::LBrace => parse_type_object(&mut *p),
         SyntaxKind::LBracket => parse_type_array(&mut *p),
         _ => {
@@ -66,6 +68,32 @@ pub fn parse_type_array(p: &mut impl Parser) {
     p.expect(SyntaxKind::RBracket);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,MapType
+/// {[string]: int}
+/// {[string]: {[string]: [int]},}
+/// {[string]: {a: string, b: int}}
+/// ```
+pub fn parse_type_map(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::MapType);
+    p.expect(SyntaxKind::LBrace);
+    p.expect(SyntaxKind::LBracket);
+    if p.peek().as_str() == "string" {
+        p.consume();
+    } else {
+        p.error("Only maps with string keys are supported. Use `{[string]: T}`");
+        // Skip the key type so that the rest of the type is still parsed
+        while !matches!(p.nth(0).kind(), SyntaxKind::RBracket | SyntaxKind::Eof) {
+            p.consume();
+        }
+    }
+    p.expect(SyntaxKind::RBracket);
+    p.expect(SyntaxKind::Colon);
+    parse_type(&mut *p);
+    p.test(SyntaxKind::Comma);
+    p.expect(SyntaxKind::RBrace);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,StructDeclaration
 /// struct Foo := { foo: bar, xxx: { aaa: bbb, } }
@@ -69,6 +69,41 @@ pub fn apply_default_properties_from_style(
                         .into(),
                         to: Type::Brush,
                     });
+                    // Only set the selection colors when needed, so that the lighter
+                    // SimpleText can still be used for texts that aren't selectable
+                    if elem.bindings.contains_key("selectable") {
+                        for (prop, palette_prop) in [
+                            ("selection-background-color", "selection-background"),
+                            ("selection-foreground-color", "selection-foreground"),
+                        ] {
+                            elem.set_binding_if_not_set(prop.into(), || Expression::Cast {
+                                from: Expression::PropertyReference(NamedReference::new(
+                                    &palette.root_element,
+                                    SmolStr::new_static(palette_prop),
+                                ))
+                                .into(),
+                                to: Type::Color,
+                            });
+                        }
+                    }
+                }
+                "StyledText" => {
+                    elem.set_binding_if_not_set("color".into(), || Expression::Cast {
+                        from: Expression::PropertyReference(NamedReference::new(
+                            &palette.root_element,
+                            SmolStr::new_static("foreground"),
+                        ))
+                        .into(),
+                        to: Type::Brush,
+                    });
+                    elem.set_binding_if_not_set("link-color".into(), || Expression::Cast {
+                        from: Expression::PropertyReference(NamedReference::new(
+                            &palette.root_element,
+                            SmolStr::new_static("accent-background"),
+                        ))
+                        .into(),
+                        to: Type::Brush,
+                    });
                 }
                 "Dialog" | "Window" => {
                     elem.set_binding_if_not_set("background".into(), || Expression::Cast {
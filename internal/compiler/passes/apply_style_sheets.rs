@@ -0,0 +1,215 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Pass that applies the rules of the `style-sheet` blocks of a document to the elements of
+//! its components.
+//!
+//! A rule sets the default value of properties: the binding is copied to the matched elements
+//! that don't set the property themselves, before the expressions are resolved. So the
+//! expression is resolved in the scope of each element, and there is no cost at runtime.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BindingExpression, Expression};
+use crate::langtype::ElementType;
+use crate::object_tree::{recurse_elem, Document, Element, PropertyVisibility};
+use crate::parser::{normalize_identifier, syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken};
+use itertools::Itertools;
+use smol_str::{SmolStr, ToSmolStr};
+use std::collections::HashMap;
+
+pub fn apply_style_sheets(doc: &Document, diag: &mut BuildDiagnostics) {
+    let rules = doc
+        .node
+        .iter()
+        .flat_map(|node| node.StyleSheet())
+        .flat_map(|style_sheet| style_sheet.StyleRule())
+        .map(StyleRule::from_node)
+        .collect::<Vec<_>>();
+    // Whether each binding of each rule was applied to, or matched, an element
+    let mut applied = rules.iter().map(|r| vec![false; r.bindings.len()]).collect::<Vec<_>>();
+    let mut matched = vec![false; rules.len()];
+
+    for component in &doc.inner_components {
+        if component.is_global() {
+            continue;
+        }
+        recurse_elem(&component.root_element, &(), &mut |elem, _| {
+            let classes = class_names(&elem.borrow(), diag);
+            if rules.is_empty() {
+                return;
+            }
+            let mut elem = elem.borrow_mut();
+            // The bindings of the matching rules, by increasing priority
+            let mut candidates = Vec::new();
+            for (rule_index, rule) in rules.iter().enumerate() {
+                let Some(specificity) = rule.specificity_for(&elem, &classes) else { continue };
+                matched[rule_index] = true;
+                for (binding_index, (name, _)) in rule.bindings.iter().enumerate() {
+                    let name = normalize_identifier(name.text());
+                    let lookup = elem.lookup_property(&name);
+                    if lookup.property_type.is_property_type()
+                        && (lookup.is_local_to_component
+                            || !matches!(
+                                lookup.property_visibility,
+                                PropertyVisibility::Private | PropertyVisibility::Output
+                            ))
+                    {
+                        candidates.push((
+                            specificity,
+                            rule_index,
+                            binding_index,
+                            lookup.resolved_name.to_smolstr(),
+                        ));
+                    }
+                }
+            }
+            candidates.sort_by_key(|(specificity, rule_index, binding_index, _)| {
+                (*specificity, *rule_index, *binding_index)
+            });
+            let mut new_bindings = HashMap::new();
+            for (_, rule_index, binding_index, name) in candidates {
+                applied[rule_index][binding_index] = true;
+                if !elem.bindings.contains_key(&name) {
+                    new_bindings.insert(name, &rules[rule_index].bindings[binding_index].1);
+                }
+            }
+            for (name, node) in new_bindings {
+                elem.bindings.insert(name, BindingExpression::new_uncompiled(node.clone()).into());
+            }
+        });
+    }
+
+    for ((rule, applied), matched) in rules.iter().zip(applied).zip(matched) {
+        if !matched {
+            continue;
+        }
+        for ((name, _), applied) in rule.bindings.iter().zip(applied) {
+            if !applied {
+                diag.push_error(
+                    format!(
+                        "'{}' is not a property that can be set on the elements matched by this rule",
+                        normalize_identifier(name.text())
+                    ),
+                    name,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the classes set with the `class` property of the element
+fn class_names(elem: &Element, diag: &mut BuildDiagnostics) -> Vec<SmolStr> {
+    if elem.lookup_property("class").property_visibility != PropertyVisibility::Constexpr {
+        // The element, or its base, declares its own `class` property
+        return Vec::new();
+    }
+    let Some(binding) = elem.bindings.get("class") else { return Vec::new() };
+    let binding = binding.borrow();
+    let Expression::Uncompiled(node) = &binding.expression else { return Vec::new() };
+    let literal = node
+        .descendants_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| {
+            !matches!(
+                t.kind(),
+                SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::Semicolon
+            )
+        })
+        .exactly_one()
+        .ok()
+        .filter(|t| t.kind() == SyntaxKind::StringLiteral)
+        .and_then(|t| crate::literals::unescape_string(t.text()));
+    match literal {
+        Some(classes) => classes.split_whitespace().map(SmolStr::from).collect(),
+        None => {
+            diag.push_error(
+                "The 'class' property must be set to a string literal".into(),
+                &*binding,
+            );
+            Vec::new()
+        }
+    }
+}
+
+struct StyleRule {
+    selectors: Vec<StyleSelector>,
+    /// The name of the property, and the BindingExpression node
+    bindings: Vec<(SyntaxToken, SyntaxNode)>,
+}
+
+impl StyleRule {
+    fn from_node(node: syntax_nodes::StyleRule) -> Self {
+        Self {
+            selectors: node.StyleSelector().map(StyleSelector::from_node).collect(),
+            bindings: node
+                .Binding()
+                .filter_map(|b| {
+                    Some((b.child_token(SyntaxKind::Identifier)?, b.BindingExpression().into()))
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the specificity of the most specific selector that matches the element, if any
+    fn specificity_for(
+        &self,
+        elem: &Element,
+        classes: &[SmolStr],
+    ) -> Option<(usize, usize, usize)> {
+        self.selectors.iter().filter(|s| s.matches(elem, classes)).map(|s| s.specificity()).max()
+    }
+}
+
+#[derive(Default)]
+struct StyleSelector {
+    type_name: Option<SmolStr>,
+    classes: Vec<SmolStr>,
+    id: Option<SmolStr>,
+}
+
+impl StyleSelector {
+    fn from_node(node: syntax_nodes::StyleSelector) -> Self {
+        let mut selector = Self::default();
+        let mut tokens = node
+            .children_with_tokens()
+            .filter_map(|t| t.into_token())
+            .filter(|t| !matches!(t.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment))
+            .peekable();
+        if let Some(t) = tokens.next_if(|t| t.kind() == SyntaxKind::Identifier) {
+            selector.type_name = Some(normalize_identifier(t.text()));
+        }
+        while let Some(t) = tokens.next() {
+            match t.kind() {
+                SyntaxKind::Dot => {
+                    selector.classes.extend(tokens.next().map(|t| t.text().into()));
+                }
+                SyntaxKind::ColorLiteral => {
+                    let mut id = t.text()[1..].to_string();
+                    while let Some(t) = tokens.next_if(|t| {
+                        matches!(
+                            t.kind(),
+                            SyntaxKind::Minus | SyntaxKind::Identifier | SyntaxKind::NumberLiteral
+                        )
+                    }) {
+                        id += t.text();
+                    }
+                    selector.id = Some(normalize_identifier(&id));
+                }
+                _ => {}
+            }
+        }
+        selector
+    }
+
+    fn matches(&self, elem: &Element, classes: &[SmolStr]) -> bool {
+        self.type_name.as_ref().is_none_or(|t| {
+            !matches!(elem.base_type, ElementType::Error) && elem.base_type.to_smolstr() == *t
+        }) && self.id.as_ref().is_none_or(|id| elem.id == *id)
+            && self.classes.iter().all(|c| classes.contains(c))
+    }
+
+    /// Like in CSS: the number of ids, then of classes, then of element types
+    fn specificity(&self) -> (usize, usize, usize) {
+        (self.id.iter().count(), self.classes.len(), self.type_name.iter().count())
+    }
+}
@@ -217,6 +217,23 @@ fn analyze_element(
             process_property(&lv.listview_height.clone().into(), P, context, reverse_aliases, diag);
             process_property(&lv.listview_width.clone().into(), P, context, reverse_aliases, diag);
         }
+        if let Some(gv) = &repeated.is_gridview {
+            for nr in [
+                &gv.viewport_y,
+                &gv.viewport_height,
+                &gv.gridview_height,
+                &gv.columns,
+                &gv.cell_height,
+                &gv.spacing,
+            ] {
+                process_property(&nr.clone().into(), P, context, reverse_aliases, diag);
+            }
+        }
+        if let Some(exit) = &repeated.exit_transition {
+            for nr in exit.frozen_properties.iter().chain([&exit.leaving, &exit.duration]) {
+                process_property(&nr.clone().into(), P, context, reverse_aliases, diag);
+            }
+        }
     }
     if let Some((h, v)) = &elem.borrow().layout_info_prop {
         process_property(&h.clone().into(), P, context, reverse_aliases, diag);
@@ -461,6 +478,25 @@ fn recurse_expression(expr: &Expression, vis: &mut impl FnMut(&PropertyPath, Rea
                 crate::layout::Layout::BoxLayout(l) => {
                     visit_layout_items_dependencies(l.elems.iter(), *o, vis)
                 }
+                crate::layout::Layout::FlexboxLayout(l) => {
+                    let main = l.main_orientation();
+                    visit_layout_items_dependencies(l.elems.iter(), main, vis);
+                    for nr in l.elems.iter().flat_map(|it| it.constraints.flex_properties()) {
+                        vis(&nr.clone().into(), P);
+                    }
+                    for nr in [&l.wrap, &l.justify_content, &l.align_items].into_iter().flatten() {
+                        vis(&nr.clone().into(), P);
+                    }
+                    if *o != main {
+                        visit_layout_items_dependencies(l.elems.iter(), *o, vis);
+                        // When wrapping, the lines along the cross axis depend on the main size
+                        if l.wrap.is_some() {
+                            if let Some(nr) = l.geometry.rect.size_reference(main) {
+                                vis(&nr.clone().into(), P);
+                            }
+                        }
+                    }
+                }
             }
 
             let mut g = l.geometry().clone();
@@ -573,12 +609,14 @@ fn visit_implicit_layout_info_dependencies(
                 vis(&NamedReference::new(item, SmolStr::new_static("width")).into(), N);
             }
         }
-        "Text" | "TextInput" => {
+        "Text" | "TextInput" | "StyledText" => {
             vis(&NamedReference::new(item, SmolStr::new_static("text")).into(), N);
             vis(&NamedReference::new(item, SmolStr::new_static("font-family")).into(), N);
             vis(&NamedReference::new(item, SmolStr::new_static("font-size")).into(), N);
             vis(&NamedReference::new(item, SmolStr::new_static("font-weight")).into(), N);
-            vis(&NamedReference::new(item, SmolStr::new_static("letter-spacing")).into(), N);
+            if base_type.as_str() != "StyledText" {
+                vis(&NamedReference::new(item, SmolStr::new_static("letter-spacing")).into(), N);
+            }
             vis(&NamedReference::new(item, SmolStr::new_static("wrap")).into(), N);
             let wrap_set = item.borrow().is_binding_set("wrap", false)
                 || item
@@ -592,7 +630,7 @@ fn visit_implicit_layout_info_dependencies(
             }
             if base_type.as_str() == "TextInput" {
                 vis(&NamedReference::new(item, SmolStr::new_static("single-line")).into(), N);
-            } else {
+            } else if base_type.as_str() == "Text" {
                 vis(&NamedReference::new(item, SmolStr::new_static("overflow")).into(), N);
             }
         }
@@ -610,6 +648,11 @@ fn visit_builtin_property(
 ) {
     let name = prop.prop.name();
     if builtin.name == "Window" {
+        if context.currently_analyzing.back().is_some_and(is_media_query_condition) {
+            // The condition of a `@media` block is about the size that the window system gives,
+            // so don't consider that it depends on the constraints of the window layout
+            return;
+        }
         for (p, orientation) in
             [("width", Orientation::Horizontal), ("height", Orientation::Vertical)]
         {
@@ -642,6 +685,12 @@ fn visit_builtin_property(
     }
 }
 
+fn is_media_query_condition(prop: &PropertyPath) -> bool {
+    prop.prop.element().borrow().property_declarations.get(prop.prop.name()).is_some_and(|d| {
+        d.node.as_ref().is_some_and(|n| n.kind() == crate::parser::SyntaxKind::MediaQuery)
+    })
+}
+
 /// Make sure that the is_set property analysis is set to any property which has a two way binding
 /// to a property that is, itself, is set
 ///
@@ -5,15 +5,22 @@
 
 use std::rc::Rc;
 
+use crate::expression_tree::Expression;
 use crate::langtype::ElementType;
-use crate::object_tree::{recurse_elem, Component};
+use crate::object_tree::{recurse_elem, Component, EnterTransitionInfo};
 
 pub fn collect_init_code(component: &Rc<Component>) {
     recurse_elem(&component.root_element, &(), &mut |elem, _| {
-        if elem.borrow().repeated.is_some() {
+        if let Some(repeated) = &elem.borrow().repeated {
             if let ElementType::Component(base) = &elem.borrow().base_type {
                 if base.parent_element.upgrade().is_some() {
                     collect_init_code(base);
+                    if let Some(enter) = &repeated.enter_transition {
+                        base.init_code
+                            .borrow_mut()
+                            .constructor_code
+                            .insert(0, enter_transition_code(enter));
+                    }
                 }
             }
         }
@@ -30,3 +37,21 @@ pub fn collect_init_code(component: &Rc<Component>) {
         collect_init_code(&popup.component);
     }
 }
+
+/// The code that starts the animations of the `in` block: the properties are evaluated in the
+/// state of the `in` block before leaving it.
+fn enter_transition_code(enter: &EnterTransitionInfo) -> Expression {
+    Expression::CodeBlock(
+        enter
+            .properties
+            .iter()
+            .map(|nr| Expression::PropertyReference(nr.clone()))
+            .chain(std::iter::once(Expression::SelfAssignment {
+                lhs: Box::new(Expression::PropertyReference(enter.entered.clone())),
+                rhs: Box::new(Expression::BoolLiteral(true)),
+                op: '=',
+                node: None,
+            }))
+            .collect(),
+    )
+}
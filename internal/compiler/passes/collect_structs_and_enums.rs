@@ -90,7 +90,7 @@ fn visit_declared_type(ty: &Type, visitor: &mut impl FnMut(&SmolStr, &Type)) {
                 visit_declared_type(sub_ty, visitor);
             }
         }
-        Type::Array(x) => visit_declared_type(x, visitor),
+        Type::Array(x) | Type::Map(x) => visit_declared_type(x, visitor),
         Type::Function(function) | Type::Callback(function) => {
             visit_declared_type(&function.return_type, visitor);
             for a in &function.args {
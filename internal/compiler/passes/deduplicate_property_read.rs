@@ -141,6 +141,9 @@ fn collect_unconditional_read_count(expr: &Expression, result: &DedupPropState)
         Expression::Condition { condition, .. } => {
             condition.visit(|sub| collect_unconditional_read_count(sub, result))
         }
+        Expression::ForLoop { array, .. } => {
+            array.visit(|sub| collect_unconditional_read_count(sub, result))
+        }
         Expression::SelfAssignment { .. } => {
             result.counts.borrow_mut().has_set = true;
         }
@@ -162,6 +165,10 @@ fn process_conditional_expressions(expr: &mut Expression, state: &DedupPropState
             process_expression(true_expr, state);
             process_expression(false_expr, state);
         }
+        Expression::ForLoop { array, body, .. } => {
+            array.visit_mut(|sub| process_conditional_expressions(sub, state));
+            process_expression(body, state);
+        }
         Expression::SelfAssignment { .. } => {
             state.counts.borrow_mut().has_set = true;
         }
@@ -183,6 +190,9 @@ fn do_replacements(expr: &mut Expression, state: &DedupPropState) {
         Expression::Condition { condition, .. } => {
             condition.visit_mut(|sub| do_replacements(sub, state));
         }
+        Expression::ForLoop { array, .. } => {
+            array.visit_mut(|sub| do_replacements(sub, state));
+        }
         _ => expr.visit_mut(|sub| do_replacements(sub, state)),
     }
 }
@@ -51,7 +51,9 @@ pub fn ensure_window(
         repeated: Default::default(),
         states: Default::default(),
         transitions: Default::default(),
+        media_queries: Default::default(),
         child_of_layout: false,
+        layout_animation: win_elem_mut.layout_animation.take(),
         has_popup_child: false,
         layout_info_prop: Default::default(),
         default_fill_parent: Default::default(),
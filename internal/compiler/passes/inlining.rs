@@ -115,6 +115,13 @@ fn inline_element(
     let mut mapping = HashMap::new();
     mapping.insert(element_key(inlined_component.root_element.clone()), elem.clone());
 
+    if elem_mut.layout_animation.is_none() {
+        elem_mut.layout_animation =
+            inlined_component.root_element.borrow().layout_animation.as_ref().map(|a| {
+                duplicate_element_with_mapping(a, &mut mapping, root_component, priority_delta)
+            });
+    }
+
     let mut new_children = Vec::with_capacity(
         elem_mut.children.len() + inlined_component.root_element.borrow().children.len(),
     );
@@ -363,12 +370,17 @@ fn duplicate_element_with_mapping(
         debug: elem.debug.clone(),
         enclosing_component: Rc::downgrade(root_component),
         states: elem.states.clone(),
+        media_queries: elem.media_queries.clone(),
         transitions: elem
             .transitions
             .iter()
             .map(|t| duplicate_transition(t, mapping, root_component, priority_delta))
             .collect(),
         child_of_layout: elem.child_of_layout,
+        layout_animation: elem
+            .layout_animation
+            .as_ref()
+            .map(|a| duplicate_element_with_mapping(a, mapping, root_component, priority_delta)),
         layout_info_prop: elem.layout_info_prop.clone(),
         default_fill_parent: elem.default_fill_parent,
         accessibility_props: elem.accessibility_props.clone(),
@@ -579,6 +591,11 @@ fn fixup_element_references(expr: &mut Expression, mapping: &Mapping) {
                     fxe(&mut e.element);
                 }
             }
+            crate::layout::Layout::FlexboxLayout(l) => {
+                for e in &mut l.elems {
+                    fxe(&mut e.element);
+                }
+            }
         },
         Expression::RepeaterModelReference { element }
         | Expression::RepeaterIndexReference { element } => fx(element),
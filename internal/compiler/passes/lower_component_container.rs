@@ -96,6 +96,9 @@ fn process_component_container(element: &ElementRc, empty_type: &ElementType) {
             index_id: Default::default(),
             is_conditional_element: true,
             is_listview: None,
+            is_gridview: None,
+            enter_transition: None,
+            exit_transition: None,
         }),
         is_component_placeholder: true,
         ..Default::default()
@@ -8,7 +8,7 @@
 use crate::diagnostics::{BuildDiagnostics, DiagnosticLevel, Spanned};
 use crate::expression_tree::*;
 use crate::langtype::ElementType;
-use crate::langtype::Type;
+use crate::langtype::{EnumerationValue, Type};
 use crate::layout::*;
 use crate::object_tree::*;
 use crate::typeloader::TypeLoader;
@@ -54,6 +54,7 @@ pub fn lower_layouts(
             diag,
         );
         check_no_layout_properties(elem, diag);
+        check_no_layout_animation(elem, diag);
     });
 }
 
@@ -111,6 +112,7 @@ fn lower_element_layout(
         "GridLayout" => lower_grid_layout(component, elem, diag, type_register),
         "HorizontalLayout" => lower_box_layout(elem, diag, Orientation::Horizontal),
         "VerticalLayout" => lower_box_layout(elem, diag, Orientation::Vertical),
+        "FlexboxLayout" => lower_flexbox_layout(elem, diag),
         "Dialog" => {
             lower_dialog_layout(elem, style_metrics, diag);
             return; // the Dialog stays in the tree as a Dialog
@@ -170,6 +172,7 @@ fn lower_grid_layout(
     let mut row = 0;
     let mut col = 0;
 
+    let layout_animation = grid_layout_element.borrow_mut().layout_animation.take();
     let layout_children = std::mem::take(&mut grid_layout_element.borrow_mut().children);
     let mut collected_children = Vec::new();
     for layout_child in layout_children {
@@ -190,6 +193,7 @@ fn lower_grid_layout(
                     (&mut row, &mut col),
                     &layout_cache_prop_h,
                     &layout_cache_prop_v,
+                    layout_animation.as_ref(),
                     diag,
                 );
                 col += 1;
@@ -212,6 +216,7 @@ fn lower_grid_layout(
                 (&mut row, &mut col),
                 &layout_cache_prop_h,
                 &layout_cache_prop_v,
+                layout_animation.as_ref(),
                 diag,
             );
             col += 1;
@@ -269,6 +274,7 @@ fn add_element(
         (row, col): (&mut u16, &mut u16),
         layout_cache_prop_h: &NamedReference,
         layout_cache_prop_v: &NamedReference,
+        layout_animation: Option<&ElementRc>,
         diag: &mut BuildDiagnostics,
     ) {
         let mut get_const_value = |name: &str| {
@@ -294,6 +300,7 @@ fn add_element(
             (rowspan, colspan),
             layout_cache_prop_h,
             layout_cache_prop_v,
+            layout_animation,
             diag,
         )
     }
@@ -305,10 +312,11 @@ fn add_element_with_coord(
         (rowspan, colspan): (u16, u16),
         layout_cache_prop_h: &NamedReference,
         layout_cache_prop_v: &NamedReference,
+        layout_animation: Option<&ElementRc>,
         diag: &mut BuildDiagnostics,
     ) {
         let index = self.elems.len();
-        if let Some(layout_item) = create_layout_item(item_element, diag) {
+        if let Some(layout_item) = create_layout_item(item_element, layout_animation, diag) {
             if layout_item.repeater_index.is_some() {
                 diag.push_error(
                     "'if' or 'for' expressions are not currently supported in grid layouts"
@@ -319,13 +327,30 @@ fn add_element_with_coord(
             }
 
             let e = &layout_item.elem;
-            set_prop_from_cache(e, "x", layout_cache_prop_h, index * 2, &None, diag);
+            let anim = &layout_item.animation;
+            set_prop_from_cache(e, "x", layout_cache_prop_h, index * 2, &None, anim, diag);
             if !layout_item.item.constraints.fixed_width {
-                set_prop_from_cache(e, "width", layout_cache_prop_h, index * 2 + 1, &None, diag);
+                set_prop_from_cache(
+                    e,
+                    "width",
+                    layout_cache_prop_h,
+                    index * 2 + 1,
+                    &None,
+                    anim,
+                    diag,
+                );
             }
-            set_prop_from_cache(e, "y", layout_cache_prop_v, index * 2, &None, diag);
+            set_prop_from_cache(e, "y", layout_cache_prop_v, index * 2, &None, anim, diag);
             if !layout_item.item.constraints.fixed_height {
-                set_prop_from_cache(e, "height", layout_cache_prop_v, index * 2 + 1, &None, diag);
+                set_prop_from_cache(
+                    e,
+                    "height",
+                    layout_cache_prop_v,
+                    index * 2 + 1,
+                    &None,
+                    anim,
+                    diag,
+                );
             }
 
             self.elems.push(GridLayoutElement {
@@ -363,6 +388,7 @@ fn lower_box_layout(
         layout_info_type().into(),
     );
 
+    let layout_animation = layout_element.borrow_mut().layout_animation.take();
     let layout_children = std::mem::take(&mut layout_element.borrow_mut().children);
 
     let (begin_padding, end_padding) = match orientation {
@@ -394,7 +420,7 @@ fn lower_box_layout(
     }
 
     for layout_child in &layout_children {
-        if let Some(item) = create_layout_item(layout_child, diag) {
+        if let Some(item) = create_layout_item(layout_child, layout_animation.as_ref(), diag) {
             let index = layout.elems.len() * 2;
             let rep_idx = &item.repeater_index;
             let (fixed_size, fixed_ortho) = match orientation {
@@ -406,7 +432,8 @@ fn lower_box_layout(
                 }
             };
             let actual_elem = &item.elem;
-            set_prop_from_cache(actual_elem, pos, &layout_cache_prop, index, rep_idx, diag);
+            let anim = &item.animation;
+            set_prop_from_cache(actual_elem, pos, &layout_cache_prop, index, rep_idx, anim, diag);
             if !fixed_size {
                 set_prop_from_cache(
                     actual_elem,
@@ -414,17 +441,19 @@ fn lower_box_layout(
                     &layout_cache_prop,
                     index + 1,
                     rep_idx,
+                    anim,
                     diag,
                 );
             }
             if let Some(pad_expr) = pad_expr.clone() {
-                actual_elem.borrow_mut().bindings.insert(pad.into(), RefCell::new(pad_expr.into()));
+                let mut binding = BindingExpression::from(pad_expr);
+                binding.animation = anim.clone();
+                actual_elem.borrow_mut().bindings.insert(pad.into(), binding.into());
             }
             if !fixed_ortho {
-                actual_elem
-                    .borrow_mut()
-                    .bindings
-                    .insert(ortho.into(), RefCell::new(size_expr.clone().into()));
+                let mut binding = BindingExpression::from(size_expr.clone());
+                binding.animation = anim.clone();
+                actual_elem.borrow_mut().bindings.insert(ortho.into(), binding.into());
             }
             layout.elems.push(item.item);
         }
@@ -464,6 +493,122 @@ fn lower_box_layout(
     }
 }
 
+fn lower_flexbox_layout(layout_element: &ElementRc, diag: &mut BuildDiagnostics) {
+    let direction_enum = crate::typeregister::BUILTIN.with(|e| e.enums.FlexDirection.clone());
+    let mut direction =
+        EnumerationValue { value: direction_enum.default_value, enumeration: direction_enum };
+    if let Some(binding) = layout_element.borrow().bindings.get("direction") {
+        let binding = binding.borrow();
+        if let Expression::EnumerationValue(val) = super::ignore_debug_hooks(&binding.expression) {
+            direction = val.clone();
+        } else {
+            diag.push_error(
+                "The `direction` property of a FlexboxLayout must be known at compile-time".into(),
+                &*binding,
+            );
+        }
+    }
+
+    let property_reference = |name: &'static str| {
+        layout_element
+            .borrow()
+            .bindings
+            .contains_key(name)
+            .then(|| NamedReference::new(layout_element, SmolStr::new_static(name)))
+    };
+    let mut layout = FlexboxLayout {
+        direction,
+        wrap: property_reference("wrap"),
+        justify_content: property_reference("justify-content"),
+        align_items: property_reference("align-items"),
+        elems: Default::default(),
+        geometry: LayoutGeometry::new(layout_element),
+    };
+
+    let layout_cache_prop_h =
+        create_new_prop(layout_element, SmolStr::new_static("layout-cache-h"), Type::LayoutCache);
+    let layout_cache_prop_v =
+        create_new_prop(layout_element, SmolStr::new_static("layout-cache-v"), Type::LayoutCache);
+    let layout_info_prop_h = create_new_prop(
+        layout_element,
+        SmolStr::new_static("layoutinfo-h"),
+        layout_info_type().into(),
+    );
+    let layout_info_prop_v = create_new_prop(
+        layout_element,
+        SmolStr::new_static("layoutinfo-v"),
+        layout_info_type().into(),
+    );
+
+    let layout_animation = layout_element.borrow_mut().layout_animation.take();
+    let layout_children = std::mem::take(&mut layout_element.borrow_mut().children);
+    for layout_child in &layout_children {
+        if let Some(item) = create_layout_item(layout_child, layout_animation.as_ref(), diag) {
+            let index = layout.elems.len() * 2;
+            let rep_idx = &item.repeater_index;
+            let e = &item.elem;
+            let anim = &item.animation;
+            set_prop_from_cache(e, "x", &layout_cache_prop_h, index, rep_idx, anim, diag);
+            if !item.item.constraints.fixed_width {
+                set_prop_from_cache(
+                    e,
+                    "width",
+                    &layout_cache_prop_h,
+                    index + 1,
+                    rep_idx,
+                    anim,
+                    diag,
+                );
+            }
+            set_prop_from_cache(e, "y", &layout_cache_prop_v, index, rep_idx, anim, diag);
+            if !item.item.constraints.fixed_height {
+                set_prop_from_cache(
+                    e,
+                    "height",
+                    &layout_cache_prop_v,
+                    index + 1,
+                    rep_idx,
+                    anim,
+                    diag,
+                );
+            }
+            layout.elems.push(item.item);
+        }
+    }
+    layout_element.borrow_mut().children = layout_children;
+    let span = layout_element.borrow().to_source_location();
+    for (prop, orientation) in [
+        (&layout_cache_prop_h, Orientation::Horizontal),
+        (&layout_cache_prop_v, Orientation::Vertical),
+    ] {
+        prop.element().borrow_mut().bindings.insert(
+            prop.name().clone(),
+            BindingExpression::new_with_span(
+                Expression::SolveLayout(Layout::FlexboxLayout(layout.clone()), orientation),
+                span.clone(),
+            )
+            .into(),
+        );
+    }
+    for (prop, orientation) in [
+        (&layout_info_prop_h, Orientation::Horizontal),
+        (&layout_info_prop_v, Orientation::Vertical),
+    ] {
+        prop.element().borrow_mut().bindings.insert(
+            prop.name().clone(),
+            BindingExpression::new_with_span(
+                Expression::ComputeLayoutInfo(Layout::FlexboxLayout(layout.clone()), orientation),
+                span.clone(),
+            )
+            .into(),
+        );
+    }
+    layout_element.borrow_mut().layout_info_prop = Some((layout_info_prop_h, layout_info_prop_v));
+    for d in layout_element.borrow_mut().debug.iter_mut() {
+        d.layout = Some(Layout::FlexboxLayout(layout.clone()));
+    }
+}
+
 fn lower_dialog_layout(
     dialog_element: &ElementRc,
     style_metrics: &Rc<Component>,
@@ -515,6 +660,7 @@ fn lower_dialog_layout(
         layout_info_type().into(),
     );
 
+    let layout_animation = dialog_element.borrow_mut().layout_animation.take();
     let mut main_widget = None;
     let mut button_roles = vec![];
     let mut seen_buttons = HashSet::new();
@@ -630,6 +776,7 @@ fn lower_dialog_layout(
                 (1, 1),
                 &layout_cache_prop_h,
                 &layout_cache_prop_v,
+                layout_animation.as_ref(),
                 diag,
             );
         } else if main_widget.is_some() {
@@ -650,6 +797,7 @@ fn lower_dialog_layout(
             (1, button_roles.len() as u16 + 1),
             &layout_cache_prop_h,
             &layout_cache_prop_v,
+            layout_animation.as_ref(),
             diag,
         );
     } else {
@@ -706,11 +854,16 @@ struct CreateLayoutItemResult {
     item: LayoutItem,
     elem: ElementRc,
     repeater_index: Option<Expression>,
+    /// The animation for the geometry properties set by the layout
+    animation: Option<PropertyAnimation>,
 }
 
 /// Create a LayoutItem for the given `item_element`  returns None is the layout is empty
+///
+/// `layout_animation` is the `animate-layout` of the layout, used if the item doesn't have its own.
 fn create_layout_item(
     item_element: &ElementRc,
+    layout_animation: Option<&ElementRc>,
     diag: &mut BuildDiagnostics,
 ) -> Option<CreateLayoutItemResult> {
     let fix_explicit_percent = |prop: &str, item: &ElementRc| {
@@ -762,33 +915,45 @@ fn create_layout_item(
     };
 
     let constraints = LayoutConstraints::new(&actual_elem, diag, DiagnosticLevel::Error);
+    let animation = own_layout_animation(&actual_elem)
+        .or_else(|| layout_animation.cloned())
+        .map(PropertyAnimation::Static);
     Some(CreateLayoutItemResult {
         item: LayoutItem { element: item_element.clone(), constraints },
         elem: actual_elem,
         repeater_index,
+        animation,
     })
 }
 
+/// Returns the `animate-layout` declared on the element, or on the root of the component it is an instance of
+fn own_layout_animation(elem: &ElementRc) -> Option<ElementRc> {
+    if let Some(a) = &elem.borrow().layout_animation {
+        return Some(a.clone());
+    }
+    let base = elem.borrow().sub_component().cloned()?;
+    own_layout_animation(&base.root_element)
+}
+
 fn set_prop_from_cache(
     elem: &ElementRc,
     prop: &str,
     layout_cache_prop: &NamedReference,
     index: usize,
     repeater_index: &Option<Expression>,
+    animation: &Option<PropertyAnimation>,
     diag: &mut BuildDiagnostics,
 ) {
-    let old = elem.borrow_mut().bindings.insert(
-        prop.into(),
-        BindingExpression::new_with_span(
-            Expression::LayoutCacheAccess {
-                layout_cache_prop: layout_cache_prop.clone(),
-                index,
-                repeater_index: repeater_index.as_ref().map(|x| Box::new(x.clone())),
-            },
-            layout_cache_prop.element().borrow().to_source_location(),
-        )
-        .into(),
+    let mut binding = BindingExpression::new_with_span(
+        Expression::LayoutCacheAccess {
+            layout_cache_prop: layout_cache_prop.clone(),
+            index,
+            repeater_index: repeater_index.as_ref().map(|x| Box::new(x.clone())),
+        },
+        layout_cache_prop.element().borrow().to_source_location(),
     );
+    binding.animation = animation.clone();
+    let old = elem.borrow_mut().bindings.insert(prop.into(), binding.into());
     if let Some(old) = old.map(RefCell::into_inner) {
         diag.push_error(
             format!("The property '{prop}' cannot be set for elements placed in this layout, because the layout is already setting it"),
@@ -820,6 +985,20 @@ fn eval_const_expr(
     }
 }
 
+/// Checks that `animate-layout` is only used on layouts or on elements placed in a layout
+fn check_no_layout_animation(elem: &ElementRc, diag: &mut BuildDiagnostics) {
+    let elem = elem.borrow();
+    if elem.layout_animation.is_none() || elem.child_of_layout {
+        return;
+    }
+    // The root of a component may be placed in a layout where the component is used
+    let component = elem.enclosing_component.upgrade().unwrap();
+    if std::ptr::eq(&*component.root_element.borrow(), &*elem) {
+        return;
+    }
+    diag.push_error("animate-layout used outside of a layout".into(), &*elem);
+}
+
 /// Checks that there is grid-layout specific properties left
 fn check_no_layout_properties(item: &ElementRc, diag: &mut BuildDiagnostics) {
     for (prop, expr) in item.borrow().bindings.iter() {
@@ -375,6 +375,9 @@ fn process_window(
             index_id: SmolStr::default(),
             is_conditional_element: true,
             is_listview: None,
+            is_gridview: None,
+            enter_transition: None,
+            exit_transition: None,
         }),
         ..Default::default()
     }
@@ -1,7 +1,8 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-//! Pass that create a state property, and change all the binding to depend on that property
+//! Pass that create a state property, and change all the binding to depend on that property.
+//! It also lowers the `@media` blocks: each block gets a boolean property for its condition.
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::diagnostics::SourceLocation;
@@ -23,6 +24,8 @@ pub fn lower_states(
     let state_info_type = tr.lookup("StateInfo");
     assert!(matches!(state_info_type, Type::Struct(ref s) if s.name.is_some()));
     recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        // Lower the media queries first, so that states take precedence over them
+        lower_media_queries_in_element(elem, diag);
         lower_state_in_element(elem, &state_info_type, diag)
     });
 }
@@ -64,43 +67,12 @@ fn lower_state_in_element(
         }
         for (ne, expr, node) in state.property_changes {
             affected_properties.insert(ne.clone());
-            let e = ne.element();
-            let property_expr = match expression_for_property(&e, ne.name()) {
-                ExpressionForProperty::TwoWayBinding => {
-                    diag.push_error(
-                    format!("Cannot change the property '{}' in a state because it is initialized with a two-way binding", ne.name()),
-                    &node
-                );
-                    continue;
-                }
-                ExpressionForProperty::Expression(e) => e,
-                ExpressionForProperty::InvalidBecauseOfIssue1461 => {
-                    diag.push_error(
-                        format!("Internal error: The expression for the default state currently cannot be represented: https://github.com/slint-ui/slint/issues/1461\nAs a workaround, add a binding for property {}", ne.name()),
-                        &node
-                    );
-                    continue;
-                }
-            };
-            let new_expr = Expression::Condition {
-                condition: Box::new(Expression::BinaryExpression {
-                    lhs: Box::new(state_property_ref.clone()),
-                    rhs: Box::new(Expression::NumberLiteral((idx + 1) as _, Unit::None)),
-                    op: '=',
-                }),
-                true_expr: Box::new(expr),
-                false_expr: Box::new(property_expr),
-            };
-            match e.borrow_mut().bindings.entry(ne.name().clone()) {
-                std::collections::btree_map::Entry::Occupied(mut e) => {
-                    e.get_mut().get_mut().expression = new_expr
-                }
-                std::collections::btree_map::Entry::Vacant(e) => {
-                    let mut r = BindingExpression::from(new_expr);
-                    r.priority = 1;
-                    e.insert(r.into());
-                }
+            let condition = Expression::BinaryExpression {
+                lhs: Box::new(state_property_ref.clone()),
+                rhs: Box::new(Expression::NumberLiteral((idx + 1) as _, Unit::None)),
+                op: '=',
             };
+            set_conditional_binding(&ne, condition, expr, &node, "a state", diag);
         }
         states_id.insert(state.id, idx as i32 + 1);
     }
@@ -117,6 +89,19 @@ fn lower_state_in_element(
         .bindings
         .insert(state_property_name, RefCell::new(state_value.into()));
 
+    let exit_duration = root_element
+        .borrow()
+        .repeated
+        .as_ref()
+        .and_then(|r| Some(r.exit_transition.as_ref()?.duration.clone()));
+    if let Some(exit_duration) = exit_duration {
+        let duration = exit_transition_duration(&root_element.borrow().transitions);
+        root_element
+            .borrow_mut()
+            .bindings
+            .insert(exit_duration.name().clone(), RefCell::new(duration.into()));
+    }
+
     lower_transitions_in_element(
         root_element,
         state_property,
@@ -126,6 +111,78 @@ fn lower_state_in_element(
     );
 }
 
+fn lower_media_queries_in_element(elem: &ElementRc, diag: &mut BuildDiagnostics) {
+    let media_queries = std::mem::take(&mut elem.borrow_mut().media_queries);
+    // Later blocks take precedence, so they must wrap the bindings of the earlier ones
+    for media_query in media_queries {
+        let condition_property = crate::layout::create_new_prop(
+            elem,
+            SmolStr::new_static("media-query-matches"),
+            Type::Bool,
+        );
+        let mut elem_mut = elem.borrow_mut();
+        // The binding analysis recognizes the condition by its node
+        elem_mut.property_declarations.get_mut(condition_property.name()).unwrap().node =
+            Some(media_query.node.clone().into());
+        elem_mut.bindings.insert(
+            condition_property.name().clone(),
+            RefCell::new(BindingExpression::new_with_span(
+                media_query.condition,
+                media_query.node.Expression().to_source_location(),
+            )),
+        );
+        drop(elem_mut);
+        for (ne, expr, node) in media_query.property_changes {
+            let condition = Expression::PropertyReference(condition_property.clone());
+            set_conditional_binding(&ne, condition, expr, &node, "a @media block", diag);
+        }
+    }
+}
+
+/// Change the binding of the property so that it is `condition ? expr : <current binding>`
+fn set_conditional_binding(
+    ne: &NamedReference,
+    condition: Expression,
+    expr: Expression,
+    node: &dyn Spanned,
+    what: &str,
+    diag: &mut BuildDiagnostics,
+) {
+    let e = ne.element();
+    let property_expr = match expression_for_property(&e, ne.name()) {
+        ExpressionForProperty::TwoWayBinding => {
+            diag.push_error(
+                format!("Cannot change the property '{}' in {what} because it is initialized with a two-way binding", ne.name()),
+                node,
+            );
+            return;
+        }
+        ExpressionForProperty::Expression(e) => e,
+        ExpressionForProperty::InvalidBecauseOfIssue1461 => {
+            diag.push_error(
+                format!("Internal error: The expression for the default state currently cannot be represented: https://github.com/slint-ui/slint/issues/1461\nAs a workaround, add a binding for property {}", ne.name()),
+                node,
+            );
+            return;
+        }
+    };
+    let new_expr = Expression::Condition {
+        condition: Box::new(condition),
+        true_expr: Box::new(expr),
+        false_expr: Box::new(property_expr),
+    };
+    match e.borrow_mut().bindings.entry(ne.name().clone()) {
+        std::collections::btree_map::Entry::Occupied(mut e) => {
+            e.get_mut().get_mut().expression = new_expr
+        }
+        std::collections::btree_map::Entry::Vacant(e) => {
+            let mut r = BindingExpression::from(new_expr);
+            r.priority = 1;
+            e.insert(r.into());
+        }
+    };
+}
+
 fn lower_transitions_in_element(
     elem: &ElementRc,
     state_property: Expression,
@@ -185,6 +242,35 @@ fn lower_transitions_in_element(
     }
 }
 
+/// Returns an expression for the time the animations of the `out` block of a repeated element take
+fn exit_transition_duration(transitions: &[Transition]) -> Expression {
+    transitions
+        .iter()
+        .filter(|t| t.state_id == EXIT_TRANSITION_STATE_ID)
+        .flat_map(|t| t.property_animations.iter())
+        .map(|(_, _, animation)| {
+            let animation = animation.borrow();
+            let binding =
+                |name: &str| animation.bindings.get(name).map(|b| b.borrow().expression.clone());
+            let duration = binding("duration").unwrap_or(Expression::NumberLiteral(0., Unit::Ms));
+            match binding("delay") {
+                Some(delay) => Expression::BinaryExpression {
+                    lhs: Box::new(duration),
+                    rhs: Box::new(delay),
+                    op: '+',
+                },
+                None => duration,
+            }
+        })
+        .reduce(|lhs, rhs| Expression::MinMax {
+            ty: Type::Duration,
+            op: MinMaxOp::Max,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        })
+        .unwrap_or(Expression::NumberLiteral(0., Unit::Ms))
+}
+
 /// Returns a suitable unique name for the "state" property
 fn compute_state_property_name(root_element: &ElementRc) -> SmolStr {
     let mut property_name = "state".to_owned();
@@ -107,7 +107,7 @@ fn ensure_pure(
                 r = false;
             }
         }
-        Expression::SelfAssignment { node, .. } => {
+        Expression::SelfAssignment { lhs, node, .. } if !is_local_variable(lhs) => {
             if let Some(diag) = diag.as_deref_mut() {
                 diag.push_diagnostic("Assignment in a pure context".into(), node, level);
             }
@@ -117,3 +117,12 @@ fn ensure_pure(
     });
     r
 }
+
+/// Returns true if the lhs of an assignment only changes a local variable, which is allowed in a pure context
+fn is_local_variable(lhs: &Expression) -> bool {
+    match lhs {
+        Expression::ReadLocalVariable { .. } => true,
+        Expression::StructFieldAccess { base, .. } => is_local_variable(base),
+        _ => false,
+    }
+}
@@ -11,6 +11,7 @@
 pub fn remove_return(doc: &crate::object_tree::Document) {
     doc.visit_all_used_components(|component| {
         crate::object_tree::visit_all_expressions(component, |e, _| {
+            lower_loops(e);
             let mut ret_ty = None;
             fn visit(e: &Expression, ret_ty: &mut Option<Type>) {
                 if ret_ty.is_some() {
@@ -32,6 +33,94 @@ fn visit(e: &Expression, ret_ty: &mut Option<Type>) {
     });
 }
 
+/// Remove the `break` and `continue` statements from the body of the `for` loops, so that the body
+/// becomes an expression of type bool which is false when the loop must stop.
+/// This is done by treating the body as a function that returns that bool.
+/// A `return` within the loop stores the returned value in a local variable and stops the loop,
+/// then the value is returned after the loop.
+fn lower_loops(e: &mut Expression) {
+    e.visit_mut(lower_loops);
+    let Expression::ForLoop { body, .. } = e else { return };
+
+    let mut returned_ty = None;
+    body.visit_recursive(&mut |e| {
+        if let Expression::ReturnStatement(x) = e {
+            returned_ty.get_or_insert_with(|| x.as_ref().map_or(Type::Void, |x| x.ty()));
+        }
+    });
+
+    static COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    let return_vars = returned_ty.map(|ty| {
+        let count = COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let flag = format_smolstr!("loop_returned{count}");
+        let value = has_value(&ty).then(|| (format_smolstr!("loop_returned_value{count}"), ty));
+        (flag, value)
+    });
+
+    replace_loop_control(body, &return_vars);
+    let new_body =
+        Expression::CodeBlock(vec![std::mem::take(&mut **body), Expression::BoolLiteral(true)]);
+    let ctx = RemoveReturnContext { ret_ty: Type::Bool };
+    **body = process_expression(new_body, true, &ctx, &Type::Bool).to_expression(&Type::Bool);
+
+    let Some((flag, value)) = return_vars else { return };
+    let mut stmts = vec![Expression::StoreLocalVariable {
+        name: flag.clone(),
+        value: Box::new(Expression::BoolLiteral(false)),
+    }];
+    if let Some((name, ty)) = &value {
+        stmts.push(Expression::StoreLocalVariable {
+            name: name.clone(),
+            value: Box::new(Expression::default_value_for_type(ty)),
+        });
+    }
+    stmts.push(std::mem::take(e));
+    stmts.push(Expression::Condition {
+        condition: Box::new(Expression::ReadLocalVariable { name: flag, ty: Type::Bool }),
+        true_expr: Box::new(Expression::ReturnStatement(
+            value.map(|(name, ty)| Box::new(Expression::ReadLocalVariable { name, ty })),
+        )),
+        false_expr: Box::new(Expression::CodeBlock(vec![])),
+    });
+    *e = Expression::CodeBlock(stmts);
+}
+
+/// Replace `break` and `continue` by a return of false or true, and `return` by an assignment of
+/// the local variables in `return_vars` followed by a `break`.
+fn replace_loop_control(
+    e: &mut Expression,
+    return_vars: &Option<(SmolStr, Option<(SmolStr, Type)>)>,
+) {
+    let assign = |name: &SmolStr, ty: Type, value: Expression| Expression::SelfAssignment {
+        lhs: Box::new(Expression::ReadLocalVariable { name: name.clone(), ty }),
+        rhs: Box::new(value),
+        op: '=',
+        node: None,
+    };
+    match e {
+        Expression::Break => {
+            *e = Expression::ReturnStatement(Some(Box::new(Expression::BoolLiteral(false))))
+        }
+        Expression::Continue => {
+            *e = Expression::ReturnStatement(Some(Box::new(Expression::BoolLiteral(true))))
+        }
+        Expression::ReturnStatement(returned) => {
+            let (flag, value) = return_vars.as_ref().expect("there was a return statement");
+            let mut stmts = vec![assign(flag, Type::Bool, Expression::BoolLiteral(true))];
+            match (returned.take(), value) {
+                (Some(returned), Some((name, ty))) => {
+                    stmts.push(assign(name, ty.clone(), *returned))
+                }
+                (Some(returned), None) => stmts.push(*returned),
+                (None, _) => {}
+            }
+            stmts.push(Expression::ReturnStatement(Some(Box::new(Expression::BoolLiteral(false)))));
+            *e = Expression::CodeBlock(stmts);
+        }
+        _ => e.visit_mut(|e| replace_loop_control(e, return_vars)),
+    }
+}
+
 fn process_expression(
     e: Expression,
     toplevel: bool,
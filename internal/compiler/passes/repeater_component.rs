@@ -5,8 +5,8 @@
 Make sure that the Repeated expression are just components without any children
  */
 
-use crate::expression_tree::{Expression, NamedReference};
-use crate::langtype::ElementType;
+use crate::expression_tree::{BuiltinFunction, Callable, Expression, NamedReference};
+use crate::langtype::{ElementType, Type};
 use crate::object_tree::*;
 use smol_str::SmolStr;
 use std::cell::RefCell;
@@ -19,8 +19,8 @@ pub fn process_repeater_components(component: &Rc<Component>) {
 
 fn create_repeater_components(component: &Rc<Component>) {
     recurse_elem(&component.root_element, &(), &mut |elem, _| {
-        let is_listview = match &elem.borrow().repeated {
-            Some(r) => r.is_listview.clone(),
+        let (is_listview, is_gridview) = match &elem.borrow().repeated {
+            Some(r) => (r.is_listview.clone(), r.is_gridview.clone()),
             None => return,
         };
         let parent_element = Rc::downgrade(elem);
@@ -52,7 +52,11 @@ fn create_repeater_components(component: &Rc<Component>) {
                 enclosing_component: Default::default(),
                 states: std::mem::take(&mut elem.states),
                 transitions: std::mem::take(&mut elem.transitions),
-                child_of_layout: elem.child_of_layout || is_listview.is_some(),
+                media_queries: std::mem::take(&mut elem.media_queries),
+                child_of_layout: elem.child_of_layout
+                    || is_listview.is_some()
+                    || is_gridview.is_some(),
+                layout_animation: elem.layout_animation.take(),
                 layout_info_prop: elem.layout_info_prop.take(),
                 default_fill_parent: elem.default_fill_parent,
                 accessibility_props: std::mem::take(&mut elem.accessibility_props),
@@ -64,7 +68,7 @@ fn create_repeater_components(component: &Rc<Component>) {
                 is_legacy_syntax: elem.is_legacy_syntax,
                 inline_depth: 0,
             })),
-            parent_element,
+            parent_element: parent_element.clone(),
             ..Component::default()
         });
 
@@ -87,6 +91,56 @@ fn create_repeater_components(component: &Rc<Component>) {
             }
         }
 
+        if let Some(gridview) = is_gridview {
+            // Place the cell in the grid according to its index:
+            // x: mod(index, columns) * (cell-width + spacing)
+            // y: (index - mod(index, columns)) / columns * (cell-height + spacing)
+            let index = || Expression::RepeaterIndexReference { element: parent_element.clone() };
+            let columns = || Expression::PropertyReference(gridview.columns.clone());
+            let column = || Expression::FunctionCall {
+                function: Callable::Builtin(BuiltinFunction::Mod),
+                arguments: vec![
+                    Expression::Cast { from: index().into(), to: Type::Float32 },
+                    Expression::Cast { from: columns().into(), to: Type::Float32 },
+                ],
+                source_location: None,
+            };
+            let row = Expression::BinaryExpression {
+                lhs: Expression::BinaryExpression {
+                    lhs: index().into(),
+                    rhs: column().into(),
+                    op: '-',
+                }
+                .into(),
+                rhs: columns().into(),
+                op: '/',
+            };
+            let position =
+                |cell_index: Expression, size: &NamedReference| Expression::BinaryExpression {
+                    lhs: cell_index.into(),
+                    rhs: Expression::BinaryExpression {
+                        lhs: Expression::PropertyReference(size.clone()).into(),
+                        rhs: Expression::PropertyReference(gridview.spacing.clone()).into(),
+                        op: '+',
+                    }
+                    .into(),
+                    op: '*',
+                };
+            for (prop, expr) in [
+                ("x", position(column(), &gridview.cell_width)),
+                ("y", position(row, &gridview.cell_height)),
+                ("width", Expression::PropertyReference(gridview.cell_width.clone())),
+                ("height", Expression::PropertyReference(gridview.cell_height.clone())),
+            ] {
+                if !comp.root_element.borrow().is_binding_set(prop, false) {
+                    comp.root_element
+                        .borrow_mut()
+                        .bindings
+                        .insert(prop.into(), RefCell::new(expr.into()));
+                }
+            }
+        }
+
         let weak = Rc::downgrade(&comp);
         recurse_elem(&comp.root_element, &(), &mut |e, _| {
             e.borrow_mut().enclosing_component = weak.clone()
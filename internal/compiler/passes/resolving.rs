@@ -17,7 +17,7 @@
 use crate::typeregister::TypeRegister;
 use core::num::IntErrorKind;
 use smol_str::{SmolStr, ToSmolStr};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
 /// This represents a scope for the Component, where Component is the repeated component, but
@@ -45,6 +45,8 @@ fn resolve_expression(
             type_register,
             type_loader: Some(type_loader),
             current_token: None,
+            local_variables: vec![],
+            loop_depth: 0,
         };
 
         let new_expr = match node.kind() {
@@ -146,6 +148,14 @@ pub fn resolve_expressions(
     }
 }
 
+/// Returns true if `s` is the type of a keyframe in the `keyframes` binding of an animation
+fn is_keyframe(ctx: &LookupCtx, s: &Struct) -> bool {
+    ctx.property_name == Some("keyframes")
+        && s.name.is_none()
+        && s.fields.len() == 3
+        && ["at", "value", "easing"].iter().all(|f| s.fields.contains_key(*f))
+}
+
 /// To be used in [`Expression::from_qualified_name_node`] to specify if the lookup is performed
 /// for two ways binding (which happens before the models and other expressions are resolved),
 /// or after that.
@@ -197,14 +207,21 @@ pub fn from_binding_expression_node(node: SyntaxNode, ctx: &mut LookupCtx) -> Se
     fn from_codeblock_node(node: syntax_nodes::CodeBlock, ctx: &mut LookupCtx) -> Expression {
         debug_assert_eq!(node.kind(), SyntaxKind::CodeBlock);
 
+        ctx.local_variables.push(Vec::new());
         let mut statements_or_exprs = node
             .children()
             .filter_map(|n| match n.kind() {
                 SyntaxKind::Expression => Some(Self::from_expression_node(n.into(), ctx)),
                 SyntaxKind::ReturnStatement => Some(Self::from_return_statement(n.into(), ctx)),
+                SyntaxKind::LetStatement => Some(Self::from_let_statement(n.into(), ctx)),
+                SyntaxKind::ForStatement => Some(Self::from_for_statement(n.into(), ctx)),
+                SyntaxKind::BreakStatement | SyntaxKind::ContinueStatement => {
+                    Some(Self::from_loop_control_statement(n, ctx))
+                }
                 _ => None,
             })
             .collect::<Vec<_>>();
+        ctx.local_variables.pop();
 
         let exit_points_and_return_types = statements_or_exprs
             .iter()
@@ -251,6 +268,87 @@ fn from_return_statement(
         }))
     }
 
+    fn from_let_statement(node: syntax_nodes::LetStatement, ctx: &mut LookupCtx) -> Expression {
+        let name = identifier_text(&node.DeclaredIdentifier()).unwrap_or_default();
+        let value = Self::from_expression_node(node.Expression(), ctx);
+        let ty = match node.Type() {
+            Some(ty) => type_from_node(ty, ctx.diag, ctx.type_register),
+            None => value.ty(),
+        };
+        if !ty.is_property_type() && ty != Type::Invalid {
+            ctx.diag.push_error(
+                format!("Cannot declare a local variable of type '{ty}'"),
+                &node.DeclaredIdentifier(),
+            );
+        }
+        Self::declare_local_variable(&name, ty.clone(), &node.DeclaredIdentifier(), ctx);
+        Expression::StoreLocalVariable {
+            name: crate::lookup::local_variable_name(&name),
+            value: Box::new(value.maybe_convert_to(ty, &node.Expression(), ctx.diag)),
+        }
+    }
+
+    fn from_for_statement(node: syntax_nodes::ForStatement, ctx: &mut LookupCtx) -> Expression {
+        let array = Self::from_expression_node(node.Expression(), ctx);
+        let element_ty = match array.ty() {
+            Type::Array(ty) => (*ty).clone(),
+            Type::Invalid => Type::Invalid,
+            ty => {
+                ctx.diag.push_error(
+                    format!("Cannot iterate over a value of type {ty}"),
+                    &node.Expression(),
+                );
+                Type::Invalid
+            }
+        };
+        let variable = identifier_text(&node.DeclaredIdentifier()).unwrap_or_default();
+        let index_variable = node.RepeatedIndex().and_then(|r| identifier_text(&r));
+
+        ctx.local_variables.push(Vec::new());
+        Self::declare_local_variable(&variable, element_ty, &node.DeclaredIdentifier(), ctx);
+        if let Some(index_variable) = &index_variable {
+            Self::declare_local_variable(
+                index_variable,
+                Type::Int32,
+                &node.RepeatedIndex().unwrap(),
+                ctx,
+            );
+        }
+        ctx.loop_depth += 1;
+        let body = Self::from_codeblock_node(node.CodeBlock(), ctx);
+        ctx.loop_depth -= 1;
+        ctx.local_variables.pop();
+
+        Expression::ForLoop {
+            array: Box::new(array),
+            variable: crate::lookup::local_variable_name(&variable),
+            index_variable: index_variable.map(|i| crate::lookup::local_variable_name(&i)),
+            body: Box::new(body),
+        }
+    }
+
+    fn from_loop_control_statement(node: SyntaxNode, ctx: &mut LookupCtx) -> Expression {
+        let is_break = node.kind() == SyntaxKind::BreakStatement;
+        if ctx.loop_depth == 0 {
+            let keyword = if is_break { "break" } else { "continue" };
+            ctx.diag.push_error(format!("'{keyword}' can only be used within a 'for' loop"), &node);
+            return Expression::Invalid;
+        }
+        if is_break {
+            Expression::Break
+        } else {
+            Expression::Continue
+        }
+    }
+
+    /// Add a local variable in the innermost scope of the context
+    fn declare_local_variable(name: &SmolStr, ty: Type, node: &dyn Spanned, ctx: &mut LookupCtx) {
+        if ctx.local_variables.iter().flatten().any(|(n, _)| n == name) {
+            ctx.diag.push_error(format!("Redeclaration of local variable '{name}'"), node);
+        }
+        ctx.local_variables.last_mut().expect("there should be a scope").push((name.clone(), ty));
+    }
+
     fn from_callback_connection(
         node: syntax_nodes::CallbackConnection,
         ctx: &mut LookupCtx,
@@ -318,6 +416,14 @@ pub fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx)
                     SyntaxKind::StringTemplate => {
                         Some(Self::from_string_template_node(node.into(), ctx))
                     }
+                    SyntaxKind::Lambda => {
+                        ctx.diag.push_error(
+                            "A lambda can only be the argument of 'filter', 'map', or 'sort-by'"
+                                .into(),
+                            &node,
+                        );
+                        Some(Self::Invalid)
+                    }
                     _ => None,
                 },
                 NodeOrToken::Token(token) => match token.kind() {
@@ -828,6 +934,28 @@ fn from_function_call_node(
             }
             return Self::Invalid;
         };
+        if let Some(LookupResult::Callable(LookupResultCallable::MemberFunction {
+            base,
+            member,
+            ..
+        })) = &function
+        {
+            let op = match **member {
+                LookupResultCallable::Macro(BuiltinMacroFunction::ArrayFilter) => {
+                    Some(ArrayTransformOp::Filter)
+                }
+                LookupResultCallable::Macro(BuiltinMacroFunction::ArrayMap) => {
+                    Some(ArrayTransformOp::Map)
+                }
+                LookupResultCallable::Macro(BuiltinMacroFunction::ArraySortBy) => {
+                    Some(ArrayTransformOp::SortBy)
+                }
+                _ => None,
+            };
+            if let Some(op) = op {
+                return Self::from_array_transform(node, base.clone(), op, sub_expr, ctx);
+            }
+        }
         let sub_expr = sub_expr.map(|n| {
             (Self::from_expression_node(n.clone(), ctx), Some(NodeOrToken::from((*n).clone())))
         });
@@ -912,6 +1040,77 @@ fn from_function_call_node(
         Expression::FunctionCall { function, arguments, source_location: Some(source_location) }
     }
 
+    /// `array.filter(x => ...)`, `array.map(x => ...)`, or `array.sort-by(x => ...)`
+    fn from_array_transform(
+        node: syntax_nodes::FunctionCallExpression,
+        array: Expression,
+        op: ArrayTransformOp,
+        mut args: impl Iterator<Item = syntax_nodes::Expression>,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let lambda = match (args.next(), args.next()) {
+            (Some(arg), None) => arg.Lambda(),
+            _ => None,
+        };
+        let Some(lambda) = lambda else {
+            let name = match op {
+                ArrayTransformOp::Filter => "filter",
+                ArrayTransformOp::Map => "map",
+                ArrayTransformOp::SortBy => "sort-by",
+            };
+            ctx.diag.push_error(
+                format!("'{name}' expects one argument of the form 'item => expression'"),
+                &node,
+            );
+            return Expression::Invalid;
+        };
+        let element_ty = match array.ty() {
+            Type::Array(ty) => (*ty).clone(),
+            _ => Type::Invalid,
+        };
+        let variable = identifier_text(&lambda.DeclaredIdentifier()).unwrap_or_default();
+
+        ctx.local_variables.push(Vec::new());
+        Self::declare_local_variable(&variable, element_ty, &lambda.DeclaredIdentifier(), ctx);
+        let expression = Self::from_expression_node(lambda.Expression(), ctx);
+        ctx.local_variables.pop();
+
+        let ty = expression.ty();
+        let expression = match op {
+            ArrayTransformOp::Filter => {
+                expression.maybe_convert_to(Type::Bool, &lambda.Expression(), ctx.diag)
+            }
+            ArrayTransformOp::Map => {
+                if !ty.is_property_type() {
+                    if ty != Type::Invalid {
+                        ctx.diag.push_error(
+                            format!("Cannot map to a value of type '{ty}'"),
+                            &lambda.Expression(),
+                        );
+                    }
+                    return Expression::Invalid;
+                }
+                expression
+            }
+            ArrayTransformOp::SortBy => {
+                if !matches!(ty, Type::String | Type::Invalid) && ty.as_unit_product().is_none() {
+                    ctx.diag.push_error(
+                        format!("Cannot sort by a value of type '{ty}'"),
+                        &lambda.Expression(),
+                    );
+                }
+                expression
+            }
+        };
+
+        Expression::ArrayTransform {
+            op,
+            array: Box::new(array),
+            variable: crate::lookup::local_variable_name(&variable),
+            expression: Box::new(expression),
+        }
+    }
+
     fn from_member_access_node(
         node: syntax_nodes::MemberAccess,
         ctx: &mut LookupCtx,
@@ -946,6 +1145,21 @@ fn from_self_assignment_node(
             lhs.try_set_rw(ctx, if op == '=' { "Assignment" } else { "Self assignment" }, &node);
         }
         let ty = lhs.ty();
+        if ty == Type::DateTime && matches!(op, '+' | '-') {
+            // `date += duration` is lowered to `date = date + duration`
+            let rhs = Self::from_expression_node(rhs_n.clone(), ctx).maybe_convert_to(
+                Type::Duration,
+                &rhs_n,
+                ctx.diag,
+            );
+            let rhs = Self::date_time_add(lhs.clone(), rhs, op, &node);
+            return Expression::SelfAssignment {
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+                op: '=',
+                node: Some(NodeOrToken::Node(node.into())),
+            };
+        }
         let expected_ty = match op {
             '=' => ty,
             '+' if ty == Type::String || ty.as_unit_product().is_some() => ty,
@@ -997,6 +1211,42 @@ fn from_binary_expression_node(
         let lhs = Self::from_expression_node(lhs_n.clone(), ctx);
         let rhs = Self::from_expression_node(rhs_n.clone(), ctx);
 
+        match (op, lhs.ty(), rhs.ty()) {
+            ('-', Type::DateTime, Type::DateTime) => {
+                return Expression::FunctionCall {
+                    function: BuiltinFunction::DateTimeDiff.into(),
+                    arguments: vec![lhs, rhs],
+                    source_location: Some(node.to_source_location()),
+                }
+            }
+            ('+' | '-', Type::DateTime, _) => {
+                let rhs = rhs.maybe_convert_to(Type::Duration, &rhs_n, ctx.diag);
+                return Self::date_time_add(lhs, rhs, op, &node);
+            }
+            ('+', _, Type::DateTime) => {
+                let lhs = lhs.maybe_convert_to(Type::Duration, &lhs_n, ctx.diag);
+                return Self::date_time_add(rhs, lhs, op, &node);
+            }
+            (_, Type::DateTime, _) | (_, _, Type::DateTime)
+                if operator_class(op) == OperatorClass::ComparisonOp =>
+            {
+                // Compare the difference with zero, so that the offset from UTC is ignored
+                return Expression::BinaryExpression {
+                    lhs: Box::new(Expression::FunctionCall {
+                        function: BuiltinFunction::DateTimeDiff.into(),
+                        arguments: vec![
+                            lhs.maybe_convert_to(Type::DateTime, &lhs_n, ctx.diag),
+                            rhs.maybe_convert_to(Type::DateTime, &rhs_n, ctx.diag),
+                        ],
+                        source_location: Some(node.to_source_location()),
+                    }),
+                    rhs: Box::new(Expression::NumberLiteral(0., Unit::Ms)),
+                    op,
+                };
+            }
+            _ => {}
+        }
+
         let expected_ty = match operator_class(op) {
             OperatorClass::ComparisonOp => {
                 let ty =
@@ -1072,6 +1322,25 @@ fn from_binary_expression_node(
         }
     }
 
+    /// `date_time + duration` or `date_time - duration`
+    fn date_time_add(
+        date_time: Expression,
+        duration: Expression,
+        op: char,
+        node: &dyn Spanned,
+    ) -> Expression {
+        let duration = if op == '-' {
+            Expression::UnaryOp { sub: Box::new(duration), op: '-' }
+        } else {
+            duration
+        };
+        Expression::FunctionCall {
+            function: BuiltinFunction::DateTimeAdd.into(),
+            arguments: vec![date_time, duration],
+            source_location: Some(node.to_source_location()),
+        }
+    }
+
     fn from_unaryop_expression_node(
         node: syntax_nodes::UnaryOpExpression,
         ctx: &mut LookupCtx,
@@ -1147,13 +1416,21 @@ fn from_index_expression_node(
     ) -> Expression {
         let (array_expr_n, index_expr_n) = node.Expression();
         let array_expr = Self::from_expression_node(array_expr_n, ctx);
+        let ty = array_expr.ty();
+        if matches!(ty, Type::Map(_)) {
+            let key_expr = Self::from_expression_node(index_expr_n.clone(), ctx).maybe_convert_to(
+                Type::String,
+                &index_expr_n,
+                ctx.diag,
+            );
+            return Expression::MapIndex { map: Box::new(array_expr), key: Box::new(key_expr) };
+        }
         let index_expr = Self::from_expression_node(index_expr_n.clone(), ctx).maybe_convert_to(
             Type::Int32,
             &index_expr_n,
             ctx.diag,
         );
 
-        let ty = array_expr.ty();
         if !matches!(ty, Type::Array(_) | Type::Invalid | Type::Function(_) | Type::Callback(_)) {
             ctx.diag.push_error(format!("{ty} is not an indexable type"), &node);
         }
@@ -1164,13 +1441,27 @@ fn from_object_literal_node(
         node: syntax_nodes::ObjectLiteral,
         ctx: &mut LookupCtx,
     ) -> Expression {
+        if node.ObjectMember().any(|n| n.child_token(SyntaxKind::StringLiteral).is_some()) {
+            return Self::from_map_literal_node(node, ctx);
+        }
+        let expected_ty = match &ctx.property_type {
+            Type::Struct(s) if is_keyframe(ctx, s) => Some(s.clone()),
+            _ => None,
+        };
         let values: HashMap<SmolStr, Expression> = node
             .ObjectMember()
             .map(|n| {
-                (
-                    identifier_text(&n).unwrap_or_default(),
-                    Expression::from_expression_node(n.Expression(), ctx),
-                )
+                let name = identifier_text(&n).unwrap_or_default();
+                // Adjust the expected return type so that easing curves and the values of the
+                // keyframes can be written like in the other bindings of the animation
+                let field_ty = expected_ty.as_ref().and_then(|s| s.fields.get(&name)).cloned();
+                let old_property_type =
+                    field_ty.map(|ty| std::mem::replace(&mut ctx.property_type, ty));
+                let e = Expression::from_expression_node(n.Expression(), ctx);
+                if let Some(old_property_type) = old_property_type {
+                    ctx.property_type = old_property_type;
+                }
+                (name, e)
             })
             .collect();
         let ty = Rc::new(Struct {
@@ -1182,9 +1473,50 @@ fn from_object_literal_node(
         Expression::Struct { ty, values }
     }
 
+    fn from_map_literal_node(node: syntax_nodes::ObjectLiteral, ctx: &mut LookupCtx) -> Expression {
+        let mut values = BTreeMap::new();
+        for n in node.ObjectMember() {
+            let key = match n.child_token(SyntaxKind::StringLiteral) {
+                Some(token) => {
+                    crate::literals::unescape_string(token.text()).unwrap_or_else(|| {
+                        ctx.diag.push_error("Cannot parse string literal".into(), &token);
+                        SmolStr::default()
+                    })
+                }
+                None => identifier_text(&n).unwrap_or_default(),
+            };
+            let value = Expression::from_expression_node(n.Expression(), ctx);
+            if values.insert(key.clone(), value).is_some() {
+                ctx.diag.push_error(format!("Duplicated key '{key}' in map literal"), &n);
+            }
+        }
+
+        let value_ty = Self::common_target_type_for_type_list(values.values().map(|e| e.ty()));
+        for e in values.values_mut() {
+            *e = core::mem::replace(e, Expression::Invalid).maybe_convert_to(
+                value_ty.clone(),
+                &node,
+                ctx.diag,
+            );
+        }
+
+        Expression::Map { value_ty, values }
+    }
+
     fn from_array_node(node: syntax_nodes::Array, ctx: &mut LookupCtx) -> Expression {
+        // Adjust the expected return type for the keyframes
+        let old_property_type = match &ctx.property_type {
+            Type::Array(ty) if matches!(&**ty, Type::Struct(s) if is_keyframe(ctx, s)) => {
+                let ty = (**ty).clone();
+                Some(std::mem::replace(&mut ctx.property_type, ty))
+            }
+            _ => None,
+        };
         let mut values: Vec<Expression> =
             node.Expression().map(|e| Expression::from_expression_node(e, ctx)).collect();
+        if let Some(old_property_type) = old_property_type {
+            ctx.property_type = old_property_type;
+        }
 
         let element_ty = if values.is_empty() {
             Type::Void
@@ -1275,6 +1607,12 @@ pub fn common_target_type_for_type_list(types: impl Iterator<Item = Type>) -> Ty
                         )
                         .into()
                     }),
+                    (Type::Map(lhs), Type::Map(rhs)) => Type::Map(
+                        Self::common_target_type_for_type_list(
+                            [(*lhs).clone(), (*rhs).clone()].into_iter(),
+                        )
+                        .into(),
+                    ),
                     (Type::Color, Type::Brush) | (Type::Brush, Type::Color) => Type::Brush,
                     (target_type, expr_ty) => {
                         if expr_ty.can_convert(&target_type) {
@@ -1591,6 +1929,9 @@ fn maybe_lookup_object(
                                 // usually something like `0..foo`
                                 format!(" of float. Range expressions are not supported in Slint, but you can use an integer as a model to repeat something multiple time. Eg: `for i in {}`", next.text())
                             }
+                            ty @ Type::Map(_) => {
+                                format!(" of the map {ty}. Use `[\"{}\"]` to look up a key", next.text())
+                            }
 
                             ty => format!(" of {ty}"),
                         };
@@ -1639,6 +1980,8 @@ fn resolve_two_way_bindings(
                                 type_register,
                                 type_loader: None,
                                 current_token: Some(node.clone().into()),
+                                local_variables: vec![],
+                                loop_depth: 0,
                             };
 
                             binding.expression = Expression::Invalid;
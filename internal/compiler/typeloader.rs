@@ -17,6 +17,12 @@
 use core::future::Future;
 use itertools::Itertools;
 
+mod design_tokens;
+mod runtime_styles;
+
+/// The path of the `std-widgets.slint` generated when several styles are compiled in
+const RUNTIME_STYLES_PATH: &str = "builtin:/runtime-styles/std-widgets.slint";
+
 enum LoadedDocument {
     Document(Document),
     Invalidated(syntax_nodes::Document),
@@ -196,6 +202,7 @@ fn snapshot_type_loader(&mut self, type_loader: &TypeLoader) -> Option<TypeLoade
             global_type_registry: self.snapshot_type_register(&type_loader.global_type_registry),
             compiler_config: type_loader.compiler_config.clone(),
             resolved_style: type_loader.resolved_style.clone(),
+            additional_styles: type_loader.additional_styles.clone(),
         })
     }
 
@@ -487,6 +494,23 @@ fn snapshot_element(
                     .collect(),
             })
             .collect();
+        target_element.media_queries = elem
+            .media_queries
+            .iter()
+            .map(|m| object_tree::MediaQuery {
+                condition: self.snapshot_expression(&m.condition),
+                property_changes: m
+                    .property_changes
+                    .iter()
+                    .map(|(nr, expr, spc)| {
+                        let nr = nr.snapshot(self);
+                        let expr = self.snapshot_expression(expr);
+                        (nr, expr, spc.clone())
+                    })
+                    .collect(),
+                node: m.node.clone(),
+            })
+            .collect();
         target_element.repeated =
             elem.repeated.as_ref().map(|r| object_tree::RepeatedElementInfo {
                 model: self.snapshot_expression(&r.model),
@@ -500,6 +524,32 @@ fn snapshot_element(
                     listview_height: lv.listview_height.snapshot(self),
                     listview_width: lv.listview_width.snapshot(self),
                 }),
+                is_gridview: r.is_gridview.as_ref().map(|gv| object_tree::GridViewInfo {
+                    viewport_y: gv.viewport_y.snapshot(self),
+                    viewport_height: gv.viewport_height.snapshot(self),
+                    gridview_height: gv.gridview_height.snapshot(self),
+                    columns: gv.columns.snapshot(self),
+                    cell_width: gv.cell_width.snapshot(self),
+                    cell_height: gv.cell_height.snapshot(self),
+                    spacing: gv.spacing.snapshot(self),
+                }),
+                enter_transition: r.enter_transition.as_ref().map(|enter| {
+                    object_tree::EnterTransitionInfo {
+                        entered: enter.entered.snapshot(self),
+                        properties: enter.properties.iter().map(|nr| nr.snapshot(self)).collect(),
+                    }
+                }),
+                exit_transition: r.exit_transition.as_ref().map(|exit| {
+                    object_tree::ExitTransitionInfo {
+                        leaving: exit.leaving.snapshot(self),
+                        duration: exit.duration.snapshot(self),
+                        frozen_properties: exit
+                            .frozen_properties
+                            .iter()
+                            .map(|nr| nr.snapshot(self))
+                            .collect(),
+                    }
+                }),
             });
 
         target_element.accessibility_props = object_tree::AccessibilityProps(
@@ -673,6 +723,9 @@ fn snapshot_layout_constraints(
                 .vertical_stretch
                 .as_ref()
                 .map(|lc| lc.snapshot(self)),
+            flex_grow: layout_constraints.flex_grow.as_ref().map(|lc| lc.snapshot(self)),
+            flex_shrink: layout_constraints.flex_shrink.as_ref().map(|lc| lc.snapshot(self)),
+            flex_basis: layout_constraints.flex_basis.as_ref().map(|lc| lc.snapshot(self)),
             fixed_width: layout_constraints.fixed_width,
             fixed_height: layout_constraints.fixed_height,
         }
@@ -718,6 +771,10 @@ fn snapshot_expression(
                 array: Box::new(self.snapshot_expression(array)),
                 index: Box::new(self.snapshot_expression(index)),
             },
+            Expression::MapIndex { map, key } => Expression::MapIndex {
+                map: Box::new(self.snapshot_expression(map)),
+                key: Box::new(self.snapshot_expression(key)),
+            },
             Expression::Cast { from, to } => {
                 Expression::Cast { from: Box::new(self.snapshot_expression(from)), to: to.clone() }
             }
@@ -765,6 +822,13 @@ fn snapshot_expression(
                     .map(|(k, v)| (k.clone(), self.snapshot_expression(v)))
                     .collect(),
             },
+            Expression::Map { value_ty, values } => Expression::Map {
+                value_ty: value_ty.clone(),
+                values: values
+                    .iter()
+                    .map(|(k, v)| (k.clone(), self.snapshot_expression(v)))
+                    .collect(),
+            },
             Expression::PathData(path) => Expression::PathData(match path {
                 expression_tree::Path::Elements(path_elements) => expression_tree::Path::Elements(
                     path_elements
@@ -812,6 +876,14 @@ fn snapshot_expression(
             Expression::ReturnStatement(expr) => Expression::ReturnStatement(
                 expr.as_ref().map(|e| Box::new(self.snapshot_expression(e))),
             ),
+            Expression::ForLoop { array, variable, index_variable, body } => Expression::ForLoop {
+                array: Box::new(self.snapshot_expression(array)),
+                variable: variable.clone(),
+                index_variable: index_variable.clone(),
+                body: Box::new(self.snapshot_expression(body)),
+            },
+            Expression::Break => Expression::Break,
+            Expression::Continue => Expression::Continue,
             Expression::LayoutCacheAccess { layout_cache_prop, index, repeater_index } => {
                 Expression::LayoutCacheAccess {
                     layout_cache_prop: layout_cache_prop.snapshot(self),
@@ -827,6 +899,14 @@ fn snapshot_expression(
                 rhs: Box::new(self.snapshot_expression(rhs)),
                 op: *op,
             },
+            Expression::ArrayTransform { op, array, variable, expression } => {
+                Expression::ArrayTransform {
+                    op: *op,
+                    array: Box::new(self.snapshot_expression(array)),
+                    variable: variable.clone(),
+                    expression: Box::new(self.snapshot_expression(expression)),
+                }
+            }
             _ => expr.clone(),
         }
     }
@@ -838,6 +918,10 @@ pub struct TypeLoader {
     /// The style that was specified in the compiler configuration, but resolved. So "native" for example is resolved to the concrete
     /// style.
     pub resolved_style: String,
+    /// When the compiler configuration specifies a comma separated list of styles, the resolved
+    /// styles after the first one (without duplicates). The `std-widgets.slint` then selects the
+    /// style at runtime.
+    pub additional_styles: Option<Vec<String>>,
     all_documents: LoadedDocuments,
 }
 
@@ -852,39 +936,53 @@ pub fn new(
         compiler_config: CompilerConfiguration,
         diag: &mut BuildDiagnostics,
     ) -> Self {
-        let mut style = compiler_config
+        let style = compiler_config
             .style
             .clone()
             .or_else(|| std::env::var("SLINT_STYLE").ok())
             .unwrap_or_else(|| "native".into());
 
-        if style == "native" {
-            style = get_native_style(&mut diag.all_loaded_files);
+        let mut styles = Vec::<String>::new();
+        for style in style.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let style = if style == "native" {
+                get_native_style(&mut diag.all_loaded_files)
+            } else {
+                style.into()
+            };
+            if !styles.contains(&style) {
+                styles.push(style);
+            }
+        }
+        if styles.is_empty() {
+            styles.push(get_native_style(&mut diag.all_loaded_files));
         }
 
         let myself = Self {
             global_type_registry,
             compiler_config,
-            resolved_style: style.clone(),
+            resolved_style: styles[0].clone(),
+            additional_styles: style.contains(',').then(|| styles[1..].to_vec()),
             all_documents: Default::default(),
         };
 
         let mut known_styles = fileaccess::styles();
         known_styles.push("native");
-        if !known_styles.contains(&style.as_ref())
-            && myself
-                .find_file_in_include_path(None, &format!("{style}/std-widgets.slint"))
-                .is_none()
-        {
-            diag.push_diagnostic_with_span(
-                format!(
-                    "Style {} is not known. Use one of the builtin styles [{}] or make sure your custom style is found in the include directories",
-                    &style,
-                    known_styles.join(", ")
-                ),
-                Default::default(),
-                crate::diagnostics::DiagnosticLevel::Error,
-            );
+        for style in &styles {
+            if !known_styles.contains(&style.as_ref())
+                && myself
+                    .find_file_in_include_path(None, &format!("{style}/std-widgets.slint"))
+                    .is_none()
+            {
+                diag.push_diagnostic_with_span(
+                    format!(
+                        "Style {} is not known. Use one of the builtin styles [{}] or make sure your custom style is found in the include directories",
+                        &style,
+                        known_styles.join(", ")
+                    ),
+                    Default::default(),
+                    crate::diagnostics::DiagnosticLevel::Error,
+                );
+            }
         }
 
         myself
@@ -1007,6 +1105,9 @@ async fn load_dependencies_recursively_impl<'a: 'b, 'b>(
                 };
                 match &import.import_kind {
                     ImportKind::ImportList(imported_types) => {
+                        if doc_path == Path::new(RUNTIME_STYLES_PATH) {
+                            Self::warn_about_fixed_style_widgets(doc, imported_types, &state.tl.resolved_style, state.diag);
+                        }
                         let mut imported_types = ImportedName::extract_imported_names(imported_types).peekable();
                         if imported_types.peek().is_some() {
                             Self::register_imported_types(doc, &import, imported_types, registry_to_populate, state.diag);
@@ -1098,6 +1199,9 @@ pub fn resolve_import_path(
     ) -> Option<(PathBuf, Option<&'static [u8]>)> {
         if let Some(maybe_library_import) = maybe_relative_path_or_url.strip_prefix('@') {
             self.find_file_in_library_path(maybe_library_import)
+        } else if maybe_relative_path_or_url.starts_with("builtin:/") {
+            crate::fileaccess::load_file(Path::new(maybe_relative_path_or_url))
+                .map(|virtual_file| (virtual_file.canon_path, virtual_file.builtin_contents))
         } else {
             let referencing_file_or_url =
                 import_token.and_then(|tok| tok.source_file().map(|s| s.path()));
@@ -1124,10 +1228,25 @@ async fn ensure_document_loaded<'a: 'b, 'b>(
     ) -> Option<PathBuf> {
         let mut borrowed_state = state.borrow_mut();
 
-        let (path_canon, builtin) = match borrowed_state
-            .tl
-            .resolve_import_path(import_token.as_ref(), file_to_import)
-        {
+        let resolved = borrowed_state.tl.resolve_import_path(import_token.as_ref(), file_to_import);
+        // When several styles are compiled in, the `std-widgets.slint` of the style is replaced by
+        // a generated one that selects the style at runtime
+        let runtime_styles = borrowed_state.tl.additional_styles.is_some()
+            && file_to_import == "std-widgets.slint"
+            && !import_token
+                .as_ref()
+                .and_then(|x| x.source_file())
+                .is_some_and(|f| f.path().starts_with("builtin:/"))
+            && resolved.as_ref().map(|x| &x.0)
+                == borrowed_state
+                    .tl
+                    .find_file_in_include_path(None, file_to_import)
+                    .as_ref()
+                    .map(|x| &x.0);
+        let resolved =
+            if runtime_styles { Some((RUNTIME_STYLES_PATH.into(), None)) } else { resolved };
+
+        let (path_canon, builtin) = match resolved {
             Some(x) => {
                 if let Some(file_name) = x.0.file_name().and_then(|f| f.to_str()) {
                     let len = file_to_import.len();
@@ -1175,6 +1294,20 @@ async fn ensure_document_loaded<'a: 'b, 'b>(
             }
         };
 
+        // A design tokens file, from which a global is generated
+        let is_design_tokens =
+            path_canon.to_str().is_some_and(|p| p.ends_with(design_tokens::EXTENSION));
+        if !is_design_tokens && path_canon.extension().is_some_and(|ext| ext == "json") {
+            borrowed_state.diag.push_error(
+                format!(
+                    "Cannot import \"{file_to_import}\": only design tokens files, with the `{}` extension, can be imported from JSON",
+                    design_tokens::EXTENSION
+                ),
+                &import_token,
+            );
+            return None;
+        }
+
         if !import_stack.insert(path_canon.clone()) {
             borrowed_state.diag.push_error(
                 format!("Recursive import of \"{}\"", path_canon.display()),
@@ -1224,7 +1357,9 @@ async fn ensure_document_loaded<'a: 'b, 'b>(
             }
             Some(doc_node)
         } else {
-            let source_code_result = if let Some(builtin) = builtin {
+            let source_code_result = if runtime_styles {
+                Ok(Box::pin(Self::load_runtime_styles(state)).await)
+            } else if let Some(builtin) = builtin {
                 Ok(String::from(
                     core::str::from_utf8(builtin)
                         .expect("internal error: embedded file is not UTF-8 source code"),
@@ -1239,6 +1374,29 @@ async fn ensure_document_loaded<'a: 'b, 'b>(
                 }
             };
             match source_code_result {
+                Ok(source) if is_design_tokens => match design_tokens::generate(&source) {
+                    Ok((source, warnings)) => {
+                        let mut state = state.borrow_mut();
+                        for warning in warnings {
+                            state.diag.push_warning(
+                                format!("In design tokens \"{file_to_import}\": {warning}"),
+                                &import_token,
+                            );
+                        }
+                        syntax_nodes::Document::new(crate::parser::parse(
+                            source,
+                            Some(&path_canon),
+                            state.diag,
+                        ))
+                    }
+                    Err(err) => {
+                        state.borrow_mut().diag.push_error(
+                            format!("Error reading design tokens \"{file_to_import}\": {err}"),
+                            &import_token,
+                        );
+                        None
+                    }
+                },
                 Ok(source) => syntax_nodes::Document::new(crate::parser::parse(
                     source,
                     Some(&path_canon),
@@ -1274,8 +1432,20 @@ async fn ensure_document_loaded<'a: 'b, 'b>(
         };
 
         let ok = if let Some(doc_node) = doc_node {
-            Self::load_file_impl(state, &path_canon, doc_node, builtin.is_some(), &import_stack)
-                .await;
+            Self::load_file_impl(
+                state,
+                &path_canon,
+                doc_node,
+                builtin.is_some() || runtime_styles || is_design_tokens,
+                &import_stack,
+            )
+            .await;
+            if runtime_styles {
+                if let Some(doc) = state.borrow().tl.get_document(&path_canon) {
+                    runtime_styles::declare_focus_functions(doc);
+                    runtime_styles::link_global_inputs(doc);
+                }
+            }
             state.borrow_mut().diag.all_loaded_files.insert(path_canon.clone());
             true
         } else {
@@ -1296,6 +1466,80 @@ async fn ensure_document_loaded<'a: 'b, 'b>(
         ok.then_some(path_canon)
     }
 
+    /// Load the `std-widgets.slint` of every style, and return the source code of the
+    /// `std-widgets.slint` that selects between them at runtime.
+    ///
+    /// The styles after the first one are loaded with their own `TypeLoader`, since the files
+    /// shared by the styles resolve their imports to the style being loaded. Their documents are
+    /// then moved to this `TypeLoader`, under `builtin:/runtime-styles/<style>/`.
+    async fn load_runtime_styles<'a>(state: &'a RefCell<BorrowedTypeLoader<'a>>) -> String {
+        let primary_path = state.borrow().tl.find_file_in_include_path(None, "std-widgets.slint");
+        let primary_path = primary_path.map(|(path, _)| path.to_string_lossy().into_owned());
+        let primary_path = match primary_path {
+            Some(path) => Self::ensure_document_loaded(state, &path, None, Default::default())
+                .await
+                .map(|path| path.to_string_lossy().into_owned()),
+            None => None,
+        };
+        let Some(primary_path) = primary_path else { return String::new() };
+
+        let (registry, config, additional_styles) = {
+            let state = state.borrow();
+            (
+                state.tl.global_type_registry.clone(),
+                state.tl.compiler_config.clone(),
+                state.tl.additional_styles.clone().unwrap_or_default(),
+            )
+        };
+        let mut style_paths = vec![];
+        for style in &additional_styles {
+            let mut diag = BuildDiagnostics::default();
+            let mut loader = TypeLoader::new(
+                registry.clone(),
+                CompilerConfiguration { style: Some(style.clone()), ..config.clone() },
+                &mut diag,
+            );
+            let path = {
+                let loader_state =
+                    RefCell::new(BorrowedTypeLoader { tl: &mut loader, diag: &mut diag });
+                Self::ensure_document_loaded(
+                    &loader_state,
+                    "std-widgets.slint",
+                    None,
+                    Default::default(),
+                )
+                .await
+            };
+            let mut state = state.borrow_mut();
+            let all_loaded_files = std::mem::take(&mut diag.all_loaded_files);
+            state.diag.extend(diag);
+            state.diag.all_loaded_files.extend(all_loaded_files);
+            let Some(path) = path else { return String::new() };
+            let relocate = |path: &Path| -> PathBuf {
+                let path = path.to_string_lossy();
+                let path = path.strip_prefix("builtin:/").unwrap_or(&path).trim_start_matches('/');
+                format!("builtin:/runtime-styles/{style}/{path}").into()
+            };
+            for (doc_path, doc) in loader.all_documents.docs {
+                state.tl.all_documents.docs.insert(relocate(&doc_path), doc);
+            }
+            style_paths.push(relocate(&path).to_string_lossy().into_owned());
+        }
+
+        let state = state.borrow();
+        let document = |path: &str| state.tl.get_document(Path::new(path)).unwrap();
+        let styles = std::iter::once((&state.tl.resolved_style, primary_path))
+            .chain(additional_styles.iter().zip(style_paths))
+            .map(|(style, path)| runtime_styles::StyleDocument {
+                style,
+                doc: document(&path),
+                path,
+            })
+            .collect::<Vec<_>>();
+        let source = runtime_styles::generate(&styles, &registry.borrow());
+        source
+    }
+
     /// Load a file, and its dependency, running only the import passes.
     ///
     /// the path must be the canonical path
@@ -1482,6 +1726,28 @@ fn register_imported_types(
         }
     }
 
+    /// Warn about the widgets imported from the generated `std-widgets.slint` that always use
+    /// the first style, because they can't be dispatched at runtime.
+    fn warn_about_fixed_style_widgets(
+        doc: &Document,
+        imported_types: &syntax_nodes::ImportIdentifierList,
+        style: &str,
+        build_diagnostics: &mut BuildDiagnostics,
+    ) {
+        for identifier in imported_types.ImportIdentifier() {
+            let name = ImportedName::from_node(identifier.clone()).external_name;
+            let Some(itertools::Either::Left(c)) = doc.exports.find(&name) else { continue };
+            if !c.is_global() && !doc.inner_components.iter().any(|x| Rc::ptr_eq(x, &c)) {
+                build_diagnostics.push_warning(
+                    format!(
+                        "'{name}' can't switch styles at runtime and always uses the {style} style"
+                    ),
+                    &identifier.ExternalName(),
+                );
+            }
+        }
+    }
+
     /// Lookup a library and filename and try to find the absolute filename based on the library path
     fn find_file_in_library_path(
         &self,
@@ -0,0 +1,287 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Conversion of a design tokens file, in the format of the
+//! [W3C Design Tokens Community Group](https://tr.designtokens.org/format/), to a `.slint` document
+//! that exports a `DesignTokens` global with one property per token.
+//!
+//! Only files with the `.tokens.json` extension are design tokens files.
+//!
+//! The name of a property is the path of the token in the groups, joined with `-`. So the token
+//! `{ "color": { "primary": { "$value": "#005fb8", "$type": "color" } } }` becomes
+//! `out property <brush> color-primary: #005fb8;`.
+//!
+//! A token can have a different value in dark or light mode with
+//! `"$extensions": { "mode": { "dark": ..., "light": ... } }`. The global then has a
+//! `color-scheme` property, which follows the color scheme of the system when it is `unknown`.
+
+use serde_json::{Map, Value};
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+/// The extension of the files that are imported as design tokens
+pub const EXTENSION: &str = ".tokens.json";
+
+struct Token<'a> {
+    /// The path of the token in the groups
+    path: Vec<&'a str>,
+    /// The `$type` of the token or of one of its groups
+    ty: Option<&'a str>,
+    value: &'a Value,
+    dark: Option<&'a Value>,
+    light: Option<&'a Value>,
+}
+
+/// Returns the source code of the generated document, and the warnings.
+pub fn generate(json: &str) -> Result<(String, Vec<String>), String> {
+    let json: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let Value::Object(root) = &json else {
+        return Err("The design tokens must be a JSON object".into());
+    };
+    let mut tokens = Vec::new();
+    collect_tokens(root, &mut Vec::new(), None, &mut tokens)?;
+
+    let by_path: HashMap<String, &Token> = tokens.iter().map(|t| (t.path.join("."), t)).collect();
+    let mut warnings = Vec::new();
+    let mut properties = BTreeMap::new();
+    let mut has_modes = false;
+    for token in &tokens {
+        let name = property_name(&token.path)?;
+        let Some(ty) = token_type(token, &by_path, &mut Vec::new())? else {
+            warnings
+                .push(format!("The token '{}' has no type and is ignored", token.path.join(".")));
+            continue;
+        };
+        let Some(slint_type) = slint_type(ty) else {
+            warnings.push(format!(
+                "The token '{}' has the unsupported type '{ty}' and is ignored",
+                token.path.join(".")
+            ));
+            continue;
+        };
+        let value = |v| {
+            value_to_source(v, ty, &by_path)
+                .map_err(|e| format!("{e} in token '{}'", token.path.join(".")))
+        };
+        let default = value(token.value)?;
+        let dark = token.dark.map(value).transpose()?.unwrap_or_else(|| default.clone());
+        let light = token.light.map(value).transpose()?.unwrap_or(default);
+        let expression = if dark == light {
+            dark
+        } else {
+            has_modes = true;
+            format!("dark-color-scheme ? {dark} : {light}")
+        };
+        // `_` and `-` are the same in identifiers
+        match properties.entry(name.replace('_', "-")) {
+            Entry::Vacant(e) => {
+                e.insert((name, token, slint_type, expression));
+            }
+            Entry::Occupied(e) => {
+                return Err(format!(
+                    "The tokens '{}' and '{}' have the same name '{}'",
+                    e.get().1.path.join("."),
+                    token.path.join("."),
+                    e.key()
+                ));
+            }
+        }
+    }
+
+    let mut result = String::from("export global DesignTokens {\n");
+    if has_modes {
+        for reserved in ["color-scheme", "dark-color-scheme"] {
+            if properties.contains_key(reserved) {
+                return Err(format!("The token name '{reserved}' is reserved"));
+            }
+        }
+        result += "    in-out property <ColorScheme> color-scheme;\n";
+        result += "    property <bool> dark-color-scheme: color-scheme == ColorScheme.unknown ? SlintInternal.color-scheme == ColorScheme.dark : color-scheme == ColorScheme.dark;\n";
+    }
+    for (name, _, ty, expression) in properties.into_values() {
+        writeln!(result, "    out property <{ty}> {name}: {expression};").unwrap();
+    }
+    result += "}\n";
+    Ok((result, warnings))
+}
+
+fn collect_tokens<'a>(
+    group: &'a Map<String, Value>,
+    path: &mut Vec<&'a str>,
+    ty: Option<&'a str>,
+    tokens: &mut Vec<Token<'a>>,
+) -> Result<(), String> {
+    let ty = group.get("$type").and_then(Value::as_str).or(ty);
+    if let Some(value) = group.get("$value") {
+        let mode = group.get("$extensions").and_then(|e| e.get("mode"));
+        tokens.push(Token {
+            path: path.clone(),
+            ty,
+            value,
+            dark: mode.and_then(|m| m.get("dark")),
+            light: mode.and_then(|m| m.get("light")),
+        });
+        return Ok(());
+    }
+    for (name, child) in group.iter().filter(|(name, _)| !name.starts_with('$')) {
+        let Value::Object(child) = child else {
+            return Err(format!(
+                "'{}' is neither a token nor a group",
+                [&path[..], &[name]].concat().join(".")
+            ));
+        };
+        path.push(name);
+        collect_tokens(child, path, ty, tokens)?;
+        path.pop();
+    }
+    Ok(())
+}
+
+/// The name of the property for the token: the path, joined with `-`, and without the characters
+/// that aren't allowed in identifiers
+fn property_name(path: &[&str]) -> Result<String, String> {
+    let name = path
+        .iter()
+        .map(|segment| {
+            segment
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '-' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("-");
+    if name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        Ok(name)
+    } else {
+        Err(format!("The token '{}' doesn't have a valid name", path.join(".")))
+    }
+}
+
+/// Returns the name of the token an alias (`"{group.token}"`) refers to
+fn alias(value: &Value) -> Option<&str> {
+    value.as_str()?.strip_prefix('{')?.strip_suffix('}')
+}
+
+/// The type of the token, which is the type of the referenced token for aliases without type
+fn token_type<'a>(
+    token: &Token<'a>,
+    by_path: &HashMap<String, &Token<'a>>,
+    visited: &mut Vec<String>,
+) -> Result<Option<&'a str>, String> {
+    if token.ty.is_some() {
+        return Ok(token.ty);
+    }
+    let Some(target) = alias(token.value) else { return Ok(None) };
+    if visited.iter().any(|v| v == target) {
+        return Err(format!("The token '{target}' refers to itself"));
+    }
+    visited.push(target.into());
+    let target = by_path.get(target).ok_or_else(|| format!("Unknown token '{target}'"))?;
+    token_type(target, by_path, visited)
+}
+
+fn slint_type(ty: &str) -> Option<&'static str> {
+    Some(match ty {
+        "color" => "brush",
+        "dimension" => "length",
+        "duration" => "duration",
+        "fontFamily" => "string",
+        "fontWeight" => "int",
+        "number" => "float",
+        _ => return None,
+    })
+}
+
+fn value_to_source(
+    value: &Value,
+    ty: &str,
+    by_path: &HashMap<String, &Token>,
+) -> Result<String, String> {
+    if let Some(target) = alias(value) {
+        let target_token =
+            by_path.get(target).ok_or_else(|| format!("Unknown token '{target}'"))?;
+        if token_type(target_token, by_path, &mut Vec::new())? != Some(ty) {
+            return Err(format!("The token '{target}' doesn't have the type '{ty}'"));
+        }
+        return property_name(&target_token.path);
+    }
+    let invalid = || format!("Invalid {ty} value '{value}'");
+    match (ty, value) {
+        ("color", Value::String(hex)) => {
+            let digits = hex.strip_prefix('#').ok_or_else(invalid)?;
+            if matches!(digits.len(), 3 | 4 | 6 | 8)
+                && digits.chars().all(|c| c.is_ascii_hexdigit())
+            {
+                Ok(hex.clone())
+            } else {
+                Err(invalid())
+            }
+        }
+        ("color", Value::Object(color)) => {
+            if let Some(hex) = color.get("hex") {
+                return value_to_source(hex, ty, by_path);
+            }
+            if color.get("colorSpace").and_then(Value::as_str) != Some("srgb") {
+                return Err(invalid());
+            }
+            let components = color
+                .get("components")
+                .and_then(Value::as_array)
+                .and_then(|c| c.iter().map(Value::as_f64).collect::<Option<Vec<_>>>())
+                .filter(|c| c.len() == 3)
+                .ok_or_else(invalid)?;
+            let alpha = color.get("alpha").and_then(Value::as_f64).unwrap_or(1.);
+            Ok(format!(
+                "rgba({}, {}, {}, {alpha})",
+                components[0] * 255.,
+                components[1] * 255.,
+                components[2] * 255.
+            ))
+        }
+        ("dimension", _) => number_with_unit(value, &["px", "rem"]).ok_or_else(invalid),
+        ("duration", _) => number_with_unit(value, &["ms", "s"]).ok_or_else(invalid),
+        ("fontFamily", Value::String(family)) => Ok(format!("{family:?}")),
+        ("fontFamily", Value::Array(families)) => {
+            families.first().and_then(Value::as_str).map(|f| format!("{f:?}")).ok_or_else(invalid)
+        }
+        ("fontWeight", Value::Number(weight)) => {
+            weight.as_f64().map(|w| format!("{}", w.round())).ok_or_else(invalid)
+        }
+        ("fontWeight", Value::String(weight)) => Ok(match weight.as_str() {
+            "thin" | "hairline" => "100",
+            "extra-light" | "ultra-light" => "200",
+            "light" => "300",
+            "normal" | "regular" | "book" => "400",
+            "medium" => "500",
+            "semi-bold" | "demi-bold" => "600",
+            "bold" => "700",
+            "extra-bold" | "ultra-bold" => "800",
+            "black" | "heavy" => "900",
+            "extra-black" | "ultra-black" => "950",
+            _ => return Err(invalid()),
+        }
+        .into()),
+        ("number", Value::Number(number)) => {
+            number.as_f64().map(|n| format!("{n}")).ok_or_else(invalid)
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Converts `"16px"` or `{ "value": 16, "unit": "px" }` with one of the given units.
+/// (A unit that ends with another one must come first.)
+fn number_with_unit(value: &Value, units: &[&str]) -> Option<String> {
+    let (number, unit) = match value {
+        Value::String(s) => {
+            let unit = units.iter().find(|u| s.ends_with(**u))?;
+            (s.strip_suffix(unit)?.trim().parse::<f64>().ok()?, *unit)
+        }
+        Value::Object(o) => {
+            let unit = o.get("unit")?.as_str()?;
+            (o.get("value")?.as_f64()?, *units.iter().find(|u| **u == unit)?)
+        }
+        _ => return None,
+    };
+    Some(format!("{number}{unit}"))
+}
@@ -0,0 +1,935 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Generation of the `std-widgets.slint` that is used when several styles are compiled in.
+//!
+//! The generated file exports a `StyleSelector` global, and for every widget and global of the
+//! first style that has the same API in all the other styles, a wrapper that forwards its API to
+//! the one selected by `StyleSelector.current-style`. Widget wrappers instantiate the widget of
+//! every style and only show the selected one, so that calls and outputs can be forwarded by id.
+//! Components that only derive from a builtin element with bindings to the globals, like the
+//! layout widgets, are re-declared to read the generated globals. Everything else, notably the
+//! widgets with `@children` which can't be forwarded, is re-exported from the first style.
+//!
+//! Components that are not used are not generated, so only the widgets actually used by the
+//! application end up dispatching at runtime.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BindingExpression, Callable, Expression};
+use crate::langtype::{BuiltinPropertyDefault, ElementType, Function, Type};
+use crate::lookup::{global_lookup, LookupCtx, LookupObject};
+use crate::namedreference::NamedReference;
+use crate::object_tree::{
+    Component, Document, Element, ElementRc, PropertyDeclaration, PropertyVisibility,
+};
+use crate::parser::{SyntaxKind, SyntaxNode};
+use crate::typeregister::TypeRegister;
+use itertools::Itertools;
+use smol_str::{format_smolstr, SmolStr};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use std::rc::Rc;
+
+/// The `std-widgets.slint` document of one of the styles
+pub struct StyleDocument<'a> {
+    pub style: &'a str,
+    /// The path from which the document can be imported
+    pub path: String,
+    pub doc: &'a Document,
+}
+
+/// The public API of a component: the name and type of the properties, callbacks and functions
+type Api = BTreeMap<SmolStr, (Type, PropertyVisibility, bool)>;
+
+/// Returns the source code of the `std-widgets.slint` that dispatches between the styles.
+/// The first style is the default one.
+pub fn generate(styles: &[StyleDocument], registry: &TypeRegister) -> String {
+    let primary = &styles[0];
+    let mut result = String::from(
+        "// This file is generated by the compiler to select between several styles at runtime\n\n",
+    );
+
+    let mut reexports = Vec::new();
+    let mut imports = vec![Vec::new(); styles.len()];
+    let mut wrappers = String::new();
+    // The components that derive from a builtin element, and the generated globals
+    let mut derived = Vec::new();
+    let mut wrapped_globals = HashMap::new();
+    for (name, compo_or_type) in primary.doc.exports.iter() {
+        let name = &name.name;
+        let compo = match compo_or_type {
+            itertools::Either::Left(compo) => compo,
+            itertools::Either::Right(_) => {
+                imports[0].push(format!("{name}"));
+                reexports.push(name.clone());
+                continue;
+            }
+        };
+        let Some(api) = public_api(compo).filter(|_| styles.len() > 1) else {
+            if styles.len() > 1 {
+                derived.push((name.clone(), compo.clone()));
+            }
+            reexports.push(name.clone());
+            continue;
+        };
+        let components = std::iter::once(Some(compo.clone()))
+            .chain(styles[1..].iter().map(|s| {
+                s.doc
+                    .exports
+                    .find(name)
+                    .and_then(|c| c.left())
+                    .filter(|c| c.is_global() == compo.is_global())
+            }))
+            .collect::<Vec<_>>();
+        let apis = std::iter::once(Some(api.clone()))
+            .chain(components[1..].iter().map(|c| c.as_ref().and_then(public_api)))
+            .collect::<Vec<_>>();
+        // A widget must have the same API in all styles, while a property of a global that is
+        // not in all the styles uses the value of the first style for these styles
+        let compatible = compo.is_global()
+            || apis.iter().all(|other| {
+                other.as_ref().is_some_and(|other| {
+                    other.len() == api.len()
+                        && api.iter().all(|(name, entry)| is_compatible(entry, other.get(name)))
+                })
+            });
+        let has_component = apis.iter().map(Option::is_some).collect::<Vec<_>>();
+        let apis = apis.into_iter().map(Option::unwrap_or_default).collect::<Vec<_>>();
+        let Some(wrapper) = compatible
+            .then(|| {
+                if compo.is_global() {
+                    generate_global_wrapper(name, &apis, styles, registry)
+                } else {
+                    generate_component_wrapper(name, &components, &apis, styles, registry)
+                }
+            })
+            .flatten()
+        else {
+            reexports.push(name.clone());
+            continue;
+        };
+        for (i, imports) in imports.iter_mut().enumerate() {
+            if has_component[i] {
+                imports.push(format!("{name} as {name}-Style{i}"));
+            }
+        }
+        if compo.is_global() {
+            wrapped_globals.insert(Rc::as_ptr(compo), (name.clone(), apis[0].clone()));
+        }
+        wrappers += &wrapper;
+    }
+    for (name, compo) in derived {
+        if let Some(wrapper) = generate_derived_component(&name, &compo, &wrapped_globals) {
+            reexports.retain(|n| *n != name);
+            wrappers += &wrapper;
+        }
+    }
+
+    for (style, imports) in styles.iter().zip(imports) {
+        if !imports.is_empty() {
+            writeln!(result, "import {{ {} }} from \"{}\";", imports.join(", "), style.path)
+                .unwrap();
+        }
+    }
+    let reexported_types = reexports
+        .iter()
+        .filter(|name| primary.doc.exports.find(name).is_some_and(|c| c.is_right()))
+        .join(", ");
+    if !reexported_types.is_empty() {
+        writeln!(result, "export {{ {reexported_types} }}").unwrap();
+    }
+    let reexported_components = reexports
+        .iter()
+        .filter(|name| primary.doc.exports.find(name).is_some_and(|c| c.is_left()))
+        .join(", ");
+    if !reexported_components.is_empty() {
+        writeln!(result, "export {{ {reexported_components} }} from \"{}\";", primary.path)
+            .unwrap();
+    }
+
+    writeln!(
+        result,
+        "\nexport global StyleSelector {{\n    in-out property <string> current-style: {:?};\n    out property <[string]> styles: [{}];\n}}\n",
+        primary.style,
+        styles.iter().map(|s| format!("{:?}", s.style)).join(", ")
+    )
+    .unwrap();
+    result + &wrappers
+}
+
+/// Returns the public API of a component that can be dispatched: a global, or a component that
+/// only derives from other components or `Empty` and has no `@children`
+fn public_api(compo: &Rc<Component>) -> Option<Api> {
+    let mut api = Api::new();
+    let mut compo = compo.clone();
+    loop {
+        if compo.child_insertion_point.borrow().is_some() || compo.id.ends_with("Impl") {
+            return None;
+        }
+        let root = compo.root_element.clone();
+        let root = root.borrow();
+        // (declarations without node are generated by the compiler, like the `focus()` function
+        // of elements with `forward-focus`, and can't be overridden)
+        for (name, decl) in root.property_declarations.iter().filter(|(_, d)| d.node.is_some()) {
+            let is_public = match &decl.property_type {
+                Type::Function(_) => decl.visibility == PropertyVisibility::Public,
+                // Callbacks of globals can't be forwarded
+                Type::Callback(_) if compo.is_global() => return None,
+                Type::Callback(_) => true,
+                _ => matches!(
+                    decl.visibility,
+                    PropertyVisibility::Input
+                        | PropertyVisibility::Output
+                        | PropertyVisibility::InOut
+                ),
+            };
+            if is_public {
+                api.entry(name.clone()).or_insert((
+                    decl.property_type.clone(),
+                    decl.visibility,
+                    decl.pure.unwrap_or(false),
+                ));
+            }
+        }
+        match &root.base_type {
+            ElementType::Component(base) => {
+                let base = base.clone();
+                drop(root);
+                compo = base;
+            }
+            ElementType::Global => return Some(api),
+            ElementType::Builtin(b) if b.name == "Empty" => return Some(api),
+            _ => return None,
+        }
+    }
+}
+
+/// Returns whether a property, callback, or function of the API is the same in the other style
+fn is_compatible(
+    (ty, visibility, _): &(Type, PropertyVisibility, bool),
+    other: Option<&(Type, PropertyVisibility, bool)>,
+) -> bool {
+    other.is_some_and(|(other_ty, other_visibility, _)| {
+        visibility == other_visibility
+            && match (ty, other_ty) {
+                (Type::Callback(a), Type::Callback(b)) | (Type::Function(a), Type::Function(b)) => {
+                    a.args == b.args && a.return_type == b.return_type
+                }
+                _ => ty == other_ty,
+            }
+    })
+}
+
+/// Generate the global `name` that dispatches to the global of each style.
+/// Returns None if one of the types can't be expressed in the generated code.
+fn generate_global_wrapper(
+    name: &str,
+    apis: &[Api],
+    styles: &[StyleDocument],
+    registry: &TypeRegister,
+) -> Option<String> {
+    let variant = |i: usize| format!("{name}-Style{i}");
+    // The other styles that have the same property or function
+    let styles_with = |prop: &SmolStr| {
+        (1..styles.len())
+            .filter(|i| is_compatible(&apis[0][prop], apis[*i].get(prop)))
+            .collect_vec()
+    };
+    let type_name = |ty: &Type| type_to_source(ty, styles[0].doc, registry);
+
+    let mut result = format!("export global {name} {{\n");
+    for (prop, (ty, visibility, pure)) in &apis[0] {
+        let indices = styles_with(prop);
+        match ty {
+            Type::Function(f) => {
+                let args = (0..f.args.len()).map(|i| format!("arg{i}")).join(", ");
+                writeln!(
+                    result,
+                    "    {}public function {prop}({}){} {{ {} }}",
+                    if *pure { "pure " } else { "" },
+                    arguments_declaration(f, false, &type_name)?,
+                    return_type_declaration(f, &type_name)?,
+                    dispatch_call(f, &format!("{prop}({args})"), &indices, &variant, styles)
+                )
+                .unwrap();
+            }
+            _ if *visibility == PropertyVisibility::Output => {
+                writeln!(
+                    result,
+                    "    out property <{}> {prop}: {};",
+                    type_name(ty)?,
+                    dispatch(prop, &indices, &variant, styles)
+                )
+                .unwrap();
+            }
+            _ => {
+                let visibility =
+                    if *visibility == PropertyVisibility::Input { "in" } else { "in-out" };
+                writeln!(result, "    {visibility} property {prop} <=> {}.{prop};", variant(0))
+                    .unwrap();
+            }
+        }
+    }
+    result += "}\n\n";
+    Some(result)
+}
+
+/// `StyleSelector.current-style == "style"` for the style at index `i`
+fn style_condition(styles: &[StyleDocument], i: usize) -> String {
+    format!("StyleSelector.current-style == {:?}", styles[i].style)
+}
+
+/// `cond1 ? variant-1.expr : (cond2 ? variant-2.expr : variant-0.expr)`, where `indices` are the
+/// other styles whose variant has `expr`
+fn dispatch(
+    expr: &str,
+    indices: &[usize],
+    variant: &impl Fn(usize) -> String,
+    styles: &[StyleDocument],
+) -> String {
+    indices.iter().rev().fold(format!("{}.{expr}", variant(0)), |acc, i| {
+        let acc = if acc.contains('?') { format!("({acc})") } else { acc };
+        format!("{} ? {}.{expr} : {acc}", style_condition(styles, *i), variant(*i))
+    })
+}
+
+/// The body of a function that calls `call` on the variant of the selected style
+fn dispatch_call(
+    f: &Function,
+    call: &str,
+    indices: &[usize],
+    variant: &impl Fn(usize) -> String,
+    styles: &[StyleDocument],
+) -> String {
+    if f.return_type == Type::Void {
+        indices
+            .iter()
+            .map(|i| {
+                format!("if ({}) {{ {}.{call}; }} else ", style_condition(styles, *i), variant(*i))
+            })
+            .join("")
+            + &format!("{{ {}.{call}; }}", variant(0))
+    } else {
+        format!("return {};", dispatch(call, indices, variant, styles))
+    }
+}
+
+/// Generate the component `name` for a component of the first style that derives from a builtin
+/// element and only binds its properties to properties of the dispatched globals, such as the
+/// `VerticalBox` whose spacing comes from `StyleMetrics`. The generated component reads the
+/// generated globals instead, so that it follows the selected style.
+/// Returns None for other components.
+fn generate_derived_component(
+    name: &str,
+    compo: &Rc<Component>,
+    wrapped_globals: &HashMap<*const Component, (SmolStr, Api)>,
+) -> Option<String> {
+    let root = compo.root_element.borrow();
+    let ElementType::Builtin(base) = &root.base_type else { return None };
+    if !root.children.is_empty()
+        || !root.states.is_empty()
+        || !root.transitions.is_empty()
+        || root.property_declarations.values().any(|d| d.node.is_some())
+        || compo.child_insertion_point.borrow().is_some()
+    {
+        return None;
+    }
+    let mut result = format!("export component {name} inherits {} {{\n", base.name);
+    for (prop, binding) in &root.bindings {
+        let binding = binding.borrow();
+        let Expression::PropertyReference(nr) =
+            crate::passes::ignore_debug_hooks(&binding.expression)
+        else {
+            return None;
+        };
+        let global = nr.element().borrow().enclosing_component.upgrade()?;
+        let (global_name, api) = wrapped_globals.get(&Rc::as_ptr(&global))?;
+        if !binding.two_way_bindings.is_empty()
+            || binding.animation.is_some()
+            || !api.contains_key(nr.name())
+        {
+            return None;
+        }
+        writeln!(result, "    {prop}: {global_name}.{};", nr.name()).unwrap();
+    }
+    result += "}\n\n";
+    Some(result)
+}
+
+/// Generate the component `name` that instantiates the widget of every style, and only shows the
+/// one of the selected style.
+///
+/// The input properties are bound to the ones of every instance, and the wrapper dispatches its
+/// output properties, its size constraints, and the calls to its functions (and `focus()`) to the
+/// instance of the selected style. (A two-way binding can't forward the output properties: the
+/// wrapper can't bind the output properties of the instances, and would link them together.)
+/// The in-out properties whose default value is computed by the widget are dispatched the same
+/// way, and a change handler sets the value assigned to the wrapper on the selected instance.
+/// Returns None if the API can't be forwarded that way.
+fn generate_component_wrapper(
+    name: &str,
+    components: &[Option<Rc<Component>>],
+    apis: &[Api],
+    styles: &[StyleDocument],
+    registry: &TypeRegister,
+) -> Option<String> {
+    let primary = components[0].as_ref()?;
+    let type_name = |ty: &Type| type_to_source(ty, styles[0].doc, registry);
+    let variant = |i: usize| format!("style{i}");
+    // Unlike in `dispatch`, the first style is only selected if none of the others is
+    let is_selected = |i: usize| {
+        if i == 0 {
+            (1..styles.len())
+                .map(|i| format!("StyleSelector.current-style != {:?}", styles[i].style))
+                .join(" && ")
+        } else {
+            style_condition(styles, i)
+        }
+    };
+    let all_styles = (1..styles.len()).collect_vec();
+
+    let mut result = format!("export component {name} {{\n");
+    // The bindings and handlers set on the instance of every style
+    let mut forwarded = String::new();
+    let defaults = DefaultValues::new(primary, &apis[0], registry);
+    for (prop, (ty, visibility, pure)) in &apis[0] {
+        match ty {
+            Type::Callback(f) => {
+                writeln!(
+                    result,
+                    "    {}callback {prop}({}){};",
+                    if *pure { "pure " } else { "" },
+                    arguments_declaration(f, true, &type_name)?,
+                    return_type_declaration(f, &type_name)?
+                )
+                .unwrap();
+                let args = (0..f.args.len()).map(|i| format!("arg{i}")).join(", ");
+                let args_decl = if args.is_empty() { String::new() } else { format!("({args})") };
+                writeln!(
+                    forwarded,
+                    "        {prop}{args_decl} => {{ return root.{prop}({args}); }}"
+                )
+                .unwrap();
+            }
+            Type::Function(f) => {
+                let args = (0..f.args.len()).map(|i| format!("arg{i}")).join(", ");
+                writeln!(
+                    result,
+                    "    {}public function {prop}({}){} {{ {} }}",
+                    if *pure { "pure " } else { "" },
+                    arguments_declaration(f, false, &type_name)?,
+                    return_type_declaration(f, &type_name)?,
+                    dispatch_call(f, &format!("{prop}({args})"), &all_styles, &variant, styles)
+                )
+                .unwrap();
+            }
+            _ => {
+                let ty = type_name(ty)?;
+                let default = match visibility {
+                    PropertyVisibility::Output => DefaultValue::Internal,
+                    _ => defaults.default_value(
+                        &primary.root_element,
+                        prop,
+                        &apis[0][prop].0,
+                        &mut HashSet::new(),
+                    ),
+                };
+                let value = dispatch(prop, &all_styles, &variant, styles);
+                match (visibility, default) {
+                    (PropertyVisibility::Output, _) => {
+                        writeln!(result, "    out property <{ty}> {prop}: {value};").unwrap();
+                    }
+                    (PropertyVisibility::InOut, DefaultValue::Internal) => {
+                        // Arrays can't be compared to know whether the value comes from the
+                        // instance
+                        if matches!(apis[0][prop].0, Type::Array(_)) {
+                            return None;
+                        }
+                        let set_selected = (0..styles.len())
+                            .map(|i| {
+                                format!(
+                                    "if ({}) && {}.{prop} != {prop} {{ {}.{prop} = {prop}; }}",
+                                    is_selected(i),
+                                    variant(i),
+                                    variant(i)
+                                )
+                            })
+                            .join(" ");
+                        writeln!(
+                            result,
+                            "    in-out property <{ty}> {prop}: {value};\n    changed {prop} => {{ {set_selected} }}"
+                        )
+                        .unwrap();
+                    }
+                    (visibility, default) => {
+                        let visibility =
+                            if *visibility == PropertyVisibility::Input { "in" } else { "in-out" };
+                        // The default value of an input property that depends on the internals
+                        // of the widget can't be kept
+                        let default = match default {
+                            DefaultValue::Source(source) => format!(": {source}"),
+                            _ => String::new(),
+                        };
+                        writeln!(result, "    {visibility} property <{ty}> {prop}{default};")
+                            .unwrap();
+                        writeln!(forwarded, "        {prop} <=> root.{prop};").unwrap();
+                    }
+                }
+            }
+        }
+    }
+
+    let focusable = components.iter().map(|c| c.as_ref().is_some_and(is_focusable)).collect_vec();
+    if focusable.contains(&true) {
+        // `declare_focus_functions` adds the `focus()` and `clear-focus()` functions, that call
+        // these ones
+        for function in ["focus", "clear-focus"] {
+            let body = (0..styles.len())
+                .filter(|i| focusable[*i])
+                .map(|i| format!("if ({}) {{ {}.{function}(); }}", is_selected(i), variant(i)))
+                .join(" ");
+            writeln!(result, "    function selected-{function}() {{ {body} }}").unwrap();
+        }
+        // The hidden instances must not keep the focus
+        let clear_hidden = (0..styles.len())
+            .filter(|i| focusable[*i])
+            .map(|i| format!("if !({}) {{ {}.clear-focus(); }}", is_selected(i), variant(i)))
+            .join(" ");
+        writeln!(
+            result,
+            "    property <string> current-style: StyleSelector.current-style;\n    changed current-style => {{ {clear_hidden} }}"
+        )
+        .unwrap();
+    }
+
+    for constraint in [
+        "min-width",
+        "min-height",
+        "max-width",
+        "max-height",
+        "preferred-width",
+        "preferred-height",
+        "horizontal-stretch",
+        "vertical-stretch",
+    ] {
+        writeln!(
+            result,
+            "    {constraint}: {};",
+            dispatch(constraint, &all_styles, &variant, styles)
+        )
+        .unwrap();
+    }
+
+    for i in 0..styles.len() {
+        writeln!(
+            result,
+            "\n    {} := {name}-Style{i} {{\n        x: 0;\n        y: 0;\n        width: 100%;\n        height: 100%;\n        visible: {};",
+            variant(i),
+            is_selected(i)
+        )
+        .unwrap();
+        result += &forwarded;
+        result += "    }\n";
+    }
+    result += "}\n\n";
+    Some(result)
+}
+
+/// Add the `focus()` and `clear-focus()` functions to the components of the generated document
+/// that forward the focus to the instance of the selected style.
+///
+/// These functions can't be declared in the generated code, because every element has them.
+pub fn declare_focus_functions(doc: &Document) {
+    for component in &doc.inner_components {
+        let root = &component.root_element;
+        if !root.borrow().property_declarations.contains_key("selected-focus") {
+            continue;
+        }
+        for function in ["focus", "clear-focus"] {
+            let selected = NamedReference::new(root, format_smolstr!("selected-{function}"));
+            let mut root = root.borrow_mut();
+            root.property_declarations.insert(
+                function.into(),
+                PropertyDeclaration {
+                    property_type: Type::Function(Rc::new(Function {
+                        return_type: Type::Void,
+                        args: vec![],
+                        arg_names: vec![],
+                    })),
+                    visibility: PropertyVisibility::Public,
+                    pure: Some(false),
+                    ..Default::default()
+                },
+            );
+            root.bindings.insert(
+                function.into(),
+                RefCell::new(
+                    Expression::FunctionCall {
+                        function: Callable::Function(selected),
+                        arguments: vec![],
+                        source_location: None,
+                    }
+                    .into(),
+                ),
+            );
+        }
+    }
+}
+
+/// Link the input properties of the globals of the other styles, such as `Palette.color-scheme`,
+/// to the ones of the generated globals, which are aliases of the properties of the first style.
+///
+/// These two-way bindings can't be written in the generated code, because a global can't set
+/// the bindings of another global.
+pub fn link_global_inputs(doc: &Document) {
+    for component in doc.inner_components.iter().filter(|c| c.is_global()) {
+        let root = &component.root_element;
+        let inputs = root
+            .borrow()
+            .property_declarations
+            .iter()
+            .filter(|(_, d)| {
+                matches!(d.visibility, PropertyVisibility::Input | PropertyVisibility::InOut)
+            })
+            .map(|(name, d)| (name.clone(), d.property_type.clone()))
+            .collect_vec();
+        for i in 1.. {
+            let variant = doc.local_registry.lookup_element(&format!("{}-Style{i}", component.id));
+            let Ok(ElementType::Component(variant)) = variant else { break };
+            let variant_root = &variant.root_element;
+            for (name, ty) in &inputs {
+                let lookup = variant_root.borrow().lookup_property(name);
+                if lookup.property_type != *ty
+                    || !matches!(
+                        lookup.property_visibility,
+                        PropertyVisibility::Input | PropertyVisibility::InOut
+                    )
+                {
+                    continue;
+                }
+                let variant_prop = SmolStr::from(lookup.resolved_name);
+                for (elem, prop) in [(root, name), (variant_root, &variant_prop)] {
+                    elem.borrow()
+                        .property_analysis
+                        .borrow_mut()
+                        .entry(prop.clone())
+                        .or_default()
+                        .is_linked = true;
+                }
+                variant_root
+                    .borrow_mut()
+                    .bindings
+                    .entry(variant_prop)
+                    .or_insert_with(|| RefCell::new(Expression::Invalid.into()))
+                    .get_mut()
+                    .two_way_bindings
+                    .push(NamedReference::new(root, name.clone()));
+            }
+        }
+    }
+}
+
+/// `arg0: type0, arg1: type1` for the declaration of a function, or of a callback with the names
+/// of its arguments
+fn arguments_declaration(
+    f: &Function,
+    with_names: bool,
+    type_name: &impl Fn(&Type) -> Option<String>,
+) -> Option<String> {
+    Some(
+        f.args
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| {
+                let ty = type_name(ty)?;
+                Some(match f.arg_names.get(i).filter(|n| !n.is_empty()) {
+                    Some(name) if with_names => format!("{name}: {ty}"),
+                    _ if with_names => ty,
+                    _ => format!("arg{i}: {ty}"),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?
+            .join(", "),
+    )
+}
+
+/// ` -> type` for the declaration of a function or callback that returns a value
+fn return_type_declaration(
+    f: &Function,
+    type_name: &impl Fn(&Type) -> Option<String>,
+) -> Option<String> {
+    Some(if f.return_type == Type::Void {
+        String::new()
+    } else {
+        format!(" -> {}", type_name(&f.return_type)?)
+    })
+}
+
+/// Whether the component forwards the focus to one of its elements
+fn is_focusable(compo: &Rc<Component>) -> bool {
+    // The focus handling pass replaced `forward-focus` by a `focus()` function
+    let mut compo = compo.clone();
+    loop {
+        let base = {
+            let root = compo.root_element.borrow();
+            if root.property_declarations.contains_key("focus") {
+                return true;
+            }
+            match &root.base_type {
+                ElementType::Component(base) => base.clone(),
+                _ => return false,
+            }
+        };
+        compo = base;
+    }
+}
+
+/// The address of an element, to use it as a key. (`ByAddress<ElementRc>` is reported as a
+/// mutable key type, although only the address is hashed)
+type ElementPtr = *const RefCell<Element>;
+
+/// The default value of a property of the widget of the first style, in the wrapper
+enum DefaultValue {
+    /// The property has no default value
+    None,
+    /// The source code of the default value, that means the same in the wrapper
+    Source(String),
+    /// The value depends on the internals of the widget
+    Internal,
+}
+
+/// Finds the default values of the properties of a widget, in its compiled elements
+struct DefaultValues<'a> {
+    api: &'a Api,
+    registry: &'a TypeRegister,
+    /// The properties of the elements of the widget that are aliases of the property of the API
+    /// of that name
+    aliases: HashMap<(ElementPtr, SmolStr), SmolStr>,
+}
+
+impl<'a> DefaultValues<'a> {
+    fn new(compo: &Rc<Component>, api: &'a Api, registry: &'a TypeRegister) -> Self {
+        let mut aliases = HashMap::new();
+        let mut queue = api
+            .keys()
+            .map(|name| (compo.root_element.clone(), name.clone(), name.clone()))
+            .collect_vec();
+        while let Some((elem, prop, api_name)) = queue.pop() {
+            // The element, and the root elements of the components it derives from
+            let mut elem = Some(elem);
+            while let Some(e) = elem {
+                if aliases.insert((Rc::as_ptr(&e), prop.clone()), api_name.clone()).is_some() {
+                    break;
+                }
+                if let Some(binding) = e.borrow().bindings.get(&prop) {
+                    for nr in &binding.borrow().two_way_bindings {
+                        queue.push((nr.element(), nr.name().clone(), api_name.clone()));
+                    }
+                }
+                elem = match &e.borrow().base_type {
+                    ElementType::Component(base) => Some(base.root_element.clone()),
+                    _ => None,
+                };
+            }
+        }
+        Self { api, registry, aliases }
+    }
+
+    /// The default value of the property `prop` of `elem`, following the aliases that were not
+    /// `visited` yet
+    fn default_value(
+        &self,
+        elem: &ElementRc,
+        prop: &SmolStr,
+        ty: &Type,
+        visited: &mut HashSet<(ElementPtr, SmolStr)>,
+    ) -> DefaultValue {
+        let mut elem = elem.clone();
+        loop {
+            if !visited.insert((Rc::as_ptr(&elem), prop.clone())) {
+                return DefaultValue::None;
+            }
+            let base = {
+                let e = elem.borrow();
+                if let Some(binding) = e.bindings.get(prop) {
+                    let binding = binding.borrow();
+                    if !matches!(binding.expression, Expression::Invalid) {
+                        return self.binding_default_value(&e, &binding, ty);
+                    }
+                    // Follow `prop <=> id.other-prop` to the default value of the other property
+                    for nr in &binding.two_way_bindings {
+                        match self.default_value(&nr.element(), nr.name(), ty, visited) {
+                            DefaultValue::None => {}
+                            default => return default,
+                        }
+                    }
+                }
+                match &e.base_type {
+                    ElementType::Component(base) => base.root_element.clone(),
+                    ElementType::Builtin(b) => {
+                        return match b.properties.get(prop).map(|p| &p.default_value) {
+                            None | Some(BuiltinPropertyDefault::None) => DefaultValue::None,
+                            Some(BuiltinPropertyDefault::Expr(e)) => literal_source(e)
+                                .map_or(DefaultValue::Internal, DefaultValue::Source),
+                            Some(_) => DefaultValue::Internal,
+                        };
+                    }
+                    _ => return DefaultValue::None,
+                }
+            };
+            elem = base;
+        }
+    }
+
+    /// The source code of a binding, if it means the same in the wrapper: it may only read the
+    /// properties of the API and things that are not in the widget's files
+    fn binding_default_value(
+        &self,
+        elem: &Element,
+        binding: &BindingExpression,
+        ty: &Type,
+    ) -> DefaultValue {
+        let mut is_self_contained = true;
+        binding.expression.visit_recursive(&mut |e| match e {
+            Expression::PropertyReference(nr)
+            | Expression::FunctionCall {
+                function: Callable::Callback(nr) | Callable::Function(nr),
+                ..
+            } => {
+                let is_global = nr
+                    .element()
+                    .borrow()
+                    .enclosing_component
+                    .upgrade()
+                    .is_some_and(|c| c.is_global());
+                is_self_contained &= is_global
+                    || self
+                        .aliases
+                        .get(&(Rc::as_ptr(&nr.element()), nr.name().clone()))
+                        .is_some_and(|name| name == nr.name());
+            }
+            Expression::EnumerationValue(v) => {
+                is_self_contained &= matches!(self.registry.lookup(&v.enumeration.name),
+                    Type::Enumeration(e) if Rc::ptr_eq(&e, &v.enumeration));
+            }
+            Expression::ElementReference(_)
+            | Expression::ImageReference { .. }
+            | Expression::ReadLocalVariable { .. }
+            | Expression::StoreLocalVariable { .. } => is_self_contained = false,
+            _ => {}
+        });
+        if !is_self_contained {
+            return DefaultValue::Internal;
+        }
+
+        // Get the source code of the binding from the syntax tree of the element
+        let Some(span) = &binding.span else {
+            // Such as the default value of a builtin property
+            return literal_source(crate::passes::ignore_debug_hooks(&binding.expression))
+                .map_or(DefaultValue::Internal, DefaultValue::Source);
+        };
+        let expression = elem.debug.first().and_then(|debug| {
+            debug
+                .node
+                .descendants()
+                .find(|n| {
+                    n.kind() == SyntaxKind::BindingExpression
+                        && usize::from(n.text_range().start()) == span.span.offset
+                })
+                .and_then(|n| n.first_child())
+        });
+        let Some(expression) = expression else { return DefaultValue::Internal };
+
+        // The names must also be looked up the same way in the wrapper
+        let mut diag = BuildDiagnostics::default();
+        let mut ctx = LookupCtx::empty_context(self.registry, &mut diag);
+        ctx.property_type = ty.clone();
+        let names_resolve = expression.descendants().all(|node| {
+            if node.kind() != SyntaxKind::QualifiedName {
+                return true;
+            }
+            let mut names = qualified_name(&node);
+            match names.next() {
+                Some(first) if first == "root" || first == "self" => {
+                    names.next().is_some_and(|name| self.api.contains_key(&name))
+                }
+                Some(first) => {
+                    self.api.contains_key(&first)
+                        || (first != "parent" && global_lookup().lookup(&ctx, &first).is_some())
+                }
+                None => false,
+            }
+        });
+        if names_resolve {
+            DefaultValue::Source(expression.text().to_string().trim().into())
+        } else {
+            DefaultValue::Internal
+        }
+    }
+}
+
+/// The identifiers of a `QualifiedName` node
+fn qualified_name(node: &SyntaxNode) -> impl Iterator<Item = SmolStr> + '_ {
+    node.children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .map(|t| crate::parser::normalize_identifier(t.text()))
+}
+
+/// The source code of the default value of a builtin property
+fn literal_source(expression: &Expression) -> Option<String> {
+    Some(match expression {
+        Expression::BoolLiteral(b) => b.to_string(),
+        Expression::NumberLiteral(n, unit) => format!("{n}{unit}"),
+        Expression::StringLiteral(s) => format!("{s:?}"),
+        Expression::EnumerationValue(v) => format!("{}.{v}", v.enumeration.name),
+        _ => return None,
+    })
+}
+
+/// Returns the type as it can be written in the generated code, if possible
+fn type_to_source(ty: &Type, primary: &Document, registry: &TypeRegister) -> Option<String> {
+    // Named types must be builtin, or exported by the primary style (and imported in the generated code)
+    let is_known = |name: &str| {
+        primary.exports.find(name).is_some_and(|t| t.right().is_some_and(|t| t == *ty))
+            || registry.lookup(name) == *ty
+    };
+    Some(match ty {
+        Type::Float32 => "float".into(),
+        Type::Int32 => "int".into(),
+        Type::String => "string".into(),
+        Type::Color => "color".into(),
+        Type::Brush => "brush".into(),
+        Type::Image => "image".into(),
+        Type::Bool => "bool".into(),
+        Type::Duration => "duration".into(),
+        Type::Angle => "angle".into(),
+        Type::Percent => "percent".into(),
+        Type::PhysicalLength => "physical-length".into(),
+        Type::LogicalLength => "length".into(),
+        Type::Rem => "relative-font-size".into(),
+        Type::Easing => "easing".into(),
+        Type::Array(ty) => format!("[{}]", type_to_source(ty, primary, registry)?),
+        Type::Struct(s) => match s.name.as_ref().map(|name| {
+            // Builtin structs are named after the Rust type, but registered under the last segment
+            name.rsplit("::").next().unwrap_or(name)
+        }) {
+            Some(name) if is_known(name) => name.to_string(),
+            Some(_) => return None,
+            None => format!(
+                "{{ {} }}",
+                s.fields
+                    .iter()
+                    .map(|(name, ty)| Some(format!(
+                        "{name}: {}",
+                        type_to_source(ty, primary, registry)?
+                    )))
+                    .collect::<Option<Vec<_>>>()?
+                    .join(", ")
+            ),
+        },
+        Type::Enumeration(e) if is_known(&e.name) => e.name.to_string(),
+        _ => return None,
+    })
+}
@@ -40,6 +40,12 @@
     ("vertical-stretch", Type::Float32),
 ];
 
+pub const RESERVED_FLEXBOX_PROPERTIES: &[(&str, Type)] = &[
+    ("flex-grow", Type::Float32),
+    ("flex-shrink", Type::Float32),
+    ("flex-basis", Type::LogicalLength),
+];
+
 pub const RESERVED_GRIDLAYOUT_PROPERTIES: &[(&str, Type)] = &[
     ("col", Type::Int32),
     ("row", Type::Int32),
@@ -87,6 +93,7 @@ pub struct BuiltinTypes {
     pub layout_info_type: Rc<Struct>,
     pub path_element_type: Type,
     pub box_layout_cell_data_type: Type,
+    pub flexbox_layout_cell_data_type: Type,
 }
 
 impl BuiltinTypes {
@@ -147,12 +154,27 @@ fn new() -> Self {
                 rust_attributes: None,
             })),
             box_layout_cell_data_type: Type::Struct(Rc::new(Struct {
-                fields: IntoIterator::into_iter([("constraint".into(), layout_info_type.into())])
-                    .collect(),
+                fields: IntoIterator::into_iter([(
+                    "constraint".into(),
+                    layout_info_type.clone().into(),
+                )])
+                .collect(),
                 name: Some("BoxLayoutCellData".into()),
                 node: None,
                 rust_attributes: None,
             })),
+            flexbox_layout_cell_data_type: Type::Struct(Rc::new(Struct {
+                fields: IntoIterator::into_iter([
+                    ("constraint".into(), layout_info_type.into()),
+                    ("flex_grow".into(), Type::Float32),
+                    ("flex_shrink".into(), Type::Float32),
+                    ("flex_basis".into(), Type::Float32),
+                ])
+                .collect(),
+                name: Some("FlexboxLayoutCellData".into()),
+                node: None,
+                rust_attributes: None,
+            })),
         }
     }
 }
@@ -224,6 +246,7 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, Proper
     RESERVED_GEOMETRY_PROPERTIES
         .iter()
         .chain(RESERVED_LAYOUT_PROPERTIES.iter())
+        .chain(RESERVED_FLEXBOX_PROPERTIES.iter())
         .chain(RESERVED_OTHER_PROPERTIES.iter())
         .chain(RESERVED_DROP_SHADOW_PROPERTIES.iter())
         .chain(RESERVED_ROTATION_PROPERTIES.iter())
@@ -257,6 +280,8 @@ pub fn reserved_properties() -> impl Iterator<Item = (&'static str, Type, Proper
                 Type::Enumeration(BUILTIN.with(|e| e.enums.AccessibleRole.clone())),
                 PropertyVisibility::Constexpr,
             ),
+            // The classes matched by the rules of a `style-sheet`
+            ("class", Type::String, PropertyVisibility::Constexpr),
         ]))
         .chain(std::iter::once(("init", noarg_callback_type(), PropertyVisibility::Private)))
 }
@@ -380,6 +405,7 @@ fn builtin_internal() -> Self {
         register.insert_type(Type::Color);
         register.insert_type(Type::ComponentFactory);
         register.insert_type(Type::Duration);
+        register.insert_type(Type::DateTime);
         register.insert_type(Type::Image);
         register.insert_type(Type::Bool);
         register.insert_type(Type::Model);
@@ -408,6 +434,7 @@ macro_rules! map_type {
             ($pub_type:ident, SharedString) => { Type::String };
             ($pub_type:ident, Image) => { Type::Image };
             ($pub_type:ident, Coord) => { Type::LogicalLength };
+            ($pub_type:ident, LogicalPosition) => { logical_point_type() };
             ($pub_type:ident, KeyboardModifiers) => { $pub_type.clone() };
             ($pub_type:ident, $_:ident) => {
                 BUILTIN.with(|e| Type::Enumeration(e.enums.$pub_type.clone()))
@@ -703,3 +730,8 @@ pub fn path_element_type() -> Type {
 pub fn box_layout_cell_data_type() -> Type {
     BUILTIN.with(|types| types.box_layout_cell_data_type.clone())
 }
+
+/// The [`Type`] for a runtime FlexboxLayoutCellData structure
+pub fn flexbox_layout_cell_data_type() -> Type {
+    BUILTIN.with(|types| types.flexbox_layout_cell_data_type.clone())
+}
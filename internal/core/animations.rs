@@ -159,6 +159,11 @@ pub enum EasingCurve {
     EaseOutBounce,
     /// Easing curve as defined at: <https://easings.net/#easeInOutBounce>
     EaseInOutBounce,
+    /// A spring with its stiffness, damping and mass.
+    ///
+    /// Spring animations are simulated physically: they ignore the duration of the animation,
+    /// and keep their velocity when the target value changes while they are running.
+    Spring([f32; 3]),
     // Custom(Box<dyn Fn(f32) -> f32>),
 }
 
@@ -374,9 +379,77 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
                 (1.0 + ease_out_bounce_curve(2.0 * value - 1.0)) / 2.0
             }
         }
+        EasingCurve::Spring(spring) => {
+            // Stretch the response of the spring, from rest, so that it settles at the end
+            let time = value * spring_settling_time(spring);
+            1.0 + spring_state(spring, -1.0, 0.0, time).0
+        }
     }
 }
 
+/// Returns the displacement from its rest position and the velocity, after `time` seconds,
+/// of a spring with the given stiffness, damping and mass that starts with `displacement`
+/// and `velocity`.
+pub(crate) fn spring_state(
+    [stiffness, damping, mass]: &[f32; 3],
+    displacement: f32,
+    velocity: f32,
+    time: f32,
+) -> (f32, f32) {
+    let omega = (stiffness / mass).sqrt();
+    let zeta = damping / (2.0 * (stiffness * mass).sqrt());
+    if !omega.is_finite() || !zeta.is_finite() || omega <= 0. {
+        return (0., 0.);
+    }
+
+    if (zeta - 1.0).abs() < 1e-3 {
+        // Critically damped
+        let c = velocity + omega * displacement;
+        let decay = (-omega * time).exp();
+        let position = displacement + c * time;
+        (decay * position, decay * (c - omega * position))
+    } else if zeta < 1.0 {
+        // Under-damped: oscillates around the rest position
+        let a = zeta * omega;
+        let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+        let b = (velocity + a * displacement) / omega_d;
+        let decay = (-a * time).exp();
+        let (sin, cos) = (omega_d * time).sin_cos();
+        (
+            decay * (displacement * cos + b * sin),
+            decay
+                * ((b * omega_d - a * displacement) * cos - (a * b + displacement * omega_d) * sin),
+        )
+    } else {
+        // Over-damped: returns to the rest position without oscillating
+        let root = omega * (zeta * zeta - 1.0).sqrt();
+        let r1 = -zeta * omega + root;
+        let r2 = -zeta * omega - root;
+        let a = (velocity - r2 * displacement) / (r1 - r2);
+        let b = displacement - a;
+        let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+        (a * e1 + b * e2, a * r1 * e1 + b * r2 * e2)
+    }
+}
+
+/// Returns the time, in seconds, after which the spring with the given stiffness, damping and mass
+/// is considered at rest when released from a unit displacement.
+fn spring_settling_time([stiffness, damping, mass]: &[f32; 3]) -> f32 {
+    let omega = (stiffness / mass).sqrt();
+    let zeta = damping / (2.0 * (stiffness * mass).sqrt());
+    // The slowest decay rate of the envelope of the displacement
+    let decay_rate =
+        if zeta < 1.0 { zeta * omega } else { omega * (zeta - (zeta * zeta - 1.0).sqrt()) };
+    if decay_rate.is_nan() || decay_rate <= 0.0 {
+        return 1.0;
+    }
+    (SPRING_REST_THRESHOLD.recip().ln() / decay_rate).min(60.0)
+}
+
+/// A spring is at rest when its displacement and velocity, relative to the distance it travels,
+/// are under this threshold.
+pub(crate) const SPRING_REST_THRESHOLD: f32 = 1e-3;
+
 /*
 #[test]
 fn easing_test() {
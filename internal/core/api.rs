@@ -626,6 +626,68 @@ pub fn try_dispatch_event(
             crate::platform::WindowEvent::PointerExited => {
                 self.0.process_mouse_input(MouseEvent::Exit)
             }
+            crate::platform::WindowEvent::TouchStarted { id, position } => {
+                for event in self.0.touch_state.started(id, position.to_euclid().cast()) {
+                    self.0.process_mouse_input(event);
+                }
+            }
+            crate::platform::WindowEvent::TouchMoved { id, position } => {
+                for event in self.0.touch_state.moved(id, position.to_euclid().cast()) {
+                    self.0.process_mouse_input(event);
+                }
+            }
+            crate::platform::WindowEvent::TouchEnded { id, position } => {
+                for event in self.0.touch_state.ended(id, position.to_euclid().cast(), false) {
+                    self.0.process_mouse_input(event);
+                }
+            }
+            crate::platform::WindowEvent::TouchCancelled { id, position } => {
+                for event in self.0.touch_state.ended(id, position.to_euclid().cast(), true) {
+                    self.0.process_mouse_input(event);
+                }
+            }
+            crate::platform::WindowEvent::PinchGesture { position, delta, phase } => {
+                self.0.process_mouse_input(MouseEvent::Gesture {
+                    position: position.to_euclid().cast(),
+                    scale: 1. + delta,
+                    rotation: 0.,
+                    phase,
+                });
+            }
+            crate::platform::WindowEvent::RotationGesture { position, delta, phase } => {
+                self.0.process_mouse_input(MouseEvent::Gesture {
+                    position: position.to_euclid().cast(),
+                    scale: 1.,
+                    rotation: delta,
+                    phase,
+                });
+            }
+            crate::platform::WindowEvent::DragMoved { position, mime_type, data } => {
+                self.0.drag_data.replace(Some(crate::items::DropEvent {
+                    mime_type,
+                    data,
+                    position: Default::default(),
+                }));
+                self.0.process_mouse_input(MouseEvent::DragMove {
+                    position: position.to_euclid().cast(),
+                });
+            }
+            crate::platform::WindowEvent::DragExited => {
+                if self.0.drag_data.borrow().is_some() {
+                    self.0.process_mouse_input(MouseEvent::Exit);
+                }
+            }
+            crate::platform::WindowEvent::Dropped { position, mime_type, data } => {
+                self.0.drag_data.replace(Some(crate::items::DropEvent {
+                    mime_type,
+                    data,
+                    position: Default::default(),
+                }));
+                self.0.process_mouse_input(MouseEvent::Drop {
+                    position: position.to_euclid().cast(),
+                });
+                self.0.drag_data.take();
+            }
 
             crate::platform::WindowEvent::KeyPressed { text } => {
                 self.0.process_key_input(crate::input::KeyEvent {
@@ -4,7 +4,223 @@
 use crate::SharedString;
 #[cfg(feature = "std")]
 use chrono::Local;
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, Timelike};
+
+/// The largest offset from UTC that can be represented, in seconds.
+const MAX_UTC_OFFSET: i32 = 86_399;
+
+/// A point in time, with a fixed offset from UTC.
+///
+/// This is the type used for `date-time` properties in Slint. It stores a timestamp with
+/// millisecond precision and the offset from UTC used to compute the calendar fields and to
+/// format the value.
+///
+/// It converts to and from the `DateTime` types of the [`chrono`](https://docs.rs/chrono) crate:
+///
+/// ```
+/// # use i_slint_core::date_time::DateTime;
+/// let date_time = DateTime::from_ymd_hms(2024, 2, 29, 13, 30, 0, 3600).unwrap();
+/// let chrono_date_time: chrono::DateTime<chrono::FixedOffset> = date_time.into();
+/// assert_eq!(chrono_date_time.to_rfc3339(), "2024-02-29T13:30:00+01:00");
+/// assert_eq!(DateTime::from(chrono_date_time), date_time);
+/// ```
+///
+/// Two values are only equal if they have the same timestamp and the same offset from UTC.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    /// Milliseconds since 1970-01-01 00:00:00 UTC
+    timestamp: i64,
+    /// Offset from UTC in seconds
+    utc_offset: i32,
+}
+
+impl DateTime {
+    /// Creates a DateTime in UTC from the number of milliseconds since the Unix epoch.
+    pub const fn from_timestamp_millis(timestamp: i64) -> Self {
+        Self { timestamp, utc_offset: 0 }
+    }
+
+    /// Creates a DateTime from the calendar date and the time of the day, in the time zone
+    /// whose offset from UTC is `utc_offset` seconds.
+    ///
+    /// Returns None if the date or the time doesn't exist.
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        utc_offset: i32,
+    ) -> Option<Self> {
+        let naive = NaiveDate::from_ymd_opt(year, month, day)?.and_hms_opt(hour, minute, second)?;
+        Self::from_naive(naive, utc_offset)
+    }
+
+    fn from_naive(naive: NaiveDateTime, utc_offset: i32) -> Option<Self> {
+        let offset = FixedOffset::east_opt(utc_offset)?;
+        Some(naive.and_local_timezone(offset).single()?.into())
+    }
+
+    /// Returns the current date and time, with the offset of the local time zone.
+    ///
+    /// Without the `std` feature, this returns the Unix epoch.
+    pub fn now() -> Self {
+        #[cfg(feature = "std")]
+        return Local::now().into();
+        #[cfg(not(feature = "std"))]
+        return Self::default();
+    }
+
+    /// Parses a date and time with a [`strftime`](https://docs.rs/chrono/latest/chrono/format/strftime/index.html)
+    /// format string.
+    ///
+    /// If the format has no offset from UTC (`%z`), the time is in the local time zone. If it has no
+    /// time of the day, the time is midnight.
+    pub fn parse(text: &str, format: &str) -> Option<Self> {
+        if let Ok(date_time) = chrono::DateTime::parse_from_str(text, format) {
+            return Some(date_time.into());
+        }
+        let naive = NaiveDateTime::parse_from_str(text, format).ok().or_else(|| {
+            NaiveDate::parse_from_str(text, format).ok().map(|date| date.and_time(NaiveTime::MIN))
+        })?;
+        #[cfg(feature = "std")]
+        return Some(naive.and_local_timezone(Local).earliest()?.into());
+        #[cfg(not(feature = "std"))]
+        return Self::from_naive(naive, 0);
+    }
+
+    /// Formats the date and time with a [`strftime`](https://docs.rs/chrono/latest/chrono/format/strftime/index.html)
+    /// format string.
+    ///
+    /// Returns an empty string if the format string is not valid.
+    pub fn format(&self, format: &str) -> SharedString {
+        use core::fmt::Write;
+        let mut result = SharedString::default();
+        match write!(result, "{}", chrono::DateTime::<FixedOffset>::from(*self).format(format)) {
+            Ok(()) => result,
+            Err(_) => SharedString::default(),
+        }
+    }
+
+    /// Returns the number of milliseconds since the Unix epoch.
+    pub const fn timestamp_millis(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Returns the offset from UTC in seconds. It's positive east of Greenwich.
+    pub const fn utc_offset(&self) -> i32 {
+        self.utc_offset
+    }
+
+    /// Returns the same point in time with another offset from UTC, in seconds.
+    ///
+    /// The offset is clamped to less than a day.
+    pub fn with_utc_offset(self, utc_offset: i32) -> Self {
+        Self { utc_offset: utc_offset.clamp(-MAX_UTC_OFFSET, MAX_UTC_OFFSET), ..self }
+    }
+
+    /// Returns the same point in time with the offset of the local time zone.
+    ///
+    /// Without the `std` feature, this returns the value unchanged.
+    pub fn to_local(self) -> Self {
+        #[cfg(feature = "std")]
+        return chrono::DateTime::<FixedOffset>::from(self).with_timezone(&Local).into();
+        #[cfg(not(feature = "std"))]
+        return self;
+    }
+
+    /// The calendar date and time of the day at the offset from UTC
+    fn naive_local(&self) -> NaiveDateTime {
+        chrono::DateTime::from_timestamp_millis(
+            self.timestamp.saturating_add(self.utc_offset as i64 * 1000),
+        )
+        .unwrap_or_default()
+        .naive_utc()
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.naive_local().year()
+    }
+
+    /// Returns the month, starting from 1.
+    pub fn month(&self) -> u32 {
+        self.naive_local().month()
+    }
+
+    /// Returns the day of the month, starting from 1.
+    pub fn day(&self) -> u32 {
+        self.naive_local().day()
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(&self) -> u32 {
+        self.naive_local().hour()
+    }
+
+    /// Returns the minute, from 0 to 59.
+    pub fn minute(&self) -> u32 {
+        self.naive_local().minute()
+    }
+
+    /// Returns the second, from 0 to 59.
+    pub fn second(&self) -> u32 {
+        self.naive_local().second()
+    }
+
+    /// Returns the day of the week, from 1 for Monday to 7 for Sunday.
+    pub fn weekday(&self) -> u32 {
+        self.naive_local().weekday().number_from_monday()
+    }
+
+    /// Returns the same time of the day at another date, or None if the date doesn't exist.
+    pub fn with_date(self, year: i32, month: u32, day: u32) -> Option<Self> {
+        let date = NaiveDate::from_ymd_opt(year, month, day)?;
+        Self::from_naive(date.and_time(self.naive_local().time()), self.utc_offset)
+    }
+
+    /// Returns the same date at another time of the day, or None if the time doesn't exist.
+    pub fn with_time(self, hour: u32, minute: u32, second: u32) -> Option<Self> {
+        let date_time = self.naive_local().date().and_hms_opt(hour, minute, second)?;
+        Self::from_naive(date_time, self.utc_offset)
+    }
+
+    /// Returns the point in time that is `millis` milliseconds later, or earlier if it's negative.
+    pub const fn add_millis(self, millis: i64) -> Self {
+        Self { timestamp: self.timestamp.saturating_add(millis), utc_offset: self.utc_offset }
+    }
+
+    /// Returns the number of milliseconds elapsed from `other` to `self`.
+    pub const fn millis_since(&self, other: &Self) -> i64 {
+        self.timestamp.saturating_sub(other.timestamp)
+    }
+}
+
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for DateTime {
+    fn from(date_time: chrono::DateTime<Tz>) -> Self {
+        Self {
+            timestamp: date_time.timestamp_millis(),
+            utc_offset: date_time.offset().fix().local_minus_utc(),
+        }
+    }
+}
+
+impl From<DateTime> for chrono::DateTime<FixedOffset> {
+    fn from(date_time: DateTime) -> Self {
+        let offset = FixedOffset::east_opt(date_time.utc_offset).unwrap_or(chrono::Utc.fix());
+        chrono::DateTime::from_timestamp_millis(date_time.timestamp)
+            .unwrap_or_default()
+            .with_timezone(&offset)
+    }
+}
+
+impl From<DateTime> for chrono::DateTime<chrono::Utc> {
+    fn from(date_time: DateTime) -> Self {
+        chrono::DateTime::from_timestamp_millis(date_time.timestamp).unwrap_or_default()
+    }
+}
 
 pub fn use_24_hour_format() -> bool {
     true
@@ -125,4 +341,117 @@ pub extern "C" fn slint_date_time_parse_date(
             false
         }
     }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_date_time_now(out: &mut DateTime) {
+        *out = DateTime::now();
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_date_time_parse(
+        text: &SharedString,
+        format: &SharedString,
+        out: &mut DateTime,
+    ) -> bool {
+        if let Some(x) = DateTime::parse(text, format) {
+            *out = x;
+            true
+        } else {
+            false
+        }
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_date_time_format(
+        date_time: &DateTime,
+        format: &SharedString,
+        out: &mut SharedString,
+    ) {
+        *out = date_time.format(format);
+    }
+
+    /// Write the year, month, day, hour, minute, second, and weekday in `fields`
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_date_time_fields(date_time: &DateTime, fields: &mut [i32; 7]) {
+        let naive = date_time.naive_local();
+        *fields = [
+            naive.year(),
+            naive.month() as i32,
+            naive.day() as i32,
+            naive.hour() as i32,
+            naive.minute() as i32,
+            naive.second() as i32,
+            naive.weekday().number_from_monday() as i32,
+        ];
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_date_time_to_local(date_time: &DateTime, out: &mut DateTime) {
+        *out = date_time.to_local();
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_date_time_with_date(
+        date_time: &DateTime,
+        year: i32,
+        month: u32,
+        day: u32,
+        out: &mut DateTime,
+    ) -> bool {
+        if let Some(x) = date_time.with_date(year, month, day) {
+            *out = x;
+            true
+        } else {
+            false
+        }
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_date_time_with_time(
+        date_time: &DateTime,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        out: &mut DateTime,
+    ) -> bool {
+        if let Some(x) = date_time.with_time(hour, minute, second) {
+            *out = x;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[test]
+fn test_date_time() {
+    let date_time = DateTime::from_ymd_hms(2024, 2, 29, 23, 30, 15, -2 * 3600).unwrap();
+    assert_eq!(date_time.timestamp_millis(), 1_709_256_615_000);
+    assert_eq!(
+        (date_time.year(), date_time.month(), date_time.day(), date_time.weekday()),
+        (2024, 2, 29, 4)
+    );
+    assert_eq!((date_time.hour(), date_time.minute(), date_time.second()), (23, 30, 15));
+    assert_eq!(date_time.format("%Y-%m-%d %H:%M:%S %z").as_str(), "2024-02-29 23:30:15 -0200");
+
+    let utc = date_time.with_utc_offset(0);
+    assert_eq!(utc.millis_since(&date_time), 0);
+    assert_ne!(utc, date_time);
+    assert_eq!((utc.day(), utc.hour()), (1, 1));
+    assert_eq!(date_time.with_utc_offset(100_000).utc_offset(), MAX_UTC_OFFSET);
+
+    let later = date_time.add_millis(45 * 1000);
+    assert_eq!((later.month(), later.day(), later.minute(), later.second()), (2, 29, 31, 0));
+    assert_eq!(later.millis_since(&date_time), 45_000);
+
+    assert_eq!(date_time.with_date(2023, 2, 29), None);
+    let moved = date_time.with_date(2023, 3, 1).unwrap().with_time(8, 0, 0).unwrap();
+    assert_eq!(moved.format("%+").as_str(), "2023-03-01T08:00:00-02:00");
+
+    assert_eq!(
+        DateTime::parse("2024-02-29 23:30:15 -0200", "%Y-%m-%d %H:%M:%S %z"),
+        Some(date_time)
+    );
+    assert_eq!(DateTime::parse("2024-02-30", "%Y-%m-%d"), None);
+    assert_eq!(date_time.format("%Q").as_str(), "");
 }
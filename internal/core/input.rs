@@ -11,13 +11,14 @@
 pub use crate::items::{FocusReason, KeyEvent, KeyboardModifiers};
 use crate::items::{ItemRef, TextCursorDirection};
 use crate::lengths::{LogicalPoint, LogicalVector};
+use crate::platform::GesturePhase;
 use crate::timers::Timer;
 use crate::window::{WindowAdapter, WindowInner};
 use crate::{Coord, Property, SharedString};
 use alloc::rc::Rc;
 use alloc::vec::Vec;
 use const_field_offset::FieldOffsets;
-use core::cell::Cell;
+use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 use core::time::Duration;
 
@@ -46,6 +47,15 @@ pub enum MouseEvent {
     /// `delta_x` is the amount of pixels to scroll in horizontal direction,
     /// `delta_y` is the amount of pixels to scroll in vertical direction.
     Wheel { position: LogicalPoint, delta_x: Coord, delta_y: Coord },
+    /// A pinch or rotation gesture, either recognized from two touch points or reported by a trackpad.
+    /// `position` is the center of the gesture.
+    /// `scale` is the factor by which the scale changed since the previous event.
+    /// `rotation` is the angle in degrees by which the gesture turned clockwise since the previous event.
+    Gesture { position: LogicalPoint, scale: f32, rotation: f32, phase: GesturePhase },
+    /// The pointer moved while dragging data, see `WindowInner::drag_data`.
+    DragMove { position: LogicalPoint },
+    /// The dragged data was dropped, see `WindowInner::drag_data`.
+    Drop { position: LogicalPoint },
     /// The mouse exited the item or component
     Exit,
 }
@@ -58,6 +68,9 @@ pub fn position(&self) -> Option<LogicalPoint> {
             MouseEvent::Released { position, .. } => Some(*position),
             MouseEvent::Moved { position } => Some(*position),
             MouseEvent::Wheel { position, .. } => Some(*position),
+            MouseEvent::Gesture { position, .. } => Some(*position),
+            MouseEvent::DragMove { position } => Some(*position),
+            MouseEvent::Drop { position } => Some(*position),
             MouseEvent::Exit => None,
         }
     }
@@ -69,6 +82,9 @@ pub fn translate(&mut self, vec: LogicalVector) {
             MouseEvent::Released { position, .. } => Some(position),
             MouseEvent::Moved { position } => Some(position),
             MouseEvent::Wheel { position, .. } => Some(position),
+            MouseEvent::Gesture { position, .. } => Some(position),
+            MouseEvent::DragMove { position } => Some(position),
+            MouseEvent::Drop { position } => Some(position),
             MouseEvent::Exit => None,
         };
         if let Some(pos) = pos {
@@ -530,6 +546,113 @@ pub fn check_repeat(&self, mouse_event: MouseEvent, click_interval: Duration) ->
     }
 }
 
+/// This state tracks the touch points that are currently in contact with the surface.
+///
+/// The primary touch point is the one that started while no other touch point was active.
+/// Only that one is translated into pointer events. When a second touch point is added, the
+/// two first touch points also produce [`MouseEvent::Gesture`] events.
+#[derive(Default)]
+pub struct TouchState {
+    points: RefCell<Vec<(u64, LogicalPoint)>>,
+    primary: Cell<Option<u64>>,
+    /// The ids of the two touch points driving the current gesture
+    gesture: Cell<Option<(u64, u64)>>,
+}
+
+type TouchEvents = core::iter::Flatten<core::array::IntoIter<Option<MouseEvent>, 2>>;
+
+impl TouchState {
+    /// Registers a new touch point and returns the mouse events to dispatch.
+    pub fn started(&self, id: u64, position: LogicalPoint) -> TouchEvents {
+        let mut points = self.points.borrow_mut();
+        match points.iter_mut().find(|(i, _)| *i == id) {
+            Some(p) => p.1 = position,
+            None => points.push((id, position)),
+        }
+        let pointer = self.primary.get().is_none().then(|| {
+            self.primary.set(Some(id));
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, click_count: 0 }
+        });
+        let gesture = match points.as_slice() {
+            [(a, pa), (b, pb), ..] if self.gesture.get().is_none() => {
+                self.gesture.set(Some((*a, *b)));
+                Some(MouseEvent::Gesture {
+                    position: gesture_center(*pa, *pb),
+                    scale: 1.,
+                    rotation: 0.,
+                    phase: GesturePhase::Started,
+                })
+            }
+            _ => None,
+        };
+        [pointer, gesture].into_iter().flatten()
+    }
+
+    /// Updates the position of a touch point and returns the mouse events to dispatch.
+    pub fn moved(&self, id: u64, position: LogicalPoint) -> TouchEvents {
+        let mut points = self.points.borrow_mut();
+        let find = |points: &[(u64, LogicalPoint)], id| {
+            points.iter().find(|(i, _)| *i == id).map(|(_, p)| *p).unwrap_or_default()
+        };
+        let gesture = self.gesture.get().filter(|(a, b)| *a == id || *b == id);
+        let old = gesture.map(|(a, b)| (find(&points, a), find(&points, b)));
+        let Some(p) = points.iter_mut().find(|(i, _)| *i == id) else {
+            return [None, None].into_iter().flatten();
+        };
+        p.1 = position;
+        let pointer = (self.primary.get() == Some(id)).then_some(MouseEvent::Moved { position });
+        let gesture = gesture.zip(old).map(|((a, b), (old_a, old_b))| {
+            let (new_a, new_b) = (find(&points, a), find(&points, b));
+            let old_v = (old_b - old_a).cast::<f32>();
+            let new_v = (new_b - new_a).cast::<f32>();
+            let old_length = old_v.length();
+            MouseEvent::Gesture {
+                position: gesture_center(new_a, new_b),
+                scale: if old_length > 0. { new_v.length() / old_length } else { 1. },
+                rotation: (new_v.angle_from_x_axis() - old_v.angle_from_x_axis())
+                    .signed()
+                    .to_degrees(),
+                phase: GesturePhase::Updated,
+            }
+        });
+        [pointer, gesture].into_iter().flatten()
+    }
+
+    /// Removes a touch point and returns the mouse events to dispatch.
+    ///
+    /// If `cancelled` is true, the primary touch point produces an exit event instead of a release,
+    /// and the gesture is cancelled instead of ended.
+    pub fn ended(&self, id: u64, position: LogicalPoint, cancelled: bool) -> TouchEvents {
+        let mut points = self.points.borrow_mut();
+        let gesture = self.gesture.get().filter(|(a, b)| *a == id || *b == id).map(|(a, b)| {
+            self.gesture.set(None);
+            let other = if a == id { b } else { a };
+            let other_position =
+                points.iter().find(|(i, _)| *i == other).map_or(position, |(_, p)| *p);
+            MouseEvent::Gesture {
+                position: gesture_center(position, other_position),
+                scale: 1.,
+                rotation: 0.,
+                phase: if cancelled { GesturePhase::Cancelled } else { GesturePhase::Ended },
+            }
+        });
+        points.retain(|(i, _)| *i != id);
+        let pointer = (self.primary.get() == Some(id)).then(|| {
+            self.primary.set(None);
+            if cancelled {
+                MouseEvent::Exit
+            } else {
+                MouseEvent::Released { position, button: PointerEventButton::Left, click_count: 0 }
+            }
+        });
+        [gesture, pointer].into_iter().flatten()
+    }
+}
+
+fn gesture_center(a: LogicalPoint, b: LogicalPoint) -> LogicalPoint {
+    a + (b - a) / (2 as Coord)
+}
+
 /// The state which a window should hold for the mouse input
 #[derive(Default)]
 pub struct MouseInputState {
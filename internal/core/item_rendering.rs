@@ -397,6 +397,28 @@ fn draw_text_input(
         _self_rc: &ItemRc,
         _size: LogicalSize,
     );
+    /// Draws a `StyledText`. The default implementation draws it run by run with
+    /// [`Self::draw_text`].
+    fn draw_styled_text(
+        &mut self,
+        text: Pin<&StyledText>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+        cache: &CachedRenderingData,
+    ) {
+        text.draw_runs(self, self_rc, size, cache);
+    }
+    /// Draws a `Text`, and its selection when it is selectable. The default implementation
+    /// draws the selection with [`Self::draw_rectangle`] and [`Self::draw_text`].
+    fn draw_selectable_text(
+        &mut self,
+        text: Pin<&ComplexText>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+        cache: &CachedRenderingData,
+    ) {
+        text.draw_with_selection(self, self_rc, size, cache);
+    }
     #[cfg(feature = "std")]
     fn draw_path(&mut self, path: Pin<&Path>, _self_rc: &ItemRc, _size: LogicalSize);
     fn draw_box_shadow(
@@ -1053,6 +1075,8 @@ fn filter_item(
     forward_rendering_call2!(fn draw_window_background(dyn RenderRectangle));
     forward_rendering_call2!(fn draw_image(dyn RenderImage));
     forward_rendering_call2!(fn draw_text(dyn RenderText));
+    forward_rendering_call2!(fn draw_styled_text(StyledText));
+    forward_rendering_call2!(fn draw_selectable_text(ComplexText));
     forward_rendering_call!(fn draw_text_input(TextInput));
     #[cfg(feature = "std")]
     forward_rendering_call!(fn draw_path(Path));
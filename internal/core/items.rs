@@ -20,6 +20,7 @@
 #![allow(non_upper_case_globals)]
 #![allow(missing_docs)] // because documenting each property of items is redundant
 
+use crate::api::LogicalPosition;
 use crate::graphics::{Brush, Color, FontRequest};
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEventResult,
@@ -52,6 +53,8 @@
 pub use text::*;
 mod input_items;
 pub use input_items::*;
+mod drag_n_drop;
+pub use drag_n_drop::*;
 mod image;
 pub use self::image::*;
 #[cfg(feature = "std")]
@@ -70,7 +73,9 @@
 type FocusReasonArg = (FocusReason,);
 type PointerEventArg = (PointerEvent,);
 type PointerScrollEventArg = (PointerScrollEvent,);
+type DropEventArg = (DropEvent,);
 type PointArg = (crate::api::LogicalPosition,);
+type StringArg = (SharedString,);
 type MenuEntryArg = (MenuEntry,);
 type MenuEntryModel = crate::model::ModelRc<MenuEntry>;
 
@@ -619,6 +624,18 @@ fn slint_get_FocusScopeVTable() -> FocusScopeVTable for FocusScope
     fn slint_get_SwipeGestureHandlerVTable() -> SwipeGestureHandlerVTable for SwipeGestureHandler
 }
 
+declare_item_vtable! {
+    fn slint_get_PinchGestureHandlerVTable() -> PinchGestureHandlerVTable for PinchGestureHandler
+}
+
+declare_item_vtable! {
+    fn slint_get_DragAreaVTable() -> DragAreaVTable for DragArea
+}
+
+declare_item_vtable! {
+    fn slint_get_DropAreaVTable() -> DropAreaVTable for DropArea
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -1596,6 +1613,10 @@ fn slint_get_ComplexTextVTable() -> ComplexTextVTable for ComplexText
     fn slint_get_SimpleTextVTable() -> SimpleTextVTable for SimpleText
 }
 
+declare_item_vtable! {
+    fn slint_get_StyledTextVTable() -> StyledTextVTable for StyledText
+}
+
 declare_item_vtable! {
     fn slint_get_TextInputVTable() -> TextInputVTable for TextInput
 }
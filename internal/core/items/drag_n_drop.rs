@@ -0,0 +1,313 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the `DragArea` and `DropArea` elements
+
+use super::{
+    DropEvent, DropEventArg, Item, ItemConsts, ItemRc, ItemRendererRef, PointerEventButton,
+    RenderingResult,
+};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalPoint, LogicalRect, LogicalSize};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Callback, Property, SharedString};
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+/// The implementation of the `DragArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DragArea {
+    pub enabled: Property<bool>,
+    pub mime_type: Property<SharedString>,
+    pub data: Property<SharedString>,
+    pressed_position: Cell<LogicalPoint>,
+    pressed: Cell<bool>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DragArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.pressed.set(false);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                self.pressed_position.set(position);
+                self.pressed.set(true);
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            MouseEvent::Exit | MouseEvent::Released { button: PointerEventButton::Left, .. } => {
+                self.pressed.set(false);
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            MouseEvent::Moved { position } => {
+                if self.is_dragging(position) {
+                    InputEventFilterResult::Intercept
+                } else {
+                    InputEventFilterResult::ForwardAndInterceptGrab
+                }
+            }
+            MouseEvent::Pressed { .. }
+            | MouseEvent::Released { .. }
+            | MouseEvent::Wheel { .. }
+            | MouseEvent::Gesture { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => InputEventFilterResult::ForwardAndIgnore,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::Pressed { button: PointerEventButton::Left, .. } => {
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Exit | MouseEvent::Released { .. } => {
+                self.pressed.set(false);
+                InputEventResult::EventIgnored
+            }
+            MouseEvent::Moved { position } => {
+                if !self.pressed.get() {
+                    return InputEventResult::EventIgnored;
+                }
+                if !self.is_dragging(position) {
+                    return InputEventResult::GrabMouse;
+                }
+                self.pressed.set(false);
+                WindowInner::from_pub(window_adapter.window()).drag_data.replace(Some(DropEvent {
+                    mime_type: self.mime_type(),
+                    data: self.data(),
+                    position: Default::default(),
+                }));
+                // Release the grab: the window delivers the following pointer events to the
+                // drop areas
+                InputEventResult::EventIgnored
+            }
+            MouseEvent::Pressed { .. }
+            | MouseEvent::Wheel { .. }
+            | MouseEvent::Gesture { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _event: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        mut geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry.size = LogicalSize::zero();
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for DragArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl DragArea {
+    /// Returns true if the pointer is pressed and moved far enough from the pressed position
+    fn is_dragging(self: Pin<&Self>, position: LogicalPoint) -> bool {
+        if !self.pressed.get() {
+            return false;
+        }
+        let threshold = super::flickable::DISTANCE_THRESHOLD.get();
+        (position - self.pressed_position.get()).square_length() > threshold * threshold
+    }
+}
+
+/// The implementation of the `DropArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DropArea {
+    pub enabled: Property<bool>,
+    pub contains_drag: Property<bool>,
+    pub can_drop: Callback<DropEventArg, bool>,
+    pub dropped: Callback<DropEventArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DropArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        match event {
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } if self.enabled() => {
+                InputEventFilterResult::ForwardEvent
+            }
+            _ => InputEventFilterResult::ForwardAndIgnore,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let contains_drag = Self::FIELD_OFFSETS.contains_drag.apply_pin(self);
+        match event {
+            MouseEvent::DragMove { position } | MouseEvent::Drop { position } => {
+                let Some(mut drop_event) =
+                    WindowInner::from_pub(window_adapter.window()).drag_data.borrow().clone()
+                else {
+                    contains_drag.set(false);
+                    return InputEventResult::EventIgnored;
+                };
+                drop_event.position = crate::lengths::logical_position_to_api(position);
+                let can_drop = !self.can_drop.has_handler()
+                    || Self::FIELD_OFFSETS.can_drop.apply_pin(self).call(&(drop_event.clone(),));
+                if !can_drop {
+                    contains_drag.set(false);
+                    return InputEventResult::EventIgnored;
+                }
+                if matches!(event, MouseEvent::Drop { .. }) {
+                    contains_drag.set(false);
+                    Self::FIELD_OFFSETS.dropped.apply_pin(self).call(&(drop_event,));
+                } else {
+                    contains_drag.set(true);
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Exit => {
+                contains_drag.set(false);
+                InputEventResult::EventIgnored
+            }
+            MouseEvent::Pressed { .. }
+            | MouseEvent::Released { .. }
+            | MouseEvent::Moved { .. }
+            | MouseEvent::Wheel { .. }
+            | MouseEvent::Gesture { .. } => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _event: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        mut geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry.size = LogicalSize::zero();
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for DropArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
@@ -308,7 +308,10 @@ fn handle_mouse_filter(
                     InputEventFilterResult::ForwardEvent
                 }
             }
-            MouseEvent::Wheel { .. } => InputEventFilterResult::ForwardEvent,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::Gesture { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => InputEventFilterResult::ForwardEvent,
             // Not the left button
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
@@ -406,6 +409,14 @@ fn handle_mouse(
                 }
                 InputEventResult::EventAccepted
             }
+            MouseEvent::Gesture { .. } => {
+                if inner.capture_events {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
         }
     }
 
@@ -14,6 +14,7 @@
 use crate::item_rendering::CachedRenderingData;
 use crate::layout::{LayoutInfo, Orientation};
 use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, PointLengths};
+use crate::platform::GesturePhase;
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use crate::window::{WindowAdapter, WindowInner};
@@ -83,6 +84,10 @@ fn input_event_filter_before_children(
             }
             return InputEventFilterResult::ForwardAndIgnore;
         }
+        if matches!(event, MouseEvent::DragMove { .. } | MouseEvent::Drop { .. }) {
+            // No hover feedback while dragging, that's for the drop areas
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
         if let Some(pos) = event.position() {
             Self::FIELD_OFFSETS.mouse_x.apply_pin(self).set(pos.x_length());
             Self::FIELD_OFFSETS.mouse_y.apply_pin(self).set(pos.y_length());
@@ -179,6 +184,15 @@ fn input_event(
                     InputEventResult::EventAccepted
                 }
             }
+            // Keep the grab while a gesture is recognized from the other touch points
+            MouseEvent::Gesture { .. } => {
+                if self.grabbed.get() {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
             MouseEvent::Wheel { delta_x, delta_y, .. } => {
                 let modifiers = window_adapter.window().0.modifiers.get().into();
                 let r = Self::FIELD_OFFSETS
@@ -480,7 +494,10 @@ fn input_event_filter_before_children(
                     }
                 }
             }
-            MouseEvent::Wheel { .. } => InputEventFilterResult::ForwardAndIgnore,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::Gesture { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => InputEventFilterResult::ForwardAndIgnore,
             // Not the left button
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
@@ -536,7 +553,16 @@ fn input_event(
                 Self::FIELD_OFFSETS.moved.apply_pin(self).call(&());
                 InputEventResult::GrabMouse
             }
-            MouseEvent::Wheel { .. } => InputEventResult::EventIgnored,
+            MouseEvent::Gesture { .. } => {
+                if self.pressed.get() {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::Wheel { .. } | MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => {
+                InputEventResult::EventIgnored
+            }
         }
     }
 
@@ -616,3 +642,177 @@ fn cancel_impl(self: Pin<&Self>) {
     let self_rc = ItemRc::new(self_component.clone(), self_index);
     s.cancel(window_adapter, &self_rc);
 }
+
+/// The implementation of the `PinchGestureHandler` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct PinchGestureHandler {
+    pub enabled: Property<bool>,
+
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub ended: Callback<VoidArg>,
+    pub cancelled: Callback<VoidArg>,
+
+    pub scale: Property<f32>,
+    pub rotation: Property<f32>,
+    pub center: Property<LogicalPosition>,
+    pub active: Property<bool>,
+
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for PinchGestureHandler {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {
+        self.scale.set(1.);
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.cancel_impl();
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+
+        match event {
+            MouseEvent::Gesture { .. } => InputEventFilterResult::Intercept,
+            // Be notified of the events grabbed by a child, to intercept them when a gesture starts
+            MouseEvent::Pressed { .. } | MouseEvent::Moved { .. } | MouseEvent::Released { .. } => {
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            MouseEvent::Wheel { .. }
+            | MouseEvent::Exit
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. } => InputEventFilterResult::ForwardAndIgnore,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::Gesture { position, scale, rotation, phase } => {
+                if !self.enabled() {
+                    return InputEventResult::EventIgnored;
+                }
+                if phase == GesturePhase::Cancelled {
+                    self.cancel_impl();
+                    return InputEventResult::EventAccepted;
+                }
+                let active = Self::FIELD_OFFSETS.active.apply_pin(self);
+                let started = !active.get();
+                if started {
+                    if phase == GesturePhase::Ended {
+                        return InputEventResult::EventIgnored;
+                    }
+                    self.scale.set(1.);
+                    self.rotation.set(0.);
+                    active.set(true);
+                }
+                self.scale.set(self.scale() * scale);
+                self.rotation.set(self.rotation() + rotation);
+                self.center.set(crate::lengths::logical_position_to_api(position));
+                if started {
+                    Self::FIELD_OFFSETS.started.apply_pin(self).call(&());
+                } else if phase != GesturePhase::Ended {
+                    Self::FIELD_OFFSETS.updated.apply_pin(self).call(&());
+                }
+                if phase == GesturePhase::Ended {
+                    active.set(false);
+                    Self::FIELD_OFFSETS.ended.apply_pin(self).call(&());
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::GrabMouse
+                }
+            }
+            MouseEvent::Exit => {
+                self.cancel_impl();
+                InputEventResult::EventIgnored
+            }
+            MouseEvent::Pressed { .. }
+            | MouseEvent::Moved { .. }
+            | MouseEvent::Released { .. }
+            | MouseEvent::Wheel { .. } => {
+                if self.active() {
+                    InputEventResult::GrabMouse
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _event: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        mut geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry.size = LogicalSize::zero();
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for PinchGestureHandler {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl PinchGestureHandler {
+    fn cancel_impl(self: Pin<&Self>) {
+        if self.active() {
+            Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+            Self::FIELD_OFFSETS.cancelled.apply_pin(self).call(&());
+        }
+    }
+}
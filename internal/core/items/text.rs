@@ -9,7 +9,7 @@
 */
 use super::{
     EventResult, FontMetrics, InputType, Item, ItemConsts, ItemRc, ItemRef, KeyEventArg,
-    KeyEventResult, KeyEventType, PointArg, PointerEventButton, RenderingResult,
+    KeyEventResult, KeyEventType, PointArg, PointerEventButton, RenderingResult, StringArg,
     TextHorizontalAlignment, TextOverflow, TextStrokeStyle, TextVerticalAlignment, TextWrap,
     VoidArg, WindowItem,
 };
@@ -18,18 +18,20 @@
     key_codes, FocusEvent, FocusEventResult, FocusReason, InputEventFilterResult, InputEventResult,
     KeyEvent, KeyboardModifiers, MouseEvent, StandardShortcut, TextShortcut,
 };
-use crate::item_rendering::{CachedRenderingData, ItemRenderer, RenderText};
+use crate::item_rendering::{CachedRenderingData, ItemRenderer, RenderRectangle, RenderText};
 use crate::layout::{LayoutInfo, Orientation};
 use crate::lengths::{
-    LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor, SizeLengths,
+    LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector, ScaleFactor, SizeLengths,
 };
 use crate::platform::Clipboard;
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
+use crate::textlayout::{SpanStyle, StyledParagraph, StyledTextLayout};
 use crate::window::{InputMethodProperties, InputMethodRequest, WindowAdapter, WindowInner};
 use crate::{Callback, Coord, Property, SharedString, SharedVector};
 use alloc::rc::Rc;
 use alloc::string::String;
+use alloc::{vec, vec::Vec};
 use const_field_offset::FieldOffsets;
 use core::cell::Cell;
 use core::pin::Pin;
@@ -60,7 +62,14 @@ pub struct ComplexText {
     pub stroke: Property<Brush>,
     pub stroke_width: Property<LogicalLength>,
     pub stroke_style: Property<TextStrokeStyle>,
+    pub selectable: Property<bool>,
+    pub selection_foreground_color: Property<Color>,
+    pub selection_background_color: Property<Color>,
     pub cached_rendering_data: CachedRenderingData,
+    anchor_position_byte_offset: Property<i32>,
+    cursor_position_byte_offset: Property<i32>,
+    /// 0 = not pressed, 1 = single press, 2 = double clicked+press , ...
+    pressed: Cell<u8>,
 }
 
 impl Item for ComplexText {
@@ -87,34 +96,125 @@ fn input_event_filter_before_children(
         _window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> InputEventFilterResult {
-        InputEventFilterResult::ForwardAndIgnore
+        if self.selectable() {
+            InputEventFilterResult::ForwardEvent
+        } else {
+            InputEventFilterResult::ForwardAndIgnore
+        }
     }
 
     fn input_event(
         self: Pin<&Self>,
-        _: MouseEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
-        _self_rc: &ItemRc,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
     ) -> InputEventResult {
-        InputEventResult::EventIgnored
+        if !self.selectable() {
+            return InputEventResult::EventIgnored;
+        }
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, click_count } => {
+                let clicked_offset =
+                    self.byte_offset_for_position(position, window_adapter, self_rc) as i32;
+                self.pressed.set((click_count % 3) + 1);
+                if !window_adapter.window().0.modifiers.get().shift() {
+                    self.anchor_position_byte_offset.set(clicked_offset);
+                }
+                self.cursor_position_byte_offset.set(clicked_offset);
+                match click_count % 3 {
+                    0 => (),
+                    1 => self.extend_selection(prev_word_boundary, next_word_boundary),
+                    2 => self.extend_selection(prev_paragraph_boundary, next_paragraph_boundary),
+                    _ => unreachable!(),
+                }
+                // Take the focus to receive the copy shortcut
+                WindowInner::from_pub(window_adapter.window()).set_focus_item(
+                    self_rc,
+                    true,
+                    FocusReason::PointerClick,
+                );
+                return InputEventResult::GrabMouse;
+            }
+            MouseEvent::Released { button: PointerEventButton::Left, .. } => {
+                self.pressed.set(0);
+                self.copy_clipboard(window_adapter, Clipboard::SelectionClipboard);
+            }
+            MouseEvent::Exit => {
+                if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                    x.set_mouse_cursor(super::MouseCursor::Default);
+                }
+                self.pressed.set(0);
+            }
+            MouseEvent::Moved { position } => {
+                if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                    x.set_mouse_cursor(super::MouseCursor::Text);
+                }
+                let pressed = self.pressed.get();
+                if pressed > 0 {
+                    let clicked_offset =
+                        self.byte_offset_for_position(position, window_adapter, self_rc) as i32;
+                    self.cursor_position_byte_offset.set(clicked_offset);
+                    match (pressed - 1) % 3 {
+                        0 => (),
+                        1 => self.extend_selection(prev_word_boundary, next_word_boundary),
+                        2 => {
+                            self.extend_selection(prev_paragraph_boundary, next_paragraph_boundary)
+                        }
+                        _ => unreachable!(),
+                    }
+                    return InputEventResult::GrabMouse;
+                }
+            }
+            _ => return InputEventResult::EventIgnored,
+        }
+        InputEventResult::EventAccepted
     }
 
     fn key_event(
         self: Pin<&Self>,
-        _: &KeyEvent,
-        _window_adapter: &Rc<dyn WindowAdapter>,
+        event: &KeyEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> KeyEventResult {
-        KeyEventResult::EventIgnored
+        if !self.selectable() || event.event_type != KeyEventType::KeyPressed {
+            return KeyEventResult::EventIgnored;
+        }
+        match event.shortcut() {
+            Some(StandardShortcut::Copy) => {
+                self.copy_clipboard(window_adapter, Clipboard::DefaultClipboard);
+                KeyEventResult::EventAccepted
+            }
+            Some(StandardShortcut::SelectAll) => {
+                self.anchor_position_byte_offset.set(0);
+                self.cursor_position_byte_offset.set(self.text().len() as i32);
+                KeyEventResult::EventAccepted
+            }
+            _ => KeyEventResult::EventIgnored,
+        }
     }
 
     fn focus_event(
         self: Pin<&Self>,
-        _: &FocusEvent,
+        event: &FocusEvent,
         _window_adapter: &Rc<dyn WindowAdapter>,
         _self_rc: &ItemRc,
     ) -> FocusEventResult {
-        FocusEventResult::FocusIgnored
+        match event {
+            // A selectable text only gets the focus when clicked, and isn't part of the focus chain
+            FocusEvent::FocusIn(reason) => {
+                if self.selectable() && *reason != FocusReason::TabNavigation {
+                    FocusEventResult::FocusAccepted
+                } else {
+                    FocusEventResult::FocusIgnored
+                }
+            }
+            FocusEvent::FocusOut(reason) => {
+                if !matches!(reason, FocusReason::WindowActivation | FocusReason::PopupActivation) {
+                    self.anchor_position_byte_offset.set(self.cursor_position_byte_offset());
+                }
+                FocusEventResult::FocusAccepted
+            }
+        }
     }
 
     fn render(
@@ -123,7 +223,7 @@ fn render(
         self_rc: &ItemRc,
         size: LogicalSize,
     ) -> RenderingResult {
-        (*backend).draw_text(self, self_rc, size, &self.cached_rendering_data);
+        (*backend).draw_selectable_text(self, self_rc, size, &self.cached_rendering_data);
         RenderingResult::ContinueRenderingChildren
     }
 
@@ -206,6 +306,204 @@ pub fn font_metrics(
         let font_request = self.font_request(self_rc);
         window_adapter.renderer().font_metrics(font_request, scale_factor)
     }
+
+    /// Returns the start and the end of the selection
+    fn selection_range(self: Pin<&Self>, text: &str) -> (usize, usize) {
+        let anchor = safe_byte_offset(self.anchor_position_byte_offset(), text);
+        let cursor = safe_byte_offset(self.cursor_position_byte_offset(), text);
+        (anchor.min(cursor), anchor.max(cursor))
+    }
+
+    /// Moves the anchor and the cursor to the boundaries around the selection
+    fn extend_selection(
+        self: Pin<&Self>,
+        prev_boundary: fn(&str, usize) -> usize,
+        next_boundary: fn(&str, usize) -> usize,
+    ) {
+        let text = self.text();
+        let anchor = safe_byte_offset(self.anchor_position_byte_offset(), &text);
+        let cursor = safe_byte_offset(self.cursor_position_byte_offset(), &text);
+        let (new_a, new_c) = if anchor <= cursor {
+            (prev_boundary(&text, anchor), next_boundary(&text, cursor))
+        } else {
+            (next_boundary(&text, anchor), prev_boundary(&text, cursor))
+        };
+        self.anchor_position_byte_offset.set(new_a as i32);
+        self.cursor_position_byte_offset.set(new_c as i32);
+    }
+
+    fn copy_clipboard(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        clipboard: Clipboard,
+    ) {
+        let text = self.text();
+        let (start, end) = self.selection_range(&text);
+        if start == end {
+            return;
+        }
+        WindowInner::from_pub(window_adapter.window())
+            .ctx
+            .platform()
+            .set_clipboard_text(&text[start..end], clipboard);
+    }
+
+    /// Returns a `TextInput` with the same text and layout as this element, so that the text input
+    /// functions of the renderer can be used for hit-testing.
+    fn as_text_input(self: Pin<&Self>) -> TextInput {
+        let text_input = TextInput::default();
+        text_input.text.set(self.text());
+        text_input.width.set(self.width());
+        text_input.height.set(self.height());
+        text_input.horizontal_alignment.set(self.horizontal_alignment());
+        text_input.vertical_alignment.set(self.vertical_alignment());
+        text_input.wrap.set(self.wrap());
+        text_input.letter_spacing.set(self.letter_spacing());
+        text_input
+    }
+
+    fn byte_offset_for_position(
+        self: Pin<&Self>,
+        pos: LogicalPoint,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> usize {
+        let text_input = core::pin::pin!(self.as_text_input());
+        window_adapter.renderer().text_input_byte_offset_for_position(
+            text_input.as_ref(),
+            pos,
+            self.font_request(self_rc),
+            ScaleFactor::new(window_adapter.window().scale_factor()),
+        )
+    }
+
+    /// Returns the rectangles covering the selection: one per line for the first and last
+    /// line, and one for the lines in between.
+    fn selection_rects(
+        self: Pin<&Self>,
+        size: LogicalSize,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> Vec<LogicalRect> {
+        let (start, end) = self.selection_range(&self.text());
+        if start == end {
+            return Vec::new();
+        }
+        let text_input = core::pin::pin!(self.as_text_input());
+        let font_request = self.font_request(self_rc);
+        let scale_factor = ScaleFactor::new(window_adapter.window().scale_factor());
+        let cursor_rect = |offset| {
+            window_adapter.renderer().text_input_cursor_rect_for_byte_offset(
+                text_input.as_ref(),
+                offset,
+                font_request.clone(),
+                scale_factor,
+            )
+        };
+        let (start, end) = (cursor_rect(start), cursor_rect(end));
+        let end_bottom_left = LogicalPoint::new(end.min_x(), end.max_y());
+        if end.center().y < start.max_y() {
+            return vec![LogicalRect::from_points([start.origin, end_bottom_left])];
+        }
+        let mut rects = vec![LogicalRect::from_points([
+            start.origin,
+            LogicalPoint::new(size.width, start.max_y()),
+        ])];
+        if end.min_y() > start.max_y() {
+            rects.push(LogicalRect::from_points([
+                LogicalPoint::new(0 as Coord, start.max_y()),
+                LogicalPoint::new(size.width, end.min_y()),
+            ]));
+        }
+        rects.push(LogicalRect::from_points([
+            LogicalPoint::new(0 as Coord, end.min_y()),
+            end_bottom_left,
+        ]));
+        rects
+    }
+
+    /// Draws the text with [`ItemRenderer::draw_text`]. When the text is selectable, the
+    /// selection is drawn behind it, and the selected text is drawn again with the selection
+    /// foreground color, clipped to the selection.
+    pub fn draw_with_selection<R: ItemRenderer + ?Sized>(
+        self: Pin<&Self>,
+        renderer: &mut R,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+        cache: &CachedRenderingData,
+    ) {
+        let selection_rects = if self.selectable() {
+            self.selection_rects(size, &renderer.window().window_adapter(), self_rc)
+        } else {
+            Vec::new()
+        };
+        let background = FilledRectangle(self.selection_background_color().into());
+        for rect in &selection_rects {
+            renderer.save_state();
+            renderer.translate(rect.origin.to_vector());
+            renderer.draw_rectangle(
+                Pin::<&FilledRectangle>::new(&background),
+                self_rc,
+                rect.size,
+                cache,
+            );
+            renderer.restore_state();
+        }
+        renderer.draw_text(self, self_rc, size, cache);
+        let selected_text =
+            SelectedText { text: self, color: self.selection_foreground_color().into() };
+        for rect in selection_rects {
+            renderer.save_state();
+            if renderer.combine_clip(rect, Default::default(), Default::default()) {
+                renderer.draw_text(Pin::<&SelectedText>::new(&selected_text), self_rc, size, cache);
+            }
+            renderer.restore_state();
+        }
+    }
+}
+
+/// The selected part of a selectable `Text`, drawn with [`ItemRenderer::draw_text`]
+struct SelectedText<'a> {
+    text: Pin<&'a ComplexText>,
+    color: Brush,
+}
+
+impl RenderText for SelectedText<'_> {
+    fn target_size(self: Pin<&Self>) -> LogicalSize {
+        self.text.target_size()
+    }
+
+    fn text(self: Pin<&Self>) -> SharedString {
+        RenderText::text(self.text)
+    }
+
+    fn font_request(self: Pin<&Self>, self_rc: &ItemRc) -> FontRequest {
+        self.text.font_request(self_rc)
+    }
+
+    fn color(self: Pin<&Self>) -> Brush {
+        self.color.clone()
+    }
+
+    fn alignment(self: Pin<&Self>) -> (TextHorizontalAlignment, TextVerticalAlignment) {
+        self.text.alignment()
+    }
+
+    fn wrap(self: Pin<&Self>) -> TextWrap {
+        RenderText::wrap(self.text)
+    }
+
+    fn overflow(self: Pin<&Self>) -> TextOverflow {
+        RenderText::overflow(self.text)
+    }
+
+    fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
+        RenderText::letter_spacing(self.text)
+    }
+
+    fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
+        RenderText::stroke(self.text)
+    }
 }
 
 /// The implementation of the `Text` element
@@ -370,6 +668,330 @@ pub fn font_metrics(
     }
 }
 
+/// The implementation of the `StyledText` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct StyledText {
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub text: Property<SharedString>,
+    pub font_size: Property<LogicalLength>,
+    pub font_weight: Property<i32>,
+    pub font_family: Property<SharedString>,
+    pub color: Property<Brush>,
+    pub link_color: Property<Brush>,
+    pub horizontal_alignment: Property<TextHorizontalAlignment>,
+    pub vertical_alignment: Property<TextVerticalAlignment>,
+    pub wrap: Property<TextWrap>,
+    pub link_clicked: Callback<StringArg>,
+    /// The url of the link under the pointer when it was pressed
+    pressed_link: Cell<SharedString>,
+    /// Set when the mouse cursor was changed because the pointer is over a link
+    hovering_link: Cell<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for StyledText {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> LayoutInfo {
+        let paragraph = StyledParagraph::parse_markdown(&self.text());
+        match orientation {
+            Orientation::Horizontal => {
+                let width = self.layout(&paragraph, None, self_rc, window_adapter).size().width;
+                let min = match self.wrap() {
+                    TextWrap::NoWrap => width,
+                    TextWrap::WordWrap | TextWrap::CharWrap => 0 as Coord,
+                };
+                LayoutInfo { min: min.ceil(), preferred: width.ceil(), ..LayoutInfo::default() }
+            }
+            Orientation::Vertical => {
+                let height = self
+                    .layout(&paragraph, Some(self.width()), self_rc, window_adapter)
+                    .size()
+                    .height
+                    .ceil();
+                LayoutInfo { min: height, preferred: height, ..LayoutInfo::default() }
+            }
+        }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        // Only the links handle events
+        if self.text().contains("](") {
+            InputEventFilterResult::ForwardEvent
+        } else {
+            InputEventFilterResult::ForwardAndIgnore
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let link = event.position().and_then(|pos| self.link_at(pos, self_rc, window_adapter));
+        let set_cursor = |cursor| {
+            if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                x.set_mouse_cursor(cursor);
+            }
+        };
+        if link.is_some() {
+            set_cursor(super::MouseCursor::Pointer);
+            self.hovering_link.set(true);
+        } else if self.hovering_link.take() {
+            set_cursor(super::MouseCursor::Default);
+        }
+
+        let pressed_link = self.pressed_link.take();
+        match event {
+            MouseEvent::Pressed { button: PointerEventButton::Left, .. } => match link {
+                Some(link) => {
+                    self.pressed_link.set(link);
+                    InputEventResult::GrabMouse
+                }
+                None => InputEventResult::EventIgnored,
+            },
+            MouseEvent::Released { button: PointerEventButton::Left, .. }
+                if !pressed_link.is_empty() =>
+            {
+                if link.as_ref() == Some(&pressed_link) {
+                    Self::FIELD_OFFSETS.link_clicked.apply_pin(self).call(&(pressed_link,));
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Moved { .. } if !pressed_link.is_empty() => {
+                self.pressed_link.set(pressed_link);
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Moved { .. } if link.is_some() => InputEventResult::EventAccepted,
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut &mut dyn ItemRenderer,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        (*backend).draw_styled_text(self, self_rc, size, &self.cached_rendering_data);
+        RenderingResult::ContinueRenderingChildren
+    }
+
+    fn bounding_rect(
+        self: core::pin::Pin<&Self>,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+        geometry: LogicalRect,
+    ) -> LogicalRect {
+        geometry
+    }
+
+    fn clips_children(self: core::pin::Pin<&Self>) -> bool {
+        false
+    }
+}
+
+impl ItemConsts for StyledText {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        StyledText,
+        CachedRenderingData,
+    > = StyledText::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl StyledText {
+    fn font_request(self: Pin<&Self>, self_rc: &ItemRc, style: &SpanStyle) -> FontRequest {
+        let mut font_request = WindowItem::resolved_font_request(
+            self_rc,
+            self.font_family(),
+            self.font_weight(),
+            self.font_size(),
+            LogicalLength::default(),
+            style.italic,
+        );
+        if style.bold {
+            font_request.weight = Some(font_request.weight.unwrap_or(400).max(700));
+        }
+        font_request
+    }
+
+    fn layout(
+        self: Pin<&Self>,
+        paragraph: &StyledParagraph,
+        max_width: Option<LogicalLength>,
+        self_rc: &ItemRc,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> StyledTextLayout {
+        let scale_factor =
+            ScaleFactor::new(WindowInner::from_pub(window_adapter.window()).scale_factor());
+        let renderer = window_adapter.renderer();
+        StyledTextLayout::new(paragraph, max_width, self.wrap(), |text, style| {
+            renderer.text_size(
+                self.font_request(self_rc, style),
+                text,
+                None,
+                scale_factor,
+                TextWrap::NoWrap,
+            )
+        })
+    }
+
+    /// Returns the url of the link at the given position, relative to the element
+    fn link_at(
+        self: Pin<&Self>,
+        pos: LogicalPoint,
+        self_rc: &ItemRc,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> Option<SharedString> {
+        let paragraph = StyledParagraph::parse_markdown(&self.text());
+        let size = LogicalSize::from_lengths(self.width(), self.height());
+        let layout = self.layout(&paragraph, Some(size.width_length()), self_rc, window_adapter);
+        let alignment = (self.horizontal_alignment(), self.vertical_alignment());
+        let (_, run) =
+            layout.positioned_runs(size, alignment).find(|(rect, _)| rect.contains(pos))?;
+        paragraph.spans[run.span_index].1.link.clone()
+    }
+
+    /// Draws the text run by run with [`ItemRenderer::draw_text`], and draws a line under the links.
+    pub fn draw_runs<R: ItemRenderer + ?Sized>(
+        self: Pin<&Self>,
+        renderer: &mut R,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+        cache: &CachedRenderingData,
+    ) {
+        let window_adapter = renderer.window().window_adapter();
+        let scale_factor = ScaleFactor::new(renderer.window().scale_factor());
+        let paragraph = StyledParagraph::parse_markdown(&self.text());
+        let layout = self.layout(&paragraph, Some(size.width_length()), self_rc, &window_adapter);
+        let alignment = (self.horizontal_alignment(), self.vertical_alignment());
+        for (rect, run) in layout.positioned_runs(size, alignment) {
+            let style = &paragraph.spans[run.span_index].1;
+            let color = match (style.color, &style.link) {
+                (Some(color), _) => color.into(),
+                (None, Some(_)) => self.link_color(),
+                (None, None) => self.color(),
+            };
+            let run_item = StyledTextRunItem {
+                text: paragraph.text[run.byte_range.clone()].into(),
+                font_request: self.font_request(self_rc, style),
+                color: color.clone(),
+                // Don't clip the overhang of italic glyphs at the end of the run
+                size: LogicalSize::new(rect.width().max(size.width - rect.min_x()), rect.height()),
+            };
+            renderer.save_state();
+            renderer.translate(rect.origin.to_vector());
+            renderer.draw_text(
+                Pin::<&StyledTextRunItem>::new(&run_item),
+                self_rc,
+                run_item.size,
+                cache,
+            );
+            if style.link.is_some() {
+                let ascent = window_adapter
+                    .renderer()
+                    .font_metrics(run_item.font_request.clone(), scale_factor)
+                    .ascent;
+                renderer.translate(LogicalVector::new(0 as Coord, ascent.ceil() + 1 as Coord));
+                let underline = LogicalSize::new(rect.width(), 1 as Coord);
+                renderer.draw_rectangle(
+                    Pin::<&FilledRectangle>::new(&FilledRectangle(color)),
+                    self_rc,
+                    underline,
+                    cache,
+                );
+            }
+            renderer.restore_state();
+        }
+    }
+}
+
+/// A run of a `StyledText` with a single style, drawn with [`ItemRenderer::draw_text`]
+struct StyledTextRunItem {
+    text: SharedString,
+    font_request: FontRequest,
+    color: Brush,
+    size: LogicalSize,
+}
+
+impl RenderText for StyledTextRunItem {
+    fn target_size(self: Pin<&Self>) -> LogicalSize {
+        self.size
+    }
+
+    fn text(self: Pin<&Self>) -> SharedString {
+        self.text.clone()
+    }
+
+    fn font_request(self: Pin<&Self>, _self_rc: &ItemRc) -> FontRequest {
+        self.font_request.clone()
+    }
+
+    fn color(self: Pin<&Self>) -> Brush {
+        self.color.clone()
+    }
+
+    fn alignment(self: Pin<&Self>) -> (TextHorizontalAlignment, TextVerticalAlignment) {
+        (TextHorizontalAlignment::Left, TextVerticalAlignment::Top)
+    }
+
+    fn wrap(self: Pin<&Self>) -> TextWrap {
+        TextWrap::NoWrap
+    }
+
+    fn overflow(self: Pin<&Self>) -> TextOverflow {
+        TextOverflow::Clip
+    }
+
+    fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
+        LogicalLength::default()
+    }
+
+    fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
+        Default::default()
+    }
+}
+
+/// A rectangle filled with a brush, such as the line under a link of a `StyledText`
+struct FilledRectangle(Brush);
+
+impl RenderRectangle for FilledRectangle {
+    fn background(self: Pin<&Self>) -> Brush {
+        self.0.clone()
+    }
+}
+
 fn text_layout_info(
     text: Pin<&dyn RenderText>,
     self_rc: &ItemRc,
@@ -5,7 +5,9 @@
 
 // cspell:ignore coord
 
-use crate::items::{DialogButtonRole, LayoutAlignment};
+use crate::items::{
+    DialogButtonRole, FlexAlignItems, FlexDirection, FlexJustifyContent, LayoutAlignment,
+};
 use crate::{slice::Slice, Coord, SharedVector};
 use alloc::vec::Vec;
 
@@ -579,8 +581,24 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
         }
     }
 
-    let res = result.make_mut_slice();
+    fill_layout_result(
+        result.make_mut_slice(),
+        &repeater_indexes,
+        layout_data.iter().map(|it| (it.pos, it.size)),
+    );
+    result
+}
 
+/// Fill `res` with the position and the size of each cell, given in the order of the cells.
+///
+/// The cells of the repeaters are put at the end, and the position they would have had is used to
+/// store the index of the first of them in `res`. The `repeater_indexes` contains, for each
+/// repeater, the index of its first cell and its number of cells.
+fn fill_layout_result(
+    res: &mut [Coord],
+    repeater_indexes: &[u32],
+    cells: impl Iterator<Item = (Coord, Coord)>,
+) {
     // The index/2 in result in which we should add the next repeated item
     let mut repeat_offset =
         res.len() / 2 - repeater_indexes.iter().skip(1).step_by(2).sum::<u32>() as usize;
@@ -588,7 +606,7 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
     let mut next_rep = 0;
     // The index/2 in result in which we should add the next non-repeated item
     let mut current_offset = 0;
-    for (idx, layout) in layout_data.iter().enumerate() {
+    for (idx, (pos, size)) in cells.enumerate() {
         let o = loop {
             if let Some(nr) = repeater_indexes.get(next_rep * 2) {
                 let nr = *nr as usize;
@@ -610,10 +628,9 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
             current_offset += 1;
             break current_offset - 1;
         };
-        res[o * 2] = layout.pos;
-        res[o * 2 + 1] = layout.size;
+        res[o * 2] = pos;
+        res[o * 2 + 1] = size;
     }
-    result
 }
 
 /// Return the LayoutInfo for a BoxLayout with the given cells.
@@ -659,6 +676,405 @@ pub fn box_layout_info_ortho(cells: Slice<BoxLayoutCellData>, padding: &Padding)
     fold
 }
 
+#[repr(C)]
+#[derive(Debug)]
+/// The FlexboxLayoutData is used to solve a FlexboxLayout, or to compute its LayoutInfo, in one
+/// orientation.
+/// The cells of both orientations are only needed for the cross axis, because the lines
+/// depend on the main axis. For the main axis, the cells of the cross axis can be empty.
+pub struct FlexboxLayoutData<'a> {
+    pub width: Coord,
+    pub height: Coord,
+    pub spacing_horizontal: Coord,
+    pub spacing_vertical: Coord,
+    pub padding_horizontal: Padding,
+    pub padding_vertical: Padding,
+    pub direction: FlexDirection,
+    pub wrap: bool,
+    pub justify_content: FlexJustifyContent,
+    pub align_items: FlexAlignItems,
+    pub cells_horizontal: Slice<'a, FlexboxLayoutCellData>,
+    pub cells_vertical: Slice<'a, FlexboxLayoutCellData>,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct FlexboxLayoutCellData {
+    pub constraint: LayoutInfo,
+    /// The share of the remaining space of its line that the cell gets along the main axis
+    pub flex_grow: f32,
+    /// How much the cell shrinks, relative to the other cells, when its line is too small
+    pub flex_shrink: f32,
+    /// The size of the cell along the main axis before growing or shrinking.
+    /// A negative value means that the preferred size is used.
+    pub flex_basis: Coord,
+}
+
+impl Default for FlexboxLayoutCellData {
+    fn default() -> Self {
+        Self {
+            constraint: LayoutInfo::default(),
+            flex_grow: 0.,
+            flex_shrink: 1.,
+            flex_basis: -1 as _,
+        }
+    }
+}
+
+impl From<BoxLayoutCellData> for FlexboxLayoutCellData {
+    fn from(cell: BoxLayoutCellData) -> Self {
+        Self { constraint: cell.constraint, ..Default::default() }
+    }
+}
+
+/// The data of a FlexboxLayout, seen along its main and cross axes
+struct FlexAxes<'a> {
+    main_size: Coord,
+    cross_size: Coord,
+    main_spacing: Coord,
+    cross_spacing: Coord,
+    main_padding: Padding,
+    cross_padding: Padding,
+    main_cells: &'a [FlexboxLayoutCellData],
+    cross_cells: &'a [FlexboxLayoutCellData],
+}
+
+impl FlexboxLayoutData<'_> {
+    fn main_orientation(&self) -> Orientation {
+        match self.direction {
+            FlexDirection::Row | FlexDirection::RowReverse => Orientation::Horizontal,
+            FlexDirection::Column | FlexDirection::ColumnReverse => Orientation::Vertical,
+        }
+    }
+
+    fn axes(&self) -> FlexAxes<'_> {
+        let horizontal = (
+            self.width,
+            self.spacing_horizontal,
+            self.padding_horizontal,
+            self.cells_horizontal.as_slice(),
+        );
+        let vertical = (
+            self.height,
+            self.spacing_vertical,
+            self.padding_vertical,
+            self.cells_vertical.as_slice(),
+        );
+        let (main, cross) = match self.main_orientation() {
+            Orientation::Horizontal => (horizontal, vertical),
+            Orientation::Vertical => (vertical, horizontal),
+        };
+        FlexAxes {
+            main_size: main.0,
+            cross_size: cross.0,
+            main_spacing: main.1,
+            cross_spacing: cross.1,
+            main_padding: main.2,
+            cross_padding: cross.2,
+            main_cells: main.3,
+            cross_cells: cross.3,
+        }
+    }
+}
+
+/// Tolerance used when checking whether the items fit in a line, so that a FlexboxLayout given
+/// its preferred size doesn't wrap because of rounding errors
+const FLEX_EPSILON: Coord = 0.01 as Coord;
+
+/// Return the min and max size of a cell. The percentages are ignored when the size of the
+/// container isn't known.
+fn flex_min_max(constraint: &LayoutInfo, container_size: Option<Coord>) -> (Coord, Coord) {
+    let (min, max) = match container_size {
+        Some(size) => (
+            constraint.min.max(constraint.min_percent * size / 100 as Coord),
+            constraint.max.min(constraint.max_percent * size / 100 as Coord),
+        ),
+        None => (constraint.min, constraint.max),
+    };
+    (min, max.max(min))
+}
+
+/// A cell along the main axis
+#[derive(Default, Clone, Copy)]
+struct FlexItem {
+    min: Coord,
+    max: Coord,
+    basis: Coord,
+    grow: f32,
+    shrink: f32,
+    pos: Coord,
+    size: Coord,
+}
+
+fn flex_items(cells: &[FlexboxLayoutCellData], container_size: Option<Coord>) -> Vec<FlexItem> {
+    cells
+        .iter()
+        .map(|c| {
+            let (min, max) = flex_min_max(&c.constraint, container_size);
+            let basis =
+                if c.flex_basis >= 0 as Coord { c.flex_basis } else { c.constraint.preferred };
+            FlexItem {
+                min,
+                max,
+                basis: basis.clamp(min, max),
+                grow: c.flex_grow,
+                shrink: c.flex_shrink,
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+/// Split the items in lines whose basis fit in the `available` size
+fn flex_lines(
+    items: &[FlexItem],
+    available: Coord,
+    spacing: Coord,
+    wrap: bool,
+) -> Vec<core::ops::Range<usize>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut line_size = 0 as Coord;
+    for (idx, item) in items.iter().enumerate() {
+        if idx == start {
+            line_size = item.basis;
+        } else if wrap && line_size + spacing + item.basis > available + FLEX_EPSILON {
+            lines.push(start..idx);
+            start = idx;
+            line_size = item.basis;
+        } else {
+            line_size += spacing + item.basis;
+        }
+    }
+    if start < items.len() {
+        lines.push(start..items.len());
+    }
+    lines
+}
+
+/// Set the size of the items of a line so that they fill the `available` size, by growing or
+/// shrinking them according to their factor and within their min and max size.
+fn flex_resolve_sizes(items: &mut [FlexItem], available: Coord, spacing: Coord) {
+    let spacings = spacing * (items.len() - 1) as Coord;
+    let growing = items.iter().map(|it| it.basis).sum::<Coord>() + spacings < available;
+    let factor = |it: &FlexItem| if growing { it.grow } else { it.shrink * it.basis as f32 };
+    let mut frozen: Vec<bool> = items.iter().map(|it| factor(it) <= 0.).collect();
+    for it in items.iter_mut() {
+        it.size = it.basis;
+    }
+    // Each iteration freezes at least one item that violates its min or max constraint
+    for _ in 0..items.len() {
+        let total_factor: f32 =
+            items.iter().zip(&frozen).filter(|(_, f)| !**f).map(|(it, _)| factor(it)).sum();
+        if total_factor <= 0. {
+            break;
+        }
+        let free = available
+            - spacings
+            - items
+                .iter()
+                .zip(&frozen)
+                .map(|(it, f)| if *f { it.size } else { it.basis })
+                .sum::<Coord>();
+        let mut violation = 0 as Coord;
+        for (it, f) in items.iter_mut().zip(&frozen) {
+            if !*f {
+                let target = it.basis + (free as f32 * factor(it) / total_factor) as Coord;
+                it.size = target.clamp(it.min, it.max);
+                violation += it.size - target;
+            }
+        }
+        if violation == 0 as Coord {
+            break;
+        }
+        for (it, f) in items.iter().zip(frozen.iter_mut()) {
+            if violation > 0 as Coord && it.size == it.min
+                || violation < 0 as Coord && it.size == it.max
+            {
+                *f = true;
+            }
+        }
+    }
+}
+
+/// Set the position of the items of a line which starts at `start`
+fn flex_place_line(
+    items: &mut [FlexItem],
+    start: Coord,
+    available: Coord,
+    spacing: Coord,
+    justify_content: FlexJustifyContent,
+    reverse: bool,
+) {
+    let count = items.len() as Coord;
+    let used = items.iter().map(|it| it.size).sum::<Coord>() + spacing * (count - 1 as Coord);
+    let remaining = (available - used).max(0 as Coord);
+    let (offset, spacing) = match justify_content {
+        FlexJustifyContent::Start => (0 as Coord, spacing),
+        FlexJustifyContent::End => (remaining, spacing),
+        FlexJustifyContent::Center => (remaining / 2 as Coord, spacing),
+        FlexJustifyContent::SpaceBetween if items.len() > 1 => {
+            (0 as Coord, spacing + remaining / (count - 1 as Coord))
+        }
+        FlexJustifyContent::SpaceBetween => (0 as Coord, spacing),
+        FlexJustifyContent::SpaceAround => {
+            (remaining / count / 2 as Coord, spacing + remaining / count)
+        }
+        FlexJustifyContent::SpaceEvenly => {
+            let space = remaining / (count + 1 as Coord);
+            (space, spacing + space)
+        }
+    };
+    let mut pos = offset;
+    for it in items {
+        it.pos = if reverse { start + available - pos - it.size } else { start + pos };
+        pos += it.size + spacing;
+    }
+}
+
+/// Return the position and size of each cell along the cross axis
+fn flex_cross_placement(
+    axes: &FlexAxes,
+    lines: &[core::ops::Range<usize>],
+    wrap: bool,
+    align_items: FlexAlignItems,
+) -> Vec<(Coord, Coord)> {
+    let constraints: Vec<_> = axes
+        .cross_cells
+        .iter()
+        .map(|c| {
+            let (min, max) = flex_min_max(&c.constraint, Some(axes.cross_size));
+            (min, max, c.constraint.preferred.clamp(min, max))
+        })
+        .collect();
+    let available = axes.cross_size - axes.cross_padding.begin - axes.cross_padding.end;
+    let mut result = Vec::new();
+    result.resize(constraints.len(), (0 as Coord, 0 as Coord));
+    let mut line_pos = axes.cross_padding.begin;
+    for line in lines {
+        // A single line takes the whole size, otherwise lines are as big as their biggest item
+        let line_size = if wrap {
+            constraints[line.clone()].iter().fold(0 as Coord, |acc, c| acc.max(c.2))
+        } else {
+            available
+        };
+        for idx in line.clone() {
+            let (min, max, preferred) = constraints[idx];
+            let size = match align_items {
+                FlexAlignItems::Stretch => line_size.min(max).max(min),
+                _ => preferred.min(line_size).max(min),
+            };
+            let offset = match align_items {
+                FlexAlignItems::Stretch | FlexAlignItems::Start => 0 as Coord,
+                FlexAlignItems::End => line_size - size,
+                FlexAlignItems::Center => (line_size - size) / 2 as Coord,
+            };
+            result[idx] = (line_pos + offset, size);
+        }
+        line_pos += line_size + axes.cross_spacing;
+    }
+    result
+}
+
+/// Solve a FlexboxLayout in the given orientation.
+///
+/// Like for [`solve_box_layout`], the result contains the position and the size of each cell,
+/// with the cells of the repeaters at the end.
+pub fn solve_flexbox_layout(
+    data: &FlexboxLayoutData,
+    orientation: Orientation,
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    let axes = data.axes();
+    let is_main = orientation == data.main_orientation();
+    let cell_count = if is_main { axes.main_cells.len() } else { axes.cross_cells.len() };
+    let mut result = SharedVector::<Coord>::default();
+    result.resize(cell_count * 2 + repeater_indexes.len(), 0 as _);
+    if cell_count == 0 || axes.main_cells.len() != cell_count {
+        return result;
+    }
+
+    let mut items = flex_items(axes.main_cells, Some(axes.main_size));
+    let available = axes.main_size - axes.main_padding.begin - axes.main_padding.end;
+    let lines = flex_lines(&items, available, axes.main_spacing, data.wrap);
+    if is_main {
+        let reverse =
+            matches!(data.direction, FlexDirection::RowReverse | FlexDirection::ColumnReverse);
+        for line in lines {
+            let items = &mut items[line];
+            flex_resolve_sizes(items, available, axes.main_spacing);
+            flex_place_line(
+                items,
+                axes.main_padding.begin,
+                available,
+                axes.main_spacing,
+                data.justify_content,
+                reverse,
+            );
+        }
+        fill_layout_result(
+            result.make_mut_slice(),
+            &repeater_indexes,
+            items.iter().map(|it| (it.pos, it.size)),
+        );
+    } else {
+        let cross = flex_cross_placement(&axes, &lines, data.wrap, data.align_items);
+        fill_layout_result(result.make_mut_slice(), &repeater_indexes, cross.into_iter());
+    }
+    result
+}
+
+/// Return the LayoutInfo of a FlexboxLayout in the given orientation.
+pub fn flexbox_layout_info(data: &FlexboxLayoutData, orientation: Orientation) -> LayoutInfo {
+    let axes = data.axes();
+    if axes.main_cells.is_empty() {
+        return LayoutInfo { max: 0 as _, ..LayoutInfo::default() };
+    }
+    let (min, preferred) = if orientation == data.main_orientation() {
+        let items = flex_items(axes.main_cells, None);
+        let extra_w = axes.main_padding.begin + axes.main_padding.end;
+        let spacings = axes.main_spacing * (items.len() - 1) as Coord;
+        // The items that don't shrink can't be smaller than their basis
+        let item_min = |it: &FlexItem| if it.shrink > 0. { it.min } else { it.basis };
+        let min = if data.wrap {
+            items.iter().map(item_min).fold(0 as Coord, |acc, m| acc.max(m))
+        } else {
+            items.iter().map(item_min).sum::<Coord>() + spacings
+        };
+        let preferred = items.iter().map(|it| it.basis).sum::<Coord>() + spacings;
+        (min + extra_w, preferred + extra_w)
+    } else {
+        if axes.cross_cells.len() != axes.main_cells.len() {
+            return LayoutInfo { max: 0 as _, ..LayoutInfo::default() };
+        }
+        let items = flex_items(axes.main_cells, Some(axes.main_size));
+        let available = axes.main_size - axes.main_padding.begin - axes.main_padding.end;
+        let lines = flex_lines(&items, available, axes.main_spacing, data.wrap);
+        let extra_w = axes.cross_padding.begin
+            + axes.cross_padding.end
+            + axes.cross_spacing * (lines.len() - 1) as Coord;
+        let (mut min, mut preferred) = (extra_w, extra_w);
+        for line in lines {
+            let (line_min, line_preferred) =
+                axes.cross_cells[line].iter().fold((0 as Coord, 0 as Coord), |acc, c| {
+                    let (min, max) = flex_min_max(&c.constraint, None);
+                    (acc.0.max(min), acc.1.max(c.constraint.preferred.clamp(min, max)))
+                });
+            min += line_min;
+            preferred += line_preferred;
+        }
+        (min, preferred)
+    };
+    LayoutInfo {
+        min,
+        max: Coord::MAX,
+        min_percent: 0 as _,
+        max_percent: 100 as _,
+        preferred,
+        stretch: 1.,
+    }
+}
+
 /// Given the cells of a layout of a Dialog, re-order the button according to the platform
 ///
 /// This function assume that the `roles` contains the roles of the button which are the first `cells`
@@ -780,6 +1196,25 @@ pub extern "C" fn slint_box_layout_info_ortho(
         super::box_layout_info_ortho(cells, padding)
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn slint_solve_flexbox_layout(
+        data: &FlexboxLayoutData,
+        orientation: Orientation,
+        repeater_indexes: Slice<u32>,
+        result: &mut SharedVector<Coord>,
+    ) {
+        *result = super::solve_flexbox_layout(data, orientation, repeater_indexes)
+    }
+
+    #[unsafe(no_mangle)]
+    /// Return the LayoutInfo for a FlexboxLayout in the given orientation.
+    pub extern "C" fn slint_flexbox_layout_info(
+        data: &FlexboxLayoutData,
+        orientation: Orientation,
+    ) -> LayoutInfo {
+        super::flexbox_layout_info(data, orientation)
+    }
+
     /// Calls [`reorder_dialog_button_layout`].
     ///
     /// Safety: `cells` must be a pointer to a mutable array of cell data, the array must have at
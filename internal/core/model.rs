@@ -5,13 +5,16 @@
 
 //! Model and Repeater
 
+use crate::animations::Instant;
 use crate::item_tree::ItemTreeVTable;
 use crate::item_tree::TraversalOrder;
-pub use crate::items::{StandardListViewItem, TableColumn};
+pub use crate::items::{StandardListViewItem, TableColumn, TreeViewItem};
 use crate::layout::Orientation;
 use crate::lengths::{LogicalLength, RectLengths};
 use crate::{Coord, Property, SharedString, SharedVector};
-pub use adapters::{FilterModel, MapModel, ReverseModel, SortModel};
+pub use adapters::{
+    FilterModel, FlattenedTreeModel, FlattenedTreeRow, MapModel, ReverseModel, SortModel,
+};
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::vec::Vec;
@@ -23,9 +26,11 @@
 pub use model_peer::*;
 use once_cell::unsync::OnceCell;
 use pin_project::pin_project;
+pub use tree::*;
 
 mod adapters;
 mod model_peer;
+mod tree;
 
 type ItemTreeRc<C> = vtable::VRc<crate::item_tree::ItemTreeVTable, C>;
 
@@ -832,6 +837,72 @@ fn box_layout_data(
     ) -> crate::layout::BoxLayoutCellData {
         crate::layout::BoxLayoutCellData::default()
     }
+
+    /// Returns what's needed to perform the layout if this ItemTrees is in a flexbox layout
+    fn flexbox_layout_data(
+        self: Pin<&Self>,
+        orientation: Orientation,
+    ) -> crate::layout::FlexboxLayoutCellData {
+        self.box_layout_data(orientation).into()
+    }
+
+    /// Called when the row of this ItemTree was removed from the model, or when the condition
+    /// of the `if` became false.
+    ///
+    /// Starts the `out` transition and returns how long the ItemTree must be kept alive for it
+    /// to finish. The default returns zero, meaning that the ItemTree is dropped right away.
+    fn start_exit_transition(&self) -> core::time::Duration {
+        core::time::Duration::ZERO
+    }
+}
+
+/// Starts the exit transition of an instance that was removed.
+/// Returns until when it must be kept alive, or None if it can be dropped right away.
+fn start_exit_transition<C: RepeatedItemTree>(instance: &ItemTreeRc<C>) -> Option<Instant> {
+    let duration = instance.start_exit_transition();
+    (!duration.is_zero()).then(|| crate::animations::current_tick() + duration)
+}
+
+/// Removes the instances whose exit transition is over from `leaving` and returns them, so that
+/// they can be dropped once `leaving` is no longer borrowed.
+/// While some instances are still leaving, this requests another animation frame so that it is
+/// called again.
+fn take_finished_exit_transitions<C: RepeatedItemTree>(
+    leaving: &mut Vec<(Instant, ItemTreeRc<C>)>,
+) -> Vec<(Instant, ItemTreeRc<C>)> {
+    if leaving.is_empty() {
+        return Vec::new();
+    }
+    let now = crate::animations::animation_tick();
+    let (finished, still_leaving) =
+        core::mem::take(leaving).into_iter().partition(|(end, _)| end.0 <= now);
+    *leaving = still_leaving;
+    finished
+}
+
+/// Visits the instances that are playing their exit transition.
+///
+/// They are only rendered, behind the other instances. As they are skipped when visiting from
+/// front to back, they no longer receive input events.
+fn visit_leaving_instances<C: RepeatedItemTree>(
+    leaving: &RefCell<Vec<(Instant, ItemTreeRc<C>)>>,
+    order: TraversalOrder,
+    mut visitor: crate::item_tree::ItemVisitorRefMut,
+    first_index: u32,
+) -> crate::item_tree::VisitChildrenResult {
+    if order == TraversalOrder::FrontToBack {
+        return crate::item_tree::VisitChildrenResult::CONTINUE;
+    }
+    let count = leaving.borrow().len();
+    for i in 0..count {
+        let c = leaving.borrow().get(i).map(|c| c.1.clone());
+        if let Some(c) = c {
+            if c.as_pin_ref().visit_children_item(-1, order, visitor.borrow_mut()).has_aborted() {
+                return crate::item_tree::VisitChildrenResult::abort(first_index + i as u32, 0);
+            }
+        }
+    }
+    crate::item_tree::VisitChildrenResult::CONTINUE
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -873,6 +944,8 @@ fn default() -> Self {
 #[pin_project]
 pub struct RepeaterTracker<T: RepeatedItemTree> {
     inner: RefCell<RepeaterInner<T>>,
+    /// The instances whose row was removed, kept alive until their exit transition ends
+    leaving: RefCell<Vec<(Instant, ItemTreeRc<T>)>>,
     #[pin]
     model: Property<ModelRc<T::Data>>,
     #[pin]
@@ -928,7 +1001,7 @@ fn row_added(self: Pin<&Self>, mut index: usize, mut count: usize) {
     }
     /// Notify the peers that rows were removed
     fn row_removed(self: Pin<&Self>, mut index: usize, mut count: usize) {
-        let mut inner = self.inner.borrow_mut();
+        let inner = self.inner.borrow();
         if index < inner.offset {
             if index + count < inner.offset {
                 return;
@@ -944,6 +1017,13 @@ fn row_removed(self: Pin<&Self>, mut index: usize, mut count: usize) {
         if (index + count) > inner.instances.len() {
             count = inner.instances.len() - index;
         }
+        let removed: Vec<_> =
+            inner.instances[index..(index + count)].iter().filter_map(|c| c.1.clone()).collect();
+        drop(inner);
+        let leaving: Vec<_> =
+            removed.into_iter().filter_map(|c| Some((start_exit_transition(&c)?, c))).collect();
+        self.leaving.borrow_mut().extend(leaving);
+        let mut inner = self.inner.borrow_mut();
         self.is_dirty.set(true);
         inner.instances.drain(index..(index + count));
         for c in inner.instances[index..].iter_mut() {
@@ -962,6 +1042,7 @@ impl<C: RepeatedItemTree> Default for RepeaterTracker<C> {
     fn default() -> Self {
         Self {
             inner: Default::default(),
+            leaving: Default::default(),
             model: Property::new_named(ModelRc::default(), "i_slint_core::Repeater::model"),
             is_dirty: Property::new_named(false, "i_slint_core::Repeater::is_dirty"),
             listview_geometry_tracker: Default::default(),
@@ -1008,6 +1089,12 @@ pub fn ensure_updated(self: Pin<&Self>, init: impl Fn() -> ItemTreeRc<C>) {
         if self.data().project_ref().is_dirty.get() {
             self.ensure_updated_impl(init, &model, model.row_count());
         }
+        self.drop_finished_exit_transitions();
+    }
+
+    fn drop_finished_exit_transitions(self: Pin<&Self>) {
+        let finished = take_finished_exit_transitions(&mut self.0.leaving.borrow_mut());
+        drop(finished);
     }
 
     // returns true if new items were created
@@ -1059,6 +1146,7 @@ pub fn ensure_updated_listview(
         // Query is_dirty to track model changes
         self.data().project_ref().is_dirty.get();
         self.data().project_ref().is_dirty.set(false);
+        self.drop_finished_exit_transitions();
 
         let mut vp_width = listview_width;
         let model = self.model();
@@ -1292,6 +1380,93 @@ pub fn ensure_updated_listview(
         }
     }
 
+    /// Same as `Self::ensure_updated` but for a GridView
+    ///
+    /// Only the cells of the rows that intersect the visible area are instantiated. When
+    /// scrolling, the instances of the cells that are no longer visible are recycled for the cells
+    /// that become visible, by updating them with the data of their new row in the model.
+    /// The position of the cells is computed by bindings on the instances themselves.
+    pub fn ensure_updated_gridview(
+        self: Pin<&Self>,
+        init: impl Fn() -> ItemTreeRc<C>,
+        viewport_height: Pin<&Property<LogicalLength>>,
+        viewport_y: LogicalLength,
+        gridview_height: LogicalLength,
+        columns: i32,
+        cell_height: LogicalLength,
+        spacing: LogicalLength,
+    ) {
+        // Query is_dirty to track model changes
+        self.data().project_ref().is_dirty.get();
+        self.data().project_ref().is_dirty.set(false);
+        self.drop_finished_exit_transitions();
+
+        let model = self.model();
+        let row_count = model.row_count();
+        let columns = columns.max(1) as usize;
+        let grid_rows = row_count.div_ceil(columns);
+        let zero = LogicalLength::zero();
+        let row_step = cell_height + spacing;
+        viewport_height.set((row_step * grid_rows as Coord - spacing).max(zero));
+
+        let (first_row, end_row) = if row_step > zero {
+            let first = (-viewport_y / row_step).get().floor().max(0 as Coord) as usize;
+            let end = ((gridview_height - viewport_y) / row_step).get().ceil().max(0 as Coord);
+            (first.min(grid_rows), (end as usize).min(grid_rows))
+        } else {
+            (0, grid_rows)
+        };
+        let new_offset = first_row * columns;
+        let new_end = (end_row * columns).min(row_count).max(new_offset);
+
+        let mut inner = self.0.inner.borrow_mut();
+        let old_offset = inner.offset;
+        let mut old_instances = core::mem::take(&mut inner.instances);
+        // The instances of the cells that are no longer visible, which can be recycled
+        let mut recycled: Vec<_> = old_instances
+            .iter_mut()
+            .enumerate()
+            .filter(|(i, _)| !(new_offset..new_end).contains(&(old_offset + i)))
+            .filter_map(|(_, c)| c.1.take())
+            .collect();
+
+        let mut indices_to_init = Vec::new();
+        let mut instances = Vec::with_capacity(new_end - new_offset);
+        for idx in new_offset..new_end {
+            let (mut state, instance) = match idx
+                .checked_sub(old_offset)
+                .and_then(|i| old_instances.get_mut(i))
+                .and_then(|c| Some((c.0, c.1.take()?)))
+            {
+                Some(c) => c,
+                None => match recycled.pop() {
+                    Some(instance) => (RepeatedInstanceState::Dirty, instance),
+                    None => {
+                        indices_to_init.push(instances.len());
+                        (RepeatedInstanceState::Dirty, init())
+                    }
+                },
+            };
+            if state == RepeatedInstanceState::Dirty {
+                if let Some(data) = model.row_data(idx) {
+                    instance.update(idx, data);
+                }
+                state = RepeatedInstanceState::Clean;
+            }
+            instances.push((state, Some(instance)));
+        }
+        inner.instances = instances;
+        inner.offset = new_offset;
+        drop(inner);
+        drop(old_instances);
+        drop(recycled);
+
+        let inner = self.0.inner.borrow();
+        for item in indices_to_init.into_iter().filter_map(|index| inner.instances.get(index)) {
+            item.1.as_ref().unwrap().init();
+        }
+    }
+
     /// Sets the data directly in the model
     pub fn model_set_row_data(self: Pin<&Self>, row: usize, data: C::Data) {
         let model = self.model();
@@ -1311,6 +1486,10 @@ pub fn visit(
     ) -> crate::item_tree::VisitChildrenResult {
         // We can't keep self.inner borrowed because the event might modify the model
         let count = self.0.inner.borrow().instances.len() as u32;
+        let r = visit_leaving_instances(&self.0.leaving, order, visitor.borrow_mut(), count);
+        if r.has_aborted() {
+            return r;
+        }
         for i in 0..count {
             let i = if order == TraversalOrder::BackToFront { i } else { count - i - 1 };
             let c = self.0.inner.borrow().instances.get(i as usize).and_then(|c| c.1.clone());
@@ -1364,6 +1543,8 @@ pub struct Conditional<C: RepeatedItemTree> {
     #[pin]
     model: Property<bool>,
     instance: RefCell<Option<ItemTreeRc<C>>>,
+    /// The instances that were removed, kept alive until their exit transition ends
+    leaving: RefCell<Vec<(Instant, ItemTreeRc<C>)>>,
 }
 
 impl<C: RepeatedItemTree> Default for Conditional<C> {
@@ -1371,6 +1552,7 @@ fn default() -> Self {
         Self {
             model: Property::new_named(false, "i_slint_core::Conditional::model"),
             instance: RefCell::new(None),
+            leaving: Default::default(),
         }
     }
 }
@@ -1382,12 +1564,18 @@ pub fn ensure_updated(self: Pin<&Self>, init: impl Fn() -> ItemTreeRc<C>) {
         let model = self.project_ref().model.get();
 
         if !model {
-            drop(self.instance.replace(None));
+            if let Some(instance) = self.instance.replace(None) {
+                if let Some(end) = start_exit_transition(&instance) {
+                    self.leaving.borrow_mut().push((end, instance));
+                }
+            }
         } else if self.instance.borrow().is_none() {
             let i = init();
             self.instance.replace(Some(i.clone()));
             i.init();
         }
+        let finished = take_finished_exit_transitions(&mut self.leaving.borrow_mut());
+        drop(finished);
     }
 
     /// Set the model binding
@@ -1401,6 +1589,10 @@ pub fn visit(
         order: TraversalOrder,
         mut visitor: crate::item_tree::ItemVisitorRefMut,
     ) -> crate::item_tree::VisitChildrenResult {
+        let r = visit_leaving_instances(&self.leaving, order, visitor.borrow_mut(), 1);
+        if r.has_aborted() {
+            return r;
+        }
         // We can't keep self.inner borrowed because the event might modify the model
         let instance = self.instance.borrow().clone();
         if let Some(c) = instance {
@@ -1456,6 +1648,17 @@ fn from(value: &str) -> Self {
     }
 }
 
+impl<T: Into<SharedString>> From<FlattenedTreeRow<T>> for TreeViewItem {
+    fn from(row: FlattenedTreeRow<T>) -> Self {
+        TreeViewItem {
+            text: row.data.into(),
+            depth: row.depth as i32,
+            has_children: row.has_children,
+            expanded: row.expanded,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
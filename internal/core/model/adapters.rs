@@ -1425,3 +1425,409 @@ fn remove_range(&self, range: core::ops::Range<usize>) {
     origin_model.insert(45, 3007);
     check_all();
 }
+
+/// A row of a [`FlattenedTreeModel`]: the data of a row of the [`TreeModel`] with its
+/// position in the tree.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FlattenedTreeRow<T> {
+    /// The data of the row in the tree model
+    pub data: T,
+    /// The number of ancestors of the row. It's 0 for the top-level rows.
+    pub depth: usize,
+    /// Whether the row has children
+    pub has_children: bool,
+    /// Whether the children of the row are shown
+    pub expanded: bool,
+}
+
+struct FlattenedTreeModelInner<M>
+where
+    M: TreeModel + 'static,
+{
+    wrapped_model: M,
+    // The index path and the expanded state of the visible rows, in display order
+    rows: RefCell<Vec<(Vec<usize>, bool)>>,
+    notify: ModelNotify,
+}
+
+impl<M> FlattenedTreeModelInner<M>
+where
+    M: TreeModel + 'static,
+{
+    fn build_rows(&self) {
+        *self.rows.borrow_mut() =
+            (0..self.wrapped_model.child_count(&[])).map(|i| (alloc::vec![i], false)).collect();
+    }
+
+    /// Returns the end of the range of rows that are descendants of `row`
+    fn subtree_end(rows: &[(Vec<usize>, bool)], row: usize) -> usize {
+        let depth = rows[row].0.len();
+        rows[row + 1..].iter().position(|r| r.0.len() <= depth).map_or(rows.len(), |p| row + 1 + p)
+    }
+
+    /// Returns the row of `parent`, and the range of rows of its descendants if they are shown
+    fn children_rows(
+        rows: &[(Vec<usize>, bool)],
+        parent: &[usize],
+    ) -> (Option<usize>, Option<core::ops::Range<usize>>) {
+        if parent.is_empty() {
+            return (None, Some(0..rows.len()));
+        }
+        match rows.iter().position(|r| r.0 == parent) {
+            Some(row) if rows[row].1 => (Some(row), Some(row + 1..Self::subtree_end(rows, row))),
+            row => (row, None),
+        }
+    }
+}
+
+impl<M> TreeModelChangeListener for FlattenedTreeModelInner<M>
+where
+    M: TreeModel + 'static,
+{
+    fn row_changed(&self, index: &[usize]) {
+        let row = self.rows.borrow().iter().position(|r| r.0 == index);
+        if let Some(row) = row {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn rows_added(&self, parent: &[usize], index: usize, count: usize) {
+        if count == 0 {
+            return;
+        }
+        let mut rows = self.rows.borrow_mut();
+        let (parent_row, range) = Self::children_rows(&rows, parent);
+        let Some(range) = range else {
+            // The parent is collapsed, but it may have gained children
+            drop(rows);
+            if let Some(parent_row) = parent_row {
+                self.notify.row_changed(parent_row);
+            }
+            return;
+        };
+
+        let depth = parent.len();
+        let mut insertion_point = range.end;
+        for row in range.clone() {
+            let path = &mut rows[row].0;
+            if path[depth] >= index {
+                insertion_point = insertion_point.min(row);
+                path[depth] += count;
+            }
+        }
+        rows.splice(
+            insertion_point..insertion_point,
+            (index..index + count).map(|i| {
+                let mut path = parent.to_vec();
+                path.push(i);
+                (path, false)
+            }),
+        );
+        drop(rows);
+        self.notify.row_added(insertion_point, count);
+    }
+
+    fn rows_removed(&self, parent: &[usize], index: usize, count: usize) {
+        if count == 0 {
+            return;
+        }
+        let mut rows = self.rows.borrow_mut();
+        let (parent_row, range) = Self::children_rows(&rows, parent);
+        let Some(range) = range else {
+            // The parent is collapsed, but it may have lost its children
+            drop(rows);
+            if let Some(parent_row) = parent_row {
+                self.notify.row_changed(parent_row);
+            }
+            return;
+        };
+
+        let depth = parent.len();
+        let start = range.clone().find(|&row| rows[row].0[depth] >= index).unwrap_or(range.end);
+        let end =
+            range.clone().find(|&row| rows[row].0[depth] >= index + count).unwrap_or(range.end);
+        for row in end..range.end {
+            rows[row].0[depth] -= count;
+        }
+        rows.drain(start..end);
+
+        // A row without children can't stay expanded
+        let collapsed_parent = parent_row.filter(|_| self.wrapped_model.child_count(parent) == 0);
+        if let Some(parent_row) = collapsed_parent {
+            rows[parent_row].1 = false;
+        }
+        drop(rows);
+
+        if end > start {
+            self.notify.row_removed(start, end - start);
+        }
+        if let Some(parent_row) = collapsed_parent {
+            self.notify.row_changed(parent_row);
+        }
+    }
+
+    fn reset(&self) {
+        self.build_rows();
+        self.notify.reset();
+    }
+}
+
+/// Provides the visible rows of a [`TreeModel`] as a flat [`Model`], for example to show them in a `TreeView`.
+///
+/// Only the top-level rows of the tree and the children of expanded rows are visible.
+/// Use [`Self::set_expanded()`] to expand or collapse a row. The rows are initially collapsed.
+///
+/// When the tree model or the expanded state of a row is updated, the rows are added to or removed from
+/// the `FlattenedTreeModel` accordingly.
+///
+/// ## Example
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use slint::{FlattenedTreeModel, Model, SharedString, TreeNode, VecTreeModel};
+/// let tree = VecTreeModel::from(vec![
+///     TreeNode::with_children(SharedString::from("src"), [
+///         TreeNode::new(SharedString::from("main.rs")),
+///     ]),
+///     TreeNode::new(SharedString::from("Cargo.toml")),
+/// ]);
+///
+/// let flattened_model = FlattenedTreeModel::new(tree);
+/// assert_eq!(flattened_model.row_count(), 2);
+///
+/// flattened_model.set_expanded(0, true);
+/// let row = flattened_model.row_data(1).unwrap();
+/// assert_eq!(row.data, SharedString::from("main.rs"));
+/// assert_eq!(row.depth, 1);
+/// assert_eq!(flattened_model.row_data(2).unwrap().data, SharedString::from("Cargo.toml"));
+/// ```
+///
+/// The rows can be converted to [`TreeViewItem`]s to be used as the model of a `TreeView`:
+/// ```
+/// # use std::rc::Rc;
+/// # use slint::{FlattenedTreeModel, Model, ModelExt, SharedString, TreeNode, TreeViewItem, VecTreeModel};
+/// let flattened_model = Rc::new(FlattenedTreeModel::new(VecTreeModel::from(vec![
+///     TreeNode::new(SharedString::from("Cargo.toml")),
+/// ])));
+/// let items = flattened_model.clone().map(TreeViewItem::from);
+/// assert_eq!(items.row_data(0).unwrap().text, SharedString::from("Cargo.toml"));
+/// ```
+pub struct FlattenedTreeModel<M>(Rc<FlattenedTreeModelInner<M>>)
+where
+    M: TreeModel + 'static;
+
+impl<M> FlattenedTreeModel<M>
+where
+    M: TreeModel + 'static,
+{
+    /// Creates a new FlattenedTreeModel based on the given `wrapped_model`, with all the rows collapsed.
+    pub fn new(wrapped_model: M) -> Self {
+        let inner = Rc::new(FlattenedTreeModelInner {
+            wrapped_model,
+            rows: RefCell::new(Vec::new()),
+            notify: Default::default(),
+        });
+        inner.build_rows();
+        let listener: Rc<dyn TreeModelChangeListener> = inner.clone();
+        inner.wrapped_model.tree_tracker().attach_listener(Rc::downgrade(&listener));
+        Self(inner)
+    }
+
+    /// Expands or collapses the row. Expanding a row without children has no effect.
+    pub fn set_expanded(&self, row: usize, expanded: bool) {
+        let mut rows = self.0.rows.borrow_mut();
+        let Some((path, is_expanded)) = rows.get(row) else { return };
+        if *is_expanded == expanded {
+            return;
+        }
+
+        if expanded {
+            let path = path.clone();
+            let count = self.0.wrapped_model.child_count(&path);
+            if count == 0 {
+                return;
+            }
+            rows[row].1 = true;
+            rows.splice(
+                row + 1..row + 1,
+                (0..count).map(|i| {
+                    let mut child = path.clone();
+                    child.push(i);
+                    (child, false)
+                }),
+            );
+            drop(rows);
+            self.0.notify.row_changed(row);
+            self.0.notify.row_added(row + 1, count);
+        } else {
+            rows[row].1 = false;
+            let end = FlattenedTreeModelInner::<M>::subtree_end(&rows, row);
+            rows.drain(row + 1..end);
+            drop(rows);
+            self.0.notify.row_changed(row);
+            if end > row + 1 {
+                self.0.notify.row_removed(row + 1, end - row - 1);
+            }
+        }
+    }
+
+    /// Expands the row if it is collapsed, collapses it otherwise.
+    pub fn toggle_expanded(&self, row: usize) {
+        let expanded = self.0.rows.borrow().get(row).is_some_and(|r| r.1);
+        self.set_expanded(row, !expanded);
+    }
+
+    /// Returns the index path in the tree model of the row, or None if `row` is out of bounds.
+    pub fn tree_index(&self, row: usize) -> Option<Vec<usize>> {
+        self.0.rows.borrow().get(row).map(|r| r.0.clone())
+    }
+
+    /// Returns a reference to the inner model
+    pub fn source_model(&self) -> &M {
+        &self.0.wrapped_model
+    }
+}
+
+impl<M> Model for FlattenedTreeModel<M>
+where
+    M: TreeModel + 'static,
+{
+    type Data = FlattenedTreeRow<M::Data>;
+
+    fn row_count(&self) -> usize {
+        self.0.rows.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let (path, expanded) = self.0.rows.borrow().get(row)?.clone();
+        Some(FlattenedTreeRow {
+            data: self.0.wrapped_model.data(&path)?,
+            depth: path.len() - 1,
+            has_children: self.0.wrapped_model.child_count(&path) > 0,
+            expanded,
+        })
+    }
+
+    /// Sets the data of the row in the tree model, and expands or collapses it.
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        let path = self.0.rows.borrow()[row].0.clone();
+        self.0.wrapped_model.set_data(&path, data.data);
+        self.set_expanded(row, data.expanded);
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod flattened_tree_tests {
+    use super::*;
+
+    fn tree() -> Rc<VecTreeModel<i32>> {
+        Rc::new(VecTreeModel::from(std::vec![
+            TreeNode::with_children(1, [TreeNode::new(11), TreeNode::new(12)]),
+            TreeNode::new(2),
+            TreeNode::with_children(3, [TreeNode::with_children(31, [TreeNode::new(311)])]),
+        ]))
+    }
+
+    fn check_content(model: &FlattenedTreeModel<Rc<VecTreeModel<i32>>>, expected: &[(i32, usize)]) {
+        assert_eq!(model.row_count(), expected.len());
+        for (i, (data, depth)) in expected.iter().enumerate() {
+            let row = model.row_data(i).unwrap();
+            assert_eq!((row.data, row.depth), (*data, *depth), "Unexpected row {i}");
+        }
+    }
+
+    #[test]
+    fn test_flattened_tree_model_expand() {
+        let model = Rc::new(FlattenedTreeModel::new(tree()));
+        let _checker = ModelChecker::new(model.clone());
+        check_content(&model, &[(1, 0), (2, 0), (3, 0)]);
+        assert!(model.row_data(0).unwrap().has_children);
+        assert!(!model.row_data(1).unwrap().has_children);
+
+        model.set_expanded(2, true);
+        model.set_expanded(3, true);
+        check_content(&model, &[(1, 0), (2, 0), (3, 0), (31, 1), (311, 2)]);
+        assert_eq!(model.tree_index(4), Some(std::vec![2, 0, 0]));
+
+        model.toggle_expanded(0);
+        check_content(&model, &[(1, 0), (11, 1), (12, 1), (2, 0), (3, 0), (31, 1), (311, 2)]);
+        assert!(model.row_data(0).unwrap().expanded);
+
+        // Leaves can't be expanded
+        model.set_expanded(1, true);
+        assert!(!model.row_data(1).unwrap().expanded);
+
+        model.set_expanded(4, false);
+        check_content(&model, &[(1, 0), (11, 1), (12, 1), (2, 0), (3, 0)]);
+
+        // The children are collapsed again when their parent is expanded again
+        model.set_expanded(4, true);
+        check_content(&model, &[(1, 0), (11, 1), (12, 1), (2, 0), (3, 0), (31, 1)]);
+    }
+
+    #[test]
+    fn test_flattened_tree_model_changes() {
+        let tree = tree();
+        let model = Rc::new(FlattenedTreeModel::new(tree.clone()));
+        let _checker = ModelChecker::new(model.clone());
+        model.set_expanded(0, true);
+        model.set_expanded(4, true);
+        check_content(&model, &[(1, 0), (11, 1), (12, 1), (2, 0), (3, 0), (31, 1)]);
+
+        tree.insert(&[0], 1, TreeNode::new(15));
+        check_content(&model, &[(1, 0), (11, 1), (15, 1), (12, 1), (2, 0), (3, 0), (31, 1)]);
+        assert_eq!(model.tree_index(3), Some(std::vec![0, 2]));
+
+        tree.insert(&[], 1, TreeNode::with_children(4, [TreeNode::new(41)]));
+        check_content(
+            &model,
+            &[(1, 0), (11, 1), (15, 1), (12, 1), (4, 0), (2, 0), (3, 0), (31, 1)],
+        );
+        assert_eq!(model.tree_index(7), Some(std::vec![3, 0]));
+
+        // Changes in collapsed rows only update the parent
+        tree.push(&[2], TreeNode::new(21));
+        assert!(model.row_data(5).unwrap().has_children);
+        tree.remove(&[3, 0, 0]);
+        check_content(
+            &model,
+            &[(1, 0), (11, 1), (15, 1), (12, 1), (4, 0), (2, 0), (3, 0), (31, 1)],
+        );
+        assert!(!model.row_data(7).unwrap().has_children);
+
+        tree.remove(&[0]);
+        check_content(&model, &[(4, 0), (2, 0), (3, 0), (31, 1)]);
+        assert_eq!(model.tree_index(3), Some(std::vec![2, 0]));
+
+        tree.set_data(&[2, 0], 32);
+        check_content(&model, &[(4, 0), (2, 0), (3, 0), (32, 1)]);
+
+        // Removing the last child collapses the parent
+        tree.remove(&[2, 0]);
+        check_content(&model, &[(4, 0), (2, 0), (3, 0)]);
+        assert!(!model.row_data(2).unwrap().expanded);
+
+        tree.set_roots(std::vec![TreeNode::new(5)]);
+        check_content(&model, &[(5, 0)]);
+    }
+
+    #[test]
+    fn test_flattened_tree_model_set_row_data() {
+        let tree = tree();
+        let model = FlattenedTreeModel::new(tree.clone());
+        let mut row = model.row_data(2).unwrap();
+        row.data = 30;
+        row.expanded = true;
+        model.set_row_data(2, row);
+        check_content(&model, &[(1, 0), (2, 0), (30, 0), (31, 1)]);
+        assert_eq!(tree.data(&[2]), Some(30));
+    }
+}
@@ -0,0 +1,310 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the hierarchical model trait and its change tracking.
+
+use super::*;
+use alloc::rc::Weak;
+
+/// A TreeModel is a hierarchical set of rows. Each row can have children rows.
+///
+/// A row is identified by its index path: the index of the row within its parent,
+/// preceded by the index path of its parent. An empty index path designates the
+/// invisible root of the tree, so `child_count(&[])` is the number of top-level rows.
+///
+/// A TreeModel can't be used directly as the model of a `for` repeater. Use a
+/// [`FlattenedTreeModel`] to present the expanded rows of the tree as a [`Model`].
+///
+/// ## Example
+///
+/// ```
+/// # use i_slint_core::model::{TreeModel, TreeModelNotify, TreeModelTracker};
+/// // A tree of two levels: groups of names
+/// struct Groups {
+///     groups: Vec<(String, Vec<String>)>,
+///     notify: TreeModelNotify,
+/// }
+///
+/// impl TreeModel for Groups {
+///     type Data = String;
+///
+///     fn child_count(&self, parent: &[usize]) -> usize {
+///         match parent {
+///             [] => self.groups.len(),
+///             [group] => self.groups.get(*group).map_or(0, |g| g.1.len()),
+///             _ => 0,
+///         }
+///     }
+///
+///     fn data(&self, index: &[usize]) -> Option<Self::Data> {
+///         match index {
+///             [group] => self.groups.get(*group).map(|g| g.0.clone()),
+///             [group, name] => self.groups.get(*group)?.1.get(*name).cloned(),
+///             _ => None,
+///         }
+///     }
+///
+///     fn tree_tracker(&self) -> &dyn TreeModelTracker {
+///         &self.notify
+///     }
+/// }
+/// ```
+pub trait TreeModel {
+    /// The data of each row
+    type Data;
+
+    /// The number of children of the row at the `parent` index path.
+    ///
+    /// An empty `parent` designates the root of the tree.
+    fn child_count(&self, parent: &[usize]) -> usize;
+
+    /// Returns the data of the row at the `index` path, or None if there is no such row.
+    fn data(&self, index: &[usize]) -> Option<Self::Data>;
+
+    /// Sets the data of the row at the `index` path.
+    ///
+    /// If the model cannot support data changes, then it is ok to do nothing.
+    /// The default implementation will print a warning to stderr.
+    ///
+    /// If the model can update the data, it should also call [`TreeModelNotify::row_changed`]
+    /// on its internal [`TreeModelNotify`].
+    fn set_data(&self, _index: &[usize], _data: Self::Data) {
+        #[cfg(feature = "std")]
+        crate::debug_log!(
+            "TreeModel::set_data called on a model of type {} which does not re-implement this method. \
+            This happens when trying to modify a read-only model",
+            core::any::type_name::<Self>(),
+        );
+    }
+
+    /// The implementation should return a reference to its [`TreeModelNotify`] field.
+    ///
+    /// You can return `&()` if your `TreeModel` is constant and does not have a TreeModelNotify field.
+    fn tree_tracker(&self) -> &dyn TreeModelTracker;
+
+    /// Return something that can be downcast'ed (typically self).
+    ///
+    /// See [`Model::as_any`].
+    fn as_any(&self) -> &dyn core::any::Any {
+        &()
+    }
+}
+
+impl<M: TreeModel> TreeModel for Rc<M> {
+    type Data = M::Data;
+
+    fn child_count(&self, parent: &[usize]) -> usize {
+        (**self).child_count(parent)
+    }
+
+    fn data(&self, index: &[usize]) -> Option<Self::Data> {
+        (**self).data(index)
+    }
+
+    fn set_data(&self, index: &[usize], data: Self::Data) {
+        (**self).set_data(index, data)
+    }
+
+    fn tree_tracker(&self) -> &dyn TreeModelTracker {
+        (**self).tree_tracker()
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        (**self).as_any()
+    }
+}
+
+/// The listener side of the change notifications of a [`TreeModel`].
+///
+/// The index paths are relative to the tree after the change.
+pub trait TreeModelChangeListener {
+    /// The data of the row at `index` has changed
+    fn row_changed(&self, index: &[usize]);
+    /// `count` rows were inserted at `index` in the children of `parent`
+    fn rows_added(&self, parent: &[usize], index: usize, count: usize);
+    /// `count` rows, and all their descendants, were removed at `index` from the children of `parent`
+    fn rows_removed(&self, parent: &[usize], index: usize, count: usize);
+    /// The tree has been changed in some way and everything needs to be reloaded
+    fn reset(&self);
+}
+
+/// This trait defines the interface that users of a tree model can use to track changes.
+pub trait TreeModelTracker {
+    /// Attach a listener. The listener will be notified when the tree changes,
+    /// for as long as it is alive.
+    fn attach_listener(&self, listener: Weak<dyn TreeModelChangeListener>);
+}
+
+impl TreeModelTracker for () {
+    fn attach_listener(&self, _listener: Weak<dyn TreeModelChangeListener>) {}
+}
+
+/// Dispatch notifications from a [`TreeModel`] to its listeners.
+/// Typically, you would want to put this in the implementation of the TreeModel
+#[derive(Default)]
+pub struct TreeModelNotify {
+    listeners: RefCell<Vec<Weak<dyn TreeModelChangeListener>>>,
+}
+
+impl TreeModelNotify {
+    fn for_each_listener(&self, f: impl Fn(&dyn TreeModelChangeListener)) {
+        // Collect the listeners first, so that they can attach further listeners while notified
+        let listeners = {
+            let mut listeners = self.listeners.borrow_mut();
+            listeners.retain(|l| l.strong_count() > 0);
+            listeners.iter().filter_map(|l| l.upgrade()).collect::<Vec<_>>()
+        };
+        for listener in listeners {
+            f(&*listener)
+        }
+    }
+
+    /// Notify the listeners that the data of the row at `index` has changed
+    pub fn row_changed(&self, index: &[usize]) {
+        self.for_each_listener(|l| l.row_changed(index))
+    }
+
+    /// Notify the listeners that `count` rows were inserted at `index` in the children of `parent`
+    pub fn rows_added(&self, parent: &[usize], index: usize, count: usize) {
+        self.for_each_listener(|l| l.rows_added(parent, index, count))
+    }
+
+    /// Notify the listeners that `count` rows were removed at `index` from the children of `parent`
+    pub fn rows_removed(&self, parent: &[usize], index: usize, count: usize) {
+        self.for_each_listener(|l| l.rows_removed(parent, index, count))
+    }
+
+    /// Notify the listeners that the tree has been changed in some way and
+    /// everything needs to be reloaded
+    pub fn reset(&self) {
+        self.for_each_listener(|l| l.reset())
+    }
+}
+
+impl TreeModelTracker for TreeModelNotify {
+    fn attach_listener(&self, listener: Weak<dyn TreeModelChangeListener>) {
+        self.listeners.borrow_mut().push(listener);
+    }
+}
+
+/// A row of a [`VecTreeModel`], with its children.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TreeNode<T> {
+    /// The data of the row
+    pub data: T,
+    /// The children rows
+    pub children: Vec<TreeNode<T>>,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates a node without children
+    pub fn new(data: T) -> Self {
+        Self { data, children: Vec::new() }
+    }
+
+    /// Creates a node with the given children
+    pub fn with_children(data: T, children: impl IntoIterator<Item = TreeNode<T>>) -> Self {
+        Self { data, children: children.into_iter().collect() }
+    }
+}
+
+/// A [`TreeModel`] backed by a `Vec<TreeNode<T>>`, the top-level rows.
+#[derive(Default)]
+pub struct VecTreeModel<T> {
+    roots: RefCell<Vec<TreeNode<T>>>,
+    notify: TreeModelNotify,
+}
+
+impl<T: 'static> VecTreeModel<T> {
+    fn with_children_of<R>(
+        &self,
+        parent: &[usize],
+        f: impl FnOnce(&mut Vec<TreeNode<T>>) -> R,
+    ) -> Option<R> {
+        let mut roots = self.roots.borrow_mut();
+        let mut children = &mut *roots;
+        for &i in parent {
+            children = &mut children.get_mut(i)?.children;
+        }
+        Some(f(children))
+    }
+
+    /// Inserts `node` at `index` in the children of `parent`.
+    ///
+    /// Panics if `parent` doesn't exist or if `index` is greater than its number of children.
+    pub fn insert(&self, parent: &[usize], index: usize, node: TreeNode<T>) {
+        self.with_children_of(parent, |children| children.insert(index, node))
+            .expect("parent row out of bounds");
+        self.notify.rows_added(parent, index, 1);
+    }
+
+    /// Appends `node` to the children of `parent`.
+    ///
+    /// Panics if `parent` doesn't exist.
+    pub fn push(&self, parent: &[usize], node: TreeNode<T>) {
+        let index = self
+            .with_children_of(parent, |children| {
+                children.push(node);
+                children.len() - 1
+            })
+            .expect("parent row out of bounds");
+        self.notify.rows_added(parent, index, 1);
+    }
+
+    /// Removes the row at `index`, with all its descendants, and returns it.
+    ///
+    /// Panics if `index` is empty or doesn't exist.
+    pub fn remove(&self, index: &[usize]) -> TreeNode<T> {
+        let (&row, parent) = index.split_last().expect("cannot remove the root of the tree");
+        let node = self
+            .with_children_of(parent, |children| children.remove(row))
+            .expect("parent row out of bounds");
+        self.notify.rows_removed(parent, row, 1);
+        node
+    }
+
+    /// Replaces all the rows of the tree.
+    pub fn set_roots(&self, roots: Vec<TreeNode<T>>) {
+        *self.roots.borrow_mut() = roots;
+        self.notify.reset();
+    }
+}
+
+impl<T> From<Vec<TreeNode<T>>> for VecTreeModel<T> {
+    fn from(roots: Vec<TreeNode<T>>) -> Self {
+        Self { roots: RefCell::new(roots), notify: Default::default() }
+    }
+}
+
+impl<T: Clone + 'static> TreeModel for VecTreeModel<T> {
+    type Data = T;
+
+    fn child_count(&self, parent: &[usize]) -> usize {
+        self.with_children_of(parent, |children| children.len()).unwrap_or(0)
+    }
+
+    fn data(&self, index: &[usize]) -> Option<Self::Data> {
+        let (&row, parent) = index.split_last()?;
+        self.with_children_of(parent, |children| children.get(row).map(|n| n.data.clone()))
+            .flatten()
+    }
+
+    fn set_data(&self, index: &[usize], data: Self::Data) {
+        let Some((&row, parent)) = index.split_last() else { return };
+        if self
+            .with_children_of(parent, |children| children.get_mut(row).map(|n| n.data = data))
+            .flatten()
+            .is_some()
+        {
+            self.notify.row_changed(index);
+        }
+    }
+
+    fn tree_tracker(&self) -> &dyn TreeModelTracker {
+        &self.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
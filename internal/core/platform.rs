@@ -282,6 +282,21 @@ pub fn duration_until_next_timer_update() -> Option<core::time::Duration> {
 pub use crate::input::key_codes::Key;
 pub use crate::input::PointerEventButton;
 
+/// The phase of a gesture, as reported by [`WindowEvent::PinchGesture`] and
+/// [`WindowEvent::RotationGesture`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum GesturePhase {
+    /// The gesture started.
+    Started,
+    /// The gesture continues.
+    Updated,
+    /// The gesture ended.
+    Ended,
+    /// The gesture was cancelled by the system.
+    Cancelled,
+}
+
 /// A event that describes user input or windowing system events.
 ///
 /// Slint backends typically receive events from the windowing system, translate them to this
@@ -320,6 +335,79 @@ pub enum WindowEvent {
     },
     /// The pointer exited the window.
     PointerExited,
+    /// A finger or stylus started touching the surface.
+    ///
+    /// The first touch point that starts while no other one is active is delivered to the items
+    /// like a press of the left mouse button. Further touch points are tracked but don't produce
+    /// pointer events.
+    TouchStarted {
+        /// An identifier for this touch point, unique among the currently active touch points.
+        /// The same id must be used for the subsequent `TouchMoved`, `TouchEnded` and
+        /// `TouchCancelled` events of this touch point.
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// An active touch point moved.
+    TouchMoved {
+        /// The identifier of the touch point, as passed in [`WindowEvent::TouchStarted`].
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// A touch point was lifted from the surface.
+    TouchEnded {
+        /// The identifier of the touch point, as passed in [`WindowEvent::TouchStarted`].
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// The system cancelled the tracking of a touch point, for example because a gesture was
+    /// recognized by the windowing system. Unlike [`WindowEvent::TouchEnded`], this doesn't
+    /// result in a click.
+    TouchCancelled {
+        /// The identifier of the touch point, as passed in [`WindowEvent::TouchStarted`].
+        id: u64,
+        position: LogicalPosition,
+    },
+    /// A pinch gesture was reported by the system, typically from a trackpad.
+    ///
+    /// Backends that receive touch points should dispatch [`WindowEvent::TouchStarted`] and friends
+    /// instead: Slint recognizes pinch gestures from these by itself.
+    PinchGesture {
+        position: LogicalPosition,
+        /// The change of scale since the previous event: the scale is multiplied by `1 + delta`.
+        delta: f32,
+        /// Whether this event starts, continues, or ends the gesture.
+        phase: GesturePhase,
+    },
+    /// A rotation gesture was reported by the system, typically from a trackpad.
+    RotationGesture {
+        position: LogicalPosition,
+        /// The rotation since the previous event, in degrees. Positive values are clockwise.
+        delta: f32,
+        /// Whether this event starts, continues, or ends the gesture.
+        phase: GesturePhase,
+    },
+    /// Data from another application is dragged over the window.
+    ///
+    /// The backend sends this event when the drag enters the window, and again when the pointer
+    /// moves if the windowing system reports it. Files are sent as a `text/uri-list`, with one URL
+    /// per line.
+    DragMoved {
+        position: LogicalPosition,
+        /// The mime type of the data, for example `text/plain` or `text/uri-list`.
+        mime_type: SharedString,
+        /// The data being dragged.
+        data: SharedString,
+    },
+    /// Data dragged from another application left the window, or the drag was cancelled.
+    DragExited,
+    /// Data from another application was dropped on the window.
+    Dropped {
+        position: LogicalPosition,
+        /// The mime type of the data, for example `text/plain` or `text/uri-list`.
+        mime_type: SharedString,
+        /// The dropped data.
+        data: SharedString,
+    },
     /// A key was pressed.
     KeyPressed {
         /// The unicode representation of the key pressed.
@@ -393,6 +481,14 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::TouchStarted { position, .. } => Some(*position),
+            WindowEvent::TouchMoved { position, .. } => Some(*position),
+            WindowEvent::TouchEnded { position, .. } => Some(*position),
+            WindowEvent::TouchCancelled { position, .. } => Some(*position),
+            WindowEvent::PinchGesture { position, .. } => Some(*position),
+            WindowEvent::RotationGesture { position, .. } => Some(*position),
+            WindowEvent::DragMoved { position, .. } => Some(*position),
+            WindowEvent::Dropped { position, .. } => Some(*position),
             _ => None,
         }
     }
@@ -4,6 +4,7 @@
 use super::*;
 use crate::graphics::{Brush, Color};
 use crate::items::PropertyAnimation;
+use crate::SharedVector;
 
 #[allow(non_camel_case_types)]
 type c_void = ();
@@ -170,25 +171,11 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: T,
     to: T,
     animation_data: &PropertyAnimation,
+    keyframes: &SharedVector<Keyframe<T>>,
 ) {
-    let d = RefCell::new(properties_animations::PropertyValueAnimationData::new(
-        from,
-        to,
-        animation_data.clone(),
-    ));
-    // Safety: The BindingCallable is for type T
+    // Safety: The binding is for type T
     unsafe {
-        handle.0.set_binding(move |val: *mut ()| {
-            let (value, finished) = d.borrow_mut().compute_interpolated_value();
-            *(val as *mut T) = value;
-            if finished {
-                BindingResult::RemoveBinding
-            } else {
-                crate::animations::CURRENT_ANIMATION_DRIVER
-                    .with(|driver| driver.set_has_active_animations());
-                BindingResult::KeepBinding
-            }
-        })
+        handle.0.set_animated_value_binding(from, to, animation_data.clone(), keyframes.clone())
     };
     handle.0.mark_dirty();
 }
@@ -200,8 +187,9 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: i32,
     to: i32,
     animation_data: &PropertyAnimation,
+    keyframes: &SharedVector<Keyframe<i32>>,
 ) {
-    c_set_animated_value(handle, from, to, animation_data)
+    c_set_animated_value(handle, from, to, animation_data, keyframes)
 }
 
 /// Internal function to set up a property animation to the specified target value for a float property.
@@ -211,8 +199,9 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: f32,
     to: f32,
     animation_data: &PropertyAnimation,
+    keyframes: &SharedVector<Keyframe<f32>>,
 ) {
-    c_set_animated_value(handle, from, to, animation_data)
+    c_set_animated_value(handle, from, to, animation_data, keyframes)
 }
 
 /// Internal function to set up a property animation to the specified target value for a color property.
@@ -222,8 +211,9 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: Color,
     to: Color,
     animation_data: &PropertyAnimation,
+    keyframes: &SharedVector<Keyframe<Color>>,
 ) {
-    c_set_animated_value(handle, from, to, animation_data);
+    c_set_animated_value(handle, from, to, animation_data, keyframes);
 }
 
 /// Internal function to set up a property animation to the specified target value for a brush property.
@@ -233,8 +223,9 @@ fn c_set_animated_value<T: InterpolatedPropertyValue + Clone>(
     from: &Brush,
     to: &Brush,
     animation_data: &PropertyAnimation,
+    keyframes: &SharedVector<Keyframe<Brush>>,
 ) {
-    c_set_animated_value(handle, from.clone(), to.clone(), animation_data);
+    c_set_animated_value(handle, from.clone(), to.clone(), animation_data, keyframes);
 }
 
 unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
@@ -243,8 +234,13 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframes: &SharedVector<Keyframe<T>>,
     transition_data: Option<
-        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+        extern "C" fn(
+            user_data: *mut c_void,
+            start_instant: &mut u64,
+            keyframes: &mut SharedVector<Keyframe<T>>,
+        ) -> PropertyAnimation,
     >,
 ) {
     let binding = core::mem::transmute::<
@@ -263,20 +259,23 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
                 | 0b10,
         ),
     };
-    let animation_data = RefCell::new(properties_animations::PropertyValueAnimationData::new(
+    let mut animation_data = properties_animations::PropertyValueAnimationData::new(
         T::default(),
         T::default(),
         animation_data.cloned().unwrap_or_default(),
-    ));
+    );
+    animation_data.set_keyframes(keyframes.clone());
+    let animation_data = RefCell::new(animation_data);
     if let Some(transition_data) = transition_data {
         handle.0.set_binding(properties_animations::AnimatedBindingCallable::<T, _> {
             original_binding,
             state: Cell::new(properties_animations::AnimatedBindingState::NotAnimating),
             animation_data,
-            compute_animation_details: move || -> properties_animations::AnimationDetail {
+            compute_animation_details: move || -> properties_animations::AnimationDetail<T> {
                 let mut start_instant = 0;
-                let anim = transition_data(user_data, &mut start_instant);
-                Some((anim, crate::animations::Instant(start_instant)))
+                let mut keyframes = SharedVector::default();
+                let anim = transition_data(user_data, &mut start_instant, &mut keyframes);
+                Some((anim, crate::animations::Instant(start_instant), keyframes))
             },
         });
     } else {
@@ -284,7 +283,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
             original_binding,
             state: Cell::new(properties_animations::AnimatedBindingState::NotAnimating),
             animation_data,
-            compute_animation_details: || -> properties_animations::AnimationDetail { None },
+            compute_animation_details: || -> properties_animations::AnimationDetail<T> { None },
         });
     }
     handle.0.mark_dirty();
@@ -298,8 +297,13 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframes: &SharedVector<Keyframe<i32>>,
     transition_data: Option<
-        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+        extern "C" fn(
+            user_data: *mut c_void,
+            start_instant: &mut u64,
+            keyframes: &mut SharedVector<Keyframe<i32>>,
+        ) -> PropertyAnimation,
     >,
 ) {
     c_set_animated_binding(
@@ -308,6 +312,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        keyframes,
         transition_data,
     );
 }
@@ -320,8 +325,13 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframes: &SharedVector<Keyframe<f32>>,
     transition_data: Option<
-        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+        extern "C" fn(
+            user_data: *mut c_void,
+            start_instant: &mut u64,
+            keyframes: &mut SharedVector<Keyframe<f32>>,
+        ) -> PropertyAnimation,
     >,
 ) {
     c_set_animated_binding(
@@ -330,6 +340,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        keyframes,
         transition_data,
     );
 }
@@ -342,8 +353,13 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframes: &SharedVector<Keyframe<Color>>,
     transition_data: Option<
-        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+        extern "C" fn(
+            user_data: *mut c_void,
+            start_instant: &mut u64,
+            keyframes: &mut SharedVector<Keyframe<Color>>,
+        ) -> PropertyAnimation,
     >,
 ) {
     c_set_animated_binding(
@@ -352,6 +368,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        keyframes,
         transition_data,
     );
 }
@@ -364,8 +381,13 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
     user_data: *mut c_void,
     drop_user_data: Option<extern "C" fn(*mut c_void)>,
     animation_data: Option<&PropertyAnimation>,
+    keyframes: &SharedVector<Keyframe<Brush>>,
     transition_data: Option<
-        extern "C" fn(user_data: *mut c_void, start_instant: &mut u64) -> PropertyAnimation,
+        extern "C" fn(
+            user_data: *mut c_void,
+            start_instant: &mut u64,
+            keyframes: &mut SharedVector<Keyframe<Brush>>,
+        ) -> PropertyAnimation,
     >,
 ) {
     c_set_animated_binding(
@@ -374,6 +396,7 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue + Clone>(
         user_data,
         drop_user_data,
         animation_data,
+        keyframes,
         transition_data,
     );
 }
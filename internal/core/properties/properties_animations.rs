@@ -3,12 +3,28 @@
 
 use super::*;
 use crate::{
+    animations::EasingCurve,
     items::{AnimationDirection, PropertyAnimation},
     lengths::LogicalLength,
+    SharedVector,
 };
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
 
+/// A stop of a keyframe animation: the animated property has `value` when the animation
+/// reaches `position`.
+#[repr(C)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Keyframe<T> {
+    /// The position of the stop in the animation, between 0 and 1
+    pub position: f32,
+    /// The value of the property at this stop
+    pub value: T,
+    /// The easing curve used to reach this stop from the previous one
+    pub easing: EasingCurve,
+}
+
+#[derive(Clone, Copy)]
 enum AnimationState {
     Delaying,
     Animating { current_iteration: u64 },
@@ -21,13 +37,93 @@ pub(super) struct PropertyValueAnimationData<T> {
     details: PropertyAnimation,
     start_time: crate::animations::Instant,
     state: AnimationState,
+    /// The velocity of a spring animation at start_time, in progress per second
+    spring_velocity: f32,
+    /// The velocity of the spring animation that was interrupted by reset(), see `current_spring_velocity`
+    retarget_velocity: f32,
+    /// The intermediate stops between from_value and to_value, sorted by position
+    keyframes: SharedVector<Keyframe<T>>,
+    /// The state and start time before reset(), to resume the animation if the target didn't change
+    interrupted: Option<(AnimationState, crate::animations::Instant)>,
 }
 
 impl<T: InterpolatedPropertyValue + Clone> PropertyValueAnimationData<T> {
     pub fn new(from_value: T, to_value: T, details: PropertyAnimation) -> Self {
         let start_time = crate::animations::current_tick();
 
-        Self { from_value, to_value, details, start_time, state: AnimationState::Delaying }
+        Self {
+            from_value,
+            to_value,
+            details,
+            start_time,
+            state: AnimationState::Delaying,
+            spring_velocity: 0.,
+            retarget_velocity: 0.,
+            keyframes: SharedVector::default(),
+            interrupted: None,
+        }
+    }
+
+    pub(super) fn set_keyframes(&mut self, mut keyframes: SharedVector<Keyframe<T>>) {
+        if keyframes.iter().any(|k| !(0. ..=1.).contains(&k.position))
+            || keyframes.windows(2).any(|w| w[0].position > w[1].position)
+        {
+            let keyframes = keyframes.make_mut_slice();
+            for k in keyframes.iter_mut() {
+                k.position = if k.position.is_nan() { 0. } else { k.position.clamp(0., 1.) };
+            }
+            keyframes.sort_by(|a, b| a.position.total_cmp(&b.position));
+        }
+        self.keyframes = keyframes;
+    }
+
+    /// Returns the value at `progress`, going through the keyframes
+    fn value_at_progress(&self, progress: f32) -> T {
+        let mut previous = (0., &self.from_value);
+        for keyframe in self.keyframes.iter() {
+            if progress <= keyframe.position {
+                return Self::interpolate_segment(previous, keyframe, progress);
+            }
+            previous = (keyframe.position, &keyframe.value);
+        }
+        // The target value is reached with the easing curve of the animation
+        let last =
+            Keyframe { position: 1., value: self.to_value.clone(), easing: self.details.easing };
+        Self::interpolate_segment(previous, &last, progress)
+    }
+
+    fn interpolate_segment(
+        (start_position, start_value): (f32, &T),
+        end: &Keyframe<T>,
+        progress: f32,
+    ) -> T {
+        let length = end.position - start_position;
+        let t = if length > 0. { ((progress - start_position) / length).clamp(0., 1.) } else { 1. };
+        start_value.interpolate(&end.value, crate::animations::easing_curve(&end.easing, t))
+    }
+
+    /// Returns the current velocity of a running spring animation, in units of the value per second
+    /// (or in progress per second if the value has no [`InterpolatedPropertyValue::delta`]),
+    /// or 0 if the animation isn't a running spring animation.
+    fn current_spring_velocity(&self) -> f32 {
+        let (AnimationState::Animating { .. }, EasingCurve::Spring(spring)) =
+            (&self.state, &self.details.easing)
+        else {
+            return 0.;
+        };
+        let time = crate::animations::current_tick().duration_since(self.start_time).as_secs_f32();
+        let velocity = crate::animations::spring_state(spring, -1., self.spring_velocity, time).1;
+        self.from_value.delta(&self.to_value).map_or(velocity, |delta| velocity * delta)
+    }
+
+    /// Sets the initial velocity of a spring animation, in the unit returned by
+    /// `current_spring_velocity`.
+    fn set_spring_velocity(&mut self, velocity: f32) {
+        self.spring_velocity = match self.from_value.delta(&self.to_value) {
+            Some(delta) if delta != 0. => velocity / delta,
+            Some(_) => 0.,
+            None => velocity,
+        };
     }
 
     pub fn compute_interpolated_value(&mut self) -> (T, bool) {
@@ -67,6 +163,23 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                 }
             }
             AnimationState::Animating { mut current_iteration } => {
+                if let EasingCurve::Spring(spring) = self.details.easing {
+                    // Springs ignore the duration, the iteration count and the direction
+                    let (displacement, velocity) = crate::animations::spring_state(
+                        &spring,
+                        -1.,
+                        self.spring_velocity,
+                        time_progress as f32 / 1000.,
+                    );
+                    if displacement.abs() < crate::animations::SPRING_REST_THRESHOLD
+                        && velocity.abs() < crate::animations::SPRING_REST_THRESHOLD
+                    {
+                        self.state = AnimationState::Done { iteration_count: 0 };
+                        return self.compute_interpolated_value();
+                    }
+                    return (self.from_value.interpolate(&self.to_value, 1. + displacement), false);
+                }
+
                 if self.details.duration <= 0 || self.details.iteration_count == 0. {
                     self.state = AnimationState::Done { iteration_count: 0 };
                     return self.compute_interpolated_value();
@@ -95,10 +208,7 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                             progress
                         }
                     };
-                    let t = crate::animations::easing_curve(&self.details.easing, progress);
-                    let val = self.from_value.interpolate(&self.to_value, t);
-
-                    (val, false)
+                    (self.value_at_progress(progress), false)
                 } else {
                     self.state =
                         AnimationState::Done { iteration_count: current_iteration.max(1) - 1 };
@@ -106,7 +216,9 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                 }
             }
             AnimationState::Done { iteration_count } => {
-                if reversed(iteration_count) {
+                if reversed(iteration_count)
+                    && !matches!(self.details.easing, EasingCurve::Spring(_))
+                {
                     (self.from_value.clone(), true)
                 } else {
                     (self.to_value.clone(), true)
@@ -116,16 +228,78 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
     }
 
     fn reset(&mut self) {
+        self.interrupted = Some((self.state, self.start_time));
+        self.retarget_velocity = self.current_spring_velocity();
         self.state = AnimationState::Delaying;
         self.start_time = crate::animations::current_tick();
     }
 }
 
+crate::thread_local!(
+/// The velocity, see `current_spring_velocity`, of the spring animation that was replaced by the last
+/// animation started with `Property::set_animated_value`
+static REPLACED_SPRING_VELOCITY: Cell<f32> = const { Cell::new(0.) }
+);
+
+/// The binding set by `Property::set_animated_value`
+struct AnimatedValueBinding<T> {
+    animation_data: Rc<RefCell<PropertyValueAnimationData<T>>>,
+}
+
+unsafe impl<T: InterpolatedPropertyValue + Clone> BindingCallable for AnimatedValueBinding<T> {
+    unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
+        let (val, finished) = self.animation_data.borrow_mut().compute_interpolated_value();
+        *(value as *mut T) = val;
+        if finished {
+            BindingResult::RemoveBinding
+        } else {
+            crate::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.set_has_active_animations());
+            BindingResult::KeepBinding
+        }
+    }
+
+    unsafe fn intercept_set_binding(self: Pin<&Self>, _new_binding: *mut BindingHolder) -> bool {
+        let velocity = self.animation_data.borrow().current_spring_velocity();
+        REPLACED_SPRING_VELOCITY.with(|v| v.set(velocity));
+        false
+    }
+}
+
+impl PropertyHandle {
+    /// Sets a binding that animates the value of the property from `from` to `to`.
+    ///
+    /// Safety: the property must be of type T
+    pub(super) unsafe fn set_animated_value_binding<T: InterpolatedPropertyValue + Clone>(
+        &self,
+        from: T,
+        to: T,
+        animation_data: PropertyAnimation,
+        keyframes: SharedVector<Keyframe<T>>,
+        #[cfg(slint_debug_property)] debug_name: &str,
+    ) {
+        let mut animation_data = PropertyValueAnimationData::new(from, to, animation_data);
+        animation_data.set_keyframes(keyframes);
+        let animation_data = Rc::new(RefCell::new(animation_data));
+        REPLACED_SPRING_VELOCITY.with(|v| v.set(0.));
+        self.set_binding(
+            AnimatedValueBinding { animation_data: animation_data.clone() },
+            #[cfg(slint_debug_property)]
+            debug_name,
+        );
+        // The animation that was replaced, if any, handed over its velocity while setting the binding
+        let velocity = REPLACED_SPRING_VELOCITY.with(|v| v.get());
+        animation_data.borrow_mut().set_spring_velocity(velocity);
+    }
+}
+
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub(super) enum AnimatedBindingState {
     Animating,
     NotAnimating,
     ShouldStart,
+    /// The binding got dirty while animating: the animation restarts only if the target changed
+    ShouldRetarget,
 }
 
 pub(super) struct AnimatedBindingCallable<T, A> {
@@ -135,9 +309,10 @@ pub(super) struct AnimatedBindingCallable<T, A> {
     pub(super) compute_animation_details: A,
 }
 
-pub(super) type AnimationDetail = Option<(PropertyAnimation, crate::animations::Instant)>;
+pub(super) type AnimationDetail<T> =
+    Option<(PropertyAnimation, crate::animations::Instant, SharedVector<Keyframe<T>>)>;
 
-unsafe impl<T: InterpolatedPropertyValue + Clone, A: Fn() -> AnimationDetail> BindingCallable
+unsafe impl<T: InterpolatedPropertyValue + Clone, A: Fn() -> AnimationDetail<T>> BindingCallable
     for AnimatedBindingCallable<T, A>
 {
     unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
@@ -160,16 +335,34 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
             AnimatedBindingState::NotAnimating => {
                 self.original_binding.update(value);
             }
-            AnimatedBindingState::ShouldStart => {
+            AnimatedBindingState::ShouldStart | AnimatedBindingState::ShouldRetarget => {
                 let value = &mut *(value as *mut T);
+                let retarget = self.state.get() == AnimatedBindingState::ShouldRetarget;
                 self.state.set(AnimatedBindingState::Animating);
                 let mut animation_data = self.animation_data.borrow_mut();
-                // animation_data.details.iteration_count = 1.;
-                animation_data.from_value = value.clone();
-                self.original_binding.update((&mut animation_data.to_value) as *mut T as *mut ());
-                if let Some((details, start_time)) = (self.compute_animation_details)() {
+                let mut target = T::default();
+                self.original_binding.update((&mut target) as *mut T as *mut ());
+                let interrupted = animation_data.interrupted.take();
+                if let Some((state, start_time)) =
+                    interrupted.filter(|_| retarget && target == animation_data.to_value)
+                {
+                    // Only a dependency changed, not the target: the running animation goes on
+                    animation_data.state = state;
                     animation_data.start_time = start_time;
-                    animation_data.details = details;
+                } else {
+                    animation_data.to_value = target;
+                    // animation_data.details.iteration_count = 1.;
+                    animation_data.from_value = value.clone();
+                    if let Some((details, start_time, keyframes)) =
+                        (self.compute_animation_details)()
+                    {
+                        animation_data.start_time = start_time;
+                        animation_data.details = details;
+                        animation_data.set_keyframes(keyframes);
+                    }
+                    // A spring keeps its velocity when its target changes
+                    let velocity = animation_data.retarget_velocity;
+                    animation_data.set_spring_velocity(velocity);
                 }
                 let (val, finished) = animation_data.compute_interpolated_value();
                 *value = val;
@@ -183,15 +376,29 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
         };
         BindingResult::KeepBinding
     }
+    unsafe fn intercept_set_binding(self: Pin<&Self>, _new_binding: *mut BindingHolder) -> bool {
+        if self.state.get() == AnimatedBindingState::Animating {
+            let velocity = self.animation_data.borrow().current_spring_velocity();
+            REPLACED_SPRING_VELOCITY.with(|v| v.set(velocity));
+        }
+        false
+    }
+
     fn mark_dirty(self: Pin<&Self>) {
-        if self.state.get() == AnimatedBindingState::ShouldStart {
-            return;
+        match self.state.get() {
+            AnimatedBindingState::ShouldStart | AnimatedBindingState::ShouldRetarget => return,
+            _ => (),
         }
         let original_dirty = self.original_binding.access(|b| b.unwrap().dirty.get());
-        if original_dirty {
-            self.state.set(AnimatedBindingState::ShouldStart);
-            self.animation_data.borrow_mut().reset();
+        if !original_dirty {
+            return;
         }
+        self.state.set(if self.state.get() == AnimatedBindingState::Animating {
+            AnimatedBindingState::ShouldRetarget
+        } else {
+            AnimatedBindingState::ShouldStart
+        });
+        self.animation_data.borrow_mut().reset();
     }
 }
 
@@ -204,24 +411,42 @@ pub trait InterpolatedPropertyValue: PartialEq + Default + 'static {
     /// easing curves it may over- or undershoot though.
     #[must_use]
     fn interpolate(&self, target_value: &Self, t: f32) -> Self;
+
+    /// Returns `target_value - self` for values that vary along a single dimension.
+    /// Spring animations use it to keep their velocity when their target value changes.
+    fn delta(&self, _target_value: &Self) -> Option<f32> {
+        None
+    }
 }
 
 impl InterpolatedPropertyValue for f32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + t * (target_value - self)
     }
+
+    fn delta(&self, target_value: &Self) -> Option<f32> {
+        Some(target_value - self)
+    }
 }
 
 impl InterpolatedPropertyValue for i32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as i32
     }
+
+    fn delta(&self, target_value: &Self) -> Option<f32> {
+        Some(*target_value as f32 - *self as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for i64 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as Self
     }
+
+    fn delta(&self, target_value: &Self) -> Option<f32> {
+        Some(*target_value as f32 - *self as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for u8 {
@@ -229,12 +454,20 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         ((*self as f32) + (t * ((*target_value as f32) - (*self as f32)))).round().clamp(0., 255.)
             as u8
     }
+
+    fn delta(&self, target_value: &Self) -> Option<f32> {
+        Some(*target_value as f32 - *self as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for LogicalLength {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         LogicalLength::new(self.get().interpolate(&target_value.get(), t))
     }
+
+    fn delta(&self, target_value: &Self) -> Option<f32> {
+        self.get().delta(&target_value.get())
+    }
 }
 
 impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
@@ -245,26 +478,25 @@ impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
     /// If other properties have binding depending of this property, these properties will
     /// be marked as dirty.
     pub fn set_animated_value(&self, value: T, animation_data: PropertyAnimation) {
+        self.set_animated_value_with_keyframes(value, animation_data, SharedVector::default())
+    }
+
+    /// Same as [`Self::set_animated_value`], but the animation goes through the specified keyframes
+    /// on its way to the value.
+    pub fn set_animated_value_with_keyframes(
+        &self,
+        value: T,
+        animation_data: PropertyAnimation,
+        keyframes: SharedVector<Keyframe<T>>,
+    ) {
         // FIXME if the current value is a dirty binding, we must run it, but we do not have the context
-        let d = RefCell::new(properties_animations::PropertyValueAnimationData::new(
-            self.get_internal(),
-            value,
-            animation_data,
-        ));
-        // Safety: the BindingCallable will cast its argument to T
+        // Safety: the property is of type T
         unsafe {
-            self.handle.set_binding(
-                move |val: *mut ()| {
-                    let (value, finished) = d.borrow_mut().compute_interpolated_value();
-                    *(val as *mut T) = value;
-                    if finished {
-                        BindingResult::RemoveBinding
-                    } else {
-                        crate::animations::CURRENT_ANIMATION_DRIVER
-                            .with(|driver| driver.set_has_active_animations());
-                        BindingResult::KeepBinding
-                    }
-                },
+            self.handle.set_animated_value_binding(
+                self.get_internal(),
+                value,
+                animation_data,
+                keyframes,
                 #[cfg(slint_debug_property)]
                 self.debug_name.borrow().as_str(),
             );
@@ -282,38 +514,23 @@ pub fn set_animated_binding(
         binding: impl Binding<T> + 'static,
         animation_data: PropertyAnimation,
     ) {
-        let binding_callable = properties_animations::AnimatedBindingCallable::<T, _> {
-            original_binding: PropertyHandle {
-                handle: Cell::new(
-                    (alloc_binding_holder(move |val: *mut ()| unsafe {
-                        let val = &mut *(val as *mut T);
-                        *(val as *mut T) = binding.evaluate(val);
-                        BindingResult::KeepBinding
-                    }) as usize)
-                        | 0b10,
-                ),
-            },
-            state: Cell::new(properties_animations::AnimatedBindingState::NotAnimating),
-            animation_data: RefCell::new(properties_animations::PropertyValueAnimationData::new(
-                T::default(),
-                T::default(),
-                animation_data,
-            )),
-            compute_animation_details: || -> properties_animations::AnimationDetail { None },
-        };
+        self.set_animated_binding_with_keyframes(binding, animation_data, SharedVector::default())
+    }
 
-        // Safety: the `AnimatedBindingCallable`'s type match the property type
-        unsafe {
-            self.handle.set_binding(
-                binding_callable,
-                #[cfg(slint_debug_property)]
-                self.debug_name.borrow().as_str(),
-            )
-        };
-        self.handle.mark_dirty(
-            #[cfg(slint_debug_property)]
-            self.debug_name.borrow().as_str(),
+    /// Same as [`Self::set_animated_binding`], but the animations go through the specified keyframes.
+    pub fn set_animated_binding_with_keyframes(
+        &self,
+        binding: impl Binding<T> + 'static,
+        animation_data: PropertyAnimation,
+        keyframes: SharedVector<Keyframe<T>>,
+    ) {
+        let mut animation_data = properties_animations::PropertyValueAnimationData::new(
+            T::default(),
+            T::default(),
+            animation_data,
         );
+        animation_data.set_keyframes(keyframes);
+        self.set_animated_binding_impl(binding, animation_data, || None);
     }
 
     /// Set a binding to this property, providing a callback for the transition animation
@@ -323,6 +540,36 @@ pub fn set_animated_binding_for_transition(
         binding: impl Binding<T> + 'static,
         compute_animation_details: impl Fn() -> (PropertyAnimation, crate::animations::Instant)
             + 'static,
+    ) {
+        self.set_animated_binding_for_transition_with_keyframes(binding, move || {
+            let (animation, start_time) = compute_animation_details();
+            (animation, start_time, SharedVector::default())
+        })
+    }
+
+    /// Same as [`Self::set_animated_binding_for_transition`], but the callback also provides
+    /// the keyframes of the transition animation.
+    pub fn set_animated_binding_for_transition_with_keyframes(
+        &self,
+        binding: impl Binding<T> + 'static,
+        compute_animation_details: impl Fn() -> (PropertyAnimation, crate::animations::Instant, SharedVector<Keyframe<T>>)
+            + 'static,
+    ) {
+        let animation_data = properties_animations::PropertyValueAnimationData::new(
+            T::default(),
+            T::default(),
+            PropertyAnimation::default(),
+        );
+        self.set_animated_binding_impl(binding, animation_data, move || {
+            Some(compute_animation_details())
+        });
+    }
+
+    fn set_animated_binding_impl(
+        &self,
+        binding: impl Binding<T> + 'static,
+        animation_data: properties_animations::PropertyValueAnimationData<T>,
+        compute_animation_details: impl Fn() -> properties_animations::AnimationDetail<T> + 'static,
     ) {
         let binding_callable = properties_animations::AnimatedBindingCallable::<T, _> {
             original_binding: PropertyHandle {
@@ -336,12 +583,8 @@ pub fn set_animated_binding_for_transition(
                 ),
             },
             state: Cell::new(properties_animations::AnimatedBindingState::NotAnimating),
-            animation_data: RefCell::new(properties_animations::PropertyValueAnimationData::new(
-                T::default(),
-                T::default(),
-                PropertyAnimation::default(),
-            )),
-            compute_animation_details: move || Some(compute_animation_details()),
+            animation_data: RefCell::new(animation_data),
+            compute_animation_details,
         };
 
         // Safety: the `AnimatedBindingCallable`'s type match the property type
@@ -853,6 +1096,51 @@ fn properties_test_animation_triggered_by_binding() {
         assert_eq!(get_prop_value(&compo.width_times_two), 400);
     }
 
+    #[test]
+    fn properties_test_animation_not_restarted_when_target_unchanged() {
+        let compo = Component::new_test_component();
+
+        let start_time = crate::animations::current_tick();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+        );
+
+        compo.feed_property.set(100);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        compo.feed_property.set(200);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 2));
+        assert_eq!(get_prop_value(&compo.width), 150);
+
+        // Re-evaluating the binding with the same target doesn't restart the animation
+        compo.feed_property.set(300);
+        compo.feed_property.set(200);
+        assert_eq!(get_prop_value(&compo.width), 150);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 3 / 4));
+        assert_eq!(get_prop_value(&compo.width), 175);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 200);
+    }
+
     #[test]
     fn properties_test_delayed_animation_triggered_by_binding() {
         let compo = Component::new_test_component();
@@ -1036,4 +1324,177 @@ fn test_loop_via_binding() {
 
         assert_eq!(get_prop_value(&compo.width), 300);
     }
+
+    const SPRING: [f32; 3] = [100., 20., 1.];
+
+    fn spring_animation() -> PropertyAnimation {
+        PropertyAnimation {
+            easing: EasingCurve::Spring(SPRING),
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        }
+    }
+
+    #[test]
+    fn properties_test_spring_animation_triggered_by_set() {
+        let compo = Component::new_test_component();
+
+        compo.width.set(0);
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(1000, spring_animation());
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_millis(100))
+        });
+        let expected = 1000. * (1. + crate::animations::spring_state(&SPRING, -1., 0., 0.1).0);
+        assert_eq!(get_prop_value(&compo.width), expected.round() as i32);
+
+        // The duration of the animation is ignored: the spring settles on its own
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + std::time::Duration::from_secs(5))
+        });
+        assert_eq!(get_prop_value(&compo.width), 1000);
+        assert_eq!(get_prop_value(&compo.width_times_two), 2000);
+        assert!(!crate::animations::CURRENT_ANIMATION_DRIVER.with(|d| d.has_active_animations()));
+    }
+
+    #[test]
+    fn properties_test_spring_animation_keeps_velocity_triggered_by_set() {
+        let compo = Component::new_test_component();
+
+        compo.width.set(0);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(1000, spring_animation());
+
+        let retarget_time = start_time + std::time::Duration::from_millis(100);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(retarget_time));
+        let value_at_retarget = get_prop_value(&compo.width);
+        assert!(value_at_retarget > 0 && value_at_retarget < 1000);
+
+        // Reverse the direction: the spring keeps moving forward for a little while
+        compo.width.set_animated_value(-1000, spring_animation());
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(retarget_time + std::time::Duration::from_millis(20))
+        });
+        assert!(get_prop_value(&compo.width) > value_at_retarget);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(retarget_time + std::time::Duration::from_secs(5))
+        });
+        assert_eq!(get_prop_value(&compo.width), -1000);
+    }
+
+    #[test]
+    fn properties_test_spring_animation_keeps_velocity_triggered_by_binding() {
+        let compo = Component::new_test_component();
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            spring_animation(),
+        );
+
+        compo.feed_property.set(0);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        let start_time = crate::animations::current_tick();
+        compo.feed_property.set(1000);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        let retarget_time = start_time + std::time::Duration::from_millis(100);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(retarget_time));
+        let value_at_retarget = get_prop_value(&compo.width);
+        assert!(value_at_retarget > 0 && value_at_retarget < 1000);
+
+        compo.feed_property.set(-1000);
+        assert_eq!(get_prop_value(&compo.width), value_at_retarget);
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(retarget_time + std::time::Duration::from_millis(20))
+        });
+        assert!(get_prop_value(&compo.width) > value_at_retarget);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(retarget_time + std::time::Duration::from_secs(5))
+        });
+        assert_eq!(get_prop_value(&compo.width), -1000);
+    }
+
+    fn keyframes() -> SharedVector<Keyframe<i32>> {
+        // Deliberately unsorted
+        SharedVector::from_slice(&[
+            Keyframe { position: 0.75, value: 50, easing: EasingCurve::Linear },
+            Keyframe { position: 0.25, value: 200, easing: EasingCurve::Linear },
+        ])
+    }
+
+    #[test]
+    fn properties_test_keyframes_triggered_by_set() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(0);
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value_with_keyframes(100, animation_details, keyframes());
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        for (progress, expected) in [(1, 100), (2, 200), (4, 125), (6, 50), (7, 75), (8, 100)] {
+            crate::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.update_animations(start_time + DURATION * progress / 8));
+            assert_eq!(get_prop_value(&compo.width), expected, "at {progress}/8");
+        }
+        assert_eq!(get_prop_value(&compo.width_times_two), 200);
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
+    #[test]
+    fn properties_test_keyframes_triggered_by_binding() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            iteration_count: 2.,
+            direction: AnimationDirection::Alternate,
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding_with_keyframes(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+            keyframes(),
+        );
+
+        assert_eq!(get_prop_value(&compo.width), 0);
+        let start_time = crate::animations::current_tick();
+        compo.feed_property.set(100);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 200);
+
+        // The second iteration goes through the keyframes backwards
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 50);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION + DURATION * 3 / 4));
+        assert_eq!(get_prop_value(&compo.width), 200);
+    }
 }
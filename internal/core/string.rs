@@ -14,6 +14,8 @@
 #[allow(unused)]
 use num_traits::Float;
 
+mod pattern;
+
 /// This macro is the same as [`std::format!`], but it returns a [`SharedString`] instead.
 ///
 /// ### Example
@@ -339,6 +341,100 @@ pub fn shared_string_from_number_precision(n: f64, precision: usize) -> SharedSt
     }
 }
 
+/// Returns the part of `s` that starts at the `start`-th character and is at most `length` characters long.
+///
+/// Characters are counted as grapheme clusters, like in the `character-count` property of strings.
+pub fn shared_string_substring(s: &str, start: i32, length: i32) -> SharedString {
+    use unicode_segmentation::UnicodeSegmentation;
+    let mut graphemes = s.grapheme_indices(true).skip(start.max(0) as usize);
+    let Some((begin, _)) = graphemes.next() else { return SharedString::default() };
+    if length <= 0 {
+        return SharedString::default();
+    }
+    let end = graphemes.nth(length as usize - 1).map_or(s.len(), |(end, _)| end);
+    s[begin..end].into()
+}
+
+/// Returns `s` where every occurrence of `from` is replaced by `to`
+pub fn shared_string_replace(s: &str, from: &str, to: &str) -> SharedString {
+    if from.is_empty() {
+        return s.into();
+    }
+    s.replace(from, to).into()
+}
+
+/// Split `s` at each occurrence of `separator`.
+///
+/// If the separator is empty, the string is split into its characters (grapheme clusters).
+pub fn shared_string_split(s: &str, separator: &str) -> SharedVector<SharedString> {
+    if separator.is_empty() {
+        use unicode_segmentation::UnicodeSegmentation;
+        s.graphemes(true).map(SharedString::from).collect()
+    } else {
+        s.split(separator).map(SharedString::from).collect()
+    }
+}
+
+/// Returns true if the `pattern` matches somewhere in `s`.
+///
+/// The pattern is a simple regular expression supporting `.`, `^`, `$`, character classes
+/// (`[a-z]`, `[^0-9]`), the `\d`, `\w` and `\s` classes and their negations, groups with
+/// alternatives (`(jpg|png)`), and the `*`, `+`, `?`, `{n}`, `{n,}` and `{n,m}` quantifiers.
+/// Like the other string functions, `.` and the classes match one grapheme cluster.
+/// An invalid pattern never matches, and neither does a pattern with too many repetitions, such
+/// as `a{100000}`.
+/// The time to match is linear in the length of `s`.
+pub fn shared_string_matches(s: &str, pattern: &str) -> bool {
+    pattern::Pattern::parse(pattern).is_some_and(|p| p.is_match(s))
+}
+
+#[test]
+fn test_string_functions() {
+    assert_eq!(shared_string_substring("hello world", 6, 3), "wor");
+    assert_eq!(shared_string_substring("hello", 3, i32::MAX), "lo");
+    assert_eq!(shared_string_substring("hello", -2, 2), "he");
+    assert_eq!(shared_string_substring("hello", 10, 2), "");
+    assert_eq!(shared_string_substring("hello", 1, 0), "");
+    assert_eq!(shared_string_substring("e\u{301}t\u{e9}", 1, 1), "t");
+    assert_eq!(shared_string_replace("a-b-c", "-", "+"), "a+b+c");
+    assert_eq!(shared_string_replace("abc", "", "+"), "abc");
+    assert_eq!(shared_string_split("a,b,,c", ",").as_slice(), ["a", "b", "", "c"]);
+    assert_eq!(shared_string_split("e\u{301}t", "").as_slice(), ["e\u{301}", "t"]);
+    assert!(shared_string_matches("hello@example.com", r"^[\w.]+@\w+\.(com|org)$"));
+    assert!(!shared_string_matches("hello@example.net", r"^[\w.]+@\w+\.(com|org)$"));
+    assert!(shared_string_matches("abc123", r"\d{3}"));
+    assert!(!shared_string_matches("abc12", r"\d{3}"));
+    assert!(shared_string_matches("e\u{301}", "^.$"));
+    assert!(!shared_string_matches("abc", "[b"));
+}
+
+#[test]
+fn test_matches_long_input() {
+    let long = "a".repeat(100_000);
+    assert!(shared_string_matches(&long, "^a*$"));
+    assert!(shared_string_matches(&long, ".*"));
+    assert!(!shared_string_matches(&long, ".*b"));
+    assert!(shared_string_matches(&(long.clone() + "b"), "^(a|b)+$"));
+    // Nested quantifiers, which take an exponential time with backtracking
+    let input = String::from(&long[..40]) + "b";
+    assert!(!shared_string_matches(&input, "^(a+)+$"));
+    assert!(!shared_string_matches(&(long.clone() + "b"), "^(a*)*$"));
+    assert!(shared_string_matches(&input, "^(a+)+b$"));
+    assert!(shared_string_matches("", "^(a*)*$"));
+    assert!(shared_string_matches("aaa", "^a{3}$"));
+    assert!(!shared_string_matches("aaaa", "^a{1,3}$"));
+    assert!(!shared_string_matches("a", "a{100000}"));
+    // Nested repetitions of empty groups, which don't add instructions but take time to compile
+    assert!(!shared_string_matches("a", "(((){10000}){10000}){10000}"));
+    assert!(!shared_string_matches("a", "(()*){10000}(){10000}(){10000}(){10000}(){10000}"));
+    assert!(shared_string_matches("a", "^(){100}a$"));
+    // Deeply nested groups, which would overflow the stack
+    assert!(!shared_string_matches("a", &"(".repeat(100_000)));
+    let nested = |depth| "(".repeat(depth) + "a" + &")".repeat(depth);
+    assert!(shared_string_matches("a", &nested(200)));
+    assert!(!shared_string_matches("a", &nested(300)));
+}
+
 #[test]
 fn simple_test() {
     use std::string::ToString;
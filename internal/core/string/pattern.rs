@@ -0,0 +1,372 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! A small regular expression matcher used by the `matches()` string function.
+//!
+//! Both the pattern and the text are processed as grapheme clusters.
+//! The pattern is compiled to a program for a non-deterministic automaton which is simulated
+//! on all the positions of the text at once (a Pike VM). This takes a time linear in the length
+//! of the text and doesn't recurse, so it can be used on any user input.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Clone)]
+enum ClassItem<'a> {
+    Grapheme(&'a str),
+    Range(char, char),
+    Digit(bool),
+    Word(bool),
+    Space(bool),
+}
+
+impl ClassItem<'_> {
+    fn matches(&self, g: &str) -> bool {
+        let single = {
+            let mut chars = g.chars();
+            chars.next().filter(|_| chars.next().is_none())
+        };
+        match self {
+            ClassItem::Grapheme(x) => *x == g,
+            ClassItem::Range(from, to) => single.is_some_and(|c| (*from..=*to).contains(&c)),
+            ClassItem::Digit(positive) => single.is_some_and(|c| c.is_ascii_digit()) == *positive,
+            ClassItem::Word(positive) => {
+                single.is_some_and(|c| c.is_alphanumeric() || c == '_') == *positive
+            }
+            ClassItem::Space(positive) => single.is_some_and(char::is_whitespace) == *positive,
+        }
+    }
+}
+
+enum Node<'a> {
+    Any,
+    Class {
+        negated: bool,
+        items: Vec<ClassItem<'a>>,
+    },
+    Start,
+    End,
+    /// A group with its alternatives
+    Group(Vec<Vec<Node<'a>>>),
+    Repeat {
+        node: Box<Node<'a>>,
+        min: usize,
+        max: usize,
+    },
+}
+
+/// An instruction of the compiled pattern
+enum Inst<'a> {
+    /// Consumes any grapheme
+    Any,
+    /// Consumes a grapheme matching the class
+    Class {
+        negated: bool,
+        items: Vec<ClassItem<'a>>,
+    },
+    /// Only continues at the start of the text
+    Start,
+    /// Only continues at the end of the text
+    End,
+    /// Continues at both instructions
+    Split(usize, usize),
+    Jump(usize),
+    Match,
+}
+
+/// The maximum number of instructions of a compiled pattern, which limits the size of the
+/// counted repetitions such as `a{1000}`
+const MAX_PROGRAM_SIZE: usize = 10_000;
+
+/// The maximum number of nodes compiled for a pattern, counting each repetition. Unlike
+/// [`MAX_PROGRAM_SIZE`], this also bounds nested repetitions of nodes without instructions,
+/// such as `((){10000}){10000}`
+const MAX_COMPILE_STEPS: usize = 4 * MAX_PROGRAM_SIZE;
+
+/// The maximum nesting depth of groups, since the parsing and the compilation recurse into groups
+const MAX_GROUP_DEPTH: usize = 256;
+
+pub struct Pattern<'a> {
+    program: Vec<Inst<'a>>,
+}
+
+impl<'a> Pattern<'a> {
+    /// Returns None if the pattern is not valid
+    pub fn parse(pattern: &'a str) -> Option<Self> {
+        let tokens = pattern.graphemes(true).collect::<Vec<_>>();
+        let mut pos = 0;
+        let alternatives = parse_alternatives(&tokens, &mut pos, 0)?;
+        if pos != tokens.len() {
+            return None;
+        }
+        let mut program = Vec::new();
+        let mut steps = MAX_COMPILE_STEPS;
+        compile_alternatives(&alternatives, &mut program, &mut steps)?;
+        program.push(Inst::Match);
+        Some(Self { program })
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        let text = text.graphemes(true).collect::<Vec<_>>();
+        let mut current = Threads::new(self.program.len());
+        let mut next = Threads::new(self.program.len());
+        let mut stack = Vec::new();
+        for pos in 0..=text.len() {
+            // Start a new match at every position
+            if self.add_thread(&mut current, &mut stack, 0, pos, text.len()) {
+                return true;
+            }
+            let Some(grapheme) = text.get(pos) else { break };
+            for &pc in &current.pcs {
+                let consumed = match &self.program[pc] {
+                    Inst::Any => true,
+                    Inst::Class { negated, items } => {
+                        items.iter().any(|i| i.matches(grapheme)) != *negated
+                    }
+                    _ => false,
+                };
+                if consumed && self.add_thread(&mut next, &mut stack, pc + 1, pos + 1, text.len()) {
+                    return true;
+                }
+            }
+            core::mem::swap(&mut current, &mut next);
+            next.pcs.clear();
+        }
+        false
+    }
+
+    /// Adds the thread at `pc`, and the threads reachable from it without consuming a grapheme,
+    /// to `threads`. Returns true if one of them matches.
+    fn add_thread(
+        &self,
+        threads: &mut Threads,
+        stack: &mut Vec<usize>,
+        pc: usize,
+        pos: usize,
+        len: usize,
+    ) -> bool {
+        stack.clear();
+        stack.push(pc);
+        while let Some(pc) = stack.pop() {
+            if threads.added_at[pc] == pos {
+                continue;
+            }
+            threads.added_at[pc] = pos;
+            match &self.program[pc] {
+                Inst::Any | Inst::Class { .. } => threads.pcs.push(pc),
+                Inst::Start if pos == 0 => stack.push(pc + 1),
+                Inst::End if pos == len => stack.push(pc + 1),
+                Inst::Start | Inst::End => (),
+                Inst::Split(a, b) => stack.extend([*b, *a]),
+                Inst::Jump(to) => stack.push(*to),
+                Inst::Match => return true,
+            }
+        }
+        false
+    }
+}
+
+/// The threads of the simulation at one position of the text
+struct Threads {
+    /// The instructions that consume a grapheme
+    pcs: Vec<usize>,
+    /// For each instruction, the last position at which it was added
+    added_at: Vec<usize>,
+}
+
+impl Threads {
+    fn new(program_size: usize) -> Self {
+        Self { pcs: Vec::new(), added_at: alloc::vec![usize::MAX; program_size] }
+    }
+}
+
+fn parse_alternatives<'a>(
+    tokens: &[&'a str],
+    pos: &mut usize,
+    depth: usize,
+) -> Option<Vec<Vec<Node<'a>>>> {
+    let mut alternatives = Vec::new();
+    let mut sequence = Vec::new();
+    while let Some(&token) = tokens.get(*pos) {
+        *pos += 1;
+        let node = match token {
+            "|" => {
+                alternatives.push(core::mem::take(&mut sequence));
+                continue;
+            }
+            ")" => {
+                *pos -= 1;
+                break;
+            }
+            "(" => {
+                if depth >= MAX_GROUP_DEPTH {
+                    return None;
+                }
+                let group = parse_alternatives(tokens, pos, depth + 1)?;
+                if tokens.get(*pos) != Some(&")") {
+                    return None;
+                }
+                *pos += 1;
+                Node::Group(group)
+            }
+            "." => Node::Any,
+            "^" => Node::Start,
+            "$" => Node::End,
+            "[" => parse_class(tokens, pos)?,
+            "\\" => {
+                let item = parse_escape(tokens, pos)?;
+                Node::Class { negated: false, items: alloc::vec![item] }
+            }
+            "*" | "+" | "?" | "{" => {
+                let (min, max) = match token {
+                    "*" => (0, usize::MAX),
+                    "+" => (1, usize::MAX),
+                    "?" => (0, 1),
+                    _ => parse_braces(tokens, pos)?,
+                };
+                let node = sequence.pop().filter(|n| !matches!(n, Node::Repeat { .. }))?;
+                Node::Repeat { node: Box::new(node), min, max }
+            }
+            _ => Node::Class { negated: false, items: alloc::vec![ClassItem::Grapheme(token)] },
+        };
+        sequence.push(node);
+    }
+    alternatives.push(sequence);
+    Some(alternatives)
+}
+
+fn parse_escape<'a>(tokens: &[&'a str], pos: &mut usize) -> Option<ClassItem<'a>> {
+    let token = *tokens.get(*pos)?;
+    *pos += 1;
+    Some(match token {
+        "d" => ClassItem::Digit(true),
+        "D" => ClassItem::Digit(false),
+        "w" => ClassItem::Word(true),
+        "W" => ClassItem::Word(false),
+        "s" => ClassItem::Space(true),
+        "S" => ClassItem::Space(false),
+        "n" => ClassItem::Grapheme("\n"),
+        "t" => ClassItem::Grapheme("\t"),
+        _ => ClassItem::Grapheme(token),
+    })
+}
+
+fn parse_class<'a>(tokens: &[&'a str], pos: &mut usize) -> Option<Node<'a>> {
+    let negated = tokens.get(*pos) == Some(&"^");
+    if negated {
+        *pos += 1;
+    }
+    let mut items = Vec::new();
+    loop {
+        let token = *tokens.get(*pos)?;
+        *pos += 1;
+        let item = match token {
+            "]" if !items.is_empty() => return Some(Node::Class { negated, items }),
+            "\\" => parse_escape(tokens, pos)?,
+            _ if tokens.get(*pos) == Some(&"-")
+                && tokens.get(*pos + 1).is_some_and(|t| *t != "]") =>
+            {
+                let to = tokens[*pos + 1];
+                *pos += 2;
+                let single = |g: &str| {
+                    let mut chars = g.chars();
+                    chars.next().filter(|_| chars.next().is_none())
+                };
+                ClassItem::Range(single(token)?, single(to)?)
+            }
+            _ => ClassItem::Grapheme(token),
+        };
+        items.push(item);
+    }
+}
+
+/// Parse the `n}`, `n,}` or `n,m}` after a `{`
+fn parse_braces(tokens: &[&str], pos: &mut usize) -> Option<(usize, usize)> {
+    let number = |pos: &mut usize| {
+        let start = *pos;
+        while tokens.get(*pos).is_some_and(|t| t.len() == 1 && t.as_bytes()[0].is_ascii_digit()) {
+            *pos += 1;
+        }
+        tokens[start..*pos].concat().parse::<usize>().ok()
+    };
+    let min = number(pos)?;
+    let max = if tokens.get(*pos) == Some(&",") {
+        *pos += 1;
+        number(pos).unwrap_or(usize::MAX)
+    } else {
+        min
+    };
+    if tokens.get(*pos) != Some(&"}") || max < min {
+        return None;
+    }
+    *pos += 1;
+    Some((min, max))
+}
+
+fn compile_alternatives<'a>(
+    alternatives: &[Vec<Node<'a>>],
+    program: &mut Vec<Inst<'a>>,
+    steps: &mut usize,
+) -> Option<()> {
+    let mut jumps_to_end = Vec::new();
+    for (i, sequence) in alternatives.iter().enumerate() {
+        let split = (i + 1 < alternatives.len()).then(|| {
+            program.push(Inst::Split(0, 0));
+            program.len() - 1
+        });
+        for node in sequence {
+            compile_node(node, program, steps)?;
+        }
+        if let Some(split) = split {
+            program.push(Inst::Jump(0));
+            jumps_to_end.push(program.len() - 1);
+            program[split] = Inst::Split(split + 1, program.len());
+        }
+    }
+    let end = program.len();
+    for jump in jumps_to_end {
+        program[jump] = Inst::Jump(end);
+    }
+    Some(())
+}
+
+fn compile_node<'a>(node: &Node<'a>, program: &mut Vec<Inst<'a>>, steps: &mut usize) -> Option<()> {
+    *steps = steps.checked_sub(1)?;
+    match node {
+        Node::Any => program.push(Inst::Any),
+        Node::Class { negated, items } => {
+            program.push(Inst::Class { negated: *negated, items: items.clone() })
+        }
+        Node::Start => program.push(Inst::Start),
+        Node::End => program.push(Inst::End),
+        Node::Group(alternatives) => compile_alternatives(alternatives, program, steps)?,
+        Node::Repeat { node, min, max } => {
+            if *min > MAX_PROGRAM_SIZE || (*max != usize::MAX && *max > MAX_PROGRAM_SIZE) {
+                return None;
+            }
+            for _ in 0..*min {
+                compile_node(node, program, steps)?;
+            }
+            if *max == usize::MAX {
+                let split = program.len();
+                program.push(Inst::Split(0, 0));
+                compile_node(node, program, steps)?;
+                program.push(Inst::Jump(split));
+                program[split] = Inst::Split(split + 1, program.len());
+            } else {
+                let mut splits = Vec::new();
+                for _ in *min..*max {
+                    splits.push(program.len());
+                    program.push(Inst::Split(0, 0));
+                    compile_node(node, program, steps)?;
+                }
+                let end = program.len();
+                for split in splits {
+                    program[split] = Inst::Split(split + 1, end);
+                }
+            }
+        }
+    }
+    (program.len() <= MAX_PROGRAM_SIZE).then_some(())
+}
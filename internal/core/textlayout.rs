@@ -48,6 +48,9 @@
 
 pub use linebreaker::TextLineBreaker;
 
+mod styled;
+pub use styled::{SpanStyle, StyledParagraph, StyledTextLayout, StyledTextLine, StyledTextRun};
+
 pub struct TextLayout<'a, Font: AbstractFont> {
     pub font: &'a Font,
     pub letter_spacing: Option<<Font as TextShaper>::Length>,
@@ -0,0 +1,443 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Layout of a paragraph made of spans with different styles, as used by the `StyledText` element.
+//!
+//! The paragraph is broken into lines at the same break opportunities as the plain text. Each
+//! fragment between two break opportunities is split at the span boundaries, and each piece is
+//! measured with the style of its span. The resulting runs are drawn by the renderer one by one.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+use euclid::num::Zero;
+
+use super::{BreakOpportunity, LineBreakIterator};
+use crate::items::{TextHorizontalAlignment, TextVerticalAlignment, TextWrap};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, SizeLengths};
+use crate::{Color, Coord, SharedString};
+
+/// The style of a span of a [`StyledParagraph`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpanStyle {
+    pub bold: bool,
+    pub italic: bool,
+    /// The color of the span, if it's not the default text color
+    pub color: Option<Color>,
+    /// The url of the link, if the span is a link
+    pub link: Option<SharedString>,
+}
+
+/// A text where ranges of bytes have a different style
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyledParagraph {
+    /// The text, without markup
+    pub text: String,
+    /// The styles of the ranges of `text`, in order and covering the whole text
+    pub spans: Vec<(Range<usize>, SpanStyle)>,
+}
+
+impl StyledParagraph {
+    /// Parses a subset of markdown: `**bold**`, `*italic*`, `[links](url)`, `<font color="#rrggbb">colors</font>`,
+    /// and backslash escapes. Markup that isn't recognized is kept as text.
+    pub fn parse_markdown(markdown: &str) -> Self {
+        let mut paragraph = Self::default();
+        let mut style = SpanStyle::default();
+        let mut colors = Vec::new();
+        // The position of the `](` ending the text of the current link, and the position after its `)`
+        let mut link_end: Option<(usize, usize)> = None;
+
+        let mut pos = 0;
+        while pos < markdown.len() {
+            if let Some((text_end, end)) = link_end {
+                if pos >= text_end {
+                    style.link = None;
+                    link_end = None;
+                    pos = end;
+                    continue;
+                }
+            }
+            // Escapes and markup in the text of a link don't extend past the end of the text
+            let rest = &markdown[pos..link_end.map_or(markdown.len(), |(text_end, _)| text_end)];
+            if let Some(escaped) = rest.strip_prefix('\\').and_then(|r| r.chars().next()) {
+                if escaped.is_ascii_punctuation() {
+                    paragraph.push(escaped, &style);
+                    pos += 1 + escaped.len_utf8();
+                    continue;
+                }
+            }
+            if rest.starts_with("**") && (style.bold || opens_emphasis(&rest[2..], "**")) {
+                style.bold = !style.bold;
+                pos += 2;
+                continue;
+            }
+            if rest.starts_with('*') && (style.italic || opens_emphasis(&rest[1..], "*")) {
+                style.italic = !style.italic;
+                pos += 1;
+                continue;
+            }
+            if rest.starts_with('[') && link_end.is_none() {
+                if let Some((text_len, url, len)) = parse_link(rest) {
+                    style.link = Some(url.into());
+                    link_end = Some((pos + 1 + text_len, pos + len));
+                    pos += 1;
+                    continue;
+                }
+            }
+            if rest.starts_with("<font ") {
+                if let Some((color, len)) = parse_font_tag(rest) {
+                    colors.push(style.color.replace(color));
+                    pos += len;
+                    continue;
+                }
+            }
+            if rest.starts_with("</font>") {
+                if let Some(color) = colors.pop() {
+                    style.color = color;
+                    pos += "</font>".len();
+                    continue;
+                }
+            }
+            let c = rest.chars().next().unwrap();
+            paragraph.push(c, &style);
+            pos += c.len_utf8();
+        }
+        paragraph
+    }
+
+    fn push(&mut self, c: char, style: &SpanStyle) {
+        let start = self.text.len();
+        self.text.push(c);
+        match self.spans.last_mut() {
+            Some((range, last_style)) if last_style == style => range.end = self.text.len(),
+            _ => self.spans.push((start..self.text.len(), style.clone())),
+        }
+    }
+}
+
+/// Returns true if the text after an emphasis delimiter starts the emphasis: it must not start with
+/// a white space and must be closed later.
+fn opens_emphasis(after: &str, delimiter: &str) -> bool {
+    after.chars().next().is_some_and(|c| !c.is_whitespace() && c != '*')
+        && after.contains(delimiter)
+}
+
+/// Parses `[text](url)` and returns the length of the text, the url, and the length of the whole link.
+fn parse_link(link: &str) -> Option<(usize, &str, usize)> {
+    let text_end = link.find("](")?;
+    let text = &link[1..text_end];
+    if text.is_empty() || text.contains(['[', '\n']) {
+        return None;
+    }
+    let url_start = text_end + 2;
+    let url_len = link[url_start..].find(')')?;
+    let url = link[url_start..url_start + url_len].trim();
+    if url.is_empty() || url.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((text.len(), url, url_start + url_len + 1))
+}
+
+/// Parses `<font color="#rrggbb">` and returns the color and the length of the tag.
+fn parse_font_tag(tag: &str) -> Option<(Color, usize)> {
+    let len = tag.find('>')? + 1;
+    let value = tag["<font ".len()..len - 1].trim().strip_prefix("color=")?;
+    let value = value.trim_matches(|c| c == '"' || c == '\'');
+    Some((parse_color(value)?, len))
+}
+
+/// Parses a `#rgb`, `#rgba`, `#rrggbb`, or `#rrggbbaa` color
+fn parse_color(color: &str) -> Option<Color> {
+    let hex = color.strip_prefix('#')?;
+    if !hex.is_ascii() {
+        return None;
+    }
+    let component = |i: usize, len: usize| {
+        u8::from_str_radix(&hex[i * len..(i + 1) * len], 16).ok().map(|c| match len {
+            1 => c * 0x11,
+            _ => c,
+        })
+    };
+    let len = match hex.len() {
+        3 | 4 => 1,
+        6 | 8 => 2,
+        _ => return None,
+    };
+    let alpha = if hex.len() == 4 * len { component(3, len)? } else { 0xff };
+    Some(Color::from_argb_u8(alpha, component(0, len)?, component(1, len)?, component(2, len)?))
+}
+
+/// A part of a line of a [`StyledTextLayout`] that has a single style
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledTextRun {
+    /// The range of the run in [`StyledParagraph::text`]
+    pub byte_range: Range<usize>,
+    /// The index of the span in [`StyledParagraph::spans`]
+    pub span_index: usize,
+    /// The position of the run relative to the start of the line
+    pub x: LogicalLength,
+    pub width: LogicalLength,
+}
+
+/// A line of a [`StyledTextLayout`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyledTextLine {
+    pub y: LogicalLength,
+    /// The width of the line, without the trailing white space
+    pub width: LogicalLength,
+    pub height: LogicalLength,
+    pub runs: Vec<StyledTextRun>,
+}
+
+/// The lines of a [`StyledParagraph`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyledTextLayout {
+    pub lines: Vec<StyledTextLine>,
+}
+
+impl StyledTextLayout {
+    /// Breaks the paragraph into lines. `measure` returns the size of a text without line breaks,
+    /// when rendered with the given style.
+    pub fn new(
+        paragraph: &StyledParagraph,
+        max_width: Option<LogicalLength>,
+        wrap: TextWrap,
+        mut measure: impl FnMut(&str, &SpanStyle) -> LogicalSize,
+    ) -> Self {
+        let text = paragraph.text.as_str();
+        let max_width = max_width.filter(|_| wrap != TextWrap::NoWrap);
+        let empty_line_height = measure(" ", &SpanStyle::default()).height_length();
+
+        let breaks: Vec<(usize, BreakOpportunity)> = if wrap == TextWrap::CharWrap {
+            text.char_indices()
+                .map(|(offset, c)| {
+                    let opportunity = if matches!(c, '\n' | '\u{2028}' | '\u{2029}') {
+                        BreakOpportunity::Mandatory
+                    } else {
+                        BreakOpportunity::Allowed
+                    };
+                    (offset + c.len_utf8(), opportunity)
+                })
+                .collect()
+        } else {
+            LineBreakIterator::new(text).collect()
+        };
+
+        let mut lines = Vec::new();
+        let mut line = StyledTextLine::default();
+        let mut line_x = LogicalLength::zero();
+        let mut trailing_whitespace = LogicalLength::zero();
+        let mut finish_line = |line: &mut StyledTextLine, line_x: &mut LogicalLength, ws| {
+            let y = line.y + line.height.max(empty_line_height);
+            let mut finished = core::mem::take(line);
+            finished.height = finished.height.max(empty_line_height);
+            finished.width = *line_x - ws;
+            lines.push(finished);
+            line.y = y;
+            *line_x = LogicalLength::zero();
+        };
+
+        let mut fragment_start = 0;
+        for (fragment_end, opportunity) in
+            breaks.into_iter().chain(core::iter::once((text.len(), BreakOpportunity::Allowed)))
+        {
+            if fragment_end <= fragment_start {
+                continue;
+            }
+            let fragment = &text[fragment_start..fragment_end];
+            let content_end = fragment_start
+                + fragment.trim_end_matches(['\n', '\r', '\u{2028}', '\u{2029}']).len();
+            let trimmed_end = fragment_start + text[fragment_start..content_end].trim_end().len();
+
+            let mut pieces = Vec::new();
+            let mut fragment_width = LogicalLength::zero();
+            let mut fragment_height = LogicalLength::zero();
+            let mut fragment_whitespace = LogicalLength::zero();
+            for (span_index, (range, style)) in paragraph.spans.iter().enumerate() {
+                let start = range.start.max(fragment_start);
+                let end = range.end.min(content_end);
+                if start >= end {
+                    continue;
+                }
+                let size = measure(&text[start..end], style);
+                if end > trimmed_end {
+                    let whitespace_start = start.max(trimmed_end);
+                    fragment_whitespace += if whitespace_start == start {
+                        size.width_length()
+                    } else {
+                        measure(&text[whitespace_start..end], style).width_length()
+                    };
+                }
+                fragment_width += size.width_length();
+                fragment_height = fragment_height.max(size.height_length());
+                pieces.push((start..end, span_index, size.width_length()));
+            }
+
+            if let Some(max_width) = max_width {
+                if !line.runs.is_empty()
+                    && line_x + fragment_width - fragment_whitespace > max_width
+                {
+                    finish_line(&mut line, &mut line_x, trailing_whitespace);
+                }
+            }
+
+            for (byte_range, span_index, width) in pieces {
+                match line.runs.last_mut() {
+                    Some(last)
+                        if last.span_index == span_index
+                            && last.byte_range.end == byte_range.start =>
+                    {
+                        last.byte_range.end = byte_range.end;
+                        last.width += width;
+                    }
+                    _ => line.runs.push(StyledTextRun { byte_range, span_index, x: line_x, width }),
+                }
+                line_x += width;
+            }
+            line.height = line.height.max(fragment_height);
+            if content_end > fragment_start {
+                trailing_whitespace = fragment_whitespace;
+            }
+
+            if opportunity == BreakOpportunity::Mandatory {
+                finish_line(&mut line, &mut line_x, trailing_whitespace);
+                trailing_whitespace = LogicalLength::zero();
+            }
+            fragment_start = fragment_end;
+        }
+        finish_line(&mut line, &mut line_x, trailing_whitespace);
+
+        Self { lines }
+    }
+
+    /// The width of the longest line and the height of all the lines
+    pub fn size(&self) -> LogicalSize {
+        let width = self.lines.iter().fold(LogicalLength::zero(), |w, line| w.max(line.width));
+        let height = self.lines.last().map_or(LogicalLength::zero(), |line| line.y + line.height);
+        LogicalSize::from_lengths(width, height)
+    }
+
+    /// Returns the geometry of each run, when the text is aligned within an element of the given size
+    pub fn positioned_runs(
+        &self,
+        size: LogicalSize,
+        (horizontal_alignment, vertical_alignment): (
+            TextHorizontalAlignment,
+            TextVerticalAlignment,
+        ),
+    ) -> impl Iterator<Item = (LogicalRect, &StyledTextRun)> {
+        let two = 2 as Coord;
+        let text_height = self.size().height_length();
+        let y = match vertical_alignment {
+            TextVerticalAlignment::Top => LogicalLength::zero(),
+            TextVerticalAlignment::Center => (size.height_length() - text_height) / two,
+            TextVerticalAlignment::Bottom => size.height_length() - text_height,
+        };
+        self.lines.iter().flat_map(move |line| {
+            let x = match horizontal_alignment {
+                TextHorizontalAlignment::Left => LogicalLength::zero(),
+                TextHorizontalAlignment::Center => (size.width_length() - line.width) / two,
+                TextHorizontalAlignment::Right => size.width_length() - line.width,
+            };
+            line.runs.iter().map(move |run| {
+                let rect = LogicalRect::new(
+                    LogicalPoint::from_lengths(x + run.x, y + line.y),
+                    LogicalSize::from_lengths(run.width, line.height),
+                );
+                (rect, run)
+            })
+        })
+    }
+}
+
+#[test]
+fn test_parse_markdown() {
+    let paragraph = StyledParagraph::parse_markdown(
+        r##"Some **bold** and *italic*, a [**link**](https://slint.dev) and <font color="#f00">red</font> \*"##,
+    );
+    assert_eq!(paragraph.text, "Some bold and italic, a link and red *");
+    let span = |text: &str| {
+        paragraph
+            .spans
+            .iter()
+            .find(|(range, _)| &paragraph.text[range.clone()] == text)
+            .unwrap()
+            .1
+            .clone()
+    };
+    assert_eq!(span("bold"), SpanStyle { bold: true, ..Default::default() });
+    assert_eq!(span("italic"), SpanStyle { italic: true, ..Default::default() });
+    assert_eq!(
+        span("link"),
+        SpanStyle { bold: true, link: Some("https://slint.dev".into()), ..Default::default() }
+    );
+    assert_eq!(
+        span("red"),
+        SpanStyle { color: Some(Color::from_rgb_u8(255, 0, 0)), ..Default::default() }
+    );
+    assert_eq!(span(" and "), SpanStyle::default());
+
+    // An escape at the end of the text of a link doesn't extend the link
+    let paragraph = StyledParagraph::parse_markdown(r"[a\](u) b");
+    assert_eq!(paragraph.text, "a\\ b");
+    assert_eq!(
+        paragraph.spans,
+        [
+            (0..2, SpanStyle { link: Some("u".into()), ..Default::default() }),
+            (2..4, SpanStyle::default())
+        ]
+    );
+
+    // Unmatched markup is kept
+    let paragraph = StyledParagraph::parse_markdown("2 * 3 = [six] <font size=3>");
+    assert_eq!(paragraph.text, "2 * 3 = [six] <font size=3>");
+    assert_eq!(paragraph.spans.len(), 1);
+}
+
+#[test]
+fn test_styled_layout() {
+    let paragraph = StyledParagraph::parse_markdown("Hello **bold world**\nand more");
+    // Each character is 10 wide, bold characters are 20 wide
+    let measure = |text: &str, style: &SpanStyle| {
+        let char_width = if style.bold { 20. } else { 10. };
+        LogicalSize::new(text.chars().count() as Coord * char_width, 10 as Coord)
+    };
+    let layout = StyledTextLayout::new(&paragraph, None, TextWrap::NoWrap, measure);
+    assert_eq!(layout.lines.len(), 2);
+    assert_eq!(layout.lines[0].width.get(), 60 as Coord + 200 as Coord);
+    assert_eq!(layout.lines[0].runs.len(), 2);
+    assert_eq!(layout.lines[0].runs[1].x.get(), 60 as Coord);
+    assert_eq!(layout.lines[1].y.get(), 10 as Coord);
+    assert_eq!(layout.size(), LogicalSize::new(260 as Coord, 20 as Coord));
+
+    let layout = StyledTextLayout::new(
+        &paragraph,
+        Some(LogicalLength::new(150 as Coord)),
+        TextWrap::WordWrap,
+        measure,
+    );
+    let lines = layout
+        .lines
+        .iter()
+        .map(|line| {
+            line.runs.iter().map(|run| &paragraph.text[run.byte_range.clone()]).collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(lines, ["Hello bold ", "world", "and more"]);
+    assert_eq!(layout.lines[0].width.get(), 140 as Coord);
+
+    let rects = layout
+        .positioned_runs(
+            LogicalSize::new(200 as Coord, 100 as Coord),
+            (TextHorizontalAlignment::Right, TextVerticalAlignment::Bottom),
+        )
+        .map(|(rect, _)| rect)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rects[0],
+        LogicalRect::new(
+            LogicalPoint::new(60 as Coord, 70 as Coord),
+            LogicalSize::new(60 as Coord, 10 as Coord)
+        )
+    );
+}
@@ -387,6 +387,226 @@ pub fn select_bundled_translation(language: &str) -> Result<(), SelectBundledTra
     })
 }
 
+/// Returns the locale used to format numbers, and make sure to register a dependency on the selected language.
+///
+/// That is the language of the selected bundled translation, or the locale of the system when
+/// there are no bundled translations.
+fn current_locale() -> SharedString {
+    let idx = global_translation_property();
+    let bundled = crate::context::GLOBAL_CONTEXT.with(|ctx| {
+        let ctx = ctx.get()?;
+        let languages = ctx.0.translations_bundle_languages.borrow();
+        languages.as_ref()?.get(idx).map(|l| SharedString::from(*l))
+    });
+    match bundled {
+        Some(language) if !language.is_empty() => language,
+        // The first language is the one of the strings in the .slint files
+        Some(_) => "en".into(),
+        #[cfg(feature = "std")]
+        None => sys_locale::get_locale().map_or_else(|| "en".into(), Into::into),
+        #[cfg(not(feature = "std"))]
+        None => "en".into(),
+    }
+}
+
+/// The symbols used to format numbers in a given language
+struct NumberSymbols {
+    decimal: char,
+    group: &'static str,
+    /// Inserted between the number and the `%` sign
+    percent_separator: &'static str,
+    /// Whether the currency symbol comes before the number (eg: "$1.00") or after it (eg: "1,00 €")
+    currency_before: bool,
+    /// The size of the groups of digits before the last group of three: 3 in most languages,
+    /// but 2 with the Indian lakh and crore grouping (eg: "1,23,45,678")
+    secondary_group: usize,
+}
+
+fn number_symbols(locale: &str) -> NumberSymbols {
+    const NBSP: &str = "\u{a0}";
+    const NNBSP: &str = "\u{202f}";
+    const APOSTROPHE: &str = "\u{2019}";
+    let mut subtags = locale.split(['.', '@']).next().unwrap_or_default().split(['-', '_']);
+    let language = subtags.next().unwrap_or_default();
+    // The region may follow a script, like in "zh-Hant-TW"
+    let region = subtags
+        .find(|s| s.len() == 2 || (s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit())))
+        .unwrap_or_default();
+    let (decimal, group, percent_separator, currency_before) = match (language, region) {
+        ("de" | "it", "CH") | ("de", "LI") => ('.', APOSTROPHE, "", true),
+        ("fr", "CH") => (',', APOSTROPHE, "", false),
+        ("de" | "es" | "da", _) => (',', ".", NBSP, false),
+        ("it" | "pt" | "el" | "id", _) => (',', ".", "", false),
+        ("nl", _) => (',', ".", "", true),
+        ("fr", _) => (',', NNBSP, NNBSP, false),
+        ("ru" | "uk" | "cs" | "sk" | "sv" | "nb" | "nn" | "no" | "fi", _) => {
+            (',', NBSP, NBSP, false)
+        }
+        ("pl" | "hu" | "bg", _) => (',', NBSP, "", false),
+        _ => ('.', ",", "", true),
+    };
+    let secondary_group = if region == "IN" || language == "hi" { 2 } else { 3 };
+    NumberSymbols { decimal, group, percent_separator, currency_before, secondary_group }
+}
+
+fn currency_symbol(currency: &str) -> &str {
+    match currency {
+        "USD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" | "CNY" => "¥",
+        "INR" => "₹",
+        "KRW" => "₩",
+        "RUB" => "₽",
+        "BRL" => "R$",
+        _ => currency,
+    }
+}
+
+/// Format a number according to the locale of the current translation.
+///
+/// `min_fraction` and `max_fraction` are the minimum and maximum number of digits after the
+/// decimal separator. When negative, the default for the style is used: 0 and 3 for decimal,
+/// 0 and 0 for percent, and 2 and 2 for currency.
+/// `currency` is an ISO 4217 currency code such as "EUR", only used with the currency style.
+pub fn format_number(
+    value: f64,
+    style: crate::items::NumberFormatStyle,
+    min_fraction: i32,
+    max_fraction: i32,
+    grouping: bool,
+    currency: &str,
+) -> SharedString {
+    format_number_with_symbols(
+        &number_symbols(&current_locale()),
+        value,
+        style,
+        min_fraction,
+        max_fraction,
+        grouping,
+        currency,
+    )
+}
+
+fn format_number_with_symbols(
+    symbols: &NumberSymbols,
+    value: f64,
+    style: crate::items::NumberFormatStyle,
+    min_fraction: i32,
+    max_fraction: i32,
+    grouping: bool,
+    currency: &str,
+) -> SharedString {
+    use crate::items::NumberFormatStyle;
+    if value.is_nan() {
+        return "NaN".into();
+    }
+    let (value, default_min, default_max) = match style {
+        NumberFormatStyle::Decimal => (value, 0, 3),
+        NumberFormatStyle::Percent => (value * 100., 0, 0),
+        NumberFormatStyle::Currency => (value, 2, 2),
+    };
+    let max_fraction = if max_fraction < 0 { default_max } else { max_fraction.min(20) as usize };
+    let min_fraction =
+        if min_fraction < 0 { default_min } else { min_fraction as usize }.min(max_fraction);
+
+    let mut number = SharedString::default();
+    let digits = crate::format!("{:.*}", max_fraction, value.abs());
+    let negative =
+        value < 0. && (value.is_infinite() || digits.bytes().any(|b| (b'1'..=b'9').contains(&b)));
+    if value.is_infinite() {
+        number.push_str("∞");
+    } else {
+        let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+        for (i, digit) in integer.chars().enumerate() {
+            // The number of digits remaining, including this one
+            let remaining = integer.len() - i;
+            if grouping && i > 0 && remaining >= 3 && (remaining - 3) % symbols.secondary_group == 0
+            {
+                number.push_str(symbols.group);
+            }
+            number.push_str(digit.encode_utf8(&mut [0; 4]));
+        }
+        // Remove the trailing zeros that are not required
+        let fraction = &fraction[..fraction.trim_end_matches('0').len().max(min_fraction)];
+        if !fraction.is_empty() {
+            number.push_str(symbols.decimal.encode_utf8(&mut [0; 4]));
+            number.push_str(fraction);
+        }
+    }
+
+    let sign = if negative { "-" } else { "" };
+    match style {
+        NumberFormatStyle::Decimal => crate::format!("{sign}{number}"),
+        NumberFormatStyle::Percent => {
+            crate::format!("{sign}{number}{}%", symbols.percent_separator)
+        }
+        NumberFormatStyle::Currency => {
+            let symbol = currency_symbol(currency);
+            if symbol.is_empty() {
+                crate::format!("{sign}{number}")
+            } else if symbols.currency_before {
+                // Separate currency codes, like in "CHF 1.00", but not symbols, like in "$1.00"
+                let space = if symbol.chars().all(char::is_alphabetic) { "\u{a0}" } else { "" };
+                crate::format!("{sign}{symbol}{space}{number}")
+            } else {
+                crate::format!("{sign}{number}\u{a0}{symbol}")
+            }
+        }
+    }
+}
+
+#[test]
+fn test_format_number() {
+    use crate::items::NumberFormatStyle;
+    let symbols = |l| {
+        let s = number_symbols(l);
+        (s.decimal, s.group)
+    };
+    assert_eq!(symbols("de_DE.UTF-8"), (',', "."));
+    assert_eq!(symbols("en-US"), ('.', ","));
+    assert_eq!(symbols("fr"), (',', "\u{202f}"));
+    assert_eq!(symbols("de-AT"), (',', "."));
+    assert_eq!(symbols("de-CH"), ('.', "\u{2019}"));
+    assert_eq!(symbols("fr_CH.UTF-8"), (',', "\u{2019}"));
+    assert_eq!(symbols("zh-Hant-TW"), ('.', ","));
+
+    let regional = |l, v| {
+        format_number_with_symbols(
+            &number_symbols(l),
+            v,
+            NumberFormatStyle::Decimal,
+            -1,
+            -1,
+            true,
+            "",
+        )
+    };
+    assert_eq!(regional("en-US", 12345678.5), "12,345,678.5");
+    assert_eq!(regional("en-IN", 12345678.5), "1,23,45,678.5");
+    assert_eq!(regional("en-IN", 123.), "123");
+    assert_eq!(regional("de-CH", 1234567.5), "1\u{2019}234\u{2019}567.5");
+    assert_eq!(regional("fr-CH", 1234.5), "1\u{2019}234,5");
+    assert_eq!(regional("en-US", f64::NEG_INFINITY), "-∞");
+
+    // Without context, the system locale is used, so only check the digits
+    let digits = |s: SharedString| {
+        s.chars().filter(|c| c.is_ascii_digit() || *c == '-').collect::<std::string::String>()
+    };
+    let decimal = |v, min, max, grouping| {
+        digits(format_number(v, NumberFormatStyle::Decimal, min, max, grouping, ""))
+    };
+    assert_eq!(decimal(1234567.891, -1, -1, true), "1234567891");
+    assert_eq!(decimal(1.5, 2, 4, true), "150");
+    assert_eq!(decimal(1.23456, -1, 2, true), "123");
+    assert_eq!(decimal(-0.0001, -1, -1, true), "0");
+    assert_eq!(decimal(-2., -1, -1, true), "-2");
+    assert_eq!(decimal(2.5, 3, 1, true), "25");
+    assert_eq!(digits(format_number(0.256, NumberFormatStyle::Percent, -1, -1, true, "")), "26");
+    assert_eq!(digits(format_number(3., NumberFormatStyle::Currency, -1, -1, true, "USD")), "300");
+    assert_eq!(format_number(f64::NAN, NumberFormatStyle::Decimal, -1, -1, true, ""), "NaN");
+}
+
 /// Error type returned from the [`select_bundled_translation`] function.
 #[derive(Debug)]
 pub enum SelectBundledTranslationError {
@@ -460,6 +460,10 @@ pub struct WindowInner {
     had_popup_on_press: Cell<bool>,
     close_requested: Callback<(), CloseRequestResponse>,
     click_state: ClickState,
+    pub(crate) touch_state: crate::input::TouchState,
+    /// The data being dragged, while a drag and drop operation is in progress.
+    /// The position of the event is not used.
+    pub(crate) drag_data: RefCell<Option<crate::items::DropEvent>>,
     pub(crate) ctx: once_cell::unsync::Lazy<crate::SlintContext>,
 }
 
@@ -518,6 +522,8 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             had_popup_on_press: Default::default(),
             close_requested: Default::default(),
             click_state: ClickState::default(),
+            touch_state: Default::default(),
+            drag_data: Default::default(),
             prevent_focus_change: Default::default(),
             // The ctx is lazy so that a Window can be initialized before the backend.
             // (for example in test_empty_window)
@@ -587,6 +593,8 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
 
         let pressed_event = matches!(event, MouseEvent::Pressed { .. });
         let released_event = matches!(event, MouseEvent::Released { .. });
+        let drag_ended = matches!(event, MouseEvent::Released { .. } | MouseEvent::Exit)
+            && self.drag_data.borrow().is_some();
 
         let window_adapter = self.window_adapter();
         let mut mouse_input_state = self.mouse_input_state.take();
@@ -649,6 +657,14 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         mouse_input_state = if let Some(mut event) =
             crate::input::handle_mouse_grab(event, &window_adapter, &mut mouse_input_state)
         {
+            if self.drag_data.borrow().is_some() {
+                // While dragging, the pointer events are delivered to the drop areas
+                event = match event {
+                    MouseEvent::Moved { position } => MouseEvent::DragMove { position },
+                    MouseEvent::Released { position, .. } => MouseEvent::Drop { position },
+                    event => event,
+                };
+            }
             let mut item_tree = self.component.borrow().upgrade();
             let mut offset = LogicalPoint::default();
             let mut menubar_item = None;
@@ -725,6 +741,10 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
 
         self.mouse_input_state.set(mouse_input_state);
 
+        if drag_ended {
+            self.drag_data.take();
+        }
+
         if let Some(popup_id) = popup_to_close {
             WindowInner::from_pub(root_adapter.window()).close_popup(popup_id);
         }
@@ -11,7 +11,7 @@
 use i_slint_core::window::WindowInner;
 use i_slint_core::{PathData, SharedVector};
 use smol_str::SmolStr;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::future::Future;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
@@ -22,6 +22,7 @@
 pub use i_slint_core::api::*;
 // keep in sync with api/rs/slint/lib.rs
 pub use i_slint_backend_selector::api::*;
+pub use i_slint_core::date_time::DateTime;
 pub use i_slint_core::graphics::{
     Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
 };
@@ -53,6 +54,10 @@ pub enum ValueType {
     Brush,
     /// Correspond to `image` type in .slint.
     Image,
+    /// Correspond to a map type such as `{[string]: int}` in .slint
+    Map,
+    /// Correspond to the `date-time` type in .slint
+    DateTime,
     /// The type is not a public type but something internal.
     #[doc(hidden)]
     Other = -1,
@@ -77,6 +82,8 @@ fn from(ty: LangType) -> Self {
             LangType::Struct { .. } => Self::Struct,
             LangType::Void => Self::Void,
             LangType::Image => Self::Image,
+            LangType::Map(_) => Self::Map,
+            LangType::DateTime => Self::DateTime,
             _ => Self::Other,
         }
     }
@@ -130,6 +137,10 @@ pub enum Value {
     #[doc(hidden)]
     /// Correspond to the `component-factory` type in .slint
     ComponentFactory(ComponentFactory) = 12,
+    /// Correspond to a map type such as `{[string]: int}` in .slint
+    Map(BTreeMap<SharedString, Value>) = 13,
+    /// Correspond to the `date-time` type in .slint
+    DateTime(DateTime) = 14,
 }
 
 impl Value {
@@ -144,6 +155,8 @@ pub fn value_type(&self) -> ValueType {
             Value::Struct(_) => ValueType::Struct,
             Value::Brush(_) => ValueType::Brush,
             Value::Image(_) => ValueType::Image,
+            Value::Map(_) => ValueType::Map,
+            Value::DateTime(_) => ValueType::DateTime,
             _ => ValueType::Other,
         }
     }
@@ -175,6 +188,8 @@ fn eq(&self, other: &Self) -> bool {
             Value::ComponentFactory(lhs) => {
                 matches!(other, Value::ComponentFactory(rhs) if lhs == rhs)
             }
+            Value::Map(lhs) => matches!(other, Value::Map(rhs) if lhs == rhs),
+            Value::DateTime(lhs) => matches!(other, Value::DateTime(rhs) if lhs == rhs),
         }
     }
 }
@@ -199,6 +214,8 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Value::EnumerationValue(n, v) => write!(f, "Value::EnumerationValue({n:?}, {v:?})"),
             Value::LayoutCache(v) => write!(f, "Value::LayoutCache({v:?})"),
             Value::ComponentFactory(factory) => write!(f, "Value::ComponentFactory({factory:?})"),
+            Value::Map(m) => write!(f, "Value::Map({m:?})"),
+            Value::DateTime(d) => write!(f, "Value::DateTime({d:?})"),
         }
     }
 }
@@ -241,6 +258,8 @@ fn try_from(v: Value) -> Result<$ty, Self::Error> {
 declare_value_conversion!(EasingCurve => [i_slint_core::animations::EasingCurve]);
 declare_value_conversion!(LayoutCache => [SharedVector<f32>] );
 declare_value_conversion!(ComponentFactory => [ComponentFactory] );
+declare_value_conversion!(Map => [BTreeMap<SharedString, Value>] );
+declare_value_conversion!(DateTime => [DateTime] );
 
 /// Implement From / TryFrom for Value that convert a `struct` to/from `Value::Struct`
 macro_rules! declare_value_struct_conversion {
@@ -1159,6 +1178,17 @@ pub fn global_functions(&self, global_name: &str) -> Option<impl Iterator<Item =
         })
     }
 
+    /// Returns a [JSON Schema](https://json-schema.org) describing the public properties,
+    /// callbacks, functions and globals of this component, together with the structs and
+    /// enums they use.
+    ///
+    /// The schema validates the values accepted by [`crate::json::value_from_json`].
+    /// See [`crate::json::component_json_schema`] for the layout.
+    #[cfg(feature = "internal-json")]
+    pub fn json_schema(&self) -> serde_json::Value {
+        crate::json::component_json_schema(self)
+    }
+
     /// The name of this Component as written in the .slint file
     pub fn name(&self) -> &str {
         // We create here a 'static guard, because unfortunately the returned type would be restricted to the guard lifetime
@@ -1495,6 +1525,28 @@ pub fn invoke_global(
         }
     }
 
+    /// Save the values of all the public properties that can be set, in this component and in
+    /// its exported globals, as a JSON object.
+    ///
+    /// The state can be restored later with [`Self::restore_state`].
+    /// See [`crate::json::save_state`] for the format.
+    #[cfg(feature = "internal-json")]
+    pub fn save_state(&self) -> serde_json::Value {
+        crate::json::save_state(self)
+    }
+
+    /// Restore the values saved with [`Self::save_state`].
+    ///
+    /// The values that match a property are set even if others fail, and an error is returned
+    /// for each value that couldn't be restored.
+    #[cfg(feature = "internal-json")]
+    pub fn restore_state(
+        &self,
+        state: &serde_json::Value,
+    ) -> Result<(), Vec<crate::json::RestoreStateError>> {
+        crate::json::restore_state(self, state)
+    }
+
     /// Find all positions of the components which are pointed by a given source location.
     ///
     /// WARNING: this is not part of the public API
@@ -1786,7 +1838,7 @@ fn component_definition_properties2() {
         Err(SetPropertyError::NoSuchProperty)
     );
 
-    assert_eq!(instance.get_property("background"), Err(GetPropertyError::NoSuchProperty));
+    assert_eq!(instance.get_property("palette-background"), Err(GetPropertyError::NoSuchProperty));
     assert_eq!(instance.get_property("xx"), Err(GetPropertyError::NoSuchProperty));
 }
 
@@ -1965,6 +2017,81 @@ fn call_functions() {
     assert_eq!(instance.get_global_property("Gl", "q"), Ok(Value::String("Hello".into())));
 }
 
+#[test]
+fn runtime_style_selection() {
+    i_slint_backend_testing::init_no_event_loop();
+    let mut compiler = Compiler::default();
+    compiler.set_style("fluent,material".into());
+    let result = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+    import { Button, CheckBox, HorizontalBox, LineEdit, ListView, Palette, Slider, StyleMetrics, StyleSelector } from "std-widgets.slint";
+    export { StyleSelector }
+    export component Test inherits Window {
+        in-out property <bool> checked;
+        in-out property <ColorScheme> color-scheme <=> Palette.color-scheme;
+        out property <brush> palette-background: Palette.background;
+        out property <length> box-spacing: box.spacing;
+        out property <length> spacing: StyleMetrics.layout-spacing;
+        out property <length> button-height: button.preferred-height;
+        out property <float> slider-maximum: slider.maximum;
+        out property <bool> edit-has-focus: edit.has-focus;
+        public function focus-edit() { edit.focus(); }
+        button := Button { text: "Hello"; }
+        CheckBox { checked <=> root.checked; }
+        slider := Slider { }
+        edit := LineEdit { }
+        ListView { }
+        box := HorizontalBox { }
+    }"#
+            .into(),
+            "".into(),
+        ),
+    );
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    assert_eq!(
+        result.diagnostics().map(|d| d.message().to_string()).collect::<Vec<_>>(),
+        ["'ListView' can't switch styles at runtime and always uses the fluent style"]
+    );
+    let instance = result.component("Test").unwrap().create().unwrap();
+
+    let Ok(Value::Model(styles)) = instance.get_global_property("StyleSelector", "styles") else {
+        panic!("StyleSelector.styles is not a model")
+    };
+    assert_eq!(
+        styles.iter().collect::<Vec<_>>(),
+        [Value::String("fluent".into()), Value::String("material".into())]
+    );
+    assert_eq!(instance.get_property("spacing"), Ok(Value::Number(8.)));
+    assert_eq!(instance.get_property("box-spacing"), Ok(Value::Number(8.)));
+    let fluent_height = instance.get_property("button-height").unwrap();
+    let fluent_background = instance.get_property("palette-background").unwrap();
+    assert_eq!(instance.get_property("slider-maximum"), Ok(Value::Number(100.)));
+    // The function calls and the output properties are forwarded right away
+    instance.invoke("focus-edit", &[]).unwrap();
+    assert_eq!(instance.get_property("edit-has-focus"), Ok(Value::Bool(true)));
+
+    instance
+        .set_global_property("StyleSelector", "current-style", Value::String("material".into()))
+        .unwrap();
+    assert_eq!(instance.get_property("spacing"), Ok(Value::Number(16.)));
+    assert_eq!(instance.get_property("box-spacing"), Ok(Value::Number(16.)));
+    assert_ne!(instance.get_property("button-height").unwrap(), fluent_height);
+    assert_eq!(instance.get_property("slider-maximum"), Ok(Value::Number(100.)));
+    instance.set_property("checked", Value::Bool(true)).unwrap();
+    assert_eq!(instance.get_property("checked"), Ok(Value::Bool(true)));
+
+    // The color scheme is set on the palettes of all the styles
+    let material_background = instance.get_property("palette-background").unwrap();
+    let dark = Value::EnumerationValue("ColorScheme".into(), "dark".into());
+    instance.set_property("color-scheme", dark).unwrap();
+    assert_ne!(instance.get_property("palette-background").unwrap(), material_background);
+    instance
+        .set_global_property("StyleSelector", "current-style", Value::String("fluent".into()))
+        .unwrap();
+    assert_ne!(instance.get_property("palette-background").unwrap(), fluent_background);
+}
+
 #[test]
 fn component_definition_struct_properties() {
     i_slint_backend_testing::init_no_event_loop();
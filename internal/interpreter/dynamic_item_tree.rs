@@ -24,12 +24,12 @@
 use i_slint_core::items::{
     AccessibleRole, ItemRef, ItemVTable, PopupClosePolicy, PropertyAnimation,
 };
-use i_slint_core::layout::{BoxLayoutCellData, LayoutInfo, Orientation};
+use i_slint_core::layout::{BoxLayoutCellData, FlexboxLayoutCellData, LayoutInfo, Orientation};
 use i_slint_core::lengths::{LogicalLength, LogicalRect};
 use i_slint_core::menus::MenuFromItemTree;
-use i_slint_core::model::{ModelRc, RepeatedItemTree, Repeater};
+use i_slint_core::model::{Model, ModelRc, RepeatedItemTree, Repeater};
 use i_slint_core::platform::PlatformError;
-use i_slint_core::properties::{ChangeTracker, InterpolatedPropertyValue};
+use i_slint_core::properties::{ChangeTracker, InterpolatedPropertyValue, Keyframe};
 use i_slint_core::rtti::{self, AnimatedBindingKind, FieldOffset, PropertyInfo};
 use i_slint_core::slice::Slice;
 use i_slint_core::timers::Timer;
@@ -171,6 +171,59 @@ fn listview_layout(self: Pin<&Self>, offset_y: &mut LogicalLength) -> LogicalLen
     fn box_layout_data(self: Pin<&Self>, o: Orientation) -> BoxLayoutCellData {
         BoxLayoutCellData { constraint: self.borrow().as_ref().layout_info(o) }
     }
+
+    fn flexbox_layout_data(self: Pin<&Self>, o: Orientation) -> FlexboxLayoutCellData {
+        generativity::make_guard!(guard);
+        let s = self.unerase(guard);
+        let constraints = s.description.original.root_constraints.borrow();
+        let value = |nr: &Option<NamedReference>, default: f32| {
+            nr.as_ref().map_or(default, |nr| {
+                eval::load_property(s.borrow_instance(), &nr.element(), nr.name())
+                    .unwrap()
+                    .try_into()
+                    .unwrap()
+            })
+        };
+        FlexboxLayoutCellData {
+            constraint: self.borrow().as_ref().layout_info(o),
+            flex_grow: value(&constraints.flex_grow, 0.),
+            flex_shrink: value(&constraints.flex_shrink, 1.),
+            flex_basis: value(&constraints.flex_basis, -1.),
+        }
+    }
+
+    fn start_exit_transition(&self) -> core::time::Duration {
+        generativity::make_guard!(guard);
+        let s = self.unerase(guard);
+        let Some(exit) = s
+            .description
+            .original
+            .parent_element
+            .upgrade()
+            .and_then(|p| p.borrow().repeated.as_ref().and_then(|r| r.exit_transition.clone()))
+        else {
+            return core::time::Duration::ZERO;
+        };
+        let instance = s.borrow_instance();
+        for nr in &exit.frozen_properties {
+            // Safety: the frozen properties are lengths, which are stored as Property<f32>
+            let prop = unsafe { &*(get_property_ptr(nr, instance) as *const Property<f32>) };
+            prop.set(prop.get_internal());
+        }
+        crate::eval::store_property(
+            instance,
+            &exit.leaving.element(),
+            exit.leaving.name(),
+            Value::Bool(true),
+        )
+        .expect("cannot set the leaving property");
+        let duration: i64 =
+            crate::eval::load_property(instance, &exit.duration.element(), exit.duration.name())
+                .expect("missing exit duration")
+                .try_into()
+                .expect("exit duration not the right type");
+        core::time::Duration::from_millis(duration.max(0) as u64)
+    }
 }
 
 impl ItemTree for ErasedItemTreeBox {
@@ -767,23 +820,15 @@ fn ensure_repeater_updated<'id>(
         );
         instance
     };
-    if let Some(lv) = &rep_in_comp
-        .item_tree_to_repeat
-        .original
-        .parent_element
-        .upgrade()
-        .unwrap()
-        .borrow()
-        .repeated
-        .as_ref()
-        .unwrap()
-        .is_listview
-    {
-        let assume_property_logical_length =
-            |prop| unsafe { Pin::new_unchecked(&*(prop as *const Property<LogicalLength>)) };
-        let get_prop = |nr: &NamedReference| -> LogicalLength {
-            eval::load_property(instance_ref, &nr.element(), nr.name()).unwrap().try_into().unwrap()
-        };
+    let parent_element = rep_in_comp.item_tree_to_repeat.original.parent_element.upgrade().unwrap();
+    let parent_element = parent_element.borrow();
+    let repeated = parent_element.repeated.as_ref().unwrap();
+    let assume_property_logical_length =
+        |prop| unsafe { Pin::new_unchecked(&*(prop as *const Property<LogicalLength>)) };
+    let get_prop = |nr: &NamedReference| -> LogicalLength {
+        eval::load_property(instance_ref, &nr.element(), nr.name()).unwrap().try_into().unwrap()
+    };
+    if let Some(lv) = &repeated.is_listview {
         repeater.ensure_updated_listview(
             init,
             assume_property_logical_length(get_property_ptr(&lv.viewport_width, instance_ref)),
@@ -792,6 +837,21 @@ fn ensure_repeater_updated<'id>(
             get_prop(&lv.listview_width),
             assume_property_logical_length(get_property_ptr(&lv.listview_height, instance_ref)),
         );
+    } else if let Some(gv) = &repeated.is_gridview {
+        let columns: i32 =
+            eval::load_property(instance_ref, &gv.columns.element(), gv.columns.name())
+                .unwrap()
+                .try_into()
+                .unwrap();
+        repeater.ensure_updated_gridview(
+            init,
+            assume_property_logical_length(get_property_ptr(&gv.viewport_height, instance_ref)),
+            get_prop(&gv.viewport_y),
+            get_prop(&gv.gridview_height),
+            columns,
+            get_prop(&gv.cell_height),
+            get_prop(&gv.spacing),
+        );
     } else {
         repeater.ensure_updated(init);
     }
@@ -982,12 +1042,16 @@ fn generate_rtti() -> HashMap<&'static str, Rc<ItemRTTI>> {
             rtti_for::<ClippedImage>(),
             rtti_for::<ComplexText>(),
             rtti_for::<SimpleText>(),
+            rtti_for::<StyledText>(),
             rtti_for::<Rectangle>(),
             rtti_for::<BasicBorderRectangle>(),
             rtti_for::<BorderRectangle>(),
             rtti_for::<TouchArea>(),
             rtti_for::<FocusScope>(),
             rtti_for::<SwipeGestureHandler>(),
+            rtti_for::<PinchGestureHandler>(),
+            rtti_for::<DragArea>(),
+            rtti_for::<DropArea>(),
             rtti_for::<Path>(),
             rtti_for::<Flickable>(),
             rtti_for::<WindowItem>(),
@@ -1214,6 +1278,7 @@ fn property_info_for_type(
             Type::Color => animated_property_info::<Color>(),
             Type::Brush => animated_property_info::<Brush>(),
             Type::Duration => animated_property_info::<i64>(),
+            Type::DateTime => property_info::<i_slint_core::date_time::DateTime>(),
             Type::Angle => animated_property_info::<f32>(),
             Type::PhysicalLength => animated_property_info::<f32>(),
             Type::LogicalLength => animated_property_info::<f32>(),
@@ -1228,6 +1293,7 @@ fn property_info_for_type(
             }
             Type::Struct(_) => property_info::<Value>(),
             Type::Array(_) => property_info::<Value>(),
+            Type::Map(_) => property_info::<Value>(),
             Type::Easing => property_info::<i_slint_core::animations::EasingCurve>(),
             Type::Percent => animated_property_info::<f32>(),
             Type::Enumeration(e) => {
@@ -1395,12 +1461,13 @@ macro_rules! match_enum_type {
 pub fn animation_for_property(
     component: InstanceRef,
     animation: &Option<i_slint_compiler::object_tree::PropertyAnimation>,
-) -> AnimatedBindingKind {
+) -> AnimatedBindingKind<Value> {
     match animation {
         Some(i_slint_compiler::object_tree::PropertyAnimation::Static(anim_elem)) => {
-            AnimatedBindingKind::Animation(eval::new_struct_with_bindings(
-                &anim_elem.borrow().bindings,
-                &mut eval::EvalLocalContext::from_component_instance(component),
+            let mut context = eval::EvalLocalContext::from_component_instance(component);
+            AnimatedBindingKind::Animation((
+                eval::new_struct_with_bindings(&anim_elem.borrow().bindings, &mut context),
+                eval_keyframes(anim_elem, &mut context),
             ))
         }
         Some(i_slint_compiler::object_tree::PropertyAnimation::Transition {
@@ -1412,7 +1479,7 @@ pub fn animation_for_property(
             let animations = animations.clone();
             let state_ref = state_ref.clone();
             AnimatedBindingKind::Transition(Box::new(
-                move || -> (PropertyAnimation, i_slint_core::animations::Instant) {
+                move || -> (PropertyAnimation, i_slint_core::animations::Instant, Vec<Keyframe<Value>>) {
                     generativity::make_guard!(guard);
                     let component = unsafe {
                         InstanceRef::from_pin_ref(
@@ -1441,6 +1508,7 @@ pub fn animation_for_property(
                                         &mut context,
                                     ),
                                     state_info.change_time,
+                                    eval_keyframes(&a.animation, &mut context),
                                 );
                             }
                             _ => {}
@@ -1454,6 +1522,30 @@ pub fn animation_for_property(
     }
 }
 
+/// Evaluates the `keyframes` binding of an animation element
+fn eval_keyframes(
+    anim_elem: &ElementRc,
+    context: &mut eval::EvalLocalContext,
+) -> Vec<Keyframe<Value>> {
+    let Some(keyframes) = anim_elem.borrow().bindings.get("keyframes").cloned() else {
+        return Vec::new();
+    };
+    let Value::Model(keyframes) = eval::eval_expression(&keyframes.borrow(), context) else {
+        return Vec::new();
+    };
+    keyframes
+        .iter()
+        .filter_map(|keyframe| {
+            let Value::Struct(keyframe) = keyframe else { return None };
+            Some(Keyframe {
+                position: f32::try_from(keyframe.get_field("at")?.clone()).ok()? / 100.,
+                value: keyframe.get_field("value")?.clone(),
+                easing: keyframe.get_field("easing")?.clone().try_into().ok()?,
+            })
+        })
+        .collect()
+}
+
 fn make_callback_eval_closure(
     expr: Expression,
     self_weak: &ErasedItemTreeBoxWeak,
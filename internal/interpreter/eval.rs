@@ -5,15 +5,15 @@
 use crate::dynamic_item_tree::{CallbackHandler, InstanceRef};
 use core::pin::Pin;
 use corelib::graphics::{GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush};
-use corelib::items::{ColorScheme, ItemRef, MenuEntry, PropertyAnimation};
+use corelib::items::{ColorScheme, ItemRef, MenuEntry};
 use corelib::menus::{Menu, MenuFromItemTree, MenuVTable};
 use corelib::model::{Model, ModelExt, ModelRc, VecModel};
-use corelib::rtti::AnimatedBindingKind;
+use corelib::rtti::{AnimatedBindingKind, AnimationWithKeyframes};
 use corelib::window::WindowInner;
-use corelib::{Brush, Color, PathData, SharedString, SharedVector};
+use corelib::{date_time::DateTime, Brush, Color, PathData, SharedString, SharedVector};
 use i_slint_compiler::expression_tree::{
-    BuiltinFunction, Callable, EasingCurve, Expression, MinMaxOp, Path as ExprPath,
-    PathElement as ExprPathElement,
+    ArrayTransformOp, BuiltinFunction, Callable, EasingCurve, Expression, MinMaxOp,
+    Path as ExprPath, PathElement as ExprPathElement,
 };
 use i_slint_compiler::langtype::Type;
 use i_slint_compiler::namedreference::NamedReference;
@@ -31,13 +31,13 @@ fn set(
         &self,
         item: Pin<ItemRef>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<AnimationWithKeyframes<Value>>,
     ) -> Result<(), ()>;
     fn set_binding(
         &self,
         item: Pin<ItemRef>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     );
     fn offset(&self) -> usize;
 
@@ -56,7 +56,7 @@ fn set(
         &self,
         item: Pin<ItemRef>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: Option<AnimationWithKeyframes<Value>>,
     ) -> Result<(), ()> {
         (*self).set(ItemRef::downcast_pin(item).unwrap(), value, animation)
     }
@@ -64,7 +64,7 @@ fn set_binding(
         &self,
         item: Pin<ItemRef>,
         binding: Box<dyn Fn() -> Value>,
-        animation: AnimatedBindingKind,
+        animation: AnimatedBindingKind<Value>,
     ) {
         (*self).set_binding(ItemRef::downcast_pin(item).unwrap(), binding, animation).unwrap();
     }
@@ -182,6 +182,18 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 }
             }
         }
+        Expression::MapIndex { map, key } => {
+            let map = eval_expression(map, local_context);
+            let key = eval_expression(key, local_context);
+            match (map, key) {
+                (Value::Map(map), Value::String(key)) => {
+                    map.get(&key).cloned().unwrap_or_else(|| default_value_for_type(&expression.ty()))
+                }
+                _ => {
+                    Value::Void
+                }
+            }
+        }
         Expression::Cast { from, to } => {
             let v = eval_expression(from, local_context);
             match (v, to) {
@@ -190,6 +202,9 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                     Value::String(i_slint_core::string::shared_string_from_number(n))
                 }
                 (Value::Number(n), Type::Color) => Color::from_argb_encoded(n as u32).into(),
+                (Value::Number(n), Type::DateTime) => {
+                    Value::DateTime(DateTime::from_timestamp_millis(n as i64))
+                }
                 (Value::Brush(brush), Type::Color) => brush.color().into(),
                 (v, _) => v,
             }
@@ -322,6 +337,12 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 .map(|(k, v)| (k.to_string(), eval_expression(v, local_context)))
                 .collect(),
         ),
+        Expression::Map { values, .. } => Value::Map(
+            values
+                .iter()
+                .map(|(k, v)| (SharedString::from(k.as_str()), eval_expression(v, local_context)))
+                .collect(),
+        ),
         Expression::PathData(data)  => {
             Value::PathData(convert_path(data, local_context))
         }
@@ -344,6 +365,9 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             EasingCurve::CubicBezier(a, b, c, d) => {
                 corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
+            EasingCurve::Spring(k, d, m) => {
+                corelib::animations::EasingCurve::Spring([*k, *d, *m])
+            }
         }),
         Expression::LinearGradient{angle, stops} => {
             let angle = eval_expression(angle, local_context);
@@ -370,6 +394,56 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             }
             local_context.return_value.clone().unwrap()
         }
+        Expression::ForLoop { array, variable, index_variable, body } => {
+            let element_ty = match array.ty() {
+                Type::Array(ty) => (*ty).clone(),
+                _ => Type::Invalid,
+            };
+            if let Value::Model(model) = eval_expression(array, local_context) {
+                model.model_tracker().track_row_count_changes();
+                for index in 0..model.row_count() {
+                    let data = model.row_data_tracked(index).unwrap_or_else(|| default_value_for_type(&element_ty));
+                    local_context.local_variables.insert(variable.clone(), data);
+                    if let Some(index_variable) = index_variable {
+                        local_context.local_variables.insert(index_variable.clone(), Value::Number(index as f64));
+                    }
+                    // The body evaluates to false when the loop must stop
+                    let keep_going = eval_expression(body, local_context);
+                    if local_context.return_value.is_some() || keep_going != Value::Bool(true) {
+                        break;
+                    }
+                }
+            }
+            Value::Void
+        }
+        Expression::ArrayTransform { op, array, variable, expression } => {
+            let Value::Model(model) = eval_expression(array, local_context) else {
+                panic!("ArrayTransform on something that is not an array")
+            };
+            let function = array_element_function(variable, expression, local_context);
+            // The function is called from within the binding once, so that the properties it
+            // reads are tracked as dependencies of the binding.
+            Value::Model(match op {
+                ArrayTransformOp::Filter => ModelRc::new(corelib::model::FilterModel::new(model, move |data| function(data) == Value::Bool(true))),
+                ArrayTransformOp::Map => {
+                    let model = ModelRc::new(corelib::model::MapModel::new(model, move |data| function(&data)));
+                    for row in 0..model.row_count() {
+                        model.row_data(row);
+                    }
+                    model
+                }
+                ArrayTransformOp::SortBy => {
+                    let model = ModelRc::new(corelib::model::SortModel::new(model, move |a, b| match (function(a), function(b)) {
+                        (Value::Number(a), Value::Number(b)) => a.total_cmp(&b),
+                        (Value::String(a), Value::String(b)) => a.cmp(&b),
+                        _ => core::cmp::Ordering::Equal,
+                    }));
+                    model.row_data(0);
+                    model
+                }
+            })
+        }
+        Expression::Break | Expression::Continue => panic!("break and continue should have been lowered by the remove_return pass"),
         Expression::LayoutCacheAccess { layout_cache_prop, index, repeater_index } => {
             let cache = load_property_helper(&ComponentInstance::InstanceRef(local_context.component_instance), &layout_cache_prop.element(), layout_cache_prop.name()).unwrap();
             if let Value::LayoutCache(cache) = cache {
@@ -402,6 +476,39 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
     }
 }
 
+/// Returns a function that evaluates `expression` for an element of an array, stored in the local
+/// `variable`. The function only keeps a weak reference to the component.
+fn array_element_function(
+    variable: &SmolStr,
+    expression: &Expression,
+    local_context: &EvalLocalContext,
+) -> impl Fn(&Value) -> Value + 'static {
+    let self_weak = local_context.component_instance.self_weak().get().unwrap().clone();
+    let local_variables = local_context.local_variables.clone();
+    let function_arguments = local_context.function_arguments.clone();
+    let variable = variable.clone();
+    let expression = expression.clone();
+    move |data| {
+        let Some(self_rc) = self_weak.upgrade() else { return Value::Void };
+        generativity::make_guard!(guard);
+        let instance = self_rc.unerase(guard);
+        let mut local_context = EvalLocalContext {
+            local_variables: local_variables.clone(),
+            function_arguments: function_arguments.clone(),
+            component_instance: instance.borrow_instance(),
+            return_value: None,
+        };
+        local_context.local_variables.insert(variable.clone(), data.clone());
+        eval_expression(&expression, &mut local_context)
+    }
+}
+
+/// Iterate over the rows of the model, registering a dependency on the row count and on each row
+fn tracked_rows(model: &ModelRc<Value>) -> impl Iterator<Item = Value> + '_ {
+    model.model_tracker().track_row_count_changes();
+    (0..model.row_count()).filter_map(|i| model.row_data_tracked(i))
+}
+
 fn call_builtin_function(
     f: BuiltinFunction,
     arguments: &[Expression],
@@ -940,6 +1047,72 @@ fn call_builtin_function(
                 panic!("Argument not a string");
             }
         }
+        BuiltinFunction::StringTrim => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to StringTrim")
+            }
+            if let Value::String(s) = eval_expression(&arguments[0], local_context) {
+                Value::String(s.trim().into())
+            } else {
+                panic!("Argument not a string");
+            }
+        }
+        BuiltinFunction::StringContains
+        | BuiltinFunction::StringStartsWith
+        | BuiltinFunction::StringEndsWith
+        | BuiltinFunction::StringSplit
+        | BuiltinFunction::StringMatches => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to {f:?}")
+            }
+            let (Value::String(s), Value::String(other)) = (
+                eval_expression(&arguments[0], local_context),
+                eval_expression(&arguments[1], local_context),
+            ) else {
+                panic!("Arguments not strings");
+            };
+            match f {
+                BuiltinFunction::StringContains => Value::Bool(s.contains(other.as_str())),
+                BuiltinFunction::StringStartsWith => Value::Bool(s.starts_with(other.as_str())),
+                BuiltinFunction::StringEndsWith => Value::Bool(s.ends_with(other.as_str())),
+                BuiltinFunction::StringSplit => {
+                    Value::Model(ModelRc::new(corelib::model::SharedVectorModel::from(
+                        corelib::string::shared_string_split(&s, &other)
+                            .iter()
+                            .cloned()
+                            .map(Value::String)
+                            .collect::<SharedVector<_>>(),
+                    )))
+                }
+                _ => Value::Bool(corelib::string::shared_string_matches(&s, &other)),
+            }
+        }
+        BuiltinFunction::StringReplace => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to StringReplace")
+            }
+            match (
+                eval_expression(&arguments[0], local_context),
+                eval_expression(&arguments[1], local_context),
+                eval_expression(&arguments[2], local_context),
+            ) {
+                (Value::String(s), Value::String(from), Value::String(to)) => {
+                    Value::String(corelib::string::shared_string_replace(&s, &from, &to))
+                }
+                _ => panic!("Arguments not strings"),
+            }
+        }
+        BuiltinFunction::StringSubstring => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to StringSubstring")
+            }
+            let Value::String(s) = eval_expression(&arguments[0], local_context) else {
+                panic!("First argument not a string");
+            };
+            let start: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let length: i32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(corelib::string::shared_string_substring(&s, start, length))
+        }
         BuiltinFunction::ColorRgbaStruct => {
             if arguments.len() != 1 {
                 panic!("internal error: incorrect argument count to ColorRGBAComponents")
@@ -1092,6 +1265,78 @@ fn call_builtin_function(
                 }
             }
         }
+        BuiltinFunction::ArraySlice => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to ArraySlice")
+            }
+            let Value::Model(model) = eval_expression(&arguments[0], local_context) else {
+                panic!("First argument not an array");
+            };
+            let start: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let end: i32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            model.model_tracker().track_row_count_changes();
+            let end = end.clamp(0, model.row_count() as i32) as usize;
+            let start = (start.max(0) as usize).min(end);
+            Value::Model(ModelRc::new(corelib::model::VecModel::from(
+                (start..end).filter_map(|i| model.row_data_tracked(i)).collect::<Vec<_>>(),
+            )))
+        }
+        BuiltinFunction::ArrayConcat => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to ArrayConcat")
+            }
+            match (
+                eval_expression(&arguments[0], local_context),
+                eval_expression(&arguments[1], local_context),
+            ) {
+                (Value::Model(first), Value::Model(second)) => {
+                    Value::Model(ModelRc::new(corelib::model::VecModel::from(
+                        tracked_rows(&first).chain(tracked_rows(&second)).collect::<Vec<_>>(),
+                    )))
+                }
+                _ => panic!("Arguments not arrays"),
+            }
+        }
+        BuiltinFunction::ArrayIndexOf | BuiltinFunction::ArrayContains => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to ArrayIndexOf")
+            }
+            let Value::Model(model) = eval_expression(&arguments[0], local_context) else {
+                panic!("First argument not an array");
+            };
+            let value = eval_expression(&arguments[1], local_context);
+            let index = tracked_rows(&model).position(|v| v == value);
+            if matches!(f, BuiltinFunction::ArrayContains) {
+                Value::Bool(index.is_some())
+            } else {
+                Value::Number(index.map_or(-1., |i| i as f64))
+            }
+        }
+        BuiltinFunction::MapHasKey => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to MapHasKey")
+            }
+            match (
+                eval_expression(&arguments[0], local_context),
+                eval_expression(&arguments[1], local_context),
+            ) {
+                (Value::Map(map), Value::String(key)) => Value::Bool(map.contains_key(&key)),
+                _ => panic!("Argument not a map and a string"),
+            }
+        }
+        BuiltinFunction::MapKeys => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to MapKeys")
+            }
+            match eval_expression(&arguments[0], local_context) {
+                Value::Map(map) => {
+                    Value::Model(ModelRc::new(corelib::model::SharedVectorModel::from(
+                        map.into_keys().map(Value::String).collect::<SharedVector<_>>(),
+                    )))
+                }
+                _ => panic!("First argument not a map"),
+            }
+        }
         BuiltinFunction::Rgb => {
             let r: i32 = eval_expression(&arguments[0], local_context).try_into().unwrap();
             let g: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
@@ -1201,6 +1446,26 @@ fn call_builtin_function(
 
             Value::String(i_slint_core::date_time::format_date(&f, d, m, y))
         }
+        BuiltinFunction::FormatNumber => {
+            let value: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let style: i_slint_core::items::NumberFormatStyle =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let min_fraction: i32 =
+                eval_expression(&arguments[2], local_context).try_into().unwrap();
+            let max_fraction: i32 =
+                eval_expression(&arguments[3], local_context).try_into().unwrap();
+            let grouping: bool = eval_expression(&arguments[4], local_context).try_into().unwrap();
+            let currency: SharedString =
+                eval_expression(&arguments[5], local_context).try_into().unwrap();
+            Value::String(i_slint_core::translations::format_number(
+                value,
+                style,
+                min_fraction,
+                max_fraction,
+                grouping,
+                &currency,
+            ))
+        }
         BuiltinFunction::DateNow => Value::Model(ModelRc::new(VecModel::from(
             i_slint_core::date_time::date_now()
                 .into_iter()
@@ -1226,6 +1491,85 @@ fn call_builtin_function(
                     .unwrap_or_default(),
             ))
         }
+        BuiltinFunction::DateTimeNow => Value::DateTime(DateTime::now()),
+        BuiltinFunction::ParseDateTime => {
+            let text: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let format: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::DateTime(DateTime::parse(text.as_str(), format.as_str()).unwrap_or_default())
+        }
+        BuiltinFunction::FormatDateTime => {
+            let date_time: DateTime =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let format: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::String(date_time.format(format.as_str()))
+        }
+        BuiltinFunction::DateTimeYear
+        | BuiltinFunction::DateTimeMonth
+        | BuiltinFunction::DateTimeDay
+        | BuiltinFunction::DateTimeHour
+        | BuiltinFunction::DateTimeMinute
+        | BuiltinFunction::DateTimeSecond
+        | BuiltinFunction::DateTimeWeekday => {
+            let date_time: DateTime =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Number(match f {
+                BuiltinFunction::DateTimeYear => date_time.year() as f64,
+                BuiltinFunction::DateTimeMonth => date_time.month() as f64,
+                BuiltinFunction::DateTimeDay => date_time.day() as f64,
+                BuiltinFunction::DateTimeHour => date_time.hour() as f64,
+                BuiltinFunction::DateTimeMinute => date_time.minute() as f64,
+                BuiltinFunction::DateTimeSecond => date_time.second() as f64,
+                _ => date_time.weekday() as f64,
+            })
+        }
+        BuiltinFunction::DateTimeUtcOffset => {
+            let date_time: DateTime =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Number(date_time.utc_offset() as f64 * 1000.)
+        }
+        BuiltinFunction::DateTimeWithUtcOffset => {
+            let date_time: DateTime =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let offset: i64 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::DateTime(date_time.with_utc_offset((offset / 1000) as i32))
+        }
+        BuiltinFunction::DateTimeToLocal => {
+            let date_time: DateTime =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::DateTime(date_time.to_local())
+        }
+        BuiltinFunction::DateTimeWithDate | BuiltinFunction::DateTimeWithTime => {
+            let date_time: DateTime =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let mut arg = |i: usize| -> i32 {
+                eval_expression(&arguments[i], local_context).try_into().unwrap()
+            };
+            let (a, b, c) = (arg(1), arg(2), arg(3));
+            let (b, c) = (u32::try_from(b).ok(), u32::try_from(c).ok());
+            let result = if matches!(f, BuiltinFunction::DateTimeWithDate) {
+                b.zip(c).and_then(|(b, c)| date_time.with_date(a, b, c))
+            } else {
+                u32::try_from(a)
+                    .ok()
+                    .zip(b.zip(c))
+                    .and_then(|(a, (b, c))| date_time.with_time(a, b, c))
+            };
+            Value::DateTime(result.unwrap_or(date_time))
+        }
+        BuiltinFunction::DateTimeAdd => {
+            let date_time: DateTime =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let duration: i64 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::DateTime(date_time.add_millis(duration))
+        }
+        BuiltinFunction::DateTimeDiff => {
+            let lhs: DateTime = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let rhs: DateTime = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Number(lhs.millis_since(&rhs) as f64)
+        }
         BuiltinFunction::TextInputFocused => Value::Bool(
             local_context.component_instance.access_window(|window| window.text_input_focused())
                 as _,
@@ -1456,6 +1800,14 @@ fn eval_assignment(lhs: &Expression, op: char, rhs: Value, local_context: &mut E
                 }
             }
         }
+        Expression::ReadLocalVariable { name, .. } => {
+            let value = if op == '=' {
+                rhs
+            } else {
+                eval(local_context.local_variables.get(name).unwrap().clone())
+            };
+            local_context.local_variables.insert(name.clone(), value);
+        }
         Expression::StructFieldAccess { base, name } => {
             if let Value::Struct(mut o) = eval_expression(base, local_context) {
                 let mut r = o.get_field(name).unwrap().clone();
@@ -1639,6 +1991,7 @@ fn check_value_type(value: &Value, ty: &Type) -> bool {
         Type::Int32 => matches!(value, Value::Number(_)),
         Type::String => matches!(value, Value::String(_)),
         Type::Color => matches!(value, Value::Brush(_)),
+        Type::DateTime => matches!(value, Value::DateTime(_)),
         Type::UnitProduct(_)
         | Type::Duration
         | Type::PhysicalLength
@@ -1657,6 +2010,9 @@ fn check_value_type(value: &Value, ty: &Type) -> bool {
         Type::Array(inner) => {
             matches!(value, Value::Model(m) if m.iter().all(|v| check_value_type(&v, inner)))
         }
+        Type::Map(inner) => {
+            matches!(value, Value::Map(m) if m.values().all(|v| check_value_type(v, inner)))
+        }
         Type::Struct(s) => {
             matches!(value, Value::Struct(str) if str.iter().all(|(k, v)| s.fields.get(k).is_some_and(|ty| check_value_type(v, ty))))
         }
@@ -1938,6 +2294,7 @@ pub fn default_value_for_type(ty: &Type) -> Value {
         Type::Duration | Type::Angle | Type::PhysicalLength | Type::LogicalLength | Type::Rem => {
             Value::Number(0.)
         }
+        Type::DateTime => Value::DateTime(Default::default()),
         Type::Image => Value::Image(Default::default()),
         Type::Bool => Value::Bool(false),
         Type::Callback { .. } => Value::Void,
@@ -1948,6 +2305,7 @@ pub fn default_value_for_type(ty: &Type) -> Value {
                 .collect::<Struct>(),
         ),
         Type::Array(_) | Type::Model => Value::Model(Default::default()),
+        Type::Map(_) => Value::Map(Default::default()),
         Type::Percent => Value::Number(0.),
         Type::Enumeration(e) => Value::EnumerationValue(
             e.name.to_string(),
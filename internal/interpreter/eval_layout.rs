@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use crate::dynamic_item_tree::InstanceRef;
+use crate::dynamic_item_tree::{ErasedItemTreeBox, InstanceRef};
 use crate::eval::{self, EvalLocalContext};
 use crate::Value;
 use i_slint_compiler::expression_tree::Expression;
@@ -9,7 +9,8 @@
 use i_slint_compiler::layout::{Layout, LayoutConstraints, LayoutGeometry, Orientation};
 use i_slint_compiler::namedreference::NamedReference;
 use i_slint_compiler::object_tree::ElementRc;
-use i_slint_core::items::{DialogButtonRole, ItemRc};
+use i_slint_core::item_tree::ItemTreeVTable;
+use i_slint_core::items::{DialogButtonRole, FlexDirection, ItemRc};
 use i_slint_core::layout::{self as core_layout};
 use i_slint_core::model::RepeatedItemTree;
 use i_slint_core::slice::Slice;
@@ -64,6 +65,9 @@ pub(crate) fn compute_layout_info(
             }
             .into()
         }
+        Layout::FlexboxLayout(flexbox_layout) => {
+            flexbox_layout_solve_or_info(flexbox_layout, orientation, false, component, &expr_eval)
+        }
     }
 }
 
@@ -129,6 +133,9 @@ pub(crate) fn solve_layout(
             )
             .into()
         }
+        Layout::FlexboxLayout(flexbox_layout) => {
+            flexbox_layout_solve_or_info(flexbox_layout, orientation, true, component, &expr_eval)
+        }
     }
 }
 
@@ -185,23 +192,7 @@ fn box_layout_data(
     let mut cells = Vec::with_capacity(box_layout.elems.len());
     for cell in &box_layout.elems {
         if cell.element.borrow().repeated.is_some() {
-            generativity::make_guard!(guard);
-            let rep = crate::dynamic_item_tree::get_repeater_by_name(
-                component,
-                cell.element.borrow().id.as_str(),
-                guard,
-            );
-            rep.0.as_ref().ensure_updated(|| {
-                let instance = crate::dynamic_item_tree::instantiate(
-                    rep.1.clone(),
-                    component.self_weak().get().cloned(),
-                    None,
-                    None,
-                    Default::default(),
-                );
-                instance
-            });
-            let component_vec = rep.0.as_ref().instances_vec();
+            let component_vec = repeater_instances(&cell.element, component);
             if let Some(ri) = repeater_indices.as_mut() {
                 ri.push(cells.len() as _);
                 ri.push(component_vec.len() as _);
@@ -237,6 +228,163 @@ fn box_layout_data(
     (cells, alignment)
 }
 
+/// Solve the FlexboxLayout in the given orientation, or compute its layout info if `solve` is false.
+///
+/// Like the generated code, only read what is needed for the orientation: the cells and the size
+/// of the cross axis are only needed to solve the cross axis.
+fn flexbox_layout_solve_or_info(
+    flexbox_layout: &i_slint_compiler::layout::FlexboxLayout,
+    orientation: Orientation,
+    solve: bool,
+    component: InstanceRef,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+) -> Value {
+    let main = flexbox_layout.main_orientation();
+    let is_cross = orientation != main;
+    let mut repeated_indices = Vec::new();
+    let main_cells = flexbox_layout_cells(
+        flexbox_layout,
+        main,
+        component,
+        expr_eval,
+        (solve && !is_cross).then_some(&mut repeated_indices),
+    );
+    let cross_cells = if is_cross {
+        flexbox_layout_cells(
+            flexbox_layout,
+            orientation,
+            component,
+            expr_eval,
+            solve.then_some(&mut repeated_indices),
+        )
+    } else {
+        Vec::new()
+    };
+    let size = |o: Orientation| {
+        let needed = if o == main {
+            solve || (is_cross && flexbox_layout.wrap.is_some())
+        } else {
+            solve && is_cross
+        };
+        if needed {
+            flexbox_layout.geometry.rect.size_reference(o).map_or(0., expr_eval)
+        } else {
+            0.
+        }
+    };
+    let (padding_horizontal, spacing_horizontal) =
+        padding_and_spacing(&flexbox_layout.geometry, Orientation::Horizontal, expr_eval);
+    let (padding_vertical, spacing_vertical) =
+        padding_and_spacing(&flexbox_layout.geometry, Orientation::Vertical, expr_eval);
+    let (cells_horizontal, cells_vertical) = match main {
+        Orientation::Horizontal => (&main_cells, &cross_cells),
+        Orientation::Vertical => (&cross_cells, &main_cells),
+    };
+    let data = core_layout::FlexboxLayoutData {
+        width: size(Orientation::Horizontal),
+        height: size(Orientation::Vertical),
+        spacing_horizontal,
+        spacing_vertical,
+        padding_horizontal,
+        padding_vertical,
+        direction: FlexDirection::from_str(&flexbox_layout.direction.to_string()).unwrap(),
+        wrap: load_or_default(component, &flexbox_layout.wrap),
+        justify_content: load_or_default(component, &flexbox_layout.justify_content),
+        align_items: load_or_default(component, &flexbox_layout.align_items),
+        cells_horizontal: Slice::from(cells_horizontal.as_slice()),
+        cells_vertical: Slice::from(cells_vertical.as_slice()),
+    };
+    if solve {
+        core_layout::solve_flexbox_layout(
+            &data,
+            to_runtime(orientation),
+            Slice::from(repeated_indices.as_slice()),
+        )
+        .into()
+    } else {
+        core_layout::flexbox_layout_info(&data, to_runtime(orientation)).into()
+    }
+}
+
+fn flexbox_layout_cells(
+    flexbox_layout: &i_slint_compiler::layout::FlexboxLayout,
+    orientation: Orientation,
+    component: InstanceRef,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+    mut repeater_indices: Option<&mut Vec<u32>>,
+) -> Vec<core_layout::FlexboxLayoutCellData> {
+    let window_adapter = component.window_adapter();
+    let mut cells = Vec::with_capacity(flexbox_layout.elems.len());
+    for cell in &flexbox_layout.elems {
+        if cell.element.borrow().repeated.is_some() {
+            let component_vec = repeater_instances(&cell.element, component);
+            if let Some(ri) = repeater_indices.as_mut() {
+                ri.push(cells.len() as _);
+                ri.push(component_vec.len() as _);
+            }
+            cells.extend(
+                component_vec
+                    .iter()
+                    .map(|x| x.as_pin_ref().flexbox_layout_data(to_runtime(orientation))),
+            );
+        } else {
+            let mut layout_info =
+                get_layout_info(&cell.element, component, &window_adapter, orientation);
+            fill_layout_info_constraints(
+                &mut layout_info,
+                &cell.constraints,
+                orientation,
+                &expr_eval,
+            );
+            let value =
+                |nr: &Option<NamedReference>, default| nr.as_ref().map_or(default, expr_eval);
+            cells.push(core_layout::FlexboxLayoutCellData {
+                constraint: layout_info,
+                flex_grow: value(&cell.constraints.flex_grow, 0.),
+                flex_shrink: value(&cell.constraints.flex_shrink, 1.),
+                flex_basis: value(&cell.constraints.flex_basis, -1.),
+            });
+        }
+    }
+    cells
+}
+
+/// Make sure the instances of the repeater are created and return them
+fn repeater_instances(
+    element: &ElementRc,
+    component: InstanceRef,
+) -> Vec<vtable::VRc<ItemTreeVTable, ErasedItemTreeBox>> {
+    generativity::make_guard!(guard);
+    let rep = crate::dynamic_item_tree::get_repeater_by_name(
+        component,
+        element.borrow().id.as_str(),
+        guard,
+    );
+    rep.0.as_ref().ensure_updated(|| {
+        let instance = crate::dynamic_item_tree::instantiate(
+            rep.1.clone(),
+            component.self_weak().get().cloned(),
+            None,
+            None,
+            Default::default(),
+        );
+        instance
+    });
+    rep.0.as_ref().instances_vec()
+}
+
+/// Load the value of the property, or return the default value if there is no property
+fn load_or_default<T: TryFrom<Value> + Default>(
+    component: InstanceRef,
+    nr: &Option<NamedReference>,
+) -> T {
+    nr.as_ref()
+        .and_then(|nr| {
+            eval::load_property(component, &nr.element(), nr.name()).unwrap().try_into().ok()
+        })
+        .unwrap_or_default()
+}
+
 pub(crate) fn fill_layout_info_constraints(
     layout_info: &mut core_layout::LayoutInfo,
     constraints: &LayoutConstraints,
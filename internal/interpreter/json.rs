@@ -49,6 +49,16 @@ fn from_json_str(t: &langtype::Type, value: &str) -> Result<Self, String> {
 
 /// Create a `Value` from a JSON Value
 pub fn value_from_json(t: &langtype::Type, v: &serde_json::Value) -> Result<Value, String> {
+    value_from_json_with_migration(t, v, &JsonMigration::default())
+}
+
+/// Create a `Value` from a JSON Value that was saved with an older version of the .slint file,
+/// applying the `migration` rules to the structs and enums
+pub fn value_from_json_with_migration(
+    t: &langtype::Type,
+    v: &serde_json::Value,
+    migration: &JsonMigration,
+) -> Result<Value, String> {
     use smol_str::ToSmolStr;
 
     fn string_to_color(s: &str) -> Option<i_slint_core::Color> {
@@ -66,6 +76,12 @@ fn string_to_color(s: &str) -> Option<i_slint_core::Color> {
                 } else {
                     s.to_smolstr()
                 };
+                let s = migration
+                    .enums
+                    .get(&e.name)
+                    .and_then(|renamed_values| renamed_values.get(&s))
+                    .cloned()
+                    .unwrap_or(s);
 
                 if e.values.contains(&s) {
                     Ok(Value::EnumerationValue(e.name.to_string(), s.into()))
@@ -81,6 +97,9 @@ fn string_to_color(s: &str) -> Option<i_slint_core::Color> {
                 }
             }
             langtype::Type::String => Ok(SharedString::from(s.as_str()).into()),
+            langtype::Type::DateTime => i_slint_core::date_time::DateTime::parse(s, "%+")
+                .map(Value::DateTime)
+                .ok_or_else(|| format!("Failed to parse date-time: {s}")),
             langtype::Type::Image => match Image::load_from_path(std::path::Path::new(s)) {
                 Ok(image) => Ok(image.into()),
                 Err(e) => Err(format!("Failed to load image from path: {s}: {e}")),
@@ -174,25 +193,55 @@ fn parse_stops<'a>(
                 Ok(Value::Model(ModelRc::new(i_slint_core::model::SharedVectorModel::from(
                     array
                         .iter()
-                        .map(|v| value_from_json(it, v))
+                        .map(|v| value_from_json_with_migration(it, v, migration))
                         .collect::<Result<SharedVector<Value>, String>>()?,
                 ))))
             }
             _ => Err("Got an array where none was expected".into()),
         },
         serde_json::Value::Object(obj) => match t {
-            langtype::Type::Struct(s) => Ok(crate::Struct(
+            langtype::Type::Struct(s) => {
+                let struct_migration = s.name.as_ref().and_then(|name| {
+                    migration.structs.get(name.rsplit("::").next().unwrap_or(name))
+                });
+                let mut fields = obj
+                    .iter()
+                    .filter_map(|(k, v)| {
+                        let mut k = crate::api::normalize_identifier(k);
+                        if let Some(struct_migration) = struct_migration {
+                            if struct_migration.removed_fields.contains(&k) {
+                                return None;
+                            }
+                            if let Some(new_name) = struct_migration.renamed_fields.get(&k) {
+                                k = new_name.clone();
+                            }
+                        }
+                        Some(match s.fields.get(&k) {
+                            Some(t) => {
+                                value_from_json_with_migration(t, v, migration).map(|v| (k, v))
+                            }
+                            None => Err(format!("Found unknown field in struct: {k}")),
+                        })
+                    })
+                    .collect::<Result<HashMap<smol_str::SmolStr, Value>, _>>()?;
+                for (k, default) in struct_migration.iter().flat_map(|m| m.default_values.iter()) {
+                    if let (false, Some(t)) = (fields.contains_key(k), s.fields.get(k)) {
+                        fields.insert(
+                            k.clone(),
+                            value_from_json_with_migration(t, default, migration)?,
+                        );
+                    }
+                }
+                Ok(crate::Struct(fields).into())
+            }
+            langtype::Type::Map(vt) => Ok(Value::Map(
                 obj.iter()
                     .map(|(k, v)| {
-                        let k = crate::api::normalize_identifier(k);
-                        match s.fields.get(&k) {
-                            Some(t) => value_from_json(t, v).map(|v| (k, v)),
-                            None => Err(format!("Found unknown field in struct: {k}")),
-                        }
+                        value_from_json_with_migration(vt, v, migration)
+                            .map(|v| (SharedString::from(k.as_str()), v))
                     })
-                    .collect::<Result<HashMap<smol_str::SmolStr, Value>, _>>()?,
-            )
-            .into()),
+                    .collect::<Result<_, _>>()?,
+            )),
             _ => Err("Got a struct where none was expected".into()),
         },
     }
@@ -204,6 +253,144 @@ pub fn value_from_json_str(t: &langtype::Type, v: &str) -> Result<Value, String>
     Value::from_json(t, &value)
 }
 
+/// Rules to load values saved with an older version of a .slint file, when its structs and
+/// enums have changed since
+///
+/// The rules are applied by [`value_from_json_with_migration`] and [`restore_state_with_migration`].
+/// Structs and enums are identified by their name, and fields and values by their name in the
+/// current version of the .slint file, except for the old names of renamed fields and values.
+///
+/// The rules can also be loaded from JSON with [`Self::from_json`], for example:
+///
+/// ```json
+/// {
+///     "structs": {
+///         "Task": {
+///             "renamed-fields": { "name": "title" },
+///             "removed-fields": ["color"],
+///             "default-values": { "priority": "Priority.low" }
+///         }
+///     },
+///     "enums": {
+///         "Priority": { "renamed-values": { "urgent": "high" } }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct JsonMigration {
+    structs: HashMap<smol_str::SmolStr, StructMigration>,
+    enums: HashMap<smol_str::SmolStr, HashMap<smol_str::SmolStr, smol_str::SmolStr>>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct StructMigration {
+    renamed_fields: HashMap<smol_str::SmolStr, smol_str::SmolStr>,
+    removed_fields: std::collections::HashSet<smol_str::SmolStr>,
+    default_values: std::collections::BTreeMap<smol_str::SmolStr, serde_json::Value>,
+}
+
+impl JsonMigration {
+    /// Load the field `old_name` of the struct `struct_name` into the field `new_name`.
+    pub fn rename_field(&mut self, struct_name: &str, old_name: &str, new_name: &str) {
+        self.struct_migration(struct_name).renamed_fields.insert(
+            crate::api::normalize_identifier(old_name),
+            crate::api::normalize_identifier(new_name),
+        );
+    }
+
+    /// Ignore the field `name` of the struct `struct_name`, which doesn't exist anymore.
+    pub fn remove_field(&mut self, struct_name: &str, name: &str) {
+        self.struct_migration(struct_name)
+            .removed_fields
+            .insert(crate::api::normalize_identifier(name));
+    }
+
+    /// Use `value` for the field `name` of the struct `struct_name` when it's missing.
+    pub fn set_default_value(&mut self, struct_name: &str, name: &str, value: serde_json::Value) {
+        self.struct_migration(struct_name)
+            .default_values
+            .insert(crate::api::normalize_identifier(name), value);
+    }
+
+    /// Load the value `old_value` of the enum `enum_name` as `new_value`.
+    pub fn rename_enum_value(&mut self, enum_name: &str, old_value: &str, new_value: &str) {
+        self.enums.entry(crate::api::normalize_identifier(enum_name)).or_default().insert(
+            crate::api::normalize_identifier(old_value),
+            crate::api::normalize_identifier(new_value),
+        );
+    }
+
+    /// Create the rules from their JSON description.
+    pub fn from_json(rules: &serde_json::Value) -> Result<Self, String> {
+        fn object<'a>(
+            value: &'a serde_json::Value,
+            what: &str,
+        ) -> Result<&'a serde_json::Map<String, serde_json::Value>, String> {
+            value.as_object().ok_or_else(|| format!("Expected an object for {what}"))
+        }
+        fn string<'a>(value: &'a serde_json::Value, what: &str) -> Result<&'a str, String> {
+            value.as_str().ok_or_else(|| format!("Expected a string for {what}"))
+        }
+
+        let mut migration = Self::default();
+        for (key, value) in object(rules, "the migration rules")? {
+            match key.as_str() {
+                "structs" => {
+                    for (struct_name, rules) in object(value, "structs")? {
+                        for (key, value) in object(rules, struct_name)? {
+                            match key.as_str() {
+                                "renamed-fields" => {
+                                    for (old_name, new_name) in object(value, key)? {
+                                        let new_name = string(new_name, old_name)?;
+                                        migration.rename_field(struct_name, old_name, new_name);
+                                    }
+                                }
+                                "removed-fields" => {
+                                    let fields = value
+                                        .as_array()
+                                        .ok_or_else(|| format!("Expected an array for {key}"))?;
+                                    for name in fields {
+                                        migration.remove_field(struct_name, string(name, key)?);
+                                    }
+                                }
+                                "default-values" => {
+                                    for (name, value) in object(value, key)? {
+                                        migration.set_default_value(
+                                            struct_name,
+                                            name,
+                                            value.clone(),
+                                        );
+                                    }
+                                }
+                                _ => return Err(format!("Unknown struct migration rule: {key}")),
+                            }
+                        }
+                    }
+                }
+                "enums" => {
+                    for (enum_name, rules) in object(value, "enums")? {
+                        for (key, value) in object(rules, enum_name)? {
+                            if key != "renamed-values" {
+                                return Err(format!("Unknown enum migration rule: {key}"));
+                            }
+                            for (old_value, new_value) in object(value, key)? {
+                                let new_value = string(new_value, old_value)?;
+                                migration.rename_enum_value(enum_name, old_value, new_value);
+                            }
+                        }
+                    }
+                }
+                _ => return Err(format!("Unknown migration rules: {key}")),
+            }
+        }
+        Ok(migration)
+    }
+
+    fn struct_migration(&mut self, struct_name: &str) -> &mut StructMigration {
+        self.structs.entry(crate::api::normalize_identifier(struct_name)).or_default()
+    }
+}
+
 /// Write the `Value` out into a JSON value
 pub fn value_to_json(value: &Value) -> Result<serde_json::Value, String> {
     fn color_to_string(color: &Color) -> String {
@@ -266,6 +453,11 @@ fn gradient_to_string_helper<'a>(
                 .map(|(k, v)| v.to_json().map(|v| (k.to_string(), v)))
                 .collect::<Result<serde_json::Map<_, _>, _>>()?,
         )),
+        Value::Map(m) => Ok(serde_json::Value::Object(
+            m.iter()
+                .map(|(k, v)| v.to_json().map(|v| (k.to_string(), v)))
+                .collect::<Result<serde_json::Map<_, _>, _>>()?,
+        )),
         Value::Brush(brush) => match brush {
             Brush::SolidColor(color) => Ok(serde_json::Value::String(color_to_string(color))),
             Brush::LinearGradient(lg) => Ok(gradient_to_string_helper(
@@ -277,6 +469,9 @@ fn gradient_to_string_helper<'a>(
             }
             _ => Err("Cannot serialize an unknown brush type".into()),
         },
+        Value::DateTime(date_time) => {
+            Ok(serde_json::Value::String(date_time.format("%+").to_string()))
+        }
         Value::PathData(_) => Err("Cannot serialize path data".into()),
         Value::EasingCurve(_) => Err("Cannot serialize a easing curve".into()),
         _ => Err("Cannot serialize an unknown value type".into()),
@@ -288,6 +483,391 @@ pub fn value_to_json_string(value: &Value) -> Result<String, String> {
     Ok(value_to_json(value)?.to_string())
 }
 
+/// Error for one value that could not be restored by [`restore_state`]
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Error, derive_more::Display)]
+#[display("{path}: {message}")]
+pub struct RestoreStateError {
+    /// The location of the value in the state, as a JSON Pointer like `/properties/counter`
+    /// or `/globals/Settings/dark-mode`
+    #[error(not(source))]
+    pub path: String,
+    /// The description of the error
+    pub message: String,
+}
+
+/// Save the values of the public properties of a component instance and of its exported globals
+///
+/// The result is an object with a `properties` object mapping the property names to their
+/// values, and a `globals` object mapping the global names to objects with their properties.
+/// Only the properties that can be set (`in` and `in-out`) are saved, and properties whose
+/// values can't be converted to JSON are skipped.
+pub fn save_state(instance: &crate::ComponentInstance) -> serde_json::Value {
+    let definition = instance.definition();
+    generativity::make_guard!(guard);
+    let description = definition.inner.unerase(guard);
+
+    let properties = save_properties(description.properties(), |name| instance.get_property(name));
+    let globals = description
+        .global_names()
+        .filter_map(|global| {
+            let values = save_properties(description.global_properties(&global)?, |name| {
+                instance.get_global_property(&global, name)
+            });
+            Some((global.to_string(), values.into()))
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    serde_json::json!({ "properties": properties, "globals": globals })
+}
+
+fn save_properties<E>(
+    properties: impl Iterator<Item = PublicProperty>,
+    get: impl Fn(&str) -> Result<Value, E>,
+) -> serde_json::Map<String, serde_json::Value> {
+    properties
+        .filter(|(_, ty, visibility)| is_restorable(ty, *visibility))
+        .filter_map(|(name, ..)| Some((name.to_string(), get(&name).ok()?.to_json().ok()?)))
+        .collect()
+}
+
+/// Restore the state saved with [`save_state`] into a component instance
+///
+/// All the values that can be restored are set, even if other values fail. An error is
+/// returned for each value that is not a public property that can be set, or that doesn't
+/// match the type of the property.
+pub fn restore_state(
+    instance: &crate::ComponentInstance,
+    state: &serde_json::Value,
+) -> Result<(), Vec<RestoreStateError>> {
+    restore_state_with_migration(instance, state, &JsonMigration::default())
+}
+
+/// Restore the state saved with [`save_state`] from an older version of the .slint file,
+/// applying the `migration` rules to the structs and enums
+pub fn restore_state_with_migration(
+    instance: &crate::ComponentInstance,
+    state: &serde_json::Value,
+    migration: &JsonMigration,
+) -> Result<(), Vec<RestoreStateError>> {
+    let mut errors = Vec::new();
+    let Some(state) = state.as_object() else {
+        errors
+            .push(RestoreStateError { path: String::new(), message: "Expected an object".into() });
+        return Err(errors);
+    };
+
+    let definition = instance.definition();
+    generativity::make_guard!(guard);
+    let description = definition.inner.unerase(guard);
+
+    for (key, value) in state {
+        let path = format!("/{}", json_pointer_escape(key));
+        match key.as_str() {
+            "properties" => restore_properties(
+                &path,
+                value,
+                description.properties(),
+                &|name, value| instance.set_property(name, value),
+                migration,
+                &mut errors,
+            ),
+            "globals" => {
+                let Some(globals) = value.as_object() else {
+                    errors.push(RestoreStateError { path, message: "Expected an object".into() });
+                    continue;
+                };
+                for (global, values) in globals {
+                    let path = format!("{path}/{}", json_pointer_escape(global));
+                    match description.global_properties(global) {
+                        Some(properties) => restore_properties(
+                            &path,
+                            values,
+                            properties,
+                            &|name, value| instance.set_global_property(global, name, value),
+                            migration,
+                            &mut errors,
+                        ),
+                        None => errors
+                            .push(RestoreStateError { path, message: "No such global".into() }),
+                    }
+                }
+            }
+            _ => errors.push(RestoreStateError { path, message: "Unexpected key".into() }),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn restore_properties(
+    path: &str,
+    values: &serde_json::Value,
+    properties: impl Iterator<Item = PublicProperty>,
+    set: &dyn Fn(&str, Value) -> Result<(), crate::SetPropertyError>,
+    migration: &JsonMigration,
+    errors: &mut Vec<RestoreStateError>,
+) {
+    let Some(values) = values.as_object() else {
+        errors.push(RestoreStateError { path: path.into(), message: "Expected an object".into() });
+        return;
+    };
+    let properties = properties
+        .map(|(name, ty, visibility)| (crate::api::normalize_identifier(&name), (ty, visibility)))
+        .collect::<HashMap<_, _>>();
+    for (name, value) in values {
+        let result = match properties.get(&crate::api::normalize_identifier(name)) {
+            Some((ty, visibility)) if is_restorable(ty, *visibility) => {
+                value_from_json_with_migration(ty, value, migration)
+                    .and_then(|value| set(name, value).map_err(|e| e.to_string()))
+            }
+            Some(_) => Err("The property can't be set".into()),
+            None => Err("No such property".into()),
+        };
+        if let Err(message) = result {
+            errors.push(RestoreStateError {
+                path: format!("{path}/{}", json_pointer_escape(name)),
+                message,
+            });
+        }
+    }
+}
+
+/// Escape a key to be used in a JSON Pointer (RFC 6901)
+fn json_pointer_escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+type PublicProperty =
+    (smol_str::SmolStr, langtype::Type, i_slint_compiler::object_tree::PropertyVisibility);
+
+fn is_restorable(
+    ty: &langtype::Type,
+    visibility: i_slint_compiler::object_tree::PropertyVisibility,
+) -> bool {
+    use i_slint_compiler::object_tree::PropertyVisibility;
+    ty.is_property_type()
+        && matches!(visibility, PropertyVisibility::Input | PropertyVisibility::InOut)
+}
+
+/// Create a [JSON Schema](https://json-schema.org) describing the public API of a component
+///
+/// The schema validates an object with the public properties of the component, in the
+/// format accepted by [`value_from_json`]. Properties that can only be read are marked with
+/// `readOnly`. Named structs and enums are collected in `$defs`.
+/// The callbacks, functions and globals are described in the `x-slint-callbacks`,
+/// `x-slint-functions` and `x-slint-globals` keywords. Each callback and function has an
+/// `arguments` schema that validates the array of arguments, and a `returns` schema.
+/// Each global is described with the same layout as the component.
+pub fn component_json_schema(definition: &crate::ComponentDefinition) -> serde_json::Value {
+    generativity::make_guard!(guard);
+    let description = definition.inner.unerase(guard);
+
+    let mut builder = JsonSchemaBuilder::default();
+    let mut schema = builder.object_schema(description.properties());
+    let globals = description
+        .global_names()
+        .filter_map(|name| {
+            let properties = description.global_properties(&name)?;
+            Some((name.to_string(), builder.object_schema(properties).into()))
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    schema.insert(
+        "$schema".into(),
+        serde_json::Value::String("https://json-schema.org/draft/2020-12/schema".into()),
+    );
+    schema.insert("title".into(), serde_json::Value::String(description.id().into()));
+    if !globals.is_empty() {
+        schema.insert("x-slint-globals".into(), globals.into());
+    }
+    if !builder.defs.is_empty() {
+        schema.insert("$defs".into(), builder.defs.into());
+    }
+    schema.into()
+}
+
+/// Converts `langtype::Type`s to JSON Schemas, collecting the named structs and enums
+#[derive(Default)]
+struct JsonSchemaBuilder {
+    defs: serde_json::Map<String, serde_json::Value>,
+    /// The type described by each entry of `defs`
+    def_types: HashMap<String, langtype::Type>,
+}
+
+impl JsonSchemaBuilder {
+    fn object_schema(
+        &mut self,
+        properties: impl Iterator<Item = PublicProperty>,
+    ) -> serde_json::Map<String, serde_json::Value> {
+        use i_slint_compiler::object_tree::PropertyVisibility;
+
+        let mut property_schemas = serde_json::Map::new();
+        let mut callbacks = serde_json::Map::new();
+        let mut functions = serde_json::Map::new();
+        for (name, ty, visibility) in properties {
+            match &ty {
+                langtype::Type::Callback(function) => {
+                    callbacks.insert(name.to_string(), self.function_schema(function));
+                }
+                langtype::Type::Function(function) => {
+                    functions.insert(name.to_string(), self.function_schema(function));
+                }
+                _ => {
+                    let Some(mut schema) = self.type_schema(&ty) else { continue };
+                    if visibility == PropertyVisibility::Output {
+                        schema["readOnly"] = true.into();
+                    }
+                    property_schemas.insert(name.to_string(), schema);
+                }
+            }
+        }
+
+        let mut schema = serde_json::Map::new();
+        schema.insert("type".into(), "object".into());
+        schema.insert("properties".into(), property_schemas.into());
+        schema.insert("additionalProperties".into(), false.into());
+        if !callbacks.is_empty() {
+            schema.insert("x-slint-callbacks".into(), callbacks.into());
+        }
+        if !functions.is_empty() {
+            schema.insert("x-slint-functions".into(), functions.into());
+        }
+        schema
+    }
+
+    fn function_schema(&mut self, function: &langtype::Function) -> serde_json::Value {
+        let arguments = function
+            .args
+            .iter()
+            .zip(function.arg_names.iter().map(Some).chain(std::iter::repeat(None)))
+            .map(|(ty, name)| {
+                // Arguments that can't be represented in JSON accept any value
+                let mut schema = self.type_schema(ty).unwrap_or_else(|| serde_json::json!({}));
+                if let Some(name) = name.filter(|name| !name.is_empty()) {
+                    schema["title"] = name.as_str().into();
+                }
+                schema
+            })
+            .collect::<Vec<_>>();
+        let count = arguments.len();
+
+        let mut schema = serde_json::json!({
+            "arguments": {
+                "type": "array",
+                "prefixItems": arguments,
+                "minItems": count,
+                "maxItems": count,
+            }
+        });
+        if function.return_type != langtype::Type::Void {
+            if let Some(returns) = self.type_schema(&function.return_type) {
+                schema["returns"] = returns;
+            }
+        }
+        schema
+    }
+
+    /// Returns `None` for types that can't be converted to or from JSON
+    fn type_schema(&mut self, ty: &langtype::Type) -> Option<serde_json::Value> {
+        use langtype::Type;
+        use serde_json::json;
+
+        Some(match ty {
+            Type::Float32
+            | Type::Duration
+            | Type::PhysicalLength
+            | Type::LogicalLength
+            | Type::Rem
+            | Type::Angle
+            | Type::Percent
+            | Type::UnitProduct(_) => json!({ "type": "number" }),
+            Type::Int32 => json!({ "type": "integer" }),
+            Type::Bool => json!({ "type": "boolean" }),
+            Type::String => json!({ "type": "string" }),
+            Type::Color => json!({
+                "type": "string",
+                "pattern": "^#([0-9a-fA-F]{3,4}|[0-9a-fA-F]{6}|[0-9a-fA-F]{8})$",
+            }),
+            // A color, or a gradient like `@linear-gradient(90deg, #ff0000ff 0%, #0000ffff 100%)`
+            Type::Brush => json!({ "type": "string" }),
+            // The path of the image file
+            Type::Image => json!({ "type": "string" }),
+            Type::DateTime => json!({ "type": "string", "format": "date-time" }),
+            Type::Array(item) => json!({ "type": "array", "items": self.type_schema(item)? }),
+            Type::Map(value) => {
+                json!({ "type": "object", "additionalProperties": self.type_schema(value)? })
+            }
+            Type::Struct(s) => match &s.name {
+                Some(name) => {
+                    let (name, is_new) =
+                        self.def_name(name.rsplit("::").next().unwrap_or(name), ty);
+                    if is_new {
+                        let schema = self.struct_schema(s);
+                        self.defs.insert(name.clone(), schema);
+                    }
+                    json!({ "$ref": format!("#/$defs/{name}") })
+                }
+                None => self.struct_schema(s),
+            },
+            Type::Enumeration(e) => {
+                let (def_name, is_new) = self.def_name(&e.name, ty);
+                if is_new {
+                    let name = &e.name;
+                    // Both `value` and `Enum.value` are accepted, and the latter is what `value_to_json` produces
+                    let values = e
+                        .values
+                        .iter()
+                        .map(|v| v.to_string())
+                        .chain(e.values.iter().map(|v| format!("{name}.{v}")))
+                        .collect::<Vec<_>>();
+                    self.defs.insert(def_name.clone(), json!({ "type": "string", "enum": values }));
+                }
+                json!({ "$ref": format!("#/$defs/{def_name}") })
+            }
+            _ => return None,
+        })
+    }
+
+    /// Returns the key in `$defs` of the named struct or enum `ty`, and whether it still needs to
+    /// be added. Types with the same name from different files get a numbered key.
+    fn def_name(&mut self, name: &str, ty: &langtype::Type) -> (String, bool) {
+        use langtype::Type;
+        let is_same = |other: &Type| match (ty, other) {
+            (Type::Enumeration(a), Type::Enumeration(b)) => {
+                std::rc::Rc::ptr_eq(a, b) || (a.name == b.name && a.values == b.values)
+            }
+            _ => ty == other,
+        };
+        let mut key = name.to_string();
+        for i in 2.. {
+            match self.def_types.get(&key) {
+                Some(other) if is_same(other) => return (key, false),
+                Some(_) => key = format!("{name}{i}"),
+                None => break,
+            }
+        }
+        self.def_types.insert(key.clone(), ty.clone());
+        (key, true)
+    }
+
+    fn struct_schema(&mut self, s: &langtype::Struct) -> serde_json::Value {
+        let properties = s
+            .fields
+            .iter()
+            .filter_map(|(name, ty)| Some((name.to_string(), self.type_schema(ty)?)))
+            .collect::<serde_json::Map<_, _>>();
+        serde_json::json!({
+            "type": "object",
+            "properties": properties,
+            "additionalProperties": false,
+        })
+    }
+}
+
 #[test]
 fn test_from_json() {
     let v = value_from_json_str(&langtype::Type::Void, "null").unwrap();
@@ -430,6 +1010,30 @@ fn test_from_json() {
         "\"@other-gradient(circle, #ff0000ff 0%, #00ff00ff 50%, #0000ffff 100%)\""
     )
     .is_err());
+
+    let v = value_from_json_str(
+        &langtype::Type::Map(std::rc::Rc::new(langtype::Type::Int32)),
+        "{\"a key\": 1, \"other_key\": 2}",
+    )
+    .unwrap();
+    assert_eq!(
+        v,
+        Value::Map(std::collections::BTreeMap::from([
+            ("a key".into(), Value::Number(1.0)),
+            ("other_key".into(), Value::Number(2.0)),
+        ]))
+    );
+
+    let v =
+        value_from_json_str(&langtype::Type::DateTime, "\"2024-02-29T23:30:15-02:00\"").unwrap();
+    assert_eq!(
+        v,
+        Value::DateTime(
+            i_slint_core::date_time::DateTime::from_ymd_hms(2024, 2, 29, 23, 30, 15, -7200)
+                .unwrap()
+        )
+    );
+    assert!(value_from_json_str(&langtype::Type::DateTime, "\"2024-02-29\"").is_err());
 }
 
 #[test]
@@ -488,6 +1092,19 @@ fn test_to_json() {
     .unwrap();
     assert_eq!(&v, "{\"is-bool\":false,\"kind\":\"test.foo\",\"string-value\":\"some string\"}");
 
+    let v = value_to_json_string(&Value::Map(std::collections::BTreeMap::from([
+        ("some key".into(), Value::Number(1.0)),
+        ("other_key".into(), Value::Number(2.0)),
+    ])))
+    .unwrap();
+    assert_eq!(&v, "{\"other_key\":2,\"some key\":1}");
+
+    let v = value_to_json_string(&Value::DateTime(
+        i_slint_core::date_time::DateTime::from_timestamp_millis(1_709_256_615_500),
+    ))
+    .unwrap();
+    assert_eq!(v, "\"2024-03-01T01:30:15.500+00:00\"");
+
     let v = value_to_json_string(&Value::Brush(Brush::SolidColor(Color::from_argb_u8(
         0xff, 0x0a, 0xb0, 0xcd,
     ))))
@@ -539,3 +1156,262 @@ fn test_to_json() {
     .unwrap();
     assert_eq!(&v, "\"@radial-gradient(circle, #ff0000ff 0%, #00ff00ff 50%, #0000ffff 100%)\"");
 }
+
+#[test]
+fn test_component_json_schema() {
+    let source = r#"
+        export enum Priority { low, high }
+        export struct Task { title: string, priority: Priority, due: date-time }
+        export global Settings {
+            in-out property <bool> dark-mode;
+            callback reset();
+        }
+        export component Main {
+            in property <[Task]> tasks;
+            in-out property <color> accent;
+            out property <int> done-count;
+            in property <{[string]: float}> totals;
+            in property <image> logo;
+            in property <easing> curve;
+            callback task-clicked(index: int, task: Task) -> bool;
+            public function clear() {}
+        }
+    "#;
+    let compiler = crate::Compiler::default();
+    let result = spin_on::spin_on(compiler.build_from_source(source.into(), Default::default()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let schema = result.component("Main").unwrap().json_schema();
+
+    assert_eq!(
+        schema,
+        serde_json::json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Main",
+            "type": "object",
+            "properties": {
+                "tasks": { "type": "array", "items": { "$ref": "#/$defs/Task" } },
+                "accent": {
+                    "type": "string",
+                    "pattern": "^#([0-9a-fA-F]{3,4}|[0-9a-fA-F]{6}|[0-9a-fA-F]{8})$",
+                },
+                "done-count": { "type": "integer", "readOnly": true },
+                "totals": { "type": "object", "additionalProperties": { "type": "number" } },
+                "logo": { "type": "string" },
+            },
+            "additionalProperties": false,
+            "x-slint-callbacks": {
+                "task-clicked": {
+                    "arguments": {
+                        "type": "array",
+                        "prefixItems": [
+                            { "type": "integer", "title": "index" },
+                            { "$ref": "#/$defs/Task", "title": "task" },
+                        ],
+                        "minItems": 2,
+                        "maxItems": 2,
+                    },
+                    "returns": { "type": "boolean" },
+                },
+            },
+            "x-slint-functions": {
+                "clear": {
+                    "arguments": { "type": "array", "prefixItems": [], "minItems": 0, "maxItems": 0 },
+                },
+            },
+            "x-slint-globals": {
+                "Settings": {
+                    "type": "object",
+                    "properties": { "dark-mode": { "type": "boolean" } },
+                    "additionalProperties": false,
+                    "x-slint-callbacks": {
+                        "reset": {
+                            "arguments": {
+                                "type": "array",
+                                "prefixItems": [],
+                                "minItems": 0,
+                                "maxItems": 0,
+                            },
+                        },
+                    },
+                },
+            },
+            "$defs": {
+                "Priority": {
+                    "type": "string",
+                    "enum": ["low", "high", "Priority.low", "Priority.high"],
+                },
+                "Task": {
+                    "type": "object",
+                    "properties": {
+                        "due": { "type": "string", "format": "date-time" },
+                        "priority": { "$ref": "#/$defs/Priority" },
+                        "title": { "type": "string" },
+                    },
+                    "additionalProperties": false,
+                },
+            },
+        })
+    );
+}
+
+#[test]
+fn test_json_schema_same_struct_names() {
+    let mut compiler = crate::Compiler::default();
+    compiler.set_file_loader(|path| {
+        let source = match path.file_name().and_then(|name| name.to_str()) {
+            Some("a.slint") => "export struct Point { x: int }",
+            Some("b.slint") => "export struct Point { name: string }",
+            _ => return Box::pin(std::future::ready(None)),
+        };
+        Box::pin(std::future::ready(Some(Ok(source.to_string()))))
+    });
+    let source = r#"
+        import { Point } from "a.slint";
+        import { Point as OtherPoint } from "b.slint";
+        export component Main {
+            in property <Point> first;
+            in property <OtherPoint> second;
+            in property <[Point]> points;
+        }
+    "#;
+    let result = spin_on::spin_on(compiler.build_from_source(source.into(), Default::default()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let schema = result.component("Main").unwrap().json_schema();
+
+    assert_eq!(schema["properties"]["first"], serde_json::json!({ "$ref": "#/$defs/Point" }));
+    assert_eq!(schema["properties"]["second"], serde_json::json!({ "$ref": "#/$defs/Point2" }));
+    assert_eq!(
+        schema["properties"]["points"]["items"],
+        serde_json::json!({ "$ref": "#/$defs/Point" })
+    );
+    assert_eq!(
+        schema["$defs"]["Point"]["properties"],
+        serde_json::json!({ "x": { "type": "integer" } })
+    );
+    assert_eq!(
+        schema["$defs"]["Point2"]["properties"],
+        serde_json::json!({ "name": { "type": "string" } })
+    );
+}
+
+#[test]
+fn test_save_and_restore_state() {
+    i_slint_backend_testing::init_no_event_loop();
+    let source = r#"
+        export struct Item { name: string, count: int }
+        export global Settings {
+            in-out property <bool> dark-mode;
+            out property <int> version: 3;
+        }
+        export component Main {
+            in-out property <int> counter: 1;
+            in property <[Item]> items: [{ name: "a", count: 2 }];
+            in property <string> title: "Hello";
+            out property <int> double: counter * 2;
+            in property <easing> curve;
+        }
+    "#;
+    let compiler = crate::Compiler::default();
+    let result = spin_on::spin_on(compiler.build_from_source(source.into(), Default::default()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let definition = result.component("Main").unwrap();
+
+    let instance = definition.create().unwrap();
+    instance.set_property("counter", Value::Number(5.)).unwrap();
+    instance.set_global_property("Settings", "dark-mode", Value::Bool(true)).unwrap();
+    let state = instance.save_state();
+    assert_eq!(
+        state,
+        serde_json::json!({
+            "properties": { "counter": 5, "items": [{ "name": "a", "count": 2 }], "title": "Hello" },
+            "globals": { "Settings": { "dark-mode": true } },
+        })
+    );
+
+    let other = definition.create().unwrap();
+    assert_eq!(other.restore_state(&state), Ok(()));
+    assert_eq!(other.get_property("double").unwrap(), Value::Number(10.));
+    assert_eq!(other.get_global_property("Settings", "dark-mode").unwrap(), Value::Bool(true));
+    assert_eq!(other.save_state(), state);
+
+    let errors = other
+        .restore_state(&serde_json::json!({
+            "properties": { "counter": "six", "double": 12, "missing": 1, "title": "Restored" },
+            "globals": { "Settings": { "version": 4 }, "Unknown": {} },
+        }))
+        .unwrap_err();
+    assert_eq!(
+        errors.iter().map(|e| e.path.as_str()).collect::<Vec<_>>(),
+        [
+            "/globals/Settings/version",
+            "/globals/Unknown",
+            "/properties/counter",
+            "/properties/double",
+            "/properties/missing",
+        ]
+    );
+    assert_eq!(errors[4].to_string(), "/properties/missing: No such property");
+    // The valid values are restored
+    assert_eq!(other.get_property("title").unwrap(), Value::String("Restored".into()));
+    assert_eq!(other.get_property("counter").unwrap(), Value::Number(5.));
+}
+
+#[test]
+fn test_json_migration() {
+    i_slint_backend_testing::init_no_event_loop();
+    let source = r#"
+        export enum Priority { low, high }
+        export struct Task { title: string, priority: Priority, done: bool }
+        export component Main {
+            in property <[Task]> tasks;
+        }
+    "#;
+    let compiler = crate::Compiler::default();
+    let result = spin_on::spin_on(compiler.build_from_source(source.into(), Default::default()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Main").unwrap().create().unwrap();
+
+    // Saved with a version where `title` was called `name`, `done` didn't exist, `color` did,
+    // and `urgent` was a priority
+    let old_state = serde_json::json!({
+        "properties": {
+            "tasks": [
+                { "name": "Write docs", "priority": "Priority.urgent", "color": "#ff0000" },
+                { "name": "Review", "priority": "low", "done": true },
+            ],
+        },
+    });
+    assert!(instance.restore_state(&old_state).is_err());
+
+    let migration = JsonMigration::from_json(&serde_json::json!({
+        "structs": {
+            "Task": {
+                "renamed-fields": { "name": "title" },
+                "removed-fields": ["color"],
+                "default-values": { "done": false },
+            },
+        },
+        "enums": { "Priority": { "renamed-values": { "urgent": "high" } } },
+    }))
+    .unwrap();
+    assert_eq!(restore_state_with_migration(&instance, &old_state, &migration), Ok(()));
+    let expected = serde_json::json!([
+        { "title": "Write docs", "priority": "Priority.high", "done": false },
+        { "title": "Review", "priority": "Priority.low", "done": true },
+    ]);
+    assert_eq!(instance.get_property("tasks").unwrap().to_json().unwrap(), expected);
+
+    let mut same_migration = JsonMigration::default();
+    same_migration.rename_field("Task", "name", "title");
+    same_migration.remove_field("Task", "color");
+    same_migration.set_default_value("Task", "done", false.into());
+    same_migration.rename_enum_value("Priority", "urgent", "high");
+    instance.set_property("tasks", Value::Model(Default::default())).unwrap();
+    assert_eq!(restore_state_with_migration(&instance, &old_state, &same_migration), Ok(()));
+    assert_eq!(instance.get_property("tasks").unwrap().to_json().unwrap(), expected);
+
+    assert!(JsonMigration::from_json(
+        &serde_json::json!({ "structs": { "Task": { "moved": {} } } })
+    )
+    .is_err());
+}
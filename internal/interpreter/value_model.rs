@@ -110,8 +110,11 @@ fn model_tracker(&self) -> &dyn ModelTracker {
 impl BoolModel {
     pub fn set_value(&self, val: bool) {
         let old = self.value.replace(val);
-        if old != val {
-            self.notify.reset();
+        // Notify row changes rather than a reset, so that the instance can play its exit transition
+        match (old, val) {
+            (false, true) => self.notify.row_added(0, 1),
+            (true, false) => self.notify.row_removed(0, 1),
+            _ => {}
         }
     }
 }
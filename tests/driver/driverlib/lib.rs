@@ -22,7 +22,7 @@ pub fn identifier(&self) -> String {
             .replace([std::path::MAIN_SEPARATOR, '-'], "_");
         if let Some(requested_style) = &self.requested_style {
             result.push('_');
-            result.push_str(requested_style);
+            result.push_str(&requested_style.replace(',', "_"));
         }
         result
     }
@@ -91,6 +91,10 @@ pub fn collect_test_cases(sub_folders: &str) -> std::io::Result<Vec<TestCase>> {
                         })
                         .cloned()
                         .collect::<Vec<_>>()
+                } else if let Some(style) =
+                    extract_style(&std::fs::read_to_string(&absolute_path).unwrap())
+                {
+                    vec![&*style.to_string().leak()]
                 } else {
                     vec![""]
                 };
@@ -241,6 +245,24 @@ fn test_extract_ignores() {
     assert_eq!(r, ["cpp", "rust", "nodejs"]);
 }
 
+/// Extract the style from a `//style:` comment, for the test cases that need a specific style
+fn extract_style(source: &str) -> Option<&str> {
+    static RX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"//style:\s*(.+)\s*\n").unwrap());
+    RX.captures(source).map(|mat| mat.get(1).unwrap().as_str().trim())
+}
+
+#[test]
+fn test_extract_style() {
+    assert!(extract_style("something").is_none());
+
+    let source = r"
+    //style: fluent,material
+    Blah {}
+";
+
+    assert_eq!(extract_style(source), Some("fluent,material"));
+}
+
 pub fn extract_cpp_namespace(source: &str) -> Option<String> {
     static RX: LazyLock<Regex> =
         LazyLock::new(|| Regex::new(r"//cpp-namespace:\s*(.+)\s*\n").unwrap());
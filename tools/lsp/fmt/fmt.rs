@@ -147,7 +147,10 @@ fn format_node(
         SyntaxKind::Transition => {
             return format_transition(node, writer, state);
         }
-        SyntaxKind::PropertyAnimation => {
+        SyntaxKind::MediaQuery => {
+            return format_media_query(node, writer, state);
+        }
+        SyntaxKind::PropertyAnimation | SyntaxKind::LayoutAnimation => {
             return format_property_animation(node, writer, state);
         }
         SyntaxKind::ObjectLiteral => {
@@ -159,6 +162,15 @@ fn format_node(
         SyntaxKind::MemberAccess => {
             return format_member_access(node, writer, state);
         }
+        SyntaxKind::StyleSheet => {
+            return format_style_sheet(node, writer, state);
+        }
+        SyntaxKind::StyleRule => {
+            return format_style_rule(node, writer, state);
+        }
+        SyntaxKind::StyleSelector => {
+            return format_style_selector(node, writer, state);
+        }
 
         _ => (),
     }
@@ -1107,6 +1119,39 @@ fn format_transition(
     Ok(())
 }
 
+fn format_media_query(
+    node: &SyntaxNode,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+) -> Result<(), std::io::Error> {
+    let mut sub = node.children_with_tokens();
+    let ok = whitespace_to(&mut sub, SyntaxKind::At, writer, state, "")?
+        && whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, "")?
+        && whitespace_to(&mut sub, SyntaxKind::LParent, writer, state, " ")?
+        && whitespace_to(&mut sub, SyntaxKind::Expression, writer, state, "")?
+        && whitespace_to(&mut sub, SyntaxKind::RParent, writer, state, "")?
+        && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, " ")?;
+
+    if !ok {
+        finish_node(sub, writer, state)?;
+        return Ok(());
+    }
+    state.indentation_level += 1;
+    state.new_line();
+    for n in sub {
+        if n.kind() == SyntaxKind::RBrace {
+            state.indentation_level -= 1;
+            state.whitespace_to_add = None;
+            state.new_line();
+            fold(n, writer, state)?;
+            state.new_line();
+        } else {
+            fold(n, writer, state)?;
+        }
+    }
+    Ok(())
+}
+
 fn format_property_animation(
     node: &SyntaxNode,
     writer: &mut impl TokenWriter,
@@ -1114,7 +1159,8 @@ fn format_property_animation(
 ) -> Result<(), std::io::Error> {
     let mut sub = node.children_with_tokens().peekable();
     let _ok = whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, "")?
-        && whitespace_to(&mut sub, SyntaxKind::QualifiedName, writer, state, " ")?;
+        && (node.kind() == SyntaxKind::LayoutAnimation
+            || whitespace_to(&mut sub, SyntaxKind::QualifiedName, writer, state, " ")?);
 
     loop {
         let next_kind = sub.peek().map(|n| n.kind()).unwrap_or(SyntaxKind::Error);
@@ -1173,6 +1219,109 @@ fn format_property_animation(
     Ok(())
 }
 
+fn format_style_sheet(
+    node: &SyntaxNode,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+) -> Result<(), std::io::Error> {
+    let mut sub = node.children_with_tokens();
+    let ok = whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, "")?
+        && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, " ")?;
+    if !ok {
+        finish_node(sub, writer, state)?;
+        return Ok(());
+    }
+
+    let has_rules = node.child_node(SyntaxKind::StyleRule).is_some();
+    if has_rules {
+        state.indentation_level += 1;
+        state.new_line();
+    }
+    for n in sub {
+        if n.kind() == SyntaxKind::RBrace {
+            state.whitespace_to_add = None;
+            if has_rules {
+                state.indentation_level -= 1;
+                state.new_line();
+            } else {
+                state.insert_whitespace(" ");
+            }
+            fold(n, writer, state)?;
+            state.new_line();
+        } else {
+            fold(n, writer, state)?;
+        }
+    }
+    Ok(())
+}
+
+fn format_style_rule(
+    node: &SyntaxNode,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+) -> Result<(), std::io::Error> {
+    let mut sub = node.children_with_tokens().peekable();
+    whitespace_to(&mut sub, SyntaxKind::StyleSelector, writer, state, "")?;
+    loop {
+        let next_kind = sub.peek().map(|n| n.kind()).unwrap_or(SyntaxKind::Error);
+        match next_kind {
+            SyntaxKind::Whitespace | SyntaxKind::Comment => {
+                let n = sub.next().unwrap();
+                state.skip_all_whitespace = true;
+                fold(n, writer, state)?;
+            }
+            SyntaxKind::Comma => {
+                whitespace_to(&mut sub, SyntaxKind::Comma, writer, state, "")?;
+            }
+            SyntaxKind::StyleSelector => {
+                whitespace_to(&mut sub, SyntaxKind::StyleSelector, writer, state, " ")?;
+            }
+            SyntaxKind::LBrace => {
+                whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, " ")?;
+                break;
+            }
+            _ => break,
+        }
+    }
+
+    let has_bindings = node.child_node(SyntaxKind::Binding).is_some();
+    if has_bindings {
+        state.indentation_level += 1;
+        state.new_line();
+    } else {
+        state.insert_whitespace(" ");
+    }
+    for n in sub {
+        if n.kind() == SyntaxKind::RBrace {
+            state.whitespace_to_add = None;
+            if has_bindings {
+                state.indentation_level -= 1;
+                state.new_line();
+            } else {
+                state.insert_whitespace(" ");
+            }
+            fold(n, writer, state)?;
+            state.new_line();
+        } else {
+            fold(n, writer, state)?;
+        }
+    }
+    Ok(())
+}
+
+fn format_style_selector(
+    node: &SyntaxNode,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+) -> Result<(), std::io::Error> {
+    // A selector is written without whitespace: `Text.title#header`
+    for n in node.children_with_tokens() {
+        state.skip_all_whitespace = true;
+        fold(n, writer, state)?;
+    }
+    Ok(())
+}
+
 fn format_object_literal(
     node: &SyntaxNode,
     writer: &mut impl TokenWriter,
@@ -2062,6 +2211,119 @@ fn multiple_property_animation() {
         );
     }
 
+    #[test]
+    fn layout_animation() {
+        assert_formatting(
+            r#"
+export component MainWindow inherits Rectangle {
+    VerticalLayout {
+        animate-layout   { duration: 170ms; easing: ease-out; }
+        Rectangle { animate-layout{duration: 170ms;} }
+    }
+}
+"#,
+            r#"
+export component MainWindow inherits Rectangle {
+    VerticalLayout {
+        animate-layout {
+            duration: 170ms;
+            easing: ease-out;
+        }
+        Rectangle {
+            animate-layout { duration: 170ms; }
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn enter_exit_transitions() {
+        assert_formatting(
+            r#"
+export component MainWindow inherits Rectangle {
+    for x in model: Rectangle {
+        in { opacity: 0; animate opacity { duration: 170ms; } }
+        out{ inner.x:-10px;}
+        inner := Rectangle {}
+    }
+}
+"#,
+            r#"
+export component MainWindow inherits Rectangle {
+    for x in model: Rectangle {
+        in {
+            opacity: 0;
+            animate opacity { duration: 170ms; }
+        }
+        out {
+            inner.x: -10px;
+        }
+        inner := Rectangle { }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn media_query() {
+        assert_formatting(
+            r#"
+export component MainWindow inherits Window {
+    @media(width<600px&&height>1px){side.width:50px;  t.text : "narrow";}
+    @media  (root.compact) {  t.text:"compact"  ;}
+}
+"#,
+            r#"
+export component MainWindow inherits Window {
+    @media (width < 600px && height > 1px) {
+        side.width: 50px;
+        t.text: "narrow";
+    }
+    @media (root.compact) {
+        t.text: "compact";
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn style_sheet() {
+        assert_formatting(
+            r#"
+style-sheet{Text{color:red;font-size:12px;}
+.title ,#header,Button.primary{ background : blue; }
+  Rectangle{}
+}
+style-sheet {}
+export component MainWindow inherits Rectangle {
+    Text { class: "title"; }
+}
+"#,
+            r#"
+style-sheet {
+    Text {
+        color: red;
+        font-size: 12px;
+    }
+    .title, #header, Button.primary {
+        background: blue;
+    }
+    Rectangle { }
+}
+style-sheet { }
+export component MainWindow inherits Rectangle {
+    Text {
+        class: "title";
+    }
+}
+"#,
+        );
+    }
+
     #[test]
     fn empty_array() {
         assert_formatting(
@@ -48,12 +48,17 @@ pub fn get_semantic_tokens(
                 SyntaxKind::SubElement => Some((self::VARIABLE, 1 << self::DEFINITION)),
                 SyntaxKind::RepeatedElement => Some((self::KEYWORD, 0)),
                 SyntaxKind::RepeatedIndex => Some((self::VARIABLE, 1 << self::DEFINITION)),
+                SyntaxKind::LetStatement
+                | SyntaxKind::ForStatement
+                | SyntaxKind::BreakStatement
+                | SyntaxKind::ContinueStatement => Some((self::KEYWORD, 0)),
                 SyntaxKind::ConditionalElement => Some((self::KEYWORD, 0)),
                 SyntaxKind::CallbackDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::CallbackConnection => Some((self::FUNCTION, 0)),
                 SyntaxKind::PropertyDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::Function => Some((self::KEYWORD, 0)),
                 SyntaxKind::PropertyAnimation => Some((self::KEYWORD, 0)),
+                SyntaxKind::LayoutAnimation => Some((self::KEYWORD, 0)),
                 SyntaxKind::EnumValue => Some((self::ENUM_MEMBER, 1 << self::DEFINITION)),
                 SyntaxKind::QualifiedName => match token.parent().parent()?.kind() {
                     SyntaxKind::Type => Some((self::TYPE, 0)),
@@ -87,10 +92,14 @@ pub fn get_semantic_tokens(
                         SyntaxKind::StructDeclaration => Some((self::TYPE, 1 << self::DEFINITION)),
                         SyntaxKind::EnumDeclaration => Some((self::ENUM, 1 << self::DEFINITION)),
                         SyntaxKind::PropertyChangedCallback => Some((self::PROPERTY, 0)),
+                        SyntaxKind::LetStatement
+                        | SyntaxKind::ForStatement
+                        | SyntaxKind::Lambda => Some((self::VARIABLE, 1 << self::DEFINITION)),
                         _ => None,
                     }
                 }
                 SyntaxKind::ChildrenPlaceholder => Some((self::MACRO, 0)),
+                SyntaxKind::MediaQuery => Some((self::MACRO, 0)),
                 SyntaxKind::Binding | SyntaxKind::TwoWayBinding => Some((self::PROPERTY, 0)),
                 SyntaxKind::ReturnStatement => Some((self::KEYWORD, 0)),
                 SyntaxKind::AtImageUrl => Some((self::MACRO, 0)),
@@ -136,6 +145,15 @@ pub fn get_semantic_tokens(
                 SyntaxKind::StructDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::EnumDeclaration => Some((self::KEYWORD, 0)),
                 SyntaxKind::PropertyChangedCallback => Some((self::KEYWORD, 0)),
+                SyntaxKind::StyleSheet => Some((self::KEYWORD, 0)),
+                // The element type, or a class after a `.`
+                SyntaxKind::StyleSelector => match token.prev_token().map(|t| t.kind()) {
+                    Some(SyntaxKind::Dot) => Some((self::VARIABLE, 0)),
+                    Some(SyntaxKind::Minus | SyntaxKind::Identifier | SyntaxKind::ColorLiteral) => {
+                        None
+                    }
+                    _ => Some((self::TYPE, 0)),
+                },
                 _ => None,
             },
             SyntaxKind::PlusEqual
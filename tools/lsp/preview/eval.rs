@@ -210,6 +210,9 @@ fn eval_expression(
             expression_tree::EasingCurve::CubicBezier(a, b, c, d) => {
                 i_slint_core::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
+            expression_tree::EasingCurve::Spring(k, d, m) => {
+                i_slint_core::animations::EasingCurve::Spring([*k, *d, *m])
+            }
         }),
         Expression::LinearGradient { angle, stops } => {
             let angle = eval_expression(angle, local_context, None);
@@ -81,6 +81,10 @@ struct Cli {
     #[arg(long, value_name = "json file", action)]
     save_data: Option<std::path::PathBuf>,
 
+    /// Store the JSON Schema of the component's properties, callbacks and globals in a json file ('-' for stdout), and exit
+    #[arg(long, value_name = "json file", action)]
+    save_schema: Option<std::path::PathBuf>,
+
     /// Specify callbacks handler.
     /// The first argument is the callback name, and the second argument is a string that is going
     /// to be passed to the shell to be executed. Occurrences of `$1` will be replaced by the first argument,
@@ -142,6 +146,19 @@ fn main() -> Result<()> {
         std::process::exit(-1);
     };
 
+    if let Some(schema_path) = args.save_schema {
+        let schema = c.json_schema();
+        if schema_path == std::path::Path::new("-") {
+            serde_json::to_writer_pretty(std::io::stdout(), &schema)?;
+        } else {
+            serde_json::to_writer_pretty(
+                BufWriter::new(std::fs::File::create(schema_path)?),
+                &schema,
+            )?;
+        }
+        return Ok(());
+    }
+
     let component = c.create()?;
     init_dialog(&component);
 